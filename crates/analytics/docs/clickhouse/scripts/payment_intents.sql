@@ -26,6 +26,7 @@ CREATE TABLE payment_intents_queue
     `organization_id` String,
     `processor_merchant_id` Nullable(String),
     `created_by` Nullable(String),
+    `attribution_tags` Nullable(String),
     `sign_flag` Int8
 ) ENGINE = Kafka SETTINGS kafka_broker_list = 'kafka0:29092',
 kafka_topic_list = 'hyperswitch-payment-intent-events',
@@ -62,6 +63,7 @@ CREATE TABLE payment_intents
     `organization_id` String,
     `processor_merchant_id` Nullable(String),
     `created_by` Nullable(String),
+    `attribution_tags` Nullable(String),
     `sign_flag` Int8,
     INDEX connectorIndex connector_id TYPE bloom_filter GRANULARITY 1,
     INDEX currencyIndex currency TYPE bloom_filter GRANULARITY 1,
@@ -102,6 +104,7 @@ CREATE MATERIALIZED VIEW payment_intents_mv TO payment_intents
     `organization_id` String,
     `processor_merchant_id` Nullable(String),
     `created_by` Nullable(String),
+    `attribution_tags` Nullable(String),
     `sign_flag` Int8
 ) AS
 SELECT
@@ -132,5 +135,6 @@ SELECT
     organization_id,
     processor_merchant_id,
     created_by,
+    attribution_tags,
     sign_flag
 FROM payment_intents_queue;
\ No newline at end of file
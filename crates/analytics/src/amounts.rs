@@ -0,0 +1,128 @@
+use std::collections::HashMap;
+
+use common_enums::Currency;
+use common_utils::types::{AmountConvertor, MinorUnit, StringMajorUnit, StringMajorUnitForCore};
+use error_stack::report;
+use router_env::logger;
+
+use crate::errors::{AnalyticsError, AnalyticsResult};
+
+/// Totals of amounts in minor units, partitioned by currency.
+///
+/// Amounts in different currencies have different exponents (`1000` is ¥1000 but $10.00), so they
+/// are never summed up together. Amounts converted to a reporting currency using the forex rates
+/// should be used for totals across currencies instead.
+#[derive(Debug, Default)]
+pub(crate) struct CurrencyPartitionedTotal(HashMap<Option<Currency>, u64>);
+
+impl CurrencyPartitionedTotal {
+    pub(crate) fn add(&mut self, currency: Option<Currency>, amount: u64) {
+        *self.0.entry(currency).or_default() += amount;
+    }
+
+    /// Returns the total of the amounts, if all of them are in the same currency.
+    ///
+    /// Totals across currencies are rejected, unless the amounts are also being converted to a
+    /// reporting currency, in which case only the converted total is reported.
+    pub(crate) fn get_single_currency_total(
+        &self,
+        is_converted_total_requested: bool,
+    ) -> AnalyticsResult<Option<u64>> {
+        let mut totals = self.0.values();
+        match (totals.next(), totals.next()) {
+            (None, _) => Ok(Some(0)),
+            (Some(total), None) => Ok(Some(*total)),
+            (Some(_), Some(_)) if is_converted_total_requested => Ok(None),
+            (Some(_), Some(_)) => Err(report!(AnalyticsError::CrossCurrencyTotal)
+                .attach_printable(format!(
+                    "Currencies: {:?}",
+                    self.0.keys().collect::<Vec<_>>()
+                ))),
+        }
+    }
+}
+
+/// Formats an amount in minor units as a decimal amount in the major unit of the currency
+pub(crate) fn get_decimal_amount(
+    amount: u64,
+    currency: Option<Currency>,
+) -> Option<StringMajorUnit> {
+    let currency = currency?;
+    i64::try_from(amount)
+        .inspect_err(|e| logger::error!("Amount conversion error: {:?}", e))
+        .ok()
+        .and_then(|amount_i64| {
+            StringMajorUnitForCore
+                .convert(MinorUnit::new(amount_i64), currency)
+                .inspect_err(|e| logger::error!("Amount formatting error: {:?}", e))
+                .ok()
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_single_currency_total() {
+        let mut total = CurrencyPartitionedTotal::default();
+        total.add(Some(Currency::USD), 1000);
+        total.add(Some(Currency::USD), 250);
+        assert_eq!(
+            total.get_single_currency_total(false).ok(),
+            Some(Some(1250))
+        );
+    }
+
+    #[test]
+    fn test_cross_currency_total_is_rejected() {
+        let mut total = CurrencyPartitionedTotal::default();
+        total.add(Some(Currency::USD), 1000);
+        total.add(Some(Currency::JPY), 1000);
+        let error = total
+            .get_single_currency_total(false)
+            .expect_err("cross currency total should be rejected");
+        assert!(matches!(
+            error.current_context(),
+            AnalyticsError::CrossCurrencyTotal
+        ));
+    }
+
+    #[test]
+    fn test_cross_currency_total_is_omitted_with_converted_total() {
+        let mut total = CurrencyPartitionedTotal::default();
+        total.add(Some(Currency::USD), 1000);
+        total.add(Some(Currency::JPY), 1000);
+        assert_eq!(total.get_single_currency_total(true).ok(), Some(None));
+    }
+
+    #[test]
+    fn test_empty_total() {
+        assert_eq!(
+            CurrencyPartitionedTotal::default()
+                .get_single_currency_total(false)
+                .ok(),
+            Some(Some(0))
+        );
+    }
+
+    #[test]
+    fn test_decimal_amount_uses_currency_exponent() {
+        let decimal_amount = |amount, currency| {
+            get_decimal_amount(amount, currency).map(|amount| amount.get_amount_as_string())
+        };
+        assert_eq!(
+            decimal_amount(1000, Some(Currency::USD)),
+            Some("10.00".to_string())
+        );
+        assert_eq!(
+            decimal_amount(1000, Some(Currency::JPY)),
+            Some("1000".to_string())
+        );
+        assert_eq!(
+            decimal_amount(1000, Some(Currency::KWD)),
+            Some("1.000".to_string())
+        );
+        assert_eq!(decimal_amount(1000, None), None);
+    }
+}
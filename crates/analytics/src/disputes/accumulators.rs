@@ -93,7 +93,9 @@ impl DisputeMetricsAccumulator {
             disputes_won: won_rate,
             disputes_lost: lost_rate,
             disputed_amount: self.disputed_amount.collect(),
+            disputed_amount_decimal: None,
             dispute_lost_amount: self.dispute_lost_amount.collect(),
+            dispute_lost_amount_decimal: None,
             total_dispute,
         }
     }
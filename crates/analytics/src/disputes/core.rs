@@ -19,6 +19,7 @@ use super::{
     DisputeMetricsAccumulator,
 };
 use crate::{
+    amounts::{get_decimal_amount, CurrencyPartitionedTotal},
     disputes::DisputeMetricAccumulator,
     enums::AuthInfo,
     errors::{AnalyticsError, AnalyticsResult},
@@ -107,17 +108,20 @@ pub async fn get_metrics(
             metrics_accumulator
         );
     }
-    let mut total_disputed_amount = 0;
-    let mut total_dispute_lost_amount = 0;
+    let mut total_disputed_amount = CurrencyPartitionedTotal::default();
+    let mut total_dispute_lost_amount = CurrencyPartitionedTotal::default();
     let query_data: Vec<DisputeMetricsBucketResponse> = metrics_accumulator
         .into_iter()
         .map(|(id, val)| {
-            let collected_values = val.collect();
+            let mut collected_values = val.collect();
             if let Some(amount) = collected_values.disputed_amount {
-                total_disputed_amount += amount;
+                collected_values.disputed_amount_decimal = get_decimal_amount(amount, id.currency);
+                total_disputed_amount.add(id.currency, amount);
             }
             if let Some(amount) = collected_values.dispute_lost_amount {
-                total_dispute_lost_amount += amount;
+                collected_values.dispute_lost_amount_decimal =
+                    get_decimal_amount(amount, id.currency);
+                total_dispute_lost_amount.add(id.currency, amount);
             }
 
             DisputeMetricsBucketResponse {
@@ -130,8 +134,9 @@ pub async fn get_metrics(
     Ok(DisputesMetricsResponse {
         query_data,
         meta_data: [DisputesAnalyticsMetadata {
-            total_disputed_amount: Some(total_disputed_amount),
-            total_dispute_lost_amount: Some(total_dispute_lost_amount),
+            total_disputed_amount: total_disputed_amount.get_single_currency_total(false)?,
+            total_dispute_lost_amount: total_dispute_lost_amount
+                .get_single_currency_total(false)?,
         }],
     })
 }
@@ -18,6 +18,8 @@ pub enum AnalyticsError {
     MissingEmail,
     #[error("Invalid URL scheme: {0}")]
     InvalidReturnUrl(String),
+    #[error("Amounts in different currencies cannot be totalled without a currency conversion")]
+    CrossCurrencyTotal,
 }
 
 impl ErrorSwitch<ApiErrorResponse> for AnalyticsError {
@@ -56,6 +58,12 @@ impl ErrorSwitch<ApiErrorResponse> for AnalyticsError {
                 format!("Invalid return URL: {invalid_url_err}"),
                 None,
             )),
+            Self::CrossCurrencyTotal => ApiErrorResponse::BadRequest(ApiError::new(
+                "IR",
+                6,
+                "Amounts in different currencies cannot be totalled without a currency conversion",
+                None,
+            )),
         }
     }
 }
@@ -1,4 +1,5 @@
 pub mod active_payments;
+mod amounts;
 pub mod api_event;
 pub mod auth_events;
 mod clickhouse;
@@ -339,8 +339,10 @@ impl PaymentIntentMetricsAccumulator {
             total_smart_retries: self.total_smart_retries.collect(),
             smart_retried_amount,
             smart_retried_amount_in_usd,
+            smart_retried_amount_decimal: None,
             smart_retried_amount_without_smart_retries,
             smart_retried_amount_without_smart_retries_in_usd,
+            smart_retried_amount_without_smart_retries_decimal: None,
             payment_intent_count: self.payment_intent_count.collect(),
             successful_payments,
             successful_payments_without_smart_retries,
@@ -354,7 +356,9 @@ impl PaymentIntentMetricsAccumulator {
             payments_success_rate_distribution_without_smart_retries,
             payments_failure_rate_distribution_without_smart_retries,
             payment_processed_amount_in_usd,
+            payment_processed_amount_decimal: None,
             payment_processed_amount_without_smart_retries_in_usd,
+            payment_processed_amount_without_smart_retries_decimal: None,
         }
     }
 }
@@ -26,6 +26,7 @@ use super::{
     PaymentIntentMetricsAccumulator,
 };
 use crate::{
+    amounts::{get_decimal_amount, CurrencyPartitionedTotal},
     enums::AuthInfo,
     errors::{AnalyticsError, AnalyticsResult},
     metrics,
@@ -175,15 +176,16 @@ pub async fn get_metrics(
 
     let mut success = 0;
     let mut success_without_smart_retries = 0;
-    let mut total_smart_retried_amount = 0;
+    let mut total_smart_retried_amount = CurrencyPartitionedTotal::default();
     let mut total_smart_retried_amount_in_usd = 0;
-    let mut total_smart_retried_amount_without_smart_retries = 0;
+    let mut total_smart_retried_amount_without_smart_retries = CurrencyPartitionedTotal::default();
     let mut total_smart_retried_amount_without_smart_retries_in_usd = 0;
     let mut total = 0;
-    let mut total_payment_processed_amount = 0;
+    let mut total_payment_processed_amount = CurrencyPartitionedTotal::default();
     let mut total_payment_processed_amount_in_usd = 0;
     let mut total_payment_processed_count = 0;
-    let mut total_payment_processed_amount_without_smart_retries = 0;
+    let mut total_payment_processed_amount_without_smart_retries =
+        CurrencyPartitionedTotal::default();
     let mut total_payment_processed_amount_without_smart_retries_in_usd = 0;
     let mut total_payment_processed_count_without_smart_retries = 0;
     let query_data: Vec<MetricsBucketResponse> = metrics_accumulator
@@ -221,7 +223,9 @@ pub async fn get_metrics(
                     None
                 };
                 collected_values.smart_retried_amount_in_usd = amount_in_usd;
-                total_smart_retried_amount += retried_amount;
+                collected_values.smart_retried_amount_decimal =
+                    get_decimal_amount(retried_amount, id.currency);
+                total_smart_retried_amount.add(id.currency, retried_amount);
                 total_smart_retried_amount_in_usd += amount_in_usd.unwrap_or(0);
             }
             if let Some(retried_amount) =
@@ -247,7 +251,9 @@ pub async fn get_metrics(
                     None
                 };
                 collected_values.smart_retried_amount_without_smart_retries_in_usd = amount_in_usd;
-                total_smart_retried_amount_without_smart_retries += retried_amount;
+                collected_values.smart_retried_amount_without_smart_retries_decimal =
+                    get_decimal_amount(retried_amount, id.currency);
+                total_smart_retried_amount_without_smart_retries.add(id.currency, retried_amount);
                 total_smart_retried_amount_without_smart_retries_in_usd +=
                     amount_in_usd.unwrap_or(0);
             }
@@ -272,8 +278,10 @@ pub async fn get_metrics(
                     None
                 };
                 collected_values.payment_processed_amount_in_usd = amount_in_usd;
+                collected_values.payment_processed_amount_decimal =
+                    get_decimal_amount(amount, id.currency);
                 total_payment_processed_amount_in_usd += amount_in_usd.unwrap_or(0);
-                total_payment_processed_amount += amount;
+                total_payment_processed_amount.add(id.currency, amount);
             }
             if let Some(count) = collected_values.payment_processed_count {
                 total_payment_processed_count += count;
@@ -300,9 +308,11 @@ pub async fn get_metrics(
                 };
                 collected_values.payment_processed_amount_without_smart_retries_in_usd =
                     amount_in_usd;
+                collected_values.payment_processed_amount_without_smart_retries_decimal =
+                    get_decimal_amount(amount, id.currency);
                 total_payment_processed_amount_without_smart_retries_in_usd +=
                     amount_in_usd.unwrap_or(0);
-                total_payment_processed_amount_without_smart_retries += amount;
+                total_payment_processed_amount_without_smart_retries.add(id.currency, amount);
             }
             if let Some(count) = collected_values.payment_processed_count_without_smart_retries {
                 total_payment_processed_count_without_smart_retries += count;
@@ -326,14 +336,16 @@ pub async fn get_metrics(
         meta_data: [PaymentIntentsAnalyticsMetadata {
             total_success_rate,
             total_success_rate_without_smart_retries,
-            total_smart_retried_amount: Some(total_smart_retried_amount),
-            total_smart_retried_amount_without_smart_retries: Some(
-                total_smart_retried_amount_without_smart_retries,
-            ),
-            total_payment_processed_amount: Some(total_payment_processed_amount),
-            total_payment_processed_amount_without_smart_retries: Some(
-                total_payment_processed_amount_without_smart_retries,
-            ),
+            total_smart_retried_amount: total_smart_retried_amount
+                .get_single_currency_total(ex_rates.is_some())?,
+            total_smart_retried_amount_without_smart_retries:
+                total_smart_retried_amount_without_smart_retries
+                    .get_single_currency_total(ex_rates.is_some())?,
+            total_payment_processed_amount: total_payment_processed_amount
+                .get_single_currency_total(ex_rates.is_some())?,
+            total_payment_processed_amount_without_smart_retries:
+                total_payment_processed_amount_without_smart_retries
+                    .get_single_currency_total(ex_rates.is_some())?,
             total_smart_retried_amount_in_usd: if ex_rates.is_some() {
                 Some(total_smart_retried_amount_in_usd)
             } else {
@@ -423,6 +435,7 @@ pub async fn get_filters(
             PaymentIntentDimensions::CardLast4 => fil.card_last_4,
             PaymentIntentDimensions::CardIssuer => fil.card_issuer,
             PaymentIntentDimensions::ErrorReason => fil.error_reason,
+            PaymentIntentDimensions::AttributionTags => fil.attribution_tags,
         })
         .collect::<Vec<String>>();
         res.query_data.push(PaymentIntentFilterValue {
@@ -62,5 +62,6 @@ pub struct PaymentIntentFilterRow {
     pub card_last_4: Option<String>,
     pub card_issuer: Option<String>,
     pub error_reason: Option<String>,
+    pub attribution_tags: Option<String>,
     pub customer_id: Option<String>,
 }
@@ -92,6 +92,14 @@ where
                 .add_filter_in_range_clause("customer_id", &self.customer_id)
                 .attach_printable("Error adding customer id filter")?;
         }
+        if !self.attribution_tags.is_empty() {
+            builder
+                .add_filter_in_range_clause(
+                    PaymentIntentDimensions::AttributionTags,
+                    &self.attribution_tags,
+                )
+                .attach_printable("Error adding attribution tags filter")?;
+        }
         Ok(())
     }
 }
@@ -3,6 +3,7 @@ mod core;
 pub mod distribution;
 pub mod filters;
 pub mod metrics;
+pub mod report;
 pub mod types;
 pub use accumulator::{
     PaymentDistributionAccumulator, PaymentMetricAccumulator, PaymentMetricsAccumulator,
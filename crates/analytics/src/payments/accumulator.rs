@@ -493,7 +493,9 @@ impl PaymentMetricsAccumulator {
             failure_reason_count,
             failure_reason_count_without_smart_retries,
             payment_processed_amount_in_usd,
+            payment_processed_amount_decimal: None,
             payment_processed_amount_without_smart_retries_usd,
+            payment_processed_amount_without_smart_retries_decimal: None,
             debit_routed_transaction_count,
             debit_routing_savings,
             debit_routing_savings_in_usd,
@@ -26,6 +26,7 @@ use super::{
     PaymentMetricsAccumulator,
 };
 use crate::{
+    amounts::{get_decimal_amount, CurrencyPartitionedTotal},
     enums::AuthInfo,
     errors::{AnalyticsError, AnalyticsResult},
     metrics,
@@ -224,9 +225,10 @@ pub async fn get_metrics(
             }
         }
     }
-    let mut total_payment_processed_amount = 0;
+    let mut total_payment_processed_amount = CurrencyPartitionedTotal::default();
     let mut total_payment_processed_count = 0;
-    let mut total_payment_processed_amount_without_smart_retries = 0;
+    let mut total_payment_processed_amount_without_smart_retries =
+        CurrencyPartitionedTotal::default();
     let mut total_payment_processed_count_without_smart_retries = 0;
     let mut total_failure_reasons_count = 0;
     let mut total_failure_reasons_count_without_smart_retries = 0;
@@ -257,7 +259,9 @@ pub async fn get_metrics(
                     None
                 };
                 collected_values.payment_processed_amount_in_usd = amount_in_usd;
-                total_payment_processed_amount += amount;
+                collected_values.payment_processed_amount_decimal =
+                    get_decimal_amount(amount, id.currency);
+                total_payment_processed_amount.add(id.currency, amount);
                 total_payment_processed_amount_in_usd += amount_in_usd.unwrap_or(0);
             }
             if let Some(count) = collected_values.payment_processed_count {
@@ -284,7 +288,9 @@ pub async fn get_metrics(
                     None
                 };
                 collected_values.payment_processed_amount_without_smart_retries_usd = amount_in_usd;
-                total_payment_processed_amount_without_smart_retries += amount;
+                collected_values.payment_processed_amount_without_smart_retries_decimal =
+                    get_decimal_amount(amount, id.currency);
+                total_payment_processed_amount_without_smart_retries.add(id.currency, amount);
                 total_payment_processed_amount_without_smart_retries_usd +=
                     amount_in_usd.unwrap_or(0);
             }
@@ -333,15 +339,16 @@ pub async fn get_metrics(
     Ok(PaymentsMetricsResponse {
         query_data,
         meta_data: [PaymentsAnalyticsMetadata {
-            total_payment_processed_amount: Some(total_payment_processed_amount),
+            total_payment_processed_amount: total_payment_processed_amount
+                .get_single_currency_total(ex_rates.is_some())?,
             total_payment_processed_amount_in_usd: if ex_rates.is_some() {
                 Some(total_payment_processed_amount_in_usd)
             } else {
                 None
             },
-            total_payment_processed_amount_without_smart_retries: Some(
-                total_payment_processed_amount_without_smart_retries,
-            ),
+            total_payment_processed_amount_without_smart_retries:
+                total_payment_processed_amount_without_smart_retries
+                    .get_single_currency_total(ex_rates.is_some())?,
             total_payment_processed_amount_without_smart_retries_usd: if ex_rates.is_some() {
                 Some(total_payment_processed_amount_without_smart_retries_usd)
             } else {
@@ -0,0 +1,194 @@
+//! Rendering of payment reports.
+
+use std::str::FromStr;
+
+use api_models::analytics::payments::PaymentReportColumn;
+use common_enums::Currency;
+use error_stack::ResultExt;
+use serde_json::{Map, Value};
+use strum::IntoEnumIterator;
+
+use crate::{
+    amounts::get_decimal_amount,
+    errors::{AnalyticsError, AnalyticsResult},
+};
+
+/// A payment in the report, with the values of the columns of the payment keyed by column name
+pub type PaymentReportRow = Map<String, Value>;
+
+/// Renders the payments as a CSV report with the requested columns, all the columns are included
+/// if none are requested
+pub fn render_payment_report(
+    columns: Option<&[PaymentReportColumn]>,
+    rows: &[PaymentReportRow],
+) -> AnalyticsResult<String> {
+    let columns = columns
+        .map(<[_]>::to_vec)
+        .unwrap_or_else(|| PaymentReportColumn::iter().collect());
+
+    let mut report = render_csv_record(columns.iter().map(ToString::to_string));
+    for row in rows {
+        let values = columns
+            .iter()
+            .map(|column| get_column_value(*column, row))
+            .collect::<AnalyticsResult<Vec<_>>>()?;
+        report.push_str(&render_csv_record(values));
+    }
+    Ok(report)
+}
+
+fn get_column_value(
+    column: PaymentReportColumn,
+    row: &PaymentReportRow,
+) -> AnalyticsResult<String> {
+    match column {
+        PaymentReportColumn::AmountDecimal => {
+            get_decimal_column_value(PaymentReportColumn::Amount, row)
+        }
+        PaymentReportColumn::AmountToCaptureDecimal => {
+            get_decimal_column_value(PaymentReportColumn::AmountToCapture, row)
+        }
+        column => Ok(row
+            .get(column.as_ref())
+            .map(render_value)
+            .unwrap_or_default()),
+    }
+}
+
+/// Formats the minor unit amount of the column in the major unit of the currency of the payment
+fn get_decimal_column_value(
+    amount_column: PaymentReportColumn,
+    row: &PaymentReportRow,
+) -> AnalyticsResult<String> {
+    let Some(amount) = row.get(amount_column.as_ref()).and_then(Value::as_u64) else {
+        return Ok(String::new());
+    };
+    let currency = row
+        .get(PaymentReportColumn::Currency.as_ref())
+        .and_then(Value::as_str)
+        .map(Currency::from_str)
+        .transpose()
+        .change_context(AnalyticsError::UnknownError)
+        .attach_printable("Invalid currency in payment report row")?;
+
+    Ok(get_decimal_amount(amount, currency)
+        .map(|amount| amount.get_amount_as_string())
+        .unwrap_or_default())
+}
+
+fn render_value(value: &Value) -> String {
+    match value {
+        Value::Null => String::new(),
+        Value::String(value) => value.clone(),
+        value => value.to_string(),
+    }
+}
+
+fn render_csv_record(values: impl IntoIterator<Item = String>) -> String {
+    let mut record = values
+        .into_iter()
+        .map(|value| {
+            if value.contains([',', '"', '\n', '\r']) {
+                format!("\"{}\"", value.replace('"', "\"\""))
+            } else {
+                value
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(",");
+    record.push('\n');
+    record
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn row(value: Value) -> PaymentReportRow {
+        match value {
+            Value::Object(row) => row,
+            _ => panic!("payment report row should be an object"),
+        }
+    }
+
+    #[test]
+    fn test_render_payment_report_with_decimal_amounts() {
+        let rows = [
+            row(json!({
+                "payment_id": "pay_usd",
+                "amount": 1000,
+                "amount_to_capture": 750,
+                "currency": "USD",
+                "metadata": "{\"order\":\"a,b\"}",
+            })),
+            row(json!({
+                "payment_id": "pay_jpy",
+                "amount": 1000,
+                "amount_to_capture": null,
+                "currency": "JPY",
+                "metadata": null,
+            })),
+            row(json!({
+                "payment_id": "pay_kwd",
+                "amount": 1500,
+                "amount_to_capture": 1500,
+                "currency": "KWD",
+            })),
+        ];
+        let columns = [
+            PaymentReportColumn::PaymentId,
+            PaymentReportColumn::Amount,
+            PaymentReportColumn::Currency,
+            PaymentReportColumn::AmountDecimal,
+            PaymentReportColumn::AmountToCaptureDecimal,
+            PaymentReportColumn::Metadata,
+        ];
+
+        let report = render_payment_report(Some(&columns), &rows).expect("report should render");
+
+        assert_eq!(
+            report,
+            "payment_id,amount,currency,amount_decimal,amount_to_capture_decimal,metadata\n\
+             pay_usd,1000,USD,10.00,7.50,\"{\"\"order\"\":\"\"a,b\"\"}\"\n\
+             pay_jpy,1000,JPY,1000,,\n\
+             pay_kwd,1500,KWD,1.500,1.500,\n"
+        );
+    }
+
+    #[test]
+    fn test_render_payment_report_with_all_columns() {
+        let rows = [row(
+            json!({ "payment_id": "pay_usd", "amount": 1000, "currency": "USD" }),
+        )];
+
+        let report = render_payment_report(None, &rows).expect("report should render");
+        let mut lines = report.lines();
+        let header = lines.next().expect("report should have a header");
+        let record = lines.next().expect("report should have a record");
+
+        let columns = header.split(',').collect::<Vec<_>>();
+        let values = record.split(',').collect::<Vec<_>>();
+        assert_eq!(columns.len(), PaymentReportColumn::iter().count());
+        assert_eq!(values.len(), columns.len());
+        let value_of = |column: &str| {
+            columns
+                .iter()
+                .zip(values.iter())
+                .find_map(|(name, value)| (*name == column).then_some(*value))
+        };
+        assert_eq!(value_of("payment_id"), Some("pay_usd"));
+        assert_eq!(value_of("amount_decimal"), Some("10.00"));
+        assert_eq!(value_of("amount_to_capture_decimal"), Some(""));
+    }
+
+    #[test]
+    fn test_render_payment_report_rejects_invalid_currency() {
+        let rows = [row(
+            json!({ "payment_id": "pay_xyz", "amount": 1000, "currency": "XYZ" }),
+        )];
+
+        assert!(render_payment_report(Some(&[PaymentReportColumn::AmountDecimal]), &rows).is_err());
+    }
+}
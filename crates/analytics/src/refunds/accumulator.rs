@@ -267,6 +267,7 @@ impl RefundMetricsAccumulator {
             refund_success_count: self.refund_success.collect(),
             refund_processed_amount,
             refund_processed_amount_in_usd,
+            refund_processed_amount_decimal: None,
             refund_processed_count,
             refund_reason_distribution: self.refund_reason_distribution.collect(),
             refund_error_message_distribution: self.refund_error_message_distribution.collect(),
@@ -26,6 +26,7 @@ use super::{
     RefundMetricsAccumulator,
 };
 use crate::{
+    amounts::{get_decimal_amount, CurrencyPartitionedTotal},
     enums::AuthInfo,
     errors::{AnalyticsError, AnalyticsResult},
     metrics,
@@ -201,7 +202,7 @@ pub async fn get_metrics(
 
     let mut success = 0;
     let mut total = 0;
-    let mut total_refund_processed_amount = 0;
+    let mut total_refund_processed_amount = CurrencyPartitionedTotal::default();
     let mut total_refund_processed_amount_in_usd = 0;
     let mut total_refund_processed_count = 0;
     let mut total_refund_reason_count = 0;
@@ -237,7 +238,9 @@ pub async fn get_metrics(
                     None
                 };
                 collected_values.refund_processed_amount_in_usd = amount_in_usd;
-                total_refund_processed_amount += amount;
+                collected_values.refund_processed_amount_decimal =
+                    get_decimal_amount(amount, id.currency);
+                total_refund_processed_amount.add(id.currency, amount);
                 total_refund_processed_amount_in_usd += amount_in_usd.unwrap_or(0);
             }
             if let Some(count) = collected_values.refund_processed_count {
@@ -263,7 +266,8 @@ pub async fn get_metrics(
         query_data,
         meta_data: [RefundsAnalyticsMetadata {
             total_refund_success_rate,
-            total_refund_processed_amount: Some(total_refund_processed_amount),
+            total_refund_processed_amount: total_refund_processed_amount
+                .get_single_currency_total(ex_rates.is_some())?,
             total_refund_processed_amount_in_usd: if ex_rates.is_some() {
                 Some(total_refund_processed_amount_in_usd)
             } else {
@@ -347,6 +351,7 @@ pub async fn get_filters(
             RefundDimensions::Connector => fil.connector,
             RefundDimensions::RefundType => fil.refund_type.map(|i| i.as_ref().to_string()),
             RefundDimensions::ProfileId => fil.profile_id,
+            RefundDimensions::MerchantId => fil.merchant_id,
             RefundDimensions::RefundReason => fil.refund_reason,
             RefundDimensions::RefundErrorMessage => fil.refund_error_message,
         })
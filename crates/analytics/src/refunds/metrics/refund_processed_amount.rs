@@ -107,6 +107,7 @@ where
                         i.connector.clone(),
                         i.refund_type.as_ref().map(|i| i.0.to_string()),
                         i.profile_id.clone(),
+                        i.merchant_id.clone(),
                         i.refund_reason.clone(),
                         i.refund_error_message.clone(),
                         TimeRange {
@@ -42,6 +42,12 @@ where
                 .attach_printable("Error adding profile id filter")?;
         }
 
+        if !self.merchant_id.is_empty() {
+            builder
+                .add_filter_in_range_clause(RefundDimensions::MerchantId, &self.merchant_id)
+                .attach_printable("Error adding merchant id filter")?;
+        }
+
         if !self.refund_reason.is_empty() {
             builder
                 .add_filter_in_range_clause(RefundDimensions::RefundReason, &self.refund_reason)
@@ -583,6 +583,10 @@ impl<'a> FromRow<'a, PgRow> for super::refunds::metrics::RefundMetricRow {
             ColumnNotFound(_) => Ok(Default::default()),
             e => Err(e),
         })?;
+        let merchant_id: Option<String> = row.try_get("merchant_id").or_else(|e| match e {
+            ColumnNotFound(_) => Ok(Default::default()),
+            e => Err(e),
+        })?;
         let refund_reason: Option<String> = row.try_get("refund_reason").or_else(|e| match e {
             ColumnNotFound(_) => Ok(Default::default()),
             e => Err(e),
@@ -613,6 +617,7 @@ impl<'a> FromRow<'a, PgRow> for super::refunds::metrics::RefundMetricRow {
             connector,
             refund_type,
             profile_id,
+            merchant_id,
             refund_reason,
             refund_error_message,
             total,
@@ -1101,6 +1106,13 @@ impl<'a> FromRow<'a, PgRow> for super::payment_intents::metrics::PaymentIntentMe
             ColumnNotFound(_) => Ok(Default::default()),
             e => Err(e),
         })?;
+        let attribution_tags: Option<String> = row
+            .try_get::<Option<sqlx::types::Json<serde_json::Value>>, _>("attribution_tags")
+            .map(|tags| tags.map(|tags| tags.0.to_string()))
+            .or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
         let total: Option<bigdecimal::BigDecimal> = row.try_get("total").or_else(|e| match e {
             ColumnNotFound(_) => Ok(Default::default()),
             e => Err(e),
@@ -1133,6 +1145,7 @@ impl<'a> FromRow<'a, PgRow> for super::payment_intents::metrics::PaymentIntentMe
             card_last_4,
             card_issuer,
             error_reason,
+            attribution_tags,
             first_attempt,
             total,
             count,
@@ -1197,6 +1210,13 @@ impl<'a> FromRow<'a, PgRow> for super::payment_intents::filters::PaymentIntentFi
             ColumnNotFound(_) => Ok(Default::default()),
             e => Err(e),
         })?;
+        let attribution_tags: Option<String> = row
+            .try_get::<Option<sqlx::types::Json<serde_json::Value>>, _>("attribution_tags")
+            .map(|tags| tags.map(|tags| tags.0.to_string()))
+            .or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
         let customer_id: Option<String> = row.try_get("customer_id").or_else(|e| match e {
             ColumnNotFound(_) => Ok(Default::default()),
             e => Err(e),
@@ -1214,6 +1234,7 @@ impl<'a> FromRow<'a, PgRow> for super::payment_intents::filters::PaymentIntentFi
             card_last_4,
             card_issuer,
             error_reason,
+            attribution_tags,
             customer_id,
         })
     }
@@ -1244,6 +1265,10 @@ impl<'a> FromRow<'a, PgRow> for super::refunds::filters::RefundFilterRow {
             ColumnNotFound(_) => Ok(Default::default()),
             e => Err(e),
         })?;
+        let merchant_id: Option<String> = row.try_get("merchant_id").or_else(|e| match e {
+            ColumnNotFound(_) => Ok(Default::default()),
+            e => Err(e),
+        })?;
         let refund_reason: Option<String> = row.try_get("refund_reason").or_else(|e| match e {
             ColumnNotFound(_) => Ok(Default::default()),
             e => Err(e),
@@ -1259,6 +1284,7 @@ impl<'a> FromRow<'a, PgRow> for super::refunds::filters::RefundFilterRow {
             connector,
             refund_type,
             profile_id,
+            merchant_id,
             refund_reason,
             refund_error_message,
         })
@@ -1290,6 +1316,10 @@ impl<'a> FromRow<'a, PgRow> for super::refunds::distribution::RefundDistribution
             ColumnNotFound(_) => Ok(Default::default()),
             e => Err(e),
         })?;
+        let merchant_id: Option<String> = row.try_get("merchant_id").or_else(|e| match e {
+            ColumnNotFound(_) => Ok(Default::default()),
+            e => Err(e),
+        })?;
         let total: Option<bigdecimal::BigDecimal> = row.try_get("total").or_else(|e| match e {
             ColumnNotFound(_) => Ok(Default::default()),
             e => Err(e),
@@ -1320,6 +1350,7 @@ impl<'a> FromRow<'a, PgRow> for super::refunds::distribution::RefundDistribution
             connector,
             refund_type,
             profile_id,
+            merchant_id,
             total,
             count,
             refund_reason,
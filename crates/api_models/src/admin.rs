@@ -2427,6 +2427,17 @@ pub struct ProfileCreate {
     /// The surcharge connector details for calculating external surcharge
     pub surcharge_connector_details: Option<SurchargeConnectorDetails>,
 
+    /// Total time budget in seconds for confirming a payment, including auto retries and 3DS step-ups.
+    /// Once the budget is used up, no further connector calls are initiated and the payment is
+    /// returned as processing, for its outcome to be polled
+    #[schema(example = 30)]
+    pub confirm_time_budget_in_secs: Option<u32>,
+
+    /// Time zone of the profile, as a name in the IANA time zone database. The periods of the
+    /// quota of the merchant start at midnight in the time zone of its default profile
+    #[schema(example = "Asia/Kolkata")]
+    pub time_zone: Option<String>,
+
     /// Flag to enable Level 2 and Level 3 processing data for card transactions
     #[schema(value_type = Option<bool>)]
     pub is_l2_l3_enabled: Option<bool>,
@@ -2602,6 +2613,17 @@ pub struct ProfileCreate {
     /// The surcharge connector details for calculating external surcharge
     pub surcharge_connector_details: Option<SurchargeConnectorDetails>,
 
+    /// Total time budget in seconds for confirming a payment, including auto retries and 3DS step-ups.
+    /// Once the budget is used up, no further connector calls are initiated and the payment is
+    /// returned as processing, for its outcome to be polled
+    #[schema(example = 30)]
+    pub confirm_time_budget_in_secs: Option<u32>,
+
+    /// Time zone of the profile, as a name in the IANA time zone database. The periods of the
+    /// quota of the merchant start at midnight in the time zone of its default profile
+    #[schema(example = "Asia/Kolkata")]
+    pub time_zone: Option<String>,
+
     /// Flag to enable Level 2 and Level 3 processing data for card transactions
     #[schema(value_type = Option<bool>)]
     pub is_l2_l3_enabled: Option<bool>,
@@ -2817,6 +2839,17 @@ pub struct ProfileResponse {
     /// The surcharge connector details for calculating external surcharge
     pub surcharge_connector_details: Option<SurchargeConnectorDetails>,
 
+    /// Total time budget in seconds for confirming a payment, including auto retries and 3DS step-ups.
+    /// Once the budget is used up, no further connector calls are initiated and the payment is
+    /// returned as processing, for its outcome to be polled
+    #[schema(value_type = Option<u32>, example = 30)]
+    pub confirm_time_budget_in_secs: Option<i64>,
+
+    /// Time zone of the profile, as a name in the IANA time zone database. The periods of the
+    /// quota of the merchant start at midnight in the time zone of its default profile
+    #[schema(example = "Asia/Kolkata")]
+    pub time_zone: Option<String>,
+
     /// Flag to enable Level 2 and Level 3 processing data for card transactions
     #[schema(value_type = Option<bool>)]
     pub is_l2_l3_enabled: Option<bool>,
@@ -3005,6 +3038,17 @@ pub struct ProfileResponse {
     /// The surcharge connector details for calculating external surcharge
     pub surcharge_connector_details: Option<SurchargeConnectorDetails>,
 
+    /// Total time budget in seconds for confirming a payment, including auto retries and 3DS step-ups.
+    /// Once the budget is used up, no further connector calls are initiated and the payment is
+    /// returned as processing, for its outcome to be polled
+    #[schema(value_type = Option<u32>, example = 30)]
+    pub confirm_time_budget_in_secs: Option<i64>,
+
+    /// Time zone of the profile, as a name in the IANA time zone database. The periods of the
+    /// quota of the merchant start at midnight in the time zone of its default profile
+    #[schema(example = "Asia/Kolkata")]
+    pub time_zone: Option<String>,
+
     /// Flag to enable Level 2 and Level 3 processing data for card transactions
     #[schema(value_type = Option<bool>)]
     pub is_l2_l3_enabled: Option<bool>,
@@ -3206,6 +3250,17 @@ pub struct ProfileUpdate {
     /// The surcharge connector details for calculating external surcharge
     pub surcharge_connector_details: Option<SurchargeConnectorDetails>,
 
+    /// Total time budget in seconds for confirming a payment, including auto retries and 3DS step-ups.
+    /// Once the budget is used up, no further connector calls are initiated and the payment is
+    /// returned as processing, for its outcome to be polled
+    #[schema(example = 30)]
+    pub confirm_time_budget_in_secs: Option<u32>,
+
+    /// Time zone of the profile, as a name in the IANA time zone database. The periods of the
+    /// quota of the merchant start at midnight in the time zone of its default profile
+    #[schema(example = "Asia/Kolkata")]
+    pub time_zone: Option<String>,
+
     /// Flag to enable Level 2 and Level 3 processing data for card transactions
     #[schema(value_type = Option<bool>)]
     pub is_l2_l3_enabled: Option<bool>,
@@ -3374,6 +3429,17 @@ pub struct ProfileUpdate {
 
     /// The surcharge connector details for calculating external surcharge
     pub surcharge_connector_details: Option<SurchargeConnectorDetails>,
+
+    /// Total time budget in seconds for confirming a payment, including auto retries and 3DS step-ups.
+    /// Once the budget is used up, no further connector calls are initiated and the payment is
+    /// returned as processing, for its outcome to be polled
+    #[schema(example = 30)]
+    pub confirm_time_budget_in_secs: Option<u32>,
+
+    /// Time zone of the profile, as a name in the IANA time zone database. The periods of the
+    /// quota of the merchant start at midnight in the time zone of its default profile
+    #[schema(example = "Asia/Kolkata")]
+    pub time_zone: Option<String>,
 }
 
 #[derive(Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
@@ -3762,8 +3828,10 @@ pub struct ExtendedCardInfoChoice {
 
 impl common_utils::events::ApiEventMetric for ExtendedCardInfoChoice {}
 
-#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, PartialEq, Eq, ToSchema)]
 pub struct ConnectorAgnosticMitChoice {
+    /// Whether connector agnostic merchant initiated transactions are enabled for the profile
+    #[schema(example = true)]
     pub enabled: bool,
 }
 
@@ -3,6 +3,8 @@ use std::{
     hash::{Hash, Hasher},
 };
 
+use common_utils::types::StringMajorUnit;
+
 use super::{ForexMetric, NameDescription, TimeRange};
 use crate::enums::{Currency, DisputeStage};
 
@@ -142,7 +144,10 @@ pub struct DisputeMetricsBucketValue {
     pub disputes_won: Option<u64>,
     pub disputes_lost: Option<u64>,
     pub disputed_amount: Option<u64>,
+    /// Disputed amount in the major unit of the currency of the bucket
+    pub disputed_amount_decimal: Option<StringMajorUnit>,
     pub dispute_lost_amount: Option<u64>,
+    pub dispute_lost_amount_decimal: Option<StringMajorUnit>,
     pub total_dispute: Option<u64>,
 }
 #[derive(Debug, serde::Serialize)]
@@ -3,7 +3,7 @@ use std::{
     hash::{Hash, Hasher},
 };
 
-use common_utils::id_type;
+use common_utils::{id_type, types::StringMajorUnit};
 
 use super::{ForexMetric, NameDescription, TimeRange};
 use crate::enums::{
@@ -38,6 +38,8 @@ pub struct PaymentIntentFilters {
     pub error_reason: Vec<String>,
     #[serde(default)]
     pub customer_id: Vec<id_type::CustomerId>,
+    #[serde(default)]
+    pub attribution_tags: Vec<String>,
 }
 
 #[derive(
@@ -75,6 +77,7 @@ pub enum PaymentIntentDimensions {
     CardLast4,
     CardIssuer,
     ErrorReason,
+    AttributionTags,
 }
 
 #[derive(
@@ -165,6 +168,7 @@ pub struct PaymentIntentMetricsBucketIdentifier {
     pub card_last_4: Option<String>,
     pub card_issuer: Option<String>,
     pub error_reason: Option<String>,
+    pub attribution_tags: Option<String>,
     #[serde(rename = "time_range")]
     pub time_bucket: TimeRange,
     #[serde(rename = "time_bucket")]
@@ -187,6 +191,7 @@ impl PaymentIntentMetricsBucketIdentifier {
         card_last_4: Option<String>,
         card_issuer: Option<String>,
         error_reason: Option<String>,
+        attribution_tags: Option<String>,
         normalized_time_range: TimeRange,
     ) -> Self {
         Self {
@@ -202,6 +207,7 @@ impl PaymentIntentMetricsBucketIdentifier {
             card_last_4,
             card_issuer,
             error_reason,
+            attribution_tags,
             time_bucket: normalized_time_range,
             start_time: normalized_time_range.start_time,
         }
@@ -222,6 +228,7 @@ impl Hash for PaymentIntentMetricsBucketIdentifier {
         self.card_last_4.hash(state);
         self.card_issuer.hash(state);
         self.error_reason.hash(state);
+        self.attribution_tags.hash(state);
         self.time_bucket.hash(state);
     }
 }
@@ -242,8 +249,11 @@ pub struct PaymentIntentMetricsBucketValue {
     pub total_smart_retries: Option<u64>,
     pub smart_retried_amount: Option<u64>,
     pub smart_retried_amount_in_usd: Option<u64>,
+    /// Smart retried amount in the major unit of the currency of the bucket
+    pub smart_retried_amount_decimal: Option<StringMajorUnit>,
     pub smart_retried_amount_without_smart_retries: Option<u64>,
     pub smart_retried_amount_without_smart_retries_in_usd: Option<u64>,
+    pub smart_retried_amount_without_smart_retries_decimal: Option<StringMajorUnit>,
     pub payment_intent_count: Option<u64>,
     pub successful_payments: Option<u32>,
     pub successful_payments_without_smart_retries: Option<u32>,
@@ -252,9 +262,12 @@ pub struct PaymentIntentMetricsBucketValue {
     pub payments_success_rate_without_smart_retries: Option<f64>,
     pub payment_processed_amount: Option<u64>,
     pub payment_processed_amount_in_usd: Option<u64>,
+    /// Payment processed amount in the major unit of the currency of the bucket
+    pub payment_processed_amount_decimal: Option<StringMajorUnit>,
     pub payment_processed_count: Option<u64>,
     pub payment_processed_amount_without_smart_retries: Option<u64>,
     pub payment_processed_amount_without_smart_retries_in_usd: Option<u64>,
+    pub payment_processed_amount_without_smart_retries_decimal: Option<StringMajorUnit>,
     pub payment_processed_count_without_smart_retries: Option<u64>,
     pub payments_success_rate_distribution_without_smart_retries: Option<f64>,
     pub payments_failure_rate_distribution_without_smart_retries: Option<f64>,
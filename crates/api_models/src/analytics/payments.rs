@@ -3,7 +3,7 @@ use std::{
     hash::{Hash, Hasher},
 };
 
-use common_utils::id_type;
+use common_utils::{id_type, types::StringMajorUnit};
 
 use super::{ForexMetric, NameDescription, TimeRange};
 use crate::enums::{
@@ -149,6 +149,10 @@ pub enum PaymentReportColumn {
     CardHolderName,
     MerchantOrderReferenceId,
     ProfileId,
+    /// `amount` in the major unit of the currency of the payment
+    AmountDecimal,
+    /// `amount_to_capture` in the major unit of the currency of the payment
+    AmountToCaptureDecimal,
 }
 
 #[derive(
@@ -373,9 +377,12 @@ pub struct PaymentMetricsBucketValue {
     pub payment_success_count: Option<u64>,
     pub payment_processed_amount: Option<u64>,
     pub payment_processed_amount_in_usd: Option<u64>,
+    /// Payment processed amount in the major unit of the currency of the bucket
+    pub payment_processed_amount_decimal: Option<StringMajorUnit>,
     pub payment_processed_count: Option<u64>,
     pub payment_processed_amount_without_smart_retries: Option<u64>,
     pub payment_processed_amount_without_smart_retries_usd: Option<u64>,
+    pub payment_processed_amount_without_smart_retries_decimal: Option<StringMajorUnit>,
     pub payment_processed_count_without_smart_retries: Option<u64>,
     pub avg_ticket_size: Option<f64>,
     pub payment_error_message: Option<Vec<ErrorResult>>,
@@ -402,3 +409,28 @@ pub struct MetricsBucketResponse {
     #[serde(flatten)]
     pub dimensions: PaymentMetricsBucketIdentifier,
 }
+
+#[cfg(test)]
+mod tests {
+    use strum::IntoEnumIterator;
+
+    use super::PaymentReportColumn;
+
+    #[test]
+    fn test_payment_report_column_header() {
+        let header = PaymentReportColumn::iter()
+            .map(|column| column.to_string())
+            .collect::<Vec<_>>()
+            .join(",");
+        assert_eq!(
+            header,
+            "payment_id,attempt_id,status,amount,currency,connector,connector_transaction_id,\
+             amount_to_capture,customer_id,created_at,order_details,error_message,capture_method,\
+             authentication_type,mandate_id,payment_method,payment_method_type,metadata,\
+             setup_future_usage,statement_descriptor_name,description,off_session,business_country,\
+             business_label,business_sub_label,allowed_payment_method_types,payment_method_data,\
+             card_network,fingerprint_id,modified_at,error_code,payment_method_id,card_holder_name,\
+             merchant_order_reference_id,profile_id,amount_decimal,amount_to_capture_decimal"
+        );
+    }
+}
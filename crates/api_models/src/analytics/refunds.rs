@@ -3,7 +3,7 @@ use std::{
     hash::{Hash, Hasher},
 };
 
-use common_utils::id_type;
+use common_utils::{id_type, types::StringMajorUnit};
 
 use crate::enums::{Currency, RefundStatus};
 
@@ -42,6 +42,8 @@ pub struct RefundFilters {
     #[serde(default)]
     pub profile_id: Vec<id_type::ProfileId>,
     #[serde(default)]
+    pub merchant_id: Vec<id_type::MerchantId>,
+    #[serde(default)]
     pub refund_reason: Vec<String>,
     #[serde(default)]
     pub refund_error_message: Vec<String>,
@@ -69,6 +71,7 @@ pub enum RefundDimensions {
     Connector,
     RefundType,
     ProfileId,
+    MerchantId,
     RefundReason,
     RefundErrorMessage,
 }
@@ -176,6 +179,7 @@ pub struct RefundMetricsBucketIdentifier {
     pub connector: Option<String>,
     pub refund_type: Option<String>,
     pub profile_id: Option<String>,
+    pub merchant_id: Option<String>,
     pub refund_reason: Option<String>,
     pub refund_error_message: Option<String>,
     #[serde(rename = "time_range")]
@@ -192,6 +196,7 @@ impl Hash for RefundMetricsBucketIdentifier {
         self.connector.hash(state);
         self.refund_type.hash(state);
         self.profile_id.hash(state);
+        self.merchant_id.hash(state);
         self.refund_reason.hash(state);
         self.refund_error_message.hash(state);
         self.time_bucket.hash(state);
@@ -215,6 +220,7 @@ impl RefundMetricsBucketIdentifier {
         connector: Option<String>,
         refund_type: Option<String>,
         profile_id: Option<String>,
+        merchant_id: Option<String>,
         refund_reason: Option<String>,
         refund_error_message: Option<String>,
         normalized_time_range: TimeRange,
@@ -225,6 +231,7 @@ impl RefundMetricsBucketIdentifier {
             connector,
             refund_type,
             profile_id,
+            merchant_id,
             refund_reason,
             refund_error_message,
             time_bucket: normalized_time_range,
@@ -241,6 +248,8 @@ pub struct RefundMetricsBucketValue {
     pub refund_success_count: Option<u64>,
     pub refund_processed_amount: Option<u64>,
     pub refund_processed_amount_in_usd: Option<u64>,
+    /// Refund processed amount in the major unit of the currency of the bucket
+    pub refund_processed_amount_decimal: Option<StringMajorUnit>,
     pub refund_processed_count: Option<u64>,
     pub refund_reason_distribution: Option<Vec<ReasonsResult>>,
     pub refund_error_message_distribution: Option<Vec<ErrorMessagesResult>>,
@@ -0,0 +1,34 @@
+use std::collections::{BTreeMap, HashMap};
+
+use hyperswitch_masking::Secret;
+use utoipa::ToSchema;
+
+#[derive(Clone, serde::Deserialize, Debug, serde::Serialize, ToSchema)]
+pub struct UnmaskedHeadersUpdate {
+    /// Headers to be logged in clear text, replacing the `unmasked_headers` config.
+    /// The `unmasked_headers` config is restored if this is not provided.
+    #[schema(example = json!(["x-request-id", "user-agent"]))]
+    pub keys: Option<Vec<String>>,
+}
+
+#[derive(Clone, serde::Serialize, Debug, ToSchema)]
+pub struct UnmaskedHeadersResponse {
+    /// Headers that are logged in clear text
+    #[schema(example = json!(["x-request-id", "user-agent"]))]
+    pub keys: Vec<String>,
+    /// Whether the headers were updated at runtime, instead of being read from the config
+    pub is_runtime_override: bool,
+}
+
+#[derive(Clone, serde::Deserialize, Debug, serde::Serialize, ToSchema)]
+pub struct UnmaskedHeadersPreviewRequest {
+    /// Headers of an incoming request, to be rendered as the request logger would log them
+    #[schema(value_type = HashMap<String, String>)]
+    pub headers: HashMap<String, Secret<String>>,
+}
+
+#[derive(Clone, serde::Serialize, Debug, ToSchema)]
+pub struct UnmaskedHeadersPreviewResponse {
+    /// Headers as they would be logged for an incoming request
+    pub headers: BTreeMap<String, String>,
+}
@@ -1,4 +1,5 @@
 use common_utils::{events::ApiEventMetric, types::MinorUnit};
+use utoipa::ToSchema;
 
 /// QueryParams to be send to convert the amount -> from_currency -> to_currency
 #[derive(Debug, serde::Deserialize)]
@@ -10,10 +11,14 @@ pub struct CurrencyConversionParams {
 }
 
 /// Response to be send for convert currency route
-#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[derive(Debug, serde::Serialize, serde::Deserialize, ToSchema)]
 #[serde(rename_all = "snake_case")]
 pub struct CurrencyConversionResponse {
+    /// The converted amount, in the lowest denomination of the target currency
+    #[schema(example = "6540")]
     pub converted_amount: String,
+    /// The currency the amount was converted to
+    #[schema(example = "USD")]
     pub currency: String,
 }
 
@@ -32,6 +32,7 @@ use crate::{
     },
     api_keys::*,
     cards_info::*,
+    configs::*,
     disputes::*,
     files::*,
     mandates::*,
@@ -149,7 +150,11 @@ impl_api_event_type!(
         OrganizationUpdateRequest,
         OrganizationId,
         CustomerListRequest,
-        RoutingEventsRequest
+        RoutingEventsRequest,
+        UnmaskedHeadersUpdate,
+        UnmaskedHeadersResponse,
+        UnmaskedHeadersPreviewRequest,
+        UnmaskedHeadersPreviewResponse
     )
 );
 
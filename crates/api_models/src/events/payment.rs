@@ -38,9 +38,10 @@ use crate::{
         PaymentsCancelRequest, PaymentsCaptureRequest, PaymentsCompleteAuthorizeRequest,
         PaymentsDynamicTaxCalculationRequest, PaymentsDynamicTaxCalculationResponse,
         PaymentsExtendAuthorizationRequest, PaymentsExternalAuthenticationRequest,
-        PaymentsExternalAuthenticationResponse, PaymentsIncrementalAuthorizationRequest,
+        PaymentsExternalAuthenticationResponse, PaymentsForceCloseRequest,
+        PaymentsForceCloseResponse, PaymentsIncrementalAuthorizationRequest,
         PaymentsManualStatusUpdateRequest, PaymentsManualStatusUpdateResponse,
-        PaymentsManualUpdateRequest, PaymentsManualUpdateResponse,
+        PaymentsManualUpdateRequest, PaymentsManualUpdateResponse, PaymentsOrderCancelledRequest,
         PaymentsPostSessionTokensRequest, PaymentsPostSessionTokensResponse, PaymentsRejectRequest,
         PaymentsRetrieveRequest, PaymentsStartRequest, PaymentsUpdateMetadataRequest,
         PaymentsUpdateMetadataResponse,
@@ -152,6 +153,15 @@ impl ApiEventMetric for PaymentsCancelPostCaptureRequest {
     }
 }
 
+#[cfg(feature = "v1")]
+impl ApiEventMetric for PaymentsOrderCancelledRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Payment {
+            payment_id: self.payment_id.clone(),
+        })
+    }
+}
+
 #[cfg(feature = "v1")]
 impl ApiEventMetric for PaymentsExtendAuthorizationRequest {
     fn get_api_event_type(&self) -> Option<ApiEventsType> {
@@ -619,6 +629,24 @@ impl ApiEventMetric for PaymentsManualUpdateRequest {
     }
 }
 
+#[cfg(feature = "v1")]
+impl ApiEventMetric for PaymentsForceCloseRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Payment {
+            payment_id: self.payment_id.clone(),
+        })
+    }
+}
+
+#[cfg(feature = "v1")]
+impl ApiEventMetric for PaymentsForceCloseResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Payment {
+            payment_id: self.payment_id.clone(),
+        })
+    }
+}
+
 #[cfg(feature = "v1")]
 impl ApiEventMetric for PaymentsManualStatusUpdateRequest {
     fn get_api_event_type(&self) -> Option<ApiEventsType> {
@@ -15,10 +15,32 @@ pub struct RouterHealthCheckResponse {
     #[cfg(feature = "dynamic_routing")]
     pub decision_engine: bool,
     pub unified_connector_service: Option<bool>,
+    /// Results of the latest connector base URL smoke test, if enabled
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub connector_smoke_test: Option<ConnectorSmokeTestReport>,
 }
 
 impl common_utils::events::ApiEventMetric for RouterHealthCheckResponse {}
 
+/// Results of a reachability check against connector base URLs
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct ConnectorSmokeTestReport {
+    /// Time at which the smoke test run completed
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub completed_at: time::PrimitiveDateTime,
+    /// Number of connector base URLs that failed the smoke test
+    pub failure_count: usize,
+    pub results: Vec<ConnectorSmokeTestResult>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct ConnectorSmokeTestResult {
+    pub connector: String,
+    pub base_url: String,
+    /// Reason for which the base URL could not be reached
+    pub error: Option<String>,
+}
+
 /// gRPC based services eligible for Health check
 #[derive(Debug, Clone, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "snake_case")]
@@ -8,6 +8,7 @@ pub mod card_issuer;
 pub mod cards_info;
 pub mod chat;
 pub mod conditional_configs;
+pub mod configs;
 pub mod connector_enums;
 pub mod connector_onboarding;
 pub mod consts;
@@ -27,6 +28,7 @@ pub mod health_check;
 pub mod launch_sage;
 pub mod mandates;
 pub mod merchant_connector_webhook_management;
+pub mod merchant_quota;
 pub mod oidc;
 pub mod open_router;
 pub mod organization;
@@ -50,6 +52,7 @@ pub mod subscription;
 pub mod superposition_proxy;
 pub mod superposition_sdk_config;
 pub mod surcharge_decision_configs;
+pub mod takeout;
 pub mod three_ds_decision_rule;
 #[cfg(feature = "tokenization_v2")]
 pub mod tokenization;
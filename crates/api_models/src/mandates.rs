@@ -58,6 +58,12 @@ pub struct MandateResponse {
     #[schema(value_type = Option<CustomerAcceptance>)]
     #[smithy(value_type = "Option<CustomerAcceptance>")]
     pub customer_acceptance: Option<common_payments_types::CustomerAcceptance>,
+    /// Where the customer acceptance was captured. For payment methods where the mandate is
+    /// accepted on the bank page, this is the completion of the redirection, unless it could not
+    /// be captured, in which case the acceptance provided at confirm is retained.
+    #[schema(value_type = CustomerAcceptanceSource)]
+    #[smithy(value_type = "CustomerAcceptanceSource")]
+    pub customer_acceptance_source: api_enums::CustomerAcceptanceSource,
 }
 
 #[derive(Default, Debug, Deserialize, Serialize, ToSchema, Clone, SmithyModel)]
@@ -0,0 +1,96 @@
+use common_utils::{events::ApiEventMetric, id_type, types::MinorUnit};
+use utoipa::ToSchema;
+
+use crate::enums::Currency;
+
+/// Period over which the usage of a quota is accounted, after which it is reset
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize, ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum QuotaPeriod {
+    Daily,
+    /// Weeks starting on Monday
+    Weekly,
+    #[default]
+    Monthly,
+}
+
+/// Limit on the total amount of the payments created in a period
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct VolumeQuota {
+    /// The limit in the lowest denomination of the currency
+    #[schema(value_type = i64, example = 10_000_000)]
+    pub amount: MinorUnit,
+    /// The reporting currency of the limit, the amounts of payments in other currencies are
+    /// converted to it using the forex rates
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: Currency,
+}
+
+/// Quota of payments a merchant is allowed to create in a period, imposed by the platform. The
+/// periods start at midnight in the time zone of the default profile of the merchant
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct MerchantQuota {
+    #[serde(default)]
+    pub period: QuotaPeriod,
+    /// Maximum number of payments that can be created in a period
+    #[schema(example = 1000)]
+    pub payment_count_limit: Option<u64>,
+    /// Maximum total amount of the payments that can be created in a period
+    pub volume_limit: Option<VolumeQuota>,
+    /// Percentage of a limit, on reaching which a `quota_threshold_reached` webhook is sent once
+    /// in a period
+    #[schema(example = 80)]
+    pub warning_threshold_percent: Option<u8>,
+}
+
+/// Number of payments created in the current period, against the limit
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, ToSchema)]
+pub struct PaymentCountUsage {
+    pub used: u64,
+    pub limit: u64,
+}
+
+/// Total amount of the payments created in the current period, against the limit
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, ToSchema)]
+pub struct VolumeUsage {
+    #[schema(value_type = i64)]
+    pub used: MinorUnit,
+    #[schema(value_type = i64)]
+    pub limit: MinorUnit,
+    #[schema(value_type = Currency)]
+    pub currency: Currency,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, ToSchema)]
+pub struct MerchantQuotaUsageResponse {
+    #[schema(value_type = String)]
+    pub merchant_id: id_type::MerchantId,
+    pub quota: MerchantQuota,
+    /// Start of the current period
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-01T00:00:00Z")]
+    pub period_start: time::PrimitiveDateTime,
+    /// End of the current period, at which the usage is reset
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    #[schema(value_type = PrimitiveDateTime, example = "2022-10-01T00:00:00Z")]
+    pub period_end: time::PrimitiveDateTime,
+    /// Absent if the number of payments is not limited
+    pub payment_count: Option<PaymentCountUsage>,
+    /// Absent if the total amount of the payments is not limited
+    pub volume: Option<VolumeUsage>,
+}
+
+/// Sent when the creation of a payment takes the usage of a quota past its warning threshold
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct QuotaThresholdReachedResponse {
+    /// The payment whose creation took the usage past the warning threshold
+    #[schema(value_type = String, example = "pay_mbabizu24mvu3mela5njyhpit4")]
+    pub payment_id: id_type::PaymentId,
+    pub usage: MerchantQuotaUsageResponse,
+}
+
+impl ApiEventMetric for MerchantQuota {}
+impl ApiEventMetric for MerchantQuotaUsageResponse {}
@@ -1578,6 +1578,11 @@ pub struct PaymentsRequest {
     #[schema(value_type = Option<BillingDescriptor>)]
     pub billing_descriptor: Option<common_types::payments::BillingDescriptor>,
 
+    /// Cost attribution tags for the payment, used to attribute payment volume to internal cost centers. At most 10 tags, with keys and values of up to 64 alphanumeric characters, `_`, `-`, `.` or `:`. Tags cannot be updated after the payment has been confirmed.
+    #[schema(value_type = Option<HashMap<String, String>>, example = json!({"cost_center": "emea-retail"}))]
+    #[smithy(value_type = "Option<Object>")]
+    pub attribution_tags: Option<common_types::payments::AttributionTags>,
+
     /// The tokenization preference for the payment method. This is used to control whether a PSP token is created or not.
     #[schema(value_type = Option<Tokenization>, example = "tokenize_at_psp")]
     pub tokenization: Option<enums::Tokenization>,
@@ -4095,14 +4100,19 @@ pub struct AdditionalCardInfo {
     /// Extended bin of card, contains the first 8 digits of card number
     pub card_extended_bin: Option<String>,
 
+    #[schema(value_type = Option<String>)]
     pub card_exp_month: Option<Secret<String>>,
 
+    #[schema(value_type = Option<String>)]
     pub card_exp_year: Option<Secret<String>>,
 
+    #[schema(value_type = Option<String>)]
     pub card_holder_name: Option<Secret<String>>,
 
     /// Additional payment checks done on the cvv and billing address by the processors.
-    /// This is a free form field and the structure varies from processor to processor
+    /// This is a free form field and the structure varies from processor to processor, except for
+    /// the `avs_street_match` and `avs_postal_code_match` results of the address verification,
+    /// which are added where the processor provides them
     pub payment_checks: Option<serde_json::Value>,
 
     /// Details about the threeds environment.
@@ -6588,6 +6598,7 @@ pub struct PaymentsCaptureRequest {
 #[derive(Debug, Clone, serde::Serialize, ToSchema)]
 pub struct PaymentsCaptureResponse {
     /// The unique identifier for the payment
+    #[schema(value_type = String)]
     pub id: id_type::GlobalPaymentId,
 
     /// Status of the payment
@@ -6611,6 +6622,7 @@ pub struct PaymentsCancelRequest {
 #[derive(Debug, Clone, serde::Serialize, ToSchema)]
 pub struct PaymentsCancelResponse {
     /// The unique identifier for the payment
+    #[schema(value_type = String)]
     pub id: id_type::GlobalPaymentId,
 
     /// Status of the payment
@@ -6625,6 +6637,7 @@ pub struct PaymentsCancelResponse {
     pub amount: PaymentAmountDetailsResponse,
 
     /// The unique identifier for the customer associated with the payment
+    #[schema(value_type = Option<String>)]
     pub customer_id: Option<id_type::GlobalCustomerId>,
 
     /// The connector used for the payment
@@ -6796,6 +6809,12 @@ pub enum NextActionData {
         #[smithy(value_type = "DDCData")]
         ddc_data: DDCData,
     },
+    /// Contains the next action of the connector, which is to be handled by the SDK of the
+    /// connector in the native mobile client
+    InvokeConnectorSdk {
+        #[smithy(value_type = "ConnectorSdkNextActionData")]
+        connector_sdk_data: ConnectorSdkNextActionData,
+    },
 }
 
 #[derive(
@@ -6834,6 +6853,19 @@ pub struct DDCData {
     pub timeout_ms: Option<i32>,
 }
 
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize, ToSchema, SmithyModel)]
+#[smithy(namespace = "com.hyperswitch.smithy.types")]
+pub struct ConnectorSdkNextActionData {
+    /// The connector whose SDK handles the next action
+    #[schema(example = "stripe")]
+    #[smithy(value_type = "String")]
+    pub connector: String,
+    /// The next action as returned by the connector, to be passed to its SDK as is
+    #[schema(value_type = Object)]
+    #[smithy(value_type = "smithy.api#Document")]
+    pub sdk_next_action: serde_json::Value,
+}
+
 #[derive(
     Clone, Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize, ToSchema, SmithyModel,
 )]
@@ -7815,6 +7847,11 @@ pub struct PaymentsResponse {
     #[schema(value_type = Option<BillingDescriptor>)]
     pub billing_descriptor: Option<common_types::payments::BillingDescriptor>,
 
+    /// Cost attribution tags of the payment
+    #[schema(value_type = Option<HashMap<String, String>>, example = json!({"cost_center": "emea-retail"}))]
+    #[smithy(value_type = "Option<Object>")]
+    pub attribution_tags: Option<common_types::payments::AttributionTags>,
+
     /// The tokenization preference for the payment method. This is used to control whether a PSP token is created or not.
     #[schema(value_type = Option<Tokenization>,example="skip_psp")]
     pub tokenization: Option<enums::Tokenization>,
@@ -9151,8 +9188,16 @@ pub struct PaymentListFilterConstraints {
     pub merchant_order_reference_id: Option<String>,
     /// Indicates the method by which a card is discovered during a payment
     pub card_discovery: Option<Vec<enums::CardDiscovery>>,
-    /// The customer email to filter payments list
+    /// The customer email to filter payments list. Payments are matched by the email provided in
+    /// the payment, or the email of the customer of the payment.
     pub customer_email: Option<Email>,
+    /// The customer phone number to filter payments list, matched the same way as the customer
+    /// email. The number is interpreted in the international format if it does not start with a
+    /// `+`.
+    pub customer_phone: Option<Secret<String>>,
+    /// The attribution tags to filter payments list. Only payments having all of the given tags
+    /// are returned.
+    pub attribution_tags: Option<common_types::payments::AttributionTags>,
 }
 
 #[cfg(feature = "v1")]
@@ -9175,6 +9220,12 @@ impl PaymentListFilterConstraints {
             && self.first_attempt.is_none()
             && self.card_discovery.is_none()
     }
+
+    /// Whether the payments are filtered by the email or phone number of the customer, which
+    /// requires the customer search permission
+    pub fn has_customer_contact_filters(&self) -> bool {
+        self.customer_email.is_some() || self.customer_phone.is_some()
+    }
 }
 
 #[derive(Clone, Debug, serde::Serialize)]
@@ -11298,6 +11349,20 @@ pub struct PaymentsCancelPostCaptureRequest {
     pub cancellation_reason: Option<String>,
 }
 
+/// Signal that the order of a payment was cancelled, so that its authorization is voided
+#[derive(Default, Debug, serde::Deserialize, serde::Serialize, Clone, ToSchema)]
+pub struct PaymentsOrderCancelledRequest {
+    /// The identifier for the payment
+    #[serde(skip)]
+    pub payment_id: id_type::PaymentId,
+    /// The system which cancelled the order, such as the order management system
+    #[schema(example = "order_management_system")]
+    pub initiated_by: String,
+    /// The reason for the order cancellation
+    #[schema(example = "out_of_stock")]
+    pub cancellation_reason: Option<String>,
+}
+
 /// Tracking data for the post_capture_void_sync workflow stored in process_tracker
 #[derive(Default, Debug, serde::Deserialize, serde::Serialize, Clone)]
 pub struct PaymentsPostCaptureVoidSyncTrackingData {
@@ -11395,6 +11460,38 @@ pub struct PaymentsManualUpdateResponse {
     pub amount_captured: Option<MinorUnit>,
 }
 
+/// Request to force-close a payment which is stuck in processing
+#[derive(Debug, serde::Serialize, serde::Deserialize, Clone, ToSchema)]
+pub struct PaymentsForceCloseRequest {
+    /// The identifier for the payment
+    #[serde(skip)]
+    pub payment_id: id_type::PaymentId,
+    /// Merchant ID
+    #[schema(value_type = String)]
+    pub merchant_id: id_type::MerchantId,
+    /// The reason for force-closing the payment, stored as the error reason of the payment attempt
+    pub reason: String,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize, Clone, ToSchema)]
+pub struct PaymentsForceCloseResponse {
+    /// The identifier for the payment
+    #[schema(value_type = String)]
+    pub payment_id: id_type::PaymentId,
+    /// The identifier for the payment attempt
+    pub attempt_id: String,
+    /// Merchant ID
+    #[schema(value_type = String)]
+    pub merchant_id: id_type::MerchantId,
+    /// Whether the payment was force-closed. If the connector has resolved the payment, the status
+    /// reported by the connector is applied to the payment instead
+    pub force_closed: bool,
+    /// The status of the payment
+    pub intent_status: enums::IntentStatus,
+    /// The status of the attempt
+    pub attempt_status: enums::AttemptStatus,
+}
+
 /// Request to manually update payment status from Review state (Dashboard API)
 #[derive(Debug, serde::Serialize, serde::Deserialize, Clone, ToSchema)]
 pub struct PaymentsManualStatusUpdateRequest {
@@ -13274,6 +13371,59 @@ mod payments_request_api_contract {
             .billing
             .is_some());
     }
+
+    #[test]
+    fn test_attribution_tags_deser() {
+        let payments_request = r#"
+        {
+            "amount": 6540,
+            "currency": "USD",
+            "attribution_tags": {
+                "cost_center": "emea-retail",
+                "team": "checkout.web"
+            }
+        }
+        "#;
+
+        let payments_request = serde_json::from_str::<PaymentsRequest>(payments_request).unwrap();
+        let attribution_tags = payments_request.attribution_tags.unwrap();
+        assert_eq!(
+            attribution_tags
+                .as_map()
+                .get("cost_center")
+                .map(String::as_str),
+            Some("emea-retail")
+        );
+    }
+
+    #[test]
+    fn test_attribution_tags_with_invalid_characters_are_rejected() {
+        let payments_request = r#"
+        {
+            "amount": 6540,
+            "currency": "USD",
+            "attribution_tags": {
+                "cost center": "emea retail"
+            }
+        }
+        "#;
+
+        assert!(serde_json::from_str::<PaymentsRequest>(payments_request).is_err());
+    }
+
+    #[test]
+    fn test_too_many_attribution_tags_are_rejected() {
+        let attribution_tags = (0..=common_types::consts::MAX_ATTRIBUTION_TAGS)
+            .map(|index| (format!("tag_{index}"), "value".to_string()))
+            .collect::<std::collections::HashMap<_, _>>();
+        let payments_request = serde_json::json!({
+            "amount": 6540,
+            "currency": "USD",
+            "attribution_tags": attribution_tags,
+        });
+
+        assert!(serde_json::from_value::<PaymentsRequest>(payments_request).is_err());
+    }
 }
 
 #[cfg(test)]
@@ -13592,6 +13742,32 @@ pub struct BillingConnectorPaymentDetails {
     pub connector_customer_id: String,
 }
 
+/// Details of a failed recurring payment being recovered, sent when a retry of the payment is
+/// scheduled and when no further retries will be attempted
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[cfg(feature = "v2")]
+pub struct RecurringPaymentRetryResponse {
+    /// The global payment id of the recurring payment
+    #[schema(value_type = String, example = "12345_pay_01926c58bc6e77c09e809964e72af8c8")]
+    pub payment_id: id_type::GlobalPaymentId,
+    /// Payment Processor Token (the mandate) with which the payment is being recovered
+    pub mandate_id: String,
+    /// Billing Connector's Customer Id
+    pub connector_customer_id: String,
+    /// Classification of the decline of the last failed attempt
+    #[schema(value_type = Option<ErrorCategory>, example = "soft_decline")]
+    pub decline_classification: Option<common_enums::ErrorCategory>,
+    /// Error details of the last failed attempt
+    pub error: Option<ErrorDetails>,
+    /// Time at which the payment will be retried, absent once the recovery is exhausted
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-10T10:11:12Z")]
+    pub next_retry_at: Option<PrimitiveDateTime>,
+    /// Number of retries left as per the retry algorithm of the profile, if it can be determined
+    #[schema(value_type = Option<u16>, example = 3)]
+    pub remaining_attempts: Option<u16>,
+}
+
 // Serialize is required because the api event requires Serialize to be implemented
 #[derive(Debug, serde::Serialize, serde::Deserialize, Clone, ToSchema)]
 #[serde(deny_unknown_fields)]
@@ -13754,6 +13930,7 @@ pub struct RecoveryPaymentsCreate {
     pub payment_method_data: CustomRecoveryPaymentMethodData,
 
     /// Type of action that needs to be taken after consuming the recovery payload. For example: scheduling a failed payment or stopping the invoice.
+    #[schema(value_type = RecoveryAction)]
     pub action: common_payments_types::RecoveryAction,
 
     /// Allow partial authorization for this payment
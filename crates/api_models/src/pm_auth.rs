@@ -3,30 +3,45 @@ use common_utils::{
     events::{ApiEventMetric, ApiEventsType},
     id_type, impl_api_event_type,
 };
+use utoipa::ToSchema;
 
-#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize, ToSchema)]
 #[serde(rename_all = "snake_case")]
 pub struct LinkTokenCreateRequest {
-    pub language: Option<String>, // optional language field to be passed
-    pub client_secret: Option<String>, // client secret to be passed in req body
-    pub payment_id: id_type::PaymentId, // payment_id to be passed in req body for redis pm_auth connector name fetch
-    pub payment_method: PaymentMethod,  // payment_method to be used for filtering pm_auth connector
-    pub payment_method_type: PaymentMethodType, // payment_method_type to be used for filtering pm_auth connector
+    /// The language of the payment method authentication flow
+    pub language: Option<String>,
+    /// The client secret of the payment
+    pub client_secret: Option<String>,
+    /// The identifier of the payment, used to fetch the payment method authentication connector
+    #[schema(value_type = String)]
+    pub payment_id: id_type::PaymentId,
+    /// The payment method used to select the payment method authentication connector
+    pub payment_method: PaymentMethod,
+    /// The payment method type used to select the payment method authentication connector
+    pub payment_method_type: PaymentMethodType,
 }
 
-#[derive(Debug, Clone, serde::Serialize)]
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
 pub struct LinkTokenCreateResponse {
-    pub link_token: String, // link_token received in response
-    pub connector: String,  // pm_auth connector name in response
+    /// The link token received from the payment method authentication connector
+    pub link_token: String,
+    /// The name of the payment method authentication connector
+    pub connector: String,
 }
 
-#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize, ToSchema)]
 #[serde(rename_all = "snake_case")]
 pub struct ExchangeTokenCreateRequest {
+    /// The public token received from the payment method authentication connector
     pub public_token: String,
+    /// The client secret of the payment
     pub client_secret: Option<String>,
+    /// The identifier of the payment, used to fetch the payment method authentication connector
+    #[schema(value_type = String)]
     pub payment_id: id_type::PaymentId,
+    /// The payment method used to select the payment method authentication connector
     pub payment_method: PaymentMethod,
+    /// The payment method type used to select the payment method authentication connector
     pub payment_method_type: PaymentMethodType,
 }
 
@@ -140,7 +140,7 @@ pub struct RefundsRetrieveBody {
 }
 
 #[cfg(feature = "v2")]
-#[derive(Default, Debug, Clone, Deserialize)]
+#[derive(Default, Debug, Clone, Deserialize, ToSchema)]
 pub struct RefundsRetrievePayload {
     /// `force_sync` with the connector to get refund details
     pub force_sync: Option<bool>,
@@ -542,7 +542,7 @@ pub struct RefundListFilters {
     pub refund_status: Vec<enums::RefundStatus>,
 }
 
-#[derive(Clone, Debug, serde::Serialize)]
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
 pub struct RefundAggregateResponse {
     /// The list of refund status with their count
     pub status_with_count: HashMap<enums::RefundStatus, i64>,
@@ -0,0 +1,118 @@
+use common_utils::{events::ApiEventMetric, id_type};
+use hyperswitch_masking::Secret;
+use utoipa::ToSchema;
+
+/// Handling of the personally identifiable information of customers in a takeout
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize, ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum TakeoutPiiHandling {
+    /// Names, emails, phone numbers and addresses are left out of the exported records
+    #[default]
+    Redact,
+    /// Names, emails, phone numbers and addresses are exported as stored
+    Include,
+}
+
+/// Redaction options applied to the records of a takeout
+#[derive(Debug, Clone, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct TakeoutRedactionOptions {
+    /// Handling of the customer details in the customers and payments exports
+    #[serde(default)]
+    pub customer_pii: TakeoutPiiHandling,
+}
+
+/// Request to generate an export of the configuration and transactional data of a merchant
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct MerchantTakeoutRequest {
+    /// Redaction options to apply to the exported records
+    #[serde(default)]
+    pub redaction: TakeoutRedactionOptions,
+}
+
+/// Resource types exported in a takeout, each exported as JSON lines
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::Display,
+    strum::EnumIter,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum TakeoutResource {
+    Profiles,
+    /// Connector accounts, without the connector credentials and webhook secrets
+    MerchantConnectorAccounts,
+    Payments,
+    Refunds,
+    Disputes,
+    Customers,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum TakeoutJobStatus {
+    /// The takeout is yet to be picked up for generation
+    Pending,
+    /// Some of the resources have been exported, the takeout is being generated
+    Processing,
+    /// All the resources have been exported and are available for download
+    Completed,
+    /// The takeout could not be generated
+    Failed,
+}
+
+/// Exported file of a takeout, containing a part of the records of a resource
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct TakeoutFile {
+    pub resource: TakeoutResource,
+    /// Name of the file, the parts of a resource are numbered in the order they were exported
+    #[schema(example = "payments_0001.jsonl")]
+    pub file_name: String,
+    /// Number of records in the file, one per line
+    pub record_count: u32,
+    /// Signed URL to download the file, only available once the takeout is completed
+    #[schema(value_type = Option<String>)]
+    pub download_url: Option<Secret<String>>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct MerchantTakeoutResponse {
+    /// The identifier of the takeout job
+    #[schema(example = "takeout_0d0ZdtbRqjHJrEYhVPrq")]
+    pub job_id: String,
+    #[schema(value_type = String)]
+    pub merchant_id: id_type::MerchantId,
+    pub status: TakeoutJobStatus,
+    /// Redaction options applied to the exported records
+    pub redaction: TakeoutRedactionOptions,
+    /// Exported files, in the order they were generated
+    pub files: Vec<TakeoutFile>,
+    /// Time at which the download URLs of the files expire
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub download_urls_expire_at: Option<time::PrimitiveDateTime>,
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: time::PrimitiveDateTime,
+}
+
+/// Signature of a takeout file download URL
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct TakeoutFileDownloadQuery {
+    /// Unix timestamp at which the URL expires
+    pub expires: i64,
+    pub signature: Secret<String>,
+}
+
+impl ApiEventMetric for MerchantTakeoutRequest {}
+impl ApiEventMetric for MerchantTakeoutResponse {}
+impl ApiEventMetric for TakeoutFileDownloadQuery {}
@@ -103,6 +103,7 @@ pub struct UnreferencedRefundResponse {
     pub error: Option<RelayError>,
 
     /// Raw response from connector
+    #[schema(value_type = Option<Object>)]
     pub raw_connector_response: Option<Secret<serde_json::Value>>,
 }
 
@@ -477,8 +477,10 @@ pub struct ListProfilesForUserInOrgAndMerchantAccountResponse {
     pub profile_name: String,
 }
 
-#[derive(Debug, serde::Serialize)]
+#[derive(Debug, serde::Serialize, ToSchema)]
 pub struct IssueEmbeddedTokenResponse {
+    /// The token for the embedded dashboard of the profile
+    #[schema(value_type = String)]
     pub token: Secret<String>,
 }
 
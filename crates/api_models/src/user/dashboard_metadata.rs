@@ -251,6 +251,8 @@ pub struct PaymentListFilterConstraintsV1 {
     pub merchant_order_reference_id: Option<String>,
     pub card_discovery: Option<Vec<enums::CardDiscovery>>,
     pub customer_email: Option<pii::Email>,
+    pub customer_phone: Option<Secret<String>>,
+    pub attribution_tags: Option<common_types::payments::AttributionTags>,
 }
 
 #[cfg(feature = "v1")]
@@ -351,6 +353,8 @@ pub struct PaymentAdvancedViewFilterConstraints {
     pub merchant_order_reference_id: Option<String>,
     pub card_discovery: Option<Vec<enums::CardDiscovery>>,
     pub customer_email: Option<pii::Email>,
+    pub customer_phone: Option<Secret<String>>,
+    pub attribution_tags: Option<common_types::payments::AttributionTags>,
 }
 
 #[cfg(feature = "v1")]
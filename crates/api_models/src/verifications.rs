@@ -1,4 +1,5 @@
 use common_utils::id_type;
+use utoipa::ToSchema;
 
 /// The request body for verification of merchant (everything except domain_names are prefilled)
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
@@ -11,17 +12,22 @@ pub struct ApplepayMerchantVerificationConfigs {
 }
 
 /// The derivation point for domain names from request body
-#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
 #[serde(rename_all = "snake_case")]
 pub struct ApplepayMerchantVerificationRequest {
+    /// The domain names to be verified with Apple Pay
+    #[schema(example = json!(["example.com"]))]
     pub domain_names: Vec<String>,
+    /// The identifier of the Apple Pay merchant connector account
+    #[schema(value_type = String, example = "mca_5apGeP94tMts6rg3U3kR")]
     pub merchant_connector_account_id: id_type::MerchantConnectorAccountId,
 }
 
 /// Response to be sent for the verify/applepay api
-#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
 #[serde(rename_all = "snake_case")]
 pub struct ApplepayMerchantResponse {
+    /// The status of the verification
     pub status_message: String,
 }
 
@@ -33,8 +39,9 @@ pub struct ApplepayGetVerifiedDomainsParam {
     pub merchant_connector_account_id: id_type::MerchantConnectorAccountId,
 }
 /// Response to be sent for derivation of the already verified domains
-#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
 #[serde(rename_all = "snake_case")]
 pub struct ApplepayVerifiedDomainsResponse {
+    /// The domain names verified with Apple Pay
     pub verified_domains: Vec<String>,
 }
@@ -484,6 +484,11 @@ pub enum OutgoingWebhookContent {
     PayoutDetails(Box<payouts::PayoutCreateResponse>),
     #[schema(value_type = ConfirmSubscriptionResponse, title = "ConfirmSubscriptionResponse")]
     SubscriptionDetails(Box<subscription::ConfirmSubscriptionResponse>),
+    #[schema(
+        value_type = QuotaThresholdReachedResponse,
+        title = "QuotaThresholdReachedResponse"
+    )]
+    QuotaUsageDetails(Box<crate::merchant_quota::QuotaThresholdReachedResponse>),
 }
 
 #[derive(Debug, Clone, Serialize, ToSchema)]
@@ -501,6 +506,11 @@ pub enum OutgoingWebhookContent {
     #[cfg(feature = "payouts")]
     #[schema(value_type = PayoutCreateResponse, title = "PayoutCreateResponse")]
     PayoutDetails(Box<payouts::PayoutCreateResponse>),
+    #[schema(
+        value_type = RecurringPaymentRetryResponse,
+        title = "RecurringPaymentRetryResponse"
+    )]
+    RecurringPaymentRetryDetails(Box<payments::RecurringPaymentRetryResponse>),
 }
 
 #[derive(Debug, Clone, Serialize)]
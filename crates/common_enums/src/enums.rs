@@ -135,6 +135,7 @@ impl From<std::io::Error> for ApplicationError {
     serde::Serialize,
     SmithyModel,
     strum::Display,
+    strum::EnumIter,
     strum::EnumString,
     ToSchema,
 )]
@@ -1834,6 +1835,9 @@ impl EventClass {
                 EventType::PaymentExpired,
                 EventType::ActionRequired,
                 EventType::SurchargePaymentSucceeded,
+                EventType::RecurringPaymentRetryScheduled,
+                EventType::RecurringPaymentRecoveryExhausted,
+                EventType::QuotaThresholdReached,
             ]),
             Self::Refunds => HashSet::from([
                 EventType::RefundSucceeded,
@@ -1923,6 +1927,13 @@ pub enum EventType {
     InvoicePaid,
     SurchargePaymentSucceeded,
     SurchargeRefundSucceeded,
+    /// A recurring payment failed and another attempt is scheduled by the retry engine
+    RecurringPaymentRetryScheduled,
+    /// A recurring payment failed and the retry engine will not attempt it again
+    RecurringPaymentRecoveryExhausted,
+    /// The payments created by the merchant in the current period reached the warning threshold
+    /// of the quota imposed by the platform
+    QuotaThresholdReached,
 }
 
 /// Maps primary payment/refund events to their corresponding surcharge events
@@ -3258,6 +3269,34 @@ pub enum MandateStatus {
     Revoked,
 }
 
+/// Indicates where the customer acceptance recorded on a mandate was captured.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    Default,
+    serde::Deserialize,
+    serde::Serialize,
+    SmithyModel,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+#[smithy(namespace = "com.hyperswitch.smithy.types")]
+pub enum CustomerAcceptanceSource {
+    /// Acceptance provided in the payment confirm request
+    #[default]
+    Confirm,
+    /// Acceptance captured when the customer completed the redirection, for payment methods
+    /// where the mandate is accepted on the bank page
+    RedirectCompletion,
+}
+
 /// Indicates the card network.
 #[derive(
     Clone,
@@ -8930,6 +8969,21 @@ pub enum PaymentSource {
     ExternalAuthenticator,
 }
 
+/// The origin of a payment status update made from a connector payload
+#[derive(Clone, Copy, Debug, Eq, PartialEq, serde::Serialize, strum::Display)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum StatusUpdateSource {
+    /// Response of a connector call made while serving a merchant API request
+    Api,
+    /// Connector payload received through an incoming webhook
+    Webhook,
+    /// Response of a connector sync call
+    Sync,
+    /// Response of a connector call made on completion of a customer redirection
+    Redirect,
+}
+
 #[derive(Default, Debug, Clone, serde::Deserialize, serde::Serialize, strum::EnumString)]
 pub enum BrowserName {
     #[default]
@@ -9427,6 +9481,7 @@ pub enum PermissionGroup {
     ReconTransactionsManage,
     ReconRulesView,
     ReconRulesManage,
+    CustomerSearchView,
 }
 
 #[derive(
@@ -9448,6 +9503,7 @@ pub enum ParentGroup {
     ReconExceptions,
     ReconTransactions,
     ReconRules,
+    CustomerSearch,
 }
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
@@ -9480,6 +9536,7 @@ pub enum Resource {
     ReconTransaction,
     ReconRule,
     SuperpositionConfig,
+    CustomerSearch,
 }
 
 #[derive(
@@ -10592,6 +10649,8 @@ pub enum ProcessTrackerRunner {
     InvoiceSyncflow,
     PayoutSyncWorkFlow,
     BatchBlocklistUpload,
+    MerchantTakeoutWorkflow,
+    CustomerContactRehashWorkflow,
 }
 
 #[derive(
@@ -11267,3 +11326,31 @@ pub enum BatchBlocklistJobStatus {
     Completed,
     Failed,
 }
+
+/// Result of matching a part of the billing address against the address on the records of the
+/// card issuer, as part of the address verification (AVS) done by the connector
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum AvsMatchResult {
+    /// The address part matches the records of the issuer
+    Match,
+    /// The address part does not match the records of the issuer
+    NoMatch,
+    /// The issuer could not verify the address part
+    Unavailable,
+    /// The address part was not verified
+    NotChecked,
+}
@@ -25,3 +25,9 @@ pub const CUSTOMER_LIST_UPPER_LIMIT: u16 = 100;
 
 /// Customer List Default Limit
 pub const CUSTOMER_LIST_DEFAULT_LIMIT: u16 = 20;
+
+/// Maximum number of attribution tags on a payment
+pub const MAX_ATTRIBUTION_TAGS: usize = 10;
+
+/// Maximum length of an attribution tag key or value
+pub const MAX_ATTRIBUTION_TAG_LENGTH: usize = 64;
@@ -1,6 +1,6 @@
 //! Payment related types
 use std::{
-    collections::{HashMap, HashSet},
+    collections::{BTreeMap, HashMap, HashSet},
     num::NonZeroU8,
 };
 
@@ -29,7 +29,7 @@ use time::PrimitiveDateTime;
 use utoipa::ToSchema;
 
 use crate::{
-    consts::PERCENTAGE_BASE,
+    consts::{MAX_ATTRIBUTION_TAGS, MAX_ATTRIBUTION_TAG_LENGTH, PERCENTAGE_BASE},
     domain::{AdyenSplitData, PostCaptureVoidData, XenditSplitSubMerchantData},
 };
 #[derive(
@@ -899,7 +899,7 @@ impl ApplePayPredecryptData {
 }
 
 /// type of action that needs to taken after consuming recovery payload
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum RecoveryAction {
     /// Stops the process tracker and update the payment intent.
@@ -916,6 +916,49 @@ pub enum RecoveryAction {
     InvalidAction,
 }
 
+/// Street and postal code results of the address verification (AVS) done by the connector. These
+/// are stored in the payment checks of the card, alongside the raw values returned by the connector
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AvsMatchDetails {
+    /// Result of matching the street of the billing address
+    pub avs_street_match: Option<enums::AvsMatchResult>,
+    /// Result of matching the postal code of the billing address
+    pub avs_postal_code_match: Option<enums::AvsMatchResult>,
+}
+
+impl AvsMatchDetails {
+    /// Get the AVS results stored in the payment checks, if the connector provided any of them
+    pub fn from_payment_checks(payment_checks: &serde_json::Value) -> Option<Self> {
+        serde_json::from_value::<Self>(payment_checks.clone())
+            .ok()
+            .filter(|details| {
+                details.avs_street_match.is_some() || details.avs_postal_code_match.is_some()
+            })
+    }
+
+    /// Store the AVS results in the payment checks, retaining the values returned by the connector
+    pub fn add_to_payment_checks(self, payment_checks: serde_json::Value) -> serde_json::Value {
+        match payment_checks {
+            serde_json::Value::Object(mut checks) => {
+                if let Some(street_match) = self.avs_street_match {
+                    checks.insert(
+                        "avs_street_match".to_string(),
+                        serde_json::Value::String(street_match.to_string()),
+                    );
+                }
+                if let Some(postal_code_match) = self.avs_postal_code_match {
+                    checks.insert(
+                        "avs_postal_code_match".to_string(),
+                        serde_json::Value::String(postal_code_match.to_string()),
+                    );
+                }
+                serde_json::Value::Object(checks)
+            }
+            payment_checks => payment_checks,
+        }
+    }
+}
+
 /// Billing Descriptor information to be sent to the payment gateway
 #[derive(
     Serialize, Deserialize, Debug, Clone, PartialEq, Eq, AsExpression, FromSqlRow, ToSchema,
@@ -1011,6 +1054,18 @@ pub struct PaymentIntentStateMetadata {
     pub total_disputed_amount: Option<MinorUnit>,
     /// Post capture void response details
     pub post_capture_void: Option<PostCaptureVoidResponse>,
+    /// Details of the operator force-closing the payment, when it was closed without the connector
+    /// resolving it
+    pub force_close: Option<ForceCloseDetails>,
+}
+
+/// Details of a payment force-closed by an operator
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, utoipa::ToSchema)]
+pub struct ForceCloseDetails {
+    /// The reason given by the operator for force-closing the payment
+    pub reason: String,
+    /// Timestamp when the payment was force-closed
+    pub closed_at: PrimitiveDateTime,
 }
 
 /// Additional metadata for payment intent state containing refunded and disputed amounts
@@ -1094,6 +1149,20 @@ impl PaymentIntentStateMetadata {
         self
     }
 
+    /// Builder method to mark the payment as force-closed by an operator
+    pub fn set_force_close_details(mut self, reason: String) -> Self {
+        self.force_close = Some(ForceCloseDetails {
+            reason,
+            closed_at: date_time::now(),
+        });
+        self
+    }
+
+    /// Check if the payment was force-closed by an operator
+    pub fn is_force_closed(&self) -> bool {
+        self.force_close.is_some()
+    }
+
     /// Get the connector reference ID for post capture void transaction if it exists
     pub fn get_connector_post_capture_void_transaction_id(&self) -> Option<String> {
         self.post_capture_void
@@ -1539,6 +1608,61 @@ pub struct InstallmentOption {
 pub struct InstallmentOptions(pub Vec<InstallmentOption>);
 impl_to_sql_from_sql_json!(InstallmentOptions);
 
+/// Cost attribution tags of a payment, mapping a tag key to its value.
+///
+/// Unlike metadata, the number of tags is limited and keys and values are restricted to
+/// alphanumeric characters, `_`, `-`, `.` and `:`, so that tags can be indexed and used to filter
+/// and group payments.
+#[derive(
+    Debug, Clone, serde::Serialize, serde::Deserialize, PartialEq, Eq, FromSqlRow, AsExpression,
+)]
+#[diesel(sql_type = Jsonb)]
+#[serde(try_from = "BTreeMap<String, String>")]
+pub struct AttributionTags(BTreeMap<String, String>);
+impl_to_sql_from_sql_json!(AttributionTags);
+
+impl AttributionTags {
+    /// Returns the tags as a map of tag key to value.
+    pub fn as_map(&self) -> &BTreeMap<String, String> {
+        &self.0
+    }
+
+    fn is_valid_tag(tag: &str) -> bool {
+        !tag.is_empty()
+            && tag.len() <= MAX_ATTRIBUTION_TAG_LENGTH
+            && tag
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.' | ':'))
+    }
+}
+
+impl TryFrom<BTreeMap<String, String>> for AttributionTags {
+    type Error = Report<errors::ValidationError>;
+
+    fn try_from(tags: BTreeMap<String, String>) -> Result<Self, errors::ValidationError> {
+        (tags.len() <= MAX_ATTRIBUTION_TAGS)
+            .then_some(())
+            .ok_or_else(|| {
+                error_stack::report!(errors::ValidationError::InvalidValue {
+                    message: format!(
+                        "attribution_tags must not contain more than {MAX_ATTRIBUTION_TAGS} tags."
+                    ),
+                })
+            })?;
+        if let Some((key, _)) = tags
+            .iter()
+            .find(|(key, value)| !Self::is_valid_tag(key) || !Self::is_valid_tag(value))
+        {
+            return Err(error_stack::report!(errors::ValidationError::InvalidValue {
+                message: format!(
+                    "attribution_tags key and value of `{key}` must be 1 to {MAX_ATTRIBUTION_TAG_LENGTH} characters long and contain only alphanumeric characters, `_`, `-`, `.` and `:`."
+                ),
+            }));
+        }
+        Ok(Self(tags))
+    }
+}
+
 /// Installment selection made by the customer during payment confirmation.
 #[derive(
     Debug,
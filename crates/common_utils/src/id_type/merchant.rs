@@ -125,6 +125,29 @@ impl MerchantId {
         format!("guard_blocklist_for_{}", self.get_string_repr())
     }
 
+    /// get_void_on_order_cancellation_key
+    pub fn get_void_on_order_cancellation_key(&self) -> String {
+        format!("void_on_order_cancellation_{}", self.get_string_repr())
+    }
+
+    /// get_avs_decision_rules_key
+    pub fn get_avs_decision_rules_key(&self) -> String {
+        format!("avs_decision_rules_{}", self.get_string_repr())
+    }
+
+    /// get_merchant_quota_key
+    pub fn get_merchant_quota_key(&self) -> String {
+        format!("merchant_quota_{}", self.get_string_repr())
+    }
+
+    /// get_merchant_quota_usage_key
+    pub fn get_merchant_quota_usage_key(&self, period_start: i64) -> String {
+        format!(
+            "merchant_quota_usage_{}_{period_start}",
+            self.get_string_repr()
+        )
+    }
+
     /// get_pre_routing_disabled_pm_pmt_key
     pub fn get_pre_routing_disabled_pm_pmt_key(&self) -> String {
         format!("pre_routing_disabled_pm_pmt_for_{}", self.get_string_repr())
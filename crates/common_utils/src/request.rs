@@ -80,6 +80,9 @@ pub struct Request {
     pub body: Option<RequestContent>,
     pub ca_certificate: Option<Secret<String>>,
     pub query_params: Option<serde_json::Value>,
+    /// Names of the headers to be sent first, in this order, for connectors which are sensitive
+    /// to the order of the request headers
+    pub header_order: Option<Vec<String>>,
 }
 
 impl std::fmt::Debug for RequestContent {
@@ -133,6 +136,7 @@ impl Request {
             body: None,
             ca_certificate: None,
             query_params: None,
+            header_order: None,
         }
     }
 
@@ -155,6 +159,32 @@ impl Request {
     pub fn add_certificate_key(&mut self, certificate_key: Option<Secret<String>>) {
         self.certificate = certificate_key;
     }
+
+    pub fn set_header_order(&mut self, header_order: Vec<String>) {
+        self.header_order = Some(header_order);
+    }
+
+    /// Returns the headers in the order in which they are to be sent. When a header order is set,
+    /// the listed headers are returned first in that order, followed by the remaining headers
+    /// sorted by name, so that the order is the same for every request. Otherwise the headers are
+    /// returned in an unspecified order.
+    pub fn get_ordered_headers(&self) -> Vec<(String, Maskable<String>)> {
+        let mut headers = self.headers.iter().cloned().collect::<Vec<_>>();
+        if let Some(header_order) = &self.header_order {
+            headers.sort_by_cached_key(|(name, value)| {
+                let position = header_order
+                    .iter()
+                    .position(|ordered_name| ordered_name.eq_ignore_ascii_case(name))
+                    .unwrap_or(header_order.len());
+                (
+                    position,
+                    name.to_ascii_lowercase(),
+                    value.clone().into_inner(),
+                )
+            });
+        }
+        headers
+    }
 }
 
 #[derive(Debug)]
@@ -248,6 +278,7 @@ impl RequestBuilder {
             body: self.body,
             ca_certificate: self.ca_certificate,
             query_params: self.query_params,
+            header_order: None,
         }
     }
 }
@@ -257,3 +288,65 @@ impl Default for RequestBuilder {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn build_request(headers: Vec<(&str, &str)>) -> Request {
+        let mut request = RequestBuilder::new()
+            .method(Method::Post)
+            .url("https://connector.example.com/payments")
+            .headers(
+                headers
+                    .into_iter()
+                    .map(|(name, value)| (name.to_string(), value.to_string().into()))
+                    .collect(),
+            )
+            .build();
+        request.set_header_order(vec![
+            "Date".to_string(),
+            "Digest".to_string(),
+            "Signature".to_string(),
+        ]);
+        request
+    }
+
+    fn header_names(request: &Request) -> Vec<String> {
+        request
+            .get_ordered_headers()
+            .into_iter()
+            .map(|(name, _)| name)
+            .collect()
+    }
+
+    #[test]
+    fn test_header_order_is_stable_across_builds() {
+        let first = build_request(vec![
+            ("x-request-id", "req_1"),
+            ("signature", "sig"),
+            ("content-type", "application/json"),
+            ("date", "Mon, 01 Jan 2024 00:00:00 GMT"),
+            ("digest", "SHA-256=abc"),
+        ]);
+        let second = build_request(vec![
+            ("digest", "SHA-256=abc"),
+            ("content-type", "application/json"),
+            ("date", "Mon, 01 Jan 2024 00:00:00 GMT"),
+            ("x-request-id", "req_1"),
+            ("signature", "sig"),
+        ]);
+
+        assert_eq!(header_names(&first), header_names(&second));
+        assert_eq!(
+            header_names(&first),
+            vec![
+                "date",
+                "digest",
+                "signature",
+                "content-type",
+                "x-request-id"
+            ]
+        );
+    }
+}
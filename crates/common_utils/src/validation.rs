@@ -23,6 +23,85 @@ pub fn validate_phone_number(phone_number: &str) -> Result<(), ValidationError>
     Ok(())
 }
 
+/// Phone number prepared for submission to a connector
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NormalizedPhoneNumber {
+    /// Phone number in the E.164 format, including the country calling code
+    E164(String),
+    /// Phone number with the formatting stripped, as it could not be interpreted for a country
+    Sanitized(String),
+}
+
+impl NormalizedPhoneNumber {
+    /// Returns the phone number, whether or not it could be normalized to the E.164 format
+    pub fn into_inner(self) -> String {
+        match self {
+            Self::E164(phone_number) | Self::Sanitized(phone_number) => phone_number,
+        }
+    }
+}
+
+/// Normalizes a phone number to the E.164 format.
+///
+/// Numbers in the national format are interpreted using the country calling code if provided,
+/// otherwise using the country of the address. Numbers that cannot be interpreted are returned
+/// with spaces, separators and other formatting stripped.
+pub fn normalize_phone_number(
+    phone_number: &str,
+    country_calling_code: Option<&str>,
+    country: Option<common_enums::CountryAlpha2>,
+) -> NormalizedPhoneNumber {
+    let phone_number = phone_number.trim();
+    let digits = phone_number
+        .chars()
+        .filter(char::is_ascii_digit)
+        .collect::<String>();
+    let (is_international, digits) = if phone_number.starts_with('+') {
+        (true, digits.as_str())
+    } else if let Some(digits) = digits.strip_prefix("00") {
+        (true, digits)
+    } else {
+        (false, digits.as_str())
+    };
+    let calling_code = country_calling_code
+        .map(|code| {
+            code.chars()
+                .filter(char::is_ascii_digit)
+                .collect::<String>()
+        })
+        .filter(|code| !code.is_empty());
+    let country_id = country.and_then(|country| country.to_string().parse().ok());
+
+    let candidates = match (is_international, calling_code, country_id) {
+        (true, _, _) => vec![(None, format!("+{digits}"))],
+        // The national trunk prefix is not dialled along with the country calling code
+        (false, Some(calling_code), country_id) => vec![
+            (country_id, format!("+{calling_code}{digits}")),
+            (
+                country_id,
+                format!("+{calling_code}{}", digits.trim_start_matches('0')),
+            ),
+        ],
+        (false, None, Some(country_id)) => vec![(Some(country_id), digits.to_string())],
+        (false, None, None) => Vec::new(),
+    };
+
+    candidates
+        .into_iter()
+        .filter_map(|(country_id, candidate)| phonenumber::parse(country_id, candidate).ok())
+        .find(phonenumber::is_valid)
+        .map(|parsed| {
+            NormalizedPhoneNumber::E164(parsed.format().mode(phonenumber::Mode::E164).to_string())
+        })
+        .unwrap_or_else(|| {
+            NormalizedPhoneNumber::Sanitized(if is_international {
+                format!("+{digits}")
+            } else {
+                digits.to_string()
+            })
+        })
+}
+
 /// Performs a simple validation against a provided email address.
 pub fn validate_email(email: &str) -> CustomResult<(), ValidationError> {
     #[deny(clippy::invalid_regex)]
@@ -206,9 +285,10 @@ impl<K: ValidateXSSOrSQLi, V: ValidateXSSOrSQLi> ValidateXSSOrSQLi
 
 #[cfg(test)]
 mod tests {
+    use common_enums::CountryAlpha2;
     use fake::{faker::internet::en::SafeEmail, Fake};
     use proptest::{
-        prop_assert,
+        collection, prop_assert, prop_assert_eq, sample,
         strategy::{Just, NewTree, Strategy},
         test_runner::TestRunner,
     };
@@ -254,6 +334,47 @@ mod tests {
         assert!(res.is_err());
     }
 
+    /// Example numbers of a country as the calling code and the national significant number,
+    /// along with whether the national format of the number starts with the trunk prefix `0`
+    const PHONE_NUMBER_SAMPLES: [(CountryAlpha2, &str, &str, bool); 5] = [
+        (CountryAlpha2::US, "1", "2015550123", false),
+        (CountryAlpha2::GB, "44", "7400123456", true),
+        (CountryAlpha2::DE, "49", "15123456789", true),
+        (CountryAlpha2::FR, "33", "612345678", true),
+        (CountryAlpha2::IN, "91", "8123456789", true),
+    ];
+
+    fn format_with_separators(phone_number: &str, separators: &[&str]) -> String {
+        phone_number
+            .chars()
+            .zip(separators.iter().chain(std::iter::repeat(&"")))
+            .map(|(character, separator)| format!("{character}{separator}"))
+            .collect()
+    }
+
+    #[test_case("(201) 555-0123", None, None, "2015550123" ; "national number without country")]
+    #[test_case("+1 (201) 555", None, None, "+1201555" ; "incomplete international number")]
+    #[test_case("12 34", None, Some(CountryAlpha2::GB), "1234" ; "invalid national number")]
+    fn test_normalize_phone_number_strips_formatting(
+        phone_number: &str,
+        country_calling_code: Option<&str>,
+        country: Option<CountryAlpha2>,
+        expected: &str,
+    ) {
+        assert_eq!(
+            normalize_phone_number(phone_number, country_calling_code, country),
+            NormalizedPhoneNumber::Sanitized(expected.to_string())
+        );
+    }
+
+    #[test]
+    fn test_normalize_phone_number_prefers_calling_code_over_address_country() {
+        assert_eq!(
+            normalize_phone_number("07400 123456", Some("+44"), Some(CountryAlpha2::US)),
+            NormalizedPhoneNumber::E164("+447400123456".to_string())
+        );
+    }
+
     proptest::proptest! {
         /// Example of unit test
         #[test]
@@ -271,6 +392,44 @@ mod tests {
         fn proptest_invalid_email(email in "[.+]@(.+)") {
             prop_assert!(validate_email(&email).is_err());
         }
+
+        #[test]
+        fn proptest_normalize_formatted_phone_number(
+            (country, calling_code, number, has_trunk_prefix) in
+                sample::select(PHONE_NUMBER_SAMPLES.to_vec()),
+            separators in collection::vec(sample::select(vec!["", " ", "-", ".", "(", ")"]), 0..16),
+        ) {
+            let expected = NormalizedPhoneNumber::E164(format!("+{calling_code}{number}"));
+            let national_number = if has_trunk_prefix {
+                format!("0{number}")
+            } else {
+                number.to_string()
+            };
+            let national_number = format_with_separators(&national_number, &separators);
+            let international_number =
+                format_with_separators(&format!("+{calling_code}{number}"), &separators);
+
+            prop_assert_eq!(
+                normalize_phone_number(&national_number, None, Some(country)),
+                expected.clone()
+            );
+            prop_assert_eq!(
+                normalize_phone_number(
+                    &national_number,
+                    Some(&format!("+{calling_code}")),
+                    Some(country)
+                ),
+                expected.clone()
+            );
+            prop_assert_eq!(
+                normalize_phone_number(&international_number, None, None),
+                expected.clone()
+            );
+            prop_assert_eq!(
+                normalize_phone_number(&format!("00{calling_code}{number}"), None, None),
+                expected
+            );
+        }
     }
 
     #[test]
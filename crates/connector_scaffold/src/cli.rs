@@ -0,0 +1,115 @@
+use std::path::PathBuf;
+
+/// Utility to generate the skeleton of a new connector integration, along with its test file, and
+/// wire it into the connector enums, registries and configuration files.
+#[derive(clap::Parser, Debug)]
+#[command(arg_required_else_help = true)]
+pub(crate) struct Args {
+    /// Name of the connector in snake case, for example `acme_pay`.
+    #[arg(short, long)]
+    pub(crate) name: String,
+
+    /// Authentication type used by the connector.
+    #[arg(value_enum, short, long, default_value = "header_key")]
+    pub(crate) auth: AuthType,
+
+    /// Comma separated list of flows to be implemented. The authorize flow is always implemented.
+    #[arg(
+        value_enum,
+        short,
+        long,
+        value_delimiter = ',',
+        default_value = "authorize,capture,void,psync,refund,rsync"
+    )]
+    pub(crate) flows: Vec<Flow>,
+
+    /// Base URL of the connector. Defaults to the address of the local mock server used by the
+    /// connector tests, so that the generated smoke tests pass out of the box.
+    #[arg(short, long, default_value = "http://127.0.0.1:9090/")]
+    pub(crate) base_url: String,
+
+    /// Root directory of the repository.
+    #[arg(short, long, value_name = "DIR", default_value = ".")]
+    pub(crate) root: PathBuf,
+}
+
+/// Authentication type used by the connector, mapped to the corresponding `ConnectorAuthType`
+/// variant.
+#[allow(clippy::enum_variant_names)]
+#[derive(clap::ValueEnum, Clone, Copy, Debug, Eq, PartialEq)]
+#[value(rename_all = "snake_case")]
+pub(crate) enum AuthType {
+    /// API key sent in the `Authorization` header.
+    HeaderKey,
+    /// API key and a secondary key, sent as basic authentication credentials.
+    BodyKey,
+    /// API key, secondary key and API secret used for signing requests.
+    SignatureKey,
+}
+
+impl AuthType {
+    /// Name of the `ConnectorAuthType` variant and the connector auth TOML table.
+    pub(crate) fn variant(self) -> &'static str {
+        match self {
+            Self::HeaderKey => "HeaderKey",
+            Self::BodyKey => "BodyKey",
+            Self::SignatureKey => "SignatureKey",
+        }
+    }
+
+    /// Name of the template section rendered for this authentication type.
+    pub(crate) fn section(self) -> &'static str {
+        match self {
+            Self::HeaderKey => "header_key",
+            Self::BodyKey => "body_key",
+            Self::SignatureKey => "signature_key",
+        }
+    }
+
+    /// Placeholder credentials, as `(key, value)` pairs, written to the configuration files.
+    pub(crate) fn placeholder_credentials(self) -> &'static [(&'static str, &'static str)] {
+        match self {
+            Self::HeaderKey => &[("api_key", "API Key")],
+            Self::BodyKey => &[("api_key", "API Key"), ("key1", "Key1")],
+            Self::SignatureKey => &[
+                ("api_key", "API Key"),
+                ("key1", "Key1"),
+                ("api_secret", "API Secret"),
+            ],
+        }
+    }
+}
+
+/// A connector flow for which the integration is generated.
+#[derive(clap::ValueEnum, Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub(crate) enum Flow {
+    Authorize,
+    Capture,
+    Void,
+    Psync,
+    Refund,
+    Rsync,
+}
+
+impl Flow {
+    pub(crate) const ALL: [Self; 6] = [
+        Self::Authorize,
+        Self::Capture,
+        Self::Void,
+        Self::Psync,
+        Self::Refund,
+        Self::Rsync,
+    ];
+
+    /// Name of the template section rendered when this flow is implemented.
+    pub(crate) fn section(self) -> &'static str {
+        match self {
+            Self::Authorize => "authorize",
+            Self::Capture => "capture",
+            Self::Void => "void",
+            Self::Psync => "psync",
+            Self::Refund => "refund",
+            Self::Rsync => "rsync",
+        }
+    }
+}
@@ -0,0 +1,129 @@
+use anyhow::ensure;
+
+use crate::cli::{AuthType, Flow};
+
+/// Name of the connector in the different cases used across the codebase
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) struct ConnectorName {
+    /// Snake case name, used for modules, configuration keys and serialized enum values
+    pub(crate) snake_case: String,
+    /// Pascal case name, used for types and enum variants
+    pub(crate) pascal_case: String,
+    /// Screaming snake case name, used for statics
+    pub(crate) screaming_snake_case: String,
+}
+
+impl ConnectorName {
+    pub(crate) fn new(name: &str) -> anyhow::Result<Self> {
+        ensure!(
+            name.starts_with(|c: char| c.is_ascii_lowercase())
+                && name
+                    .chars()
+                    .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+                && !name.ends_with('_')
+                && !name.contains("__"),
+            "Connector name `{name}` must be in snake case, for example `acme_pay`"
+        );
+
+        let pascal_case = name
+            .split('_')
+            .map(|word| {
+                let mut chars = word.chars();
+                chars
+                    .next()
+                    .map(|first| first.to_ascii_uppercase().to_string() + chars.as_str())
+                    .unwrap_or_default()
+            })
+            .collect();
+
+        Ok(Self {
+            snake_case: name.to_owned(),
+            pascal_case,
+            screaming_snake_case: name.to_ascii_uppercase(),
+        })
+    }
+}
+
+/// Everything required to generate and wire a connector
+#[derive(Clone, Debug)]
+pub(crate) struct ConnectorScaffold {
+    pub(crate) name: ConnectorName,
+    pub(crate) auth: AuthType,
+    pub(crate) flows: Vec<Flow>,
+    pub(crate) base_url: String,
+}
+
+impl ConnectorScaffold {
+    pub(crate) fn new(
+        name: &str,
+        auth: AuthType,
+        flows: &[Flow],
+        base_url: &str,
+    ) -> anyhow::Result<Self> {
+        ensure!(
+            base_url.starts_with("http://") || base_url.starts_with("https://"),
+            "Base URL `{base_url}` must be an HTTP(S) URL"
+        );
+
+        // The authorize flow is always implemented, and flows are kept in a stable order
+        let mut flows = flows.to_vec();
+        flows.push(Flow::Authorize);
+        flows.sort();
+        flows.dedup();
+
+        let base_url = if base_url.ends_with('/') {
+            base_url.to_owned()
+        } else {
+            format!("{base_url}/")
+        };
+
+        Ok(Self {
+            name: ConnectorName::new(name)?,
+            auth,
+            flows,
+            base_url,
+        })
+    }
+
+    pub(crate) fn implements(&self, flow: Flow) -> bool {
+        self.flows.contains(&flow)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn connector_name_is_converted_to_all_cases() {
+        let name = ConnectorName::new("acme_pay2").unwrap();
+
+        assert_eq!(name.snake_case, "acme_pay2");
+        assert_eq!(name.pascal_case, "AcmePay2");
+        assert_eq!(name.screaming_snake_case, "ACME_PAY2");
+    }
+
+    #[test]
+    fn invalid_connector_names_are_rejected() {
+        for name in ["", "AcmePay", "acme-pay", "2acme", "acme_", "acme__pay"] {
+            assert!(ConnectorName::new(name).is_err(), "{name}");
+        }
+    }
+
+    #[test]
+    fn authorize_flow_is_always_implemented() {
+        let scaffold = ConnectorScaffold::new(
+            "acme",
+            AuthType::HeaderKey,
+            &[Flow::Refund, Flow::Capture, Flow::Refund],
+            "https://sandbox.acme.com",
+        )
+        .unwrap();
+
+        assert_eq!(
+            scaffold.flows,
+            vec![Flow::Authorize, Flow::Capture, Flow::Refund]
+        );
+        assert_eq!(scaffold.base_url, "https://sandbox.acme.com/");
+    }
+}
@@ -0,0 +1,82 @@
+#![allow(clippy::print_stdout)]
+
+mod cli;
+mod connector;
+mod templates;
+mod wiring;
+
+use anyhow::{ensure, Context};
+
+use crate::connector::ConnectorScaffold;
+
+fn main() -> anyhow::Result<()> {
+    let args = <cli::Args as clap::Parser>::parse();
+    let scaffold = ConnectorScaffold::new(&args.name, args.auth, &args.flows, &args.base_url)?;
+    let connector = scaffold.name.snake_case.as_str();
+
+    ensure!(
+        args.root
+            .join("crates/hyperswitch_connectors/src/connectors.rs")
+            .is_file(),
+        "`{}` is not the root of the repository",
+        args.root.display()
+    );
+
+    let generated_files = templates::render_connector_files(&scaffold)?;
+    for (path, _) in &generated_files {
+        ensure!(
+            !args.root.join(path).exists(),
+            "`{}` already exists, connector `{connector}` may have already been added",
+            path.display()
+        );
+    }
+
+    // Compute all the edits before writing anything, so that the repository is left untouched if
+    // any of the files cannot be edited
+    let edited_files = wiring::connector_edits(&scaffold)
+        .into_iter()
+        .map(|file| {
+            let path = args.root.join(file.path);
+            let contents = std::fs::read_to_string(&path)
+                .with_context(|| format!("Failed to read `{}`", file.path))?;
+            let edited =
+                wiring::apply_edits(file.path, &contents, &scaffold.name.snake_case, &file.edits)
+                    .with_context(|| format!("Failed to wire connector into `{}`", file.path))?;
+            Ok((path, edited))
+        })
+        .collect::<anyhow::Result<Vec<_>>>()?;
+
+    for (path, contents) in generated_files {
+        let path = args.root.join(path);
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)
+                .with_context(|| format!("Failed to create `{}`", parent.display()))?;
+        }
+        std::fs::write(&path, contents)
+            .with_context(|| format!("Failed to write `{}`", path.display()))?;
+        println!("Created {}", path.display());
+    }
+
+    for (path, contents) in edited_files {
+        std::fs::write(&path, contents)
+            .with_context(|| format!("Failed to write `{}`", path.display()))?;
+        println!("Updated {}", path.display());
+    }
+
+    println!(
+        "
+Connector `{connector}` has been generated. Next steps:
+
+1. Format the code and check that it compiles:
+       cargo +nightly fmt --all
+       cargo check --package router
+2. Run the smoke tests against the local mock server:
+       export CONNECTOR_AUTH_FILE_PATH=\"$(pwd)/crates/router/tests/connectors/sample_auth.toml\"
+       cargo test --package router --test connectors -- {connector}
+3. Implement the TODOs in `crates/hyperswitch_connectors/src/connectors/{connector}.rs` and
+   `crates/hyperswitch_connectors/src/connectors/{connector}/transformers.rs`, and replace the base
+   URL and placeholder credentials in the configuration files with the connector's own."
+    );
+
+    Ok(())
+}
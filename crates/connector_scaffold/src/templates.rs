@@ -0,0 +1,214 @@
+//! Rendering of the connector source files from the embedded templates.
+//!
+//! Templates support two constructs:
+//!
+//! - `{{variable}}` placeholders, replaced by the value of the variable.
+//! - Sections, delimited by `{{#section}}` (or `{{^section}}` for an inverted section) and
+//!   `{{/section}}` on lines of their own. The lines of a section are only rendered if the section
+//!   is enabled (or disabled, for an inverted section). Sections may be nested.
+
+use std::{collections::HashSet, path::PathBuf};
+
+use anyhow::{bail, ensure, Context};
+
+use crate::{cli::Flow, connector::ConnectorScaffold};
+
+const CONNECTOR_TEMPLATE: &str = include_str!("../templates/connector.rs.tmpl");
+const TRANSFORMERS_TEMPLATE: &str = include_str!("../templates/transformers.rs.tmpl");
+const TEST_TEMPLATE: &str = include_str!("../templates/test.rs.tmpl");
+
+/// Variables and enabled sections a template is rendered with
+#[derive(Debug, Default)]
+pub(crate) struct TemplateContext {
+    variables: Vec<(&'static str, String)>,
+    sections: HashSet<&'static str>,
+}
+
+impl TemplateContext {
+    pub(crate) fn for_scaffold(scaffold: &ConnectorScaffold) -> Self {
+        let mut context = Self::default()
+            .variable("connector", &scaffold.name.snake_case)
+            .variable("Connector", &scaffold.name.pascal_case)
+            .variable("CONNECTOR", &scaffold.name.screaming_snake_case)
+            .variable("auth_type", scaffold.auth.variant())
+            .section(scaffold.auth.section());
+
+        for flow in Flow::ALL {
+            if scaffold.implements(flow) {
+                context = context.section(flow.section());
+            }
+        }
+
+        if scaffold.implements(Flow::Refund) || scaffold.implements(Flow::Rsync) {
+            context = context.section("refunds");
+        }
+
+        context
+    }
+
+    pub(crate) fn variable(mut self, name: &'static str, value: &str) -> Self {
+        self.variables.push((name, value.to_owned()));
+        self
+    }
+
+    pub(crate) fn section(mut self, name: &'static str) -> Self {
+        self.sections.insert(name);
+        self
+    }
+}
+
+/// Render a template with the given context
+pub(crate) fn render(template: &str, context: &TemplateContext) -> anyhow::Result<String> {
+    // Each entry records whether the lines of the section are rendered
+    let mut open_sections: Vec<(&str, bool)> = Vec::new();
+    let mut output = String::with_capacity(template.len());
+
+    for (index, line) in template.lines().enumerate() {
+        let line_number = index + 1;
+        let tag = line
+            .trim()
+            .strip_prefix("{{")
+            .and_then(|tag| tag.strip_suffix("}}"));
+
+        match tag.and_then(|tag| tag.split_at_checked(1)) {
+            Some(("#", name)) => {
+                open_sections.push((name, context.sections.contains(name)));
+                continue;
+            }
+            Some(("^", name)) => {
+                open_sections.push((name, !context.sections.contains(name)));
+                continue;
+            }
+            Some(("/", name)) => {
+                match open_sections.pop() {
+                    Some((open_name, _)) if open_name == name => {}
+                    Some((open_name, _)) => bail!(
+                        "Line {line_number}: section `{name}` closed while `{open_name}` is open"
+                    ),
+                    None => bail!("Line {line_number}: section `{name}` closed but never opened"),
+                }
+                continue;
+            }
+            _ => {}
+        }
+
+        if open_sections.iter().all(|(_, rendered)| *rendered) {
+            let rendered_line = context
+                .variables
+                .iter()
+                .fold(line.to_owned(), |line, (name, value)| {
+                    line.replace(&format!("{{{{{name}}}}}"), value)
+                });
+            ensure!(
+                !rendered_line.contains("{{"),
+                "Line {line_number}: unknown template variable in `{}`",
+                line.trim()
+            );
+            output.push_str(&rendered_line);
+            output.push('\n');
+        }
+    }
+
+    if let Some((name, _)) = open_sections.last() {
+        bail!("Section `{name}` is never closed");
+    }
+
+    Ok(output)
+}
+
+/// Render the connector module, its transformers and its test file, relative to the repository
+/// root
+pub(crate) fn render_connector_files(
+    scaffold: &ConnectorScaffold,
+) -> anyhow::Result<Vec<(PathBuf, String)>> {
+    let context = TemplateContext::for_scaffold(scaffold);
+    let connector = &scaffold.name.snake_case;
+    let connectors_dir = PathBuf::from("crates/hyperswitch_connectors/src/connectors");
+
+    Ok(vec![
+        (
+            connectors_dir.join(format!("{connector}.rs")),
+            render(CONNECTOR_TEMPLATE, &context).context("Failed to render connector module")?,
+        ),
+        (
+            connectors_dir.join(connector).join("transformers.rs"),
+            render(TRANSFORMERS_TEMPLATE, &context)
+                .context("Failed to render connector transformers")?,
+        ),
+        (
+            PathBuf::from("crates/router/tests/connectors").join(format!("{connector}.rs")),
+            render(TEST_TEMPLATE, &context).context("Failed to render connector tests")?,
+        ),
+    ])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::AuthType;
+
+    fn context() -> TemplateContext {
+        TemplateContext::default()
+            .variable("Connector", "AcmePay")
+            .section("capture")
+    }
+
+    #[test]
+    fn variables_are_replaced() {
+        let rendered = render("pub struct {{Connector}};\n", &context()).unwrap();
+
+        assert_eq!(rendered, "pub struct AcmePay;\n");
+    }
+
+    #[test]
+    fn sections_are_rendered_only_when_enabled() {
+        let template = "\
+a
+{{#capture}}
+b
+{{/capture}}
+{{^capture}}
+c
+{{/capture}}
+{{#refund}}
+d
+{{#capture}}
+e
+{{/capture}}
+{{/refund}}
+";
+
+        assert_eq!(render(template, &context()).unwrap(), "a\nb\n");
+    }
+
+    #[test]
+    fn unknown_variables_and_unbalanced_sections_are_rejected() {
+        assert!(render("{{connector}}\n", &context()).is_err());
+        assert!(render("{{#capture}}\n", &context()).is_err());
+        assert!(render("{{/capture}}\n", &context()).is_err());
+        assert!(render("{{#capture}}\n{{/refund}}\n", &context()).is_err());
+    }
+
+    #[test]
+    fn templates_render_for_every_flow_and_auth_type_combination() {
+        let auth_types = [
+            AuthType::HeaderKey,
+            AuthType::BodyKey,
+            AuthType::SignatureKey,
+        ];
+
+        for auth in auth_types {
+            for flows in [&[][..], &Flow::ALL[..], &[Flow::Capture, Flow::Refund][..]] {
+                let scaffold =
+                    ConnectorScaffold::new("acme_pay", auth, flows, "https://acme.com").unwrap();
+                let files = render_connector_files(&scaffold).unwrap();
+
+                assert_eq!(files.len(), 3);
+                for (path, contents) in files {
+                    assert!(!contents.contains("{{"), "{}", path.display());
+                    assert!(!contents.contains("}}}"), "{}", path.display());
+                }
+            }
+        }
+    }
+}
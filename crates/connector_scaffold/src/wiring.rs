@@ -0,0 +1,755 @@
+//! Wiring of the connector into the connector enums, registries and configuration files of the
+//! repository.
+//!
+//! Entries are inserted in alphabetical order where the surrounding entries allow it, and appended
+//! to the block otherwise. Formatting of the edited Rust files is left to `rustfmt`.
+
+use std::path::Path;
+
+use anyhow::{bail, ensure, Context};
+
+use crate::connector::ConnectorScaffold;
+
+/// An existing entry of a block, matched by the trimmed line starting with `prefix`, followed by
+/// an identifier and `suffix`
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct EntryPattern {
+    prefix: &'static str,
+    suffix: &'static str,
+}
+
+impl EntryPattern {
+    const fn new(prefix: &'static str, suffix: &'static str) -> Self {
+        Self { prefix, suffix }
+    }
+
+    /// Identifier of the entry on the line, if the line is an entry
+    fn key<'a>(&self, line: &'a str) -> Option<&'a str> {
+        let rest = line.trim().strip_prefix(self.prefix)?;
+        let key_length = rest
+            .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+            .unwrap_or(rest.len());
+        let (key, rest) = rest.split_at(key_length);
+
+        (!key.is_empty() && rest.starts_with(self.suffix)).then_some(key)
+    }
+}
+
+/// A single edit to a file
+#[derive(Debug)]
+pub(crate) enum Edit {
+    /// Insert the lines right after the line matching the last anchor, each anchor being searched
+    /// for after the line matching the previous one.
+    InsertAfter {
+        anchors: &'static [&'static str],
+        lines: Vec<String>,
+    },
+    /// Insert the lines among the entries of the block opened by the line matching the last
+    /// anchor, before the first entry sorting after the connector. The lines are indented like the
+    /// existing entries. Without anchors, the entries are searched for at the top level of the
+    /// file.
+    InsertSorted {
+        anchors: &'static [&'static str],
+        entry: EntryPattern,
+        lines: Vec<String>,
+    },
+    /// Same as [`Edit::InsertSorted`], for every block opened by a line starting with the prefix.
+    InsertSortedInEveryBlock {
+        block_prefix: &'static str,
+        entry: EntryPattern,
+        lines: Vec<String>,
+    },
+    /// Append the lines at the end of the file, separated from the existing contents by an empty
+    /// line.
+    Append { lines: Vec<String> },
+}
+
+/// Edits to a file, relative to the repository root
+#[derive(Debug)]
+pub(crate) struct FileEdits {
+    pub(crate) path: &'static str,
+    pub(crate) edits: Vec<Edit>,
+}
+
+/// Syntax of the edited file, which determines how the end of a block is found
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Syntax {
+    /// Blocks end with the bracket closing the one opened on the first line of the block
+    Rust,
+    /// Blocks (tables) end at the next table header
+    Toml,
+}
+
+impl Syntax {
+    fn of(path: &str) -> Self {
+        if Path::new(path).extension().is_some_and(|ext| ext == "toml") {
+            Self::Toml
+        } else {
+            Self::Rust
+        }
+    }
+}
+
+/// Edits required to wire the connector into the repository
+pub(crate) fn connector_edits(scaffold: &ConnectorScaffold) -> Vec<FileEdits> {
+    let connector = scaffold.name.snake_case.as_str();
+    let pascal = scaffold.name.pascal_case.as_str();
+    let auth_type = scaffold.auth.variant();
+    let base_url = scaffold.base_url.as_str();
+
+    let base_url_edit = || Edit::InsertSorted {
+        anchors: &["[connectors]"],
+        entry: EntryPattern::new("", ".base_url"),
+        lines: vec![format!(r#"{connector}.base_url = "{base_url}""#)],
+    };
+    let connector_toml_edit = || Edit::Append {
+        lines: [
+            format!("[{connector}]"),
+            format!("[{connector}.connector_auth.{auth_type}]"),
+        ]
+        .into_iter()
+        .chain(
+            scaffold
+                .auth
+                .placeholder_credentials()
+                .iter()
+                .map(|(key, value)| format!(r#"{key} = "{value}""#)),
+        )
+        .collect(),
+    };
+    let default_implementations_edit = || Edit::InsertSortedInEveryBlock {
+        block_prefix: "default_imp_for_",
+        entry: EntryPattern::new("connectors::", ","),
+        lines: vec![format!("connectors::{pascal},")],
+    };
+    let connector_mapping_edit = || {
+        Edit::InsertSorted {
+        anchors: &[
+            "match enums::Connector::from_str(connector_name) {",
+            "Ok(name) => match name {",
+        ],
+        entry: EntryPattern::new("enums::Connector::", " =>"),
+        lines: vec![format!(
+            "enums::Connector::{pascal} => Ok(ConnectorEnum::Old(Box::new(connector::{pascal}::new()))),"
+        )],
+    }
+    };
+
+    let mut file_edits = vec![
+        FileEdits {
+            path: "crates/hyperswitch_connectors/src/connectors.rs",
+            edits: vec![
+                Edit::InsertSorted {
+                    anchors: &[],
+                    entry: EntryPattern::new("pub mod ", ";"),
+                    lines: vec![format!("pub mod {connector};")],
+                },
+                Edit::InsertAfter {
+                    anchors: &["pub use self::{"],
+                    lines: vec![format!("    {connector}::{pascal},")],
+                },
+            ],
+        },
+        FileEdits {
+            path: "crates/hyperswitch_connectors/src/default_implementations.rs",
+            edits: vec![default_implementations_edit()],
+        },
+        FileEdits {
+            path: "crates/hyperswitch_connectors/src/default_implementations_v2.rs",
+            edits: vec![default_implementations_edit()],
+        },
+        FileEdits {
+            path: "crates/router/src/connector.rs",
+            edits: vec![Edit::InsertAfter {
+                anchors: &["pub use hyperswitch_connectors::connectors::{"],
+                lines: vec![format!("    {connector}, {connector}::{pascal},")],
+            }],
+        },
+        FileEdits {
+            path: "crates/hyperswitch_domain_models/src/connector_endpoints.rs",
+            edits: vec![Edit::InsertSorted {
+                anchors: &["pub struct Connectors {"],
+                entry: EntryPattern::new("pub ", ":"),
+                lines: vec![format!("pub {connector}: ConnectorParams,")],
+            }],
+        },
+        FileEdits {
+            path: "crates/common_enums/src/connector_enums.rs",
+            edits: vec![
+                Edit::InsertSorted {
+                    anchors: &["pub enum Connector {"],
+                    entry: EntryPattern::new("", ","),
+                    lines: vec![format!("{pascal},")],
+                },
+                Edit::InsertAfter {
+                    anchors: &["// Add Separate authentication support for connectors"],
+                    lines: vec![format!("            | Self::{pascal}")],
+                },
+            ],
+        },
+        FileEdits {
+            path: "crates/euclid/src/enums.rs",
+            edits: vec![
+                Edit::InsertSorted {
+                    anchors: &["pub enum RoutableConnectors {"],
+                    entry: EntryPattern::new("", ","),
+                    lines: vec![format!("{pascal},")],
+                },
+                Edit::InsertSorted {
+                    anchors: &[
+                        "fn try_from(connector: Connector) -> Result<Self, Self::Error> {",
+                        "match connector {",
+                    ],
+                    entry: EntryPattern::new("Connector::", " =>"),
+                    lines: vec![format!("Connector::{pascal} => Ok(Self::{pascal}),")],
+                },
+                Edit::InsertSorted {
+                    anchors: &[
+                        "fn from(routable_connector: RoutableConnectors) -> Self {",
+                        "match routable_connector {",
+                    ],
+                    entry: EntryPattern::new("RoutableConnectors::", " =>"),
+                    lines: vec![format!("RoutableConnectors::{pascal} => Self::{pascal},")],
+                },
+            ],
+        },
+        FileEdits {
+            path: "crates/router/src/types/connector_transformers.rs",
+            edits: vec![Edit::InsertSorted {
+                anchors: &[
+                    "fn foreign_try_from(from: api_enums::Connector) -> Result<Self, Self::Error> {",
+                    "Ok(match from {",
+                ],
+                entry: EntryPattern::new("api_enums::Connector::", " =>"),
+                lines: vec![format!("api_enums::Connector::{pascal} => Self::{pascal},")],
+            }],
+        },
+        FileEdits {
+            path: "crates/router/src/core/connector_validation.rs",
+            edits: vec![Edit::InsertSorted {
+                anchors: &["match self.connector_name {"],
+                entry: EntryPattern::new("api_enums::Connector::", " =>"),
+                lines: vec![
+                    format!("api_enums::Connector::{pascal} => {{"),
+                    format!(
+                        "    {connector}::transformers::{pascal}AuthType::try_from(self.auth_type)?;"
+                    ),
+                    "    Ok(())".to_owned(),
+                    "}".to_owned(),
+                ],
+            }],
+        },
+        FileEdits {
+            path: "crates/router/src/types/api/connector_mapping.rs",
+            edits: vec![connector_mapping_edit()],
+        },
+        FileEdits {
+            path: "crates/router/src/types/api/feature_matrix.rs",
+            edits: vec![connector_mapping_edit()],
+        },
+        FileEdits {
+            path: "crates/connector_configs/src/connector.rs",
+            edits: vec![
+                Edit::InsertSorted {
+                    anchors: &["pub struct ConnectorConfig {"],
+                    entry: EntryPattern::new("pub ", ":"),
+                    lines: vec![format!("pub {connector}: Option<ConnectorTomlConfig>,")],
+                },
+                Edit::InsertSorted {
+                    anchors: &["pub fn get_connector_config(", "match connector {"],
+                    entry: EntryPattern::new("Connector::", " =>"),
+                    lines: vec![format!(
+                        "Connector::{pascal} => Ok(connector_data.{connector}),"
+                    )],
+                },
+            ],
+        },
+        FileEdits {
+            path: "crates/test_utils/src/connector_auth.rs",
+            edits: vec![Edit::InsertSorted {
+                anchors: &["pub struct ConnectorAuthentication {"],
+                entry: EntryPattern::new("pub ", ":"),
+                lines: vec![format!("pub {connector}: Option<{auth_type}>,")],
+            }],
+        },
+        FileEdits {
+            path: "crates/router/tests/connectors/main.rs",
+            edits: vec![Edit::InsertSorted {
+                anchors: &[],
+                entry: EntryPattern::new("mod ", ";"),
+                lines: vec![format!("mod {connector};")],
+            }],
+        },
+        FileEdits {
+            path: "crates/router/tests/connectors/sample_auth.toml",
+            edits: vec![Edit::Append {
+                lines: std::iter::once(format!("[{connector}]"))
+                    .chain(
+                        scaffold
+                            .auth
+                            .placeholder_credentials()
+                            .iter()
+                            .map(|(key, value)| format!(r#"{key} = "{value}""#)),
+                    )
+                    .collect(),
+            }],
+        },
+    ];
+
+    file_edits.extend(
+        [
+            "crates/connector_configs/toml/development.toml",
+            "crates/connector_configs/toml/sandbox.toml",
+            "crates/connector_configs/toml/production.toml",
+        ]
+        .into_iter()
+        .map(|path| FileEdits {
+            path,
+            edits: vec![connector_toml_edit()],
+        }),
+    );
+
+    file_edits.extend(
+        [
+            "config/config.example.toml",
+            "config/development.toml",
+            "config/docker_compose.toml",
+            "config/deployments/integration_test.toml",
+            "config/deployments/production.toml",
+            "config/deployments/sandbox.toml",
+            "loadtest/config/development.toml",
+        ]
+        .into_iter()
+        .map(|path| FileEdits {
+            path,
+            edits: vec![base_url_edit()],
+        }),
+    );
+
+    file_edits
+}
+
+/// Apply the edits to the contents of the file at `path`, returning the edited contents
+pub(crate) fn apply_edits(
+    path: &str,
+    contents: &str,
+    name: &str,
+    edits: &[Edit],
+) -> anyhow::Result<String> {
+    let syntax = Syntax::of(path);
+    let mut lines: Vec<String> = contents.lines().map(ToOwned::to_owned).collect();
+
+    for edit in edits {
+        match edit {
+            Edit::InsertAfter {
+                anchors,
+                lines: new_lines,
+            } => {
+                let anchor = find_anchors(&lines, anchors)?;
+                lines.splice(anchor + 1..anchor + 1, new_lines.iter().cloned());
+            }
+            Edit::InsertSorted {
+                anchors,
+                entry,
+                lines: new_lines,
+            } => {
+                let (index, indentation) = if anchors.is_empty() {
+                    sorted_position(&lines, None, syntax, *entry, name)
+                } else {
+                    let anchor = find_anchors(&lines, anchors)?;
+                    sorted_position(&lines, Some(anchor), syntax, *entry, name)
+                }
+                .with_context(|| format!("No entries found after {anchors:?}"))?;
+                insert_indented(&mut lines, index, &indentation, new_lines);
+            }
+            Edit::InsertSortedInEveryBlock {
+                block_prefix,
+                entry,
+                lines: new_lines,
+            } => {
+                let blocks: Vec<usize> = lines
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, line)| line.starts_with(block_prefix))
+                    .map(|(index, _)| index)
+                    .collect();
+                ensure!(
+                    !blocks.is_empty(),
+                    "No blocks starting with `{block_prefix}`"
+                );
+
+                // Insert starting from the last block so that the positions of the previous ones
+                // remain valid
+                for block in blocks.into_iter().rev() {
+                    let (index, indentation) =
+                        sorted_position(&lines, Some(block), syntax, *entry, name).with_context(
+                            || format!("No entries found in block on line {}", block + 1),
+                        )?;
+                    insert_indented(&mut lines, index, &indentation, new_lines);
+                }
+            }
+            Edit::Append { lines: new_lines } => {
+                lines.push(String::new());
+                lines.extend(new_lines.iter().cloned());
+            }
+        }
+    }
+
+    let mut edited = lines.join("\n");
+    edited.push('\n');
+    Ok(edited)
+}
+
+/// Index of the line matching the last anchor
+fn find_anchors(lines: &[String], anchors: &[&str]) -> anyhow::Result<usize> {
+    let mut start = 0;
+    let mut found = None;
+
+    for anchor in anchors {
+        let Some(offset) = lines
+            .iter()
+            .skip(start)
+            .position(|line| line.trim() == *anchor)
+        else {
+            bail!("Line `{anchor}` not found");
+        };
+        found = Some(start + offset);
+        start += offset + 1;
+    }
+
+    found.context("No anchors provided")
+}
+
+/// An existing entry of a block
+struct BlockEntry {
+    sort_key: String,
+    /// First line of the entry, including its attributes and comments
+    start: usize,
+    /// Last line of the entry
+    end: usize,
+    indentation: String,
+}
+
+/// Position at which the entry for the connector is to be inserted in the block opened on the
+/// `block_start` line, or at the top level of the file, along with the indentation of the
+/// existing entries.
+///
+/// As some of the lists are not strictly sorted, the entry is inserted right after the entry
+/// immediately preceding it in alphabetical order, or before the first entry if there is none.
+fn sorted_position(
+    lines: &[String],
+    block_start: Option<usize>,
+    syntax: Syntax,
+    entry: EntryPattern,
+    name: &str,
+) -> Option<(usize, String)> {
+    let sort_key = |key: &str| key.replace('_', "").to_ascii_lowercase();
+    let name = sort_key(name);
+    let first_line = block_start.map_or(0, |start| start + 1);
+    // Brackets are only tracked in Rust files, where entries are at the depth of the brackets
+    // opened on the first line of the block
+    let balance = |line: &str| match syntax {
+        Syntax::Rust => bracket_balance(line),
+        Syntax::Toml => 0,
+    };
+    let entry_depth = block_start
+        .and_then(|start| lines.get(start))
+        .map_or(0, |line| balance(line));
+    let mut depth = entry_depth;
+
+    let mut entries: Vec<BlockEntry> = Vec::new();
+    let mut current_entry: Option<BlockEntry> = None;
+
+    for (index, line) in lines.iter().enumerate().skip(first_line) {
+        let line_depth = depth;
+        depth += balance(line);
+
+        let is_block_end = match syntax {
+            Syntax::Rust => depth < entry_depth,
+            Syntax::Toml => block_start.is_some() && line.trim_start().starts_with('['),
+        };
+        if is_block_end {
+            break;
+        }
+
+        if let Some(key) = entry.key(line).filter(|_| line_depth == entry_depth) {
+            current_entry = Some(BlockEntry {
+                sort_key: sort_key(key),
+                start: skip_attributes_backwards(lines, index, first_line),
+                end: index,
+                indentation: leading_whitespace(line),
+            });
+        }
+
+        // Entries spanning multiple lines, such as match arms, end once the depth is back to the
+        // depth of the entries
+        if depth == entry_depth {
+            if let Some(mut block_entry) = current_entry.take() {
+                block_entry.end = index;
+                entries.push(block_entry);
+            }
+        }
+    }
+
+    let preceding = entries
+        .iter()
+        .filter(|block_entry| block_entry.sort_key < name)
+        .max_by(|a, b| a.sort_key.cmp(&b.sort_key));
+
+    match preceding {
+        Some(block_entry) => Some((block_entry.end + 1, block_entry.indentation.clone())),
+        None => entries
+            .first()
+            .map(|block_entry| (block_entry.start, block_entry.indentation.clone())),
+    }
+}
+
+/// Move the insertion position above the attributes and comments of the entry on the line
+fn skip_attributes_backwards(lines: &[String], mut index: usize, first_line: usize) -> usize {
+    while index > first_line {
+        let is_attribute_or_comment = lines.get(index - 1).is_some_and(|previous| {
+            let previous = previous.trim_start();
+            previous.starts_with("#[") || previous.starts_with("//")
+        });
+        if !is_attribute_or_comment {
+            break;
+        }
+        index -= 1;
+    }
+    index
+}
+
+/// Number of opening brackets minus the number of closing brackets on the line, ignoring string
+/// literals and comments
+fn bracket_balance(line: &str) -> i32 {
+    let mut balance = 0;
+    let mut in_string = false;
+    let mut escaped = false;
+    let mut previous = None;
+
+    for c in line.chars() {
+        if in_string {
+            match c {
+                _ if escaped => escaped = false,
+                '\\' => escaped = true,
+                '"' => in_string = false,
+                _ => {}
+            }
+        } else {
+            match c {
+                '"' => in_string = true,
+                '/' if previous == Some('/') => break,
+                '{' | '(' | '[' => balance += 1,
+                '}' | ')' | ']' => balance -= 1,
+                _ => {}
+            }
+        }
+        previous = Some(c);
+    }
+
+    balance
+}
+
+fn leading_whitespace(line: &str) -> String {
+    line.chars().take_while(|c| c.is_whitespace()).collect()
+}
+
+fn insert_indented(lines: &mut Vec<String>, index: usize, indentation: &str, new_lines: &[String]) {
+    lines.splice(
+        index..index,
+        new_lines.iter().map(|line| format!("{indentation}{line}")),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn apply(path: &str, contents: &str, edit: Edit) -> String {
+        apply_edits(path, contents, "acme_pay", &[edit]).unwrap()
+    }
+
+    #[test]
+    fn entries_are_inserted_in_alphabetical_order() {
+        let contents = "\
+pub enum Connector {
+    Aci,
+    #[cfg(feature = \"dummy_connector\")]
+    Bambora,
+}
+";
+        let edited = apply(
+            "enums.rs",
+            contents,
+            Edit::InsertSorted {
+                anchors: &["pub enum Connector {"],
+                entry: EntryPattern::new("", ","),
+                lines: vec!["AcmePay,".to_owned()],
+            },
+        );
+
+        assert_eq!(
+            edited,
+            "\
+pub enum Connector {
+    Aci,
+    AcmePay,
+    #[cfg(feature = \"dummy_connector\")]
+    Bambora,
+}
+"
+        );
+    }
+
+    #[test]
+    fn multiline_entries_are_appended_after_the_last_entry() {
+        let contents = "\
+match self.connector_name {
+    api_enums::Connector::Aci => {
+        aci::transformers::AciAuthType::try_from(self.auth_type)?;
+        Ok(())
+    }
+}
+";
+        let edited = apply(
+            "connector_validation.rs",
+            contents,
+            Edit::InsertSorted {
+                anchors: &["match self.connector_name {"],
+                entry: EntryPattern::new("api_enums::Connector::", " =>"),
+                lines: vec![
+                    "api_enums::Connector::AcmePay => {".to_owned(),
+                    "    Ok(())".to_owned(),
+                    "}".to_owned(),
+                ],
+            },
+        );
+
+        assert_eq!(
+            edited,
+            "\
+match self.connector_name {
+    api_enums::Connector::Aci => {
+        aci::transformers::AciAuthType::try_from(self.auth_type)?;
+        Ok(())
+    }
+    api_enums::Connector::AcmePay => {
+        Ok(())
+    }
+}
+"
+        );
+    }
+
+    #[test]
+    fn entries_are_inserted_in_every_block() {
+        let contents = "\
+default_imp_for_a!(
+    connectors::Aci,
+    connectors::Zift,
+);
+
+default_imp_for_b!(
+    #[cfg(feature = \"payouts\")]
+    connectors::Zift,
+);
+";
+        let edited = apply(
+            "default_implementations.rs",
+            contents,
+            Edit::InsertSortedInEveryBlock {
+                block_prefix: "default_imp_for_",
+                entry: EntryPattern::new("connectors::", ","),
+                lines: vec!["connectors::AcmePay,".to_owned()],
+            },
+        );
+
+        assert_eq!(
+            edited,
+            "\
+default_imp_for_a!(
+    connectors::Aci,
+    connectors::AcmePay,
+    connectors::Zift,
+);
+
+default_imp_for_b!(
+    connectors::AcmePay,
+    #[cfg(feature = \"payouts\")]
+    connectors::Zift,
+);
+"
+        );
+    }
+
+    #[test]
+    fn toml_entries_are_inserted_in_the_table() {
+        let contents = "\
+[connectors]
+aci.base_url = \"https://aci.com/\"
+adyen.payout_base_url = \"https://adyen.com/\"
+zift.base_url = \"https://zift.io/\"
+
+[other]
+acme_pay_ignored.base_url = \"\"
+";
+        let edited = apply(
+            "config/development.toml",
+            contents,
+            Edit::InsertSorted {
+                anchors: &["[connectors]"],
+                entry: EntryPattern::new("", ".base_url"),
+                lines: vec!["acme_pay.base_url = \"http://127.0.0.1:9090/\"".to_owned()],
+            },
+        );
+
+        assert_eq!(
+            edited,
+            "\
+[connectors]
+aci.base_url = \"https://aci.com/\"
+acme_pay.base_url = \"http://127.0.0.1:9090/\"
+adyen.payout_base_url = \"https://adyen.com/\"
+zift.base_url = \"https://zift.io/\"
+
+[other]
+acme_pay_ignored.base_url = \"\"
+"
+        );
+    }
+
+    #[test]
+    fn lines_are_inserted_after_anchors_and_appended() {
+        let contents = "pub use self::{\n    aci::Aci,\n};\n\n[aci]\napi_key = \"API Key\"";
+        let edited = apply_edits(
+            "connectors.rs",
+            contents,
+            "acme_pay",
+            &[
+                Edit::InsertAfter {
+                    anchors: &["pub use self::{"],
+                    lines: vec!["    acme_pay::AcmePay,".to_owned()],
+                },
+                Edit::Append {
+                    lines: vec!["[acme_pay]".to_owned()],
+                },
+            ],
+        )
+        .unwrap();
+
+        assert_eq!(
+            edited,
+            "pub use self::{\n    acme_pay::AcmePay,\n    aci::Aci,\n};\n\n[aci]\napi_key = \"API Key\"\n\n[acme_pay]\n"
+        );
+        assert!(apply_edits(
+            "connectors.rs",
+            contents,
+            "acme_pay",
+            &[Edit::InsertAfter {
+                anchors: &["pub use missing::{"],
+                lines: vec![],
+            }],
+        )
+        .is_err());
+    }
+}
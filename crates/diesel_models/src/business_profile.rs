@@ -88,6 +88,8 @@ pub struct Profile {
     pub payment_method_blocking: Option<PaymentMethodBlockingConfig>,
     pub default_fallback_routing: Option<pii::SecretSerdeValue>,
     pub surcharge_connector_details: Option<SurchargeConnectorDetails>,
+    pub confirm_time_budget_in_secs: Option<i64>,
+    pub time_zone: Option<String>,
 }
 
 #[cfg(feature = "v1")]
@@ -155,6 +157,8 @@ pub struct ProfileNew {
     pub payment_method_blocking: Option<PaymentMethodBlockingConfig>,
     pub default_fallback_routing: Option<pii::SecretSerdeValue>,
     pub surcharge_connector_details: Option<SurchargeConnectorDetails>,
+    pub confirm_time_budget_in_secs: Option<i64>,
+    pub time_zone: Option<String>,
 }
 
 #[cfg(feature = "v1")]
@@ -224,6 +228,8 @@ pub struct ProfileUpdateInternal {
     pub payment_method_blocking: Option<PaymentMethodBlockingConfig>,
     pub default_fallback_routing: Option<pii::SecretSerdeValue>,
     pub surcharge_connector_details: Option<SurchargeConnectorDetails>,
+    pub confirm_time_budget_in_secs: Option<i64>,
+    pub time_zone: Option<String>,
 }
 
 /// Note: The order of fields in the struct is important.
@@ -296,6 +302,8 @@ pub struct Profile {
     pub payment_method_blocking: Option<PaymentMethodBlockingConfig>,
     pub default_fallback_routing: Option<pii::SecretSerdeValue>,
     pub surcharge_connector_details: Option<SurchargeConnectorDetails>,
+    pub confirm_time_budget_in_secs: Option<i64>,
+    pub time_zone: Option<String>,
     pub routing_algorithm_id: Option<common_utils::id_type::RoutingId>,
     pub order_fulfillment_time: Option<i64>,
     pub order_fulfillment_time_origin: Option<common_enums::OrderFulfillmentTimeOrigin>,
@@ -371,6 +379,8 @@ pub struct ProfileNew {
     pub routing_algorithm_id: Option<common_utils::id_type::RoutingId>,
     pub default_fallback_routing: Option<pii::SecretSerdeValue>,
     pub surcharge_connector_details: Option<SurchargeConnectorDetails>,
+    pub confirm_time_budget_in_secs: Option<i64>,
+    pub time_zone: Option<String>,
     pub order_fulfillment_time: Option<i64>,
     pub order_fulfillment_time_origin: Option<common_enums::OrderFulfillmentTimeOrigin>,
     pub frm_routing_algorithm_id: Option<String>,
@@ -441,6 +451,8 @@ pub struct ProfileUpdateInternal {
     pub payout_routing_algorithm_id: Option<common_utils::id_type::RoutingId>,
     pub default_fallback_routing: Option<pii::SecretSerdeValue>,
     pub surcharge_connector_details: Option<SurchargeConnectorDetails>,
+    pub confirm_time_budget_in_secs: Option<i64>,
+    pub time_zone: Option<String>,
     pub three_ds_decision_manager_config: Option<common_types::payments::DecisionManagerRecord>,
     pub should_collect_cvv_during_payment:
         Option<primitive_wrappers::ShouldCollectCvvDuringPayment>,
@@ -0,0 +1,26 @@
+use diesel::{Identifiable, Insertable, Queryable, Selectable};
+
+use crate::schema::connector_customer_mapping;
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = connector_customer_mapping)]
+pub struct ConnectorCustomerMappingNew {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub customer_id: common_utils::id_type::CustomerId,
+    pub connector_label: String,
+    pub connector_customer_id: String,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Identifiable, Queryable, Selectable)]
+#[diesel(
+    table_name = connector_customer_mapping,
+    primary_key(merchant_id, customer_id, connector_label),
+    check_for_backend(diesel::pg::Pg)
+)]
+pub struct ConnectorCustomerMapping {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub customer_id: common_utils::id_type::CustomerId,
+    pub connector_label: String,
+    pub connector_customer_id: String,
+    pub created_at: time::PrimitiveDateTime,
+}
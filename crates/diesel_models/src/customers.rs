@@ -35,6 +35,8 @@ pub struct CustomerNew {
     pub last_modified_by: Option<String>,
     pub document_details: Option<Encryption>,
     pub id: Option<common_utils::id_type::GlobalCustomerId>,
+    pub email_hash: Option<String>,
+    pub phone_hash: Option<String>,
 }
 
 #[cfg(feature = "v1")]
@@ -68,6 +70,8 @@ impl From<CustomerNew> for Customer {
             created_by: customer_new.created_by,
             last_modified_by: customer_new.last_modified_by,
             id: customer_new.id,
+            email_hash: customer_new.email_hash,
+            phone_hash: customer_new.phone_hash,
         }
     }
 }
@@ -167,6 +171,8 @@ pub struct Customer {
     pub last_modified_by: Option<String>,
     pub document_details: Option<Encryption>,
     pub id: Option<common_utils::id_type::GlobalCustomerId>,
+    pub email_hash: Option<String>,
+    pub phone_hash: Option<String>,
 }
 
 #[cfg(feature = "v2")]
@@ -231,6 +237,8 @@ pub struct CustomerUpdateInternal {
     pub tax_registration_id: Option<Encryption>,
     pub last_modified_by: Option<String>,
     pub document_details: Option<Encryption>,
+    pub email_hash: Option<Option<String>>,
+    pub phone_hash: Option<Option<String>>,
 }
 
 #[cfg(feature = "v2")]
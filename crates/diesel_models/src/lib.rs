@@ -13,6 +13,7 @@ pub mod authorization;
 pub mod blocklist;
 pub mod blocklist_fingerprint;
 pub mod callback_mapper;
+pub mod connector_customer_mapping;
 pub mod customers;
 pub mod dispute;
 pub mod dynamic_routing_stats;
@@ -73,11 +74,11 @@ pub type StorageResult<T> = error_stack::Result<T, errors::DatabaseError>;
 pub type PgPooledConn = async_bb8_diesel::Connection<diesel::PgConnection>;
 pub use self::{
     address::*, api_keys::*, callback_mapper::*, capture::*, cards_info::*, configs::*,
-    customers::*, dispute::*, ephemeral_key::*, events::*, file::*, generic_link::*,
-    hyperswitch_ai_interaction::*, locker_mock_up::*, mandate::*, merchant_account::*,
-    merchant_connector_account::*, payment_attempt::*, payment_intent::*, payment_method::*,
-    payout_attempt::*, payouts::*, process_tracker::*, refund::*, reverse_lookup::*,
-    user_authentication_method::*,
+    connector_customer_mapping::*, customers::*, dispute::*, ephemeral_key::*, events::*, file::*,
+    generic_link::*, hyperswitch_ai_interaction::*, locker_mock_up::*, mandate::*,
+    merchant_account::*, merchant_connector_account::*, payment_attempt::*, payment_intent::*,
+    payment_method::*, payout_attempt::*, payouts::*, process_tracker::*, refund::*,
+    reverse_lookup::*, user_authentication_method::*,
 };
 /// The types and implementations provided by this module are required for the schema generated by
 /// `diesel_cli` 2.0 to work with the types defined in Rust code. This is because
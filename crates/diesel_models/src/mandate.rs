@@ -38,6 +38,7 @@ pub struct Mandate {
     // This is the extended version of customer user agent that can store string upto 2048 characters unlike customer user agent that can store 255 characters at max
     pub customer_user_agent_extended: Option<String>,
     pub network_transaction_link_id: Option<String>,
+    pub customer_acceptance_source: Option<storage_enums::CustomerAcceptanceSource>,
 }
 
 #[derive(
@@ -78,6 +79,7 @@ pub struct MandateNew {
     pub updated_by: Option<String>,
     pub customer_user_agent_extended: Option<String>,
     pub network_transaction_link_id: Option<String>,
+    pub customer_acceptance_source: Option<storage_enums::CustomerAcceptanceSource>,
 }
 
 impl Mandate {
@@ -119,6 +121,12 @@ pub enum MandateUpdate {
         payment_method_id: String,
         original_payment_id: Option<common_utils::id_type::PaymentId>,
     },
+    CustomerAcceptanceUpdate {
+        customer_accepted_at: Option<PrimitiveDateTime>,
+        customer_ip_address: Option<Secret<String, pii::IpAddress>>,
+        customer_user_agent_extended: Option<String>,
+        customer_acceptance_source: storage_enums::CustomerAcceptanceSource,
+    },
 }
 
 impl MandateUpdate {
@@ -157,6 +165,10 @@ pub struct MandateUpdateInternal {
     payment_method_id: Option<String>,
     original_payment_id: Option<common_utils::id_type::PaymentId>,
     updated_by: Option<String>,
+    customer_accepted_at: Option<PrimitiveDateTime>,
+    customer_ip_address: Option<Secret<String, pii::IpAddress>>,
+    customer_user_agent_extended: Option<String>,
+    customer_acceptance_source: Option<storage_enums::CustomerAcceptanceSource>,
 }
 
 impl From<MandateUpdate> for MandateUpdateInternal {
@@ -170,6 +182,10 @@ impl From<MandateUpdate> for MandateUpdateInternal {
                 payment_method_id: None,
                 original_payment_id: None,
                 updated_by: None,
+                customer_accepted_at: None,
+                customer_ip_address: None,
+                customer_user_agent_extended: None,
+                customer_acceptance_source: None,
             },
             MandateUpdate::CaptureAmountUpdate { amount_captured } => Self {
                 mandate_status: None,
@@ -179,6 +195,10 @@ impl From<MandateUpdate> for MandateUpdateInternal {
                 payment_method_id: None,
                 original_payment_id: None,
                 updated_by: None,
+                customer_accepted_at: None,
+                customer_ip_address: None,
+                customer_user_agent_extended: None,
+                customer_acceptance_source: None,
             },
             MandateUpdate::ConnectorReferenceUpdate {
                 connector_mandate_ids,
@@ -198,6 +218,18 @@ impl From<MandateUpdate> for MandateUpdateInternal {
                 original_payment_id,
                 ..Default::default()
             },
+            MandateUpdate::CustomerAcceptanceUpdate {
+                customer_accepted_at,
+                customer_ip_address,
+                customer_user_agent_extended,
+                customer_acceptance_source,
+            } => Self {
+                customer_accepted_at,
+                customer_ip_address,
+                customer_user_agent_extended,
+                customer_acceptance_source: Some(customer_acceptance_source),
+                ..Default::default()
+            },
         }
     }
 }
@@ -234,6 +266,7 @@ impl From<&MandateNew> for Mandate {
             // Using customer_user_agent as a fallback
             customer_user_agent_extended: mandate_new.get_customer_user_agent_extended(),
             network_transaction_link_id: mandate_new.network_transaction_link_id.clone(),
+            customer_acceptance_source: mandate_new.customer_acceptance_source,
         }
     }
 }
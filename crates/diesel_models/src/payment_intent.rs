@@ -204,6 +204,9 @@ pub struct PaymentIntent {
     pub profile_acquirer_id: Option<common_utils::id_type::ProfileAcquirerId>,
     pub external_surcharge_strategy: Option<common_enums::SurchargeStrategy>,
     pub external_surcharge_applicable: Option<bool>,
+    pub attribution_tags: Option<common_types::payments::AttributionTags>,
+    pub customer_email_hash: Option<String>,
+    pub customer_phone_hash: Option<String>,
 }
 
 #[derive(Clone, Debug, serde::Deserialize, serde::Serialize, diesel::AsExpression, PartialEq)]
@@ -518,6 +521,9 @@ pub struct PaymentIntentNew {
     pub profile_acquirer_id: Option<common_utils::id_type::ProfileAcquirerId>,
     pub external_surcharge_strategy: Option<common_enums::SurchargeStrategy>,
     pub external_surcharge_applicable: Option<bool>,
+    pub attribution_tags: Option<common_types::payments::AttributionTags>,
+    pub customer_email_hash: Option<String>,
+    pub customer_phone_hash: Option<String>,
 }
 
 #[cfg(feature = "v2")]
@@ -566,6 +572,8 @@ pub enum PaymentIntentUpdate {
         billing_address_id: Option<String>,
         customer_details: Option<Encryption>,
         updated_by: String,
+        customer_email_hash: Option<String>,
+        customer_phone_hash: Option<String>,
     },
     MerchantStatusUpdate {
         status: storage_enums::IntentStatus,
@@ -632,6 +640,16 @@ pub enum PaymentIntentUpdate {
         status: storage_enums::IntentStatus,
         updated_by: String,
     },
+    ForceCloseUpdate {
+        status: storage_enums::IntentStatus,
+        state_metadata: PaymentIntentStateMetadata,
+        updated_by: String,
+    },
+    ContactHashUpdate {
+        customer_email_hash: Option<String>,
+        customer_phone_hash: Option<String>,
+        updated_by: String,
+    },
 }
 
 #[cfg(feature = "v2")]
@@ -713,6 +731,9 @@ pub struct PaymentIntentUpdateFields {
     pub profile_acquirer_id: Option<common_utils::id_type::ProfileAcquirerId>,
     pub external_surcharge_strategy: Option<common_enums::SurchargeStrategy>,
     pub external_surcharge_applicable: Option<bool>,
+    pub attribution_tags: Option<common_types::payments::AttributionTags>,
+    pub customer_email_hash: Option<String>,
+    pub customer_phone_hash: Option<String>,
 }
 
 // TODO: uncomment fields as necessary
@@ -829,6 +850,9 @@ pub struct PaymentIntentUpdateInternal {
     pub profile_acquirer_id: Option<common_utils::id_type::ProfileAcquirerId>,
     pub external_surcharge_strategy: Option<common_enums::SurchargeStrategy>,
     pub external_surcharge_applicable: Option<bool>,
+    pub attribution_tags: Option<common_types::payments::AttributionTags>,
+    pub customer_email_hash: Option<String>,
+    pub customer_phone_hash: Option<String>,
 }
 
 #[cfg(feature = "v1")]
@@ -902,6 +926,9 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 profile_acquirer_id: None,
                 external_surcharge_strategy: None,
                 external_surcharge_applicable: None,
+                attribution_tags: None,
+                customer_email_hash: None,
+                customer_phone_hash: None,
             },
             PaymentIntentUpdate::StateMetadataUpdate {
                 state_metadata,
@@ -963,6 +990,9 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 installment_options: None,
                 external_surcharge_strategy: None,
                 external_surcharge_applicable: None,
+                attribution_tags: None,
+                customer_email_hash: None,
+                customer_phone_hash: None,
             },
             PaymentIntentUpdate::Update(value) => Self {
                 amount: Some(value.amount),
@@ -1022,6 +1052,9 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 profile_acquirer_id: value.profile_acquirer_id,
                 external_surcharge_strategy: value.external_surcharge_strategy,
                 external_surcharge_applicable: value.external_surcharge_applicable,
+                attribution_tags: value.attribution_tags,
+                customer_email_hash: value.customer_email_hash,
+                customer_phone_hash: value.customer_phone_hash,
             },
             PaymentIntentUpdate::PaymentCreateUpdate {
                 return_url,
@@ -1031,6 +1064,8 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 billing_address_id,
                 customer_details,
                 updated_by,
+                customer_email_hash,
+                customer_phone_hash,
             } => Self {
                 return_url: None, // deprecated
                 status,
@@ -1088,6 +1123,9 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 profile_acquirer_id: None,
                 external_surcharge_strategy: None,
                 external_surcharge_applicable: None,
+                attribution_tags: None,
+                customer_email_hash,
+                customer_phone_hash,
             },
             PaymentIntentUpdate::PGStatusUpdate {
                 status,
@@ -1151,6 +1189,9 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 profile_acquirer_id: None,
                 external_surcharge_strategy: None,
                 external_surcharge_applicable: None,
+                attribution_tags: None,
+                customer_email_hash: None,
+                customer_phone_hash: None,
             },
             PaymentIntentUpdate::MerchantStatusUpdate {
                 status,
@@ -1214,6 +1255,9 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 profile_acquirer_id: None,
                 external_surcharge_strategy: None,
                 external_surcharge_applicable: None,
+                attribution_tags: None,
+                customer_email_hash: None,
+                customer_phone_hash: None,
             },
             PaymentIntentUpdate::ResponseUpdate {
                 // amount,
@@ -1285,6 +1329,9 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 profile_acquirer_id: None,
                 external_surcharge_strategy: None,
                 external_surcharge_applicable: None,
+                attribution_tags: None,
+                customer_email_hash: None,
+                customer_phone_hash: None,
             },
             PaymentIntentUpdate::PaymentAttemptAndAttemptCountUpdate {
                 active_attempt_id,
@@ -1347,6 +1394,9 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 profile_acquirer_id: None,
                 external_surcharge_strategy: None,
                 external_surcharge_applicable: None,
+                attribution_tags: None,
+                customer_email_hash: None,
+                customer_phone_hash: None,
             },
             PaymentIntentUpdate::StatusAndAttemptUpdate {
                 status,
@@ -1410,6 +1460,9 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 profile_acquirer_id: None,
                 external_surcharge_strategy: None,
                 external_surcharge_applicable: None,
+                attribution_tags: None,
+                customer_email_hash: None,
+                customer_phone_hash: None,
             },
             PaymentIntentUpdate::ApproveUpdate {
                 status,
@@ -1472,6 +1525,9 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 profile_acquirer_id: None,
                 external_surcharge_strategy: None,
                 external_surcharge_applicable: None,
+                attribution_tags: None,
+                customer_email_hash: None,
+                customer_phone_hash: None,
             },
             PaymentIntentUpdate::RejectUpdate {
                 status,
@@ -1534,6 +1590,9 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 profile_acquirer_id: None,
                 external_surcharge_strategy: None,
                 external_surcharge_applicable: None,
+                attribution_tags: None,
+                customer_email_hash: None,
+                customer_phone_hash: None,
             },
             PaymentIntentUpdate::SurchargeApplicableUpdate {
                 surcharge_applicable,
@@ -1595,6 +1654,9 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 profile_acquirer_id: None,
                 external_surcharge_strategy: None,
                 external_surcharge_applicable: None,
+                attribution_tags: None,
+                customer_email_hash: None,
+                customer_phone_hash: None,
             },
             PaymentIntentUpdate::ExternalSurchargeApplicableUpdate {
                 external_surcharge_applicable,
@@ -1656,6 +1718,9 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 shipping_cost: None,
                 profile_acquirer_id: None,
                 external_surcharge_strategy: None,
+                attribution_tags: None,
+                customer_email_hash: None,
+                customer_phone_hash: None,
             },
             PaymentIntentUpdate::IncrementalAuthorizationAmountUpdate { amount } => Self {
                 amount: Some(amount),
@@ -1714,6 +1779,9 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 profile_acquirer_id: None,
                 external_surcharge_strategy: None,
                 external_surcharge_applicable: None,
+                attribution_tags: None,
+                customer_email_hash: None,
+                customer_phone_hash: None,
             },
             PaymentIntentUpdate::AuthorizationCountUpdate {
                 authorization_count,
@@ -1774,6 +1842,9 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 profile_acquirer_id: None,
                 external_surcharge_strategy: None,
                 external_surcharge_applicable: None,
+                attribution_tags: None,
+                customer_email_hash: None,
+                customer_phone_hash: None,
             },
             PaymentIntentUpdate::CompleteAuthorizeUpdate {
                 shipping_address_id,
@@ -1834,6 +1905,9 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 profile_acquirer_id: None,
                 external_surcharge_strategy: None,
                 external_surcharge_applicable: None,
+                attribution_tags: None,
+                customer_email_hash: None,
+                customer_phone_hash: None,
             },
             PaymentIntentUpdate::ManualUpdate {
                 status,
@@ -1896,6 +1970,9 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 profile_acquirer_id: None,
                 external_surcharge_strategy: None,
                 external_surcharge_applicable: None,
+                attribution_tags: None,
+                customer_email_hash: None,
+                customer_phone_hash: None,
             },
             PaymentIntentUpdate::SessionResponseUpdate {
                 tax_details,
@@ -1959,6 +2036,9 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 profile_acquirer_id: None,
                 external_surcharge_strategy: None,
                 external_surcharge_applicable: None,
+                attribution_tags: None,
+                customer_email_hash: None,
+                customer_phone_hash: None,
             },
             PaymentIntentUpdate::RecurrenceUpdate { status, updated_by } => Self {
                 status: Some(status),
@@ -2017,6 +2097,139 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 profile_acquirer_id: None,
                 external_surcharge_strategy: None,
                 external_surcharge_applicable: None,
+                attribution_tags: None,
+                customer_email_hash: None,
+                customer_phone_hash: None,
+            },
+            PaymentIntentUpdate::ForceCloseUpdate {
+                status,
+                state_metadata,
+                updated_by,
+            } => Self {
+                status: Some(status),
+                updated_by,
+                session_expiry: None,
+                amount: None,
+                currency: None,
+                amount_captured: None,
+                customer_id: None,
+                return_url: None,
+                setup_future_usage: None,
+                off_session: None,
+                metadata: None,
+                connector_metadata: None,
+                billing_address_id: None,
+                shipping_address_id: None,
+                modified_at: common_utils::date_time::now(),
+                active_attempt_id: None,
+                business_country: None,
+                business_label: None,
+                description: None,
+                statement_descriptor_name: None,
+                statement_descriptor_suffix: None,
+                billing_descriptor: None,
+                order_details: None,
+                attempt_count: None,
+                merchant_decision: None,
+                payment_confirm_source: None,
+                surcharge_applicable: None,
+                incremental_authorization_allowed: None,
+                authorization_count: None,
+                fingerprint_id: None,
+                request_external_three_ds_authentication: None,
+                frm_metadata: None,
+                customer_details: None,
+                billing_details: None,
+                merchant_order_reference_id: None,
+                shipping_details: None,
+                is_payment_processor_token_flow: None,
+                tax_details: None,
+                force_3ds_challenge: None,
+                is_iframe_redirection_enabled: None,
+                extended_return_url: None,
+                payment_channel: None,
+                feature_metadata: None,
+                state_metadata: Some(state_metadata),
+                installment_options: None,
+                tax_status: None,
+                discount_amount: None,
+                order_date: None,
+                shipping_amount_tax: None,
+                duty_amount: None,
+                enable_partial_authorization: None,
+                enable_overcapture: None,
+                shipping_cost: None,
+                profile_acquirer_id: None,
+                external_surcharge_strategy: None,
+                external_surcharge_applicable: None,
+                attribution_tags: None,
+                customer_email_hash: None,
+                customer_phone_hash: None,
+            },
+            PaymentIntentUpdate::ContactHashUpdate {
+                customer_email_hash,
+                customer_phone_hash,
+                updated_by,
+            } => Self {
+                status: None,
+                updated_by,
+                session_expiry: None,
+                amount: None,
+                currency: None,
+                amount_captured: None,
+                customer_id: None,
+                return_url: None,
+                setup_future_usage: None,
+                off_session: None,
+                metadata: None,
+                connector_metadata: None,
+                billing_address_id: None,
+                shipping_address_id: None,
+                modified_at: common_utils::date_time::now(),
+                active_attempt_id: None,
+                business_country: None,
+                business_label: None,
+                description: None,
+                statement_descriptor_name: None,
+                statement_descriptor_suffix: None,
+                billing_descriptor: None,
+                order_details: None,
+                attempt_count: None,
+                merchant_decision: None,
+                payment_confirm_source: None,
+                surcharge_applicable: None,
+                incremental_authorization_allowed: None,
+                authorization_count: None,
+                fingerprint_id: None,
+                request_external_three_ds_authentication: None,
+                frm_metadata: None,
+                customer_details: None,
+                billing_details: None,
+                merchant_order_reference_id: None,
+                shipping_details: None,
+                is_payment_processor_token_flow: None,
+                tax_details: None,
+                force_3ds_challenge: None,
+                is_iframe_redirection_enabled: None,
+                extended_return_url: None,
+                payment_channel: None,
+                feature_metadata: None,
+                state_metadata: None,
+                installment_options: None,
+                tax_status: None,
+                discount_amount: None,
+                order_date: None,
+                shipping_amount_tax: None,
+                duty_amount: None,
+                enable_partial_authorization: None,
+                enable_overcapture: None,
+                shipping_cost: None,
+                profile_acquirer_id: None,
+                external_surcharge_strategy: None,
+                external_surcharge_applicable: None,
+                attribution_tags: None,
+                customer_email_hash,
+                customer_phone_hash,
             },
         }
     }
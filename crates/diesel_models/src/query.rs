@@ -13,6 +13,7 @@ pub mod batch_blocklist_job;
 pub mod blocklist;
 pub mod blocklist_fingerprint;
 pub mod callback_mapper;
+pub mod connector_customer_mapping;
 pub mod customers;
 pub mod dashboard_metadata;
 pub mod dispute;
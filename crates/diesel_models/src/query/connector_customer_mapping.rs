@@ -0,0 +1,46 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+
+use super::generics;
+use crate::{
+    connector_customer_mapping::{ConnectorCustomerMapping, ConnectorCustomerMappingNew},
+    schema::connector_customer_mapping::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl ConnectorCustomerMappingNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<ConnectorCustomerMapping> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl ConnectorCustomerMapping {
+    pub async fn find_by_merchant_id_customer_id_connector_label(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        customer_id: &common_utils::id_type::CustomerId,
+        connector_label: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::customer_id.eq(customer_id.to_owned()))
+                .and(dsl::connector_label.eq(connector_label.to_owned())),
+        )
+        .await
+    }
+
+    pub async fn delete_by_merchant_id_customer_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        customer_id: &common_utils::id_type::CustomerId,
+    ) -> StorageResult<bool> {
+        generics::generic_delete::<<Self as HasTable>::Table, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::customer_id.eq(customer_id.to_owned())),
+        )
+        .await
+    }
+}
@@ -323,6 +323,9 @@ diesel::table! {
         payment_method_blocking -> Nullable<Jsonb>,
         default_fallback_routing -> Nullable<Jsonb>,
         surcharge_connector_details -> Nullable<Jsonb>,
+        confirm_time_budget_in_secs -> Nullable<Int8>,
+        #[max_length = 64]
+        time_zone -> Nullable<Varchar>,
         #[max_length = 64]
         routing_algorithm_id -> Nullable<Varchar>,
         order_fulfillment_time -> Nullable<Int8>,
@@ -469,6 +472,23 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    connector_customer_mapping (merchant_id, customer_id, connector_label) {
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        customer_id -> Varchar,
+        #[max_length = 255]
+        connector_label -> Varchar,
+        #[max_length = 255]
+        connector_customer_id -> Varchar,
+        created_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -992,6 +1012,8 @@ diesel::table! {
         customer_user_agent_extended -> Nullable<Varchar>,
         #[max_length = 255]
         network_transaction_link_id -> Nullable<Varchar>,
+        #[max_length = 32]
+        customer_acceptance_source -> Nullable<Varchar>,
     }
 }
 
@@ -1957,6 +1979,7 @@ diesel::allow_tables_to_appear_in_same_query!(
     card_issuers,
     cards_info,
     configs,
+    connector_customer_mapping,
     customers,
     dashboard_metadata,
     dispute,
@@ -0,0 +1,241 @@
+use std::{fs::OpenOptions, path::PathBuf, sync::Arc};
+
+use diesel_models::kv::DatabaseOperation;
+use error_stack::ResultExt;
+use rdkafka::{
+    producer::{BaseRecord, DeliveryResult, ProducerContext, ThreadedProducer},
+    ClientContext,
+};
+use serde::{Deserialize, Serialize};
+use tokio::{fs::File, io::AsyncWriteExt, sync::Mutex};
+
+use crate::{errors, logger, metrics};
+
+/// Destination of the audit records of the entries applied by the drainer
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(tag = "sink", rename_all = "snake_case")]
+pub enum AuditSinkConfig {
+    #[default]
+    Disabled,
+    /// Append the audit records as JSON lines to a file
+    File { path: PathBuf },
+    /// Publish the audit records to a Kafka topic
+    Kafka { brokers: Vec<String>, topic: String },
+}
+
+impl AuditSinkConfig {
+    pub(crate) fn validate(&self) -> Result<(), errors::DrainerError> {
+        use common_utils::{ext_traits::ConfigExt, fp_utils::when};
+
+        match self {
+            Self::Disabled => Ok(()),
+            Self::File { path } => when(path.as_os_str().is_empty(), || {
+                Err(errors::DrainerError::ConfigParsingError(
+                    "audit sink file path must not be empty".into(),
+                ))
+            }),
+            Self::Kafka { brokers, topic } => {
+                when(brokers.is_empty(), || {
+                    Err(errors::DrainerError::ConfigParsingError(
+                        "audit sink Kafka brokers must not be empty".into(),
+                    ))
+                })?;
+                when(topic.is_default_or_empty(), || {
+                    Err(errors::DrainerError::ConfigParsingError(
+                        "audit sink Kafka topic must not be empty".into(),
+                    ))
+                })
+            }
+        }
+    }
+
+    /// Creates the configured audit sink, or `None` if auditing is disabled
+    pub(crate) fn create_sink(&self) -> errors::DrainerResult<Option<Arc<dyn AuditSink>>> {
+        match self {
+            Self::Disabled => Ok(None),
+            Self::File { path } => Ok(Some(Arc::new(FileAuditSink::new(path)?))),
+            Self::Kafka { brokers, topic } => {
+                Ok(Some(Arc::new(KafkaAuditSink::new(brokers, topic)?)))
+            }
+        }
+    }
+}
+
+/// Record of a database mutation successfully applied by the drainer
+#[derive(Debug, Serialize)]
+pub struct AuditRecord {
+    /// Entity ID / Partitioning key of the stream entry
+    pub entity_id: String,
+    pub entity_type: String,
+    pub operation: DatabaseOperation,
+    /// Application request ID used for correlation
+    pub request_id: String,
+    /// Time at which the entry was applied to the database
+    pub applied_at: i64,
+}
+
+#[async_trait::async_trait]
+pub trait AuditSink: Send + Sync {
+    async fn record(&self, record: &AuditRecord) -> errors::DrainerResult<()>;
+}
+
+/// Emits the audit record of an applied entry, without failing the drain of the stream
+pub(crate) async fn audit_applied_entry(sink: &dyn AuditSink, record: AuditRecord) {
+    if let Err(error) = sink.record(&record).await {
+        logger::error!(?error, ?record, "Failed to emit audit record");
+        metrics::AUDIT_RECORD_FAILURES.add(1, &[]);
+    }
+}
+
+pub struct FileAuditSink {
+    file: Mutex<File>,
+}
+
+impl FileAuditSink {
+    pub fn new(path: &PathBuf) -> errors::DrainerResult<Self> {
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .change_context(errors::DrainerError::AuditSinkError(
+                "Failed to open the audit log file".to_string(),
+            ))?;
+        Ok(Self {
+            file: Mutex::new(File::from_std(file)),
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl AuditSink for FileAuditSink {
+    async fn record(&self, record: &AuditRecord) -> errors::DrainerResult<()> {
+        let mut line = serde_json::to_vec(record).change_context(
+            errors::DrainerError::AuditSinkError("Failed to serialize audit record".to_string()),
+        )?;
+        line.push(b'\n');
+
+        let mut file = self.file.lock().await;
+        file.write_all(&line)
+            .await
+            .change_context(errors::DrainerError::AuditSinkError(
+                "Failed to write to the audit log file".to_string(),
+            ))?;
+        file.flush()
+            .await
+            .change_context(errors::DrainerError::AuditSinkError(
+                "Failed to flush the audit log file".to_string(),
+            ))
+    }
+}
+
+/// Reports the audit records which could not be delivered to Kafka. Publishing a record only
+/// enqueues it, the delivery is reported to the context once the brokers acknowledge it.
+pub struct AuditDeliveryContext;
+
+impl ClientContext for AuditDeliveryContext {}
+
+impl ProducerContext for AuditDeliveryContext {
+    type DeliveryOpaque = ();
+
+    fn delivery(
+        &self,
+        delivery_result: &DeliveryResult<'_>,
+        _delivery_opaque: Self::DeliveryOpaque,
+    ) {
+        if let Err((error, _message)) = delivery_result {
+            logger::error!(?error, "Failed to deliver audit record to Kafka");
+            metrics::AUDIT_RECORD_FAILURES.add(1, &[]);
+        }
+    }
+}
+
+pub struct KafkaAuditSink {
+    producer: ThreadedProducer<AuditDeliveryContext>,
+    topic: String,
+}
+
+impl KafkaAuditSink {
+    pub fn new(brokers: &[String], topic: &str) -> errors::DrainerResult<Self> {
+        let producer = rdkafka::ClientConfig::new()
+            .set("bootstrap.servers", brokers.join(","))
+            .create_with_context(AuditDeliveryContext)
+            .change_context(errors::DrainerError::AuditSinkError(
+                "Failed to create the audit Kafka producer".to_string(),
+            ))?;
+        Ok(Self {
+            producer,
+            topic: topic.to_owned(),
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl AuditSink for KafkaAuditSink {
+    async fn record(&self, record: &AuditRecord) -> errors::DrainerResult<()> {
+        let payload = serde_json::to_vec(record).change_context(
+            errors::DrainerError::AuditSinkError("Failed to serialize audit record".to_string()),
+        )?;
+        self.producer
+            .send(
+                BaseRecord::to(&self.topic)
+                    .key(&record.entity_id)
+                    .payload(&payload),
+            )
+            .map_err(|(error, _record)| {
+                error_stack::report!(error).change_context(errors::DrainerError::AuditSinkError(
+                    "Failed to publish audit record to Kafka".to_string(),
+                ))
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn audit_record(entity_id: &str, operation: DatabaseOperation) -> AuditRecord {
+        AuditRecord {
+            entity_id: entity_id.to_string(),
+            entity_type: "PaymentAttempt".to_string(),
+            operation,
+            request_id: "request_123".to_string(),
+            applied_at: 1_700_000_000,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_file_sink_writes_one_record_per_applied_entry() {
+        let path = std::env::temp_dir().join(format!(
+            "drainer_audit_{}.log",
+            common_utils::generate_id_with_default_len("test")
+        ));
+        let sink = FileAuditSink::new(&path).unwrap();
+
+        audit_applied_entry(&sink, audit_record("pay_1", DatabaseOperation::Insert)).await;
+        audit_applied_entry(&sink, audit_record("pay_1", DatabaseOperation::Update)).await;
+        audit_applied_entry(&sink, audit_record("pay_2", DatabaseOperation::Insert)).await;
+
+        let contents = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        let records = contents
+            .lines()
+            .map(|line| serde_json::from_str::<serde_json::Value>(line).unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(records.len(), 3);
+        assert_eq!(
+            records
+                .iter()
+                .map(|record| (record["entity_id"].as_str(), record["operation"].as_str()))
+                .collect::<Vec<_>>(),
+            vec![
+                (Some("pay_1"), Some("insert")),
+                (Some("pay_1"), Some("update")),
+                (Some("pay_2"), Some("insert")),
+            ]
+        );
+        assert!(records
+            .iter()
+            .all(|record| record["applied_at"] == 1_700_000_000));
+    }
+}
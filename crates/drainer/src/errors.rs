@@ -17,6 +17,8 @@ pub enum DrainerError {
     UnexpectedError(String),
     #[error("I/O: {0}")]
     IoError(std::io::Error),
+    #[error("Error while emitting audit records: {0}")]
+    AuditSinkError(String),
 }
 
 #[derive(Debug, Error, Clone, serde::Serialize)]
@@ -11,8 +11,10 @@ use tokio::{
 };
 
 use crate::{
-    errors, instrument, logger, metrics, query::ExecuteQuery, tracing, utils, DrainerSettings,
-    Store, StreamData,
+    audit::{self, AuditSink},
+    errors, instrument, logger, metrics,
+    query::ExecuteQuery,
+    tracing, utils, DrainerSettings, Store, StreamData,
 };
 
 /// Handler handles the spawning and closing of drainer
@@ -37,12 +39,14 @@ pub struct HandlerInner {
     conf: DrainerSettings,
     stores: HashMap<id_type::TenantId, Arc<Store>>,
     running: Arc<atomic::AtomicBool>,
+    audit_sink: Option<Arc<dyn AuditSink>>,
 }
 
 impl Handler {
     pub fn from_conf(
         conf: DrainerSettings,
         stores: HashMap<id_type::TenantId, Arc<Store>>,
+        audit_sink: Option<Arc<dyn AuditSink>>,
     ) -> Self {
         let shutdown_interval = Duration::from_millis(conf.shutdown_interval.into());
         let loop_interval = Duration::from_millis(conf.loop_interval.into());
@@ -58,6 +62,7 @@ impl Handler {
             conf,
             stores,
             running,
+            audit_sink,
         };
 
         Self {
@@ -84,6 +89,7 @@ impl Handler {
                             self.conf.max_read_count,
                             self.active_tasks.clone(),
                             jobs_picked.clone(),
+                            self.audit_sink.clone(),
                         )
                         .in_current_span(),
                     );
@@ -176,6 +182,7 @@ async fn drainer_handler(
     max_read_count: u64,
     active_tasks: Arc<atomic::AtomicU64>,
     jobs_picked: Arc<atomic::AtomicU8>,
+    audit_sink: Option<Arc<dyn AuditSink>>,
 ) -> errors::DrainerResult<()> {
     active_tasks.fetch_add(1, atomic::Ordering::Release);
 
@@ -186,6 +193,7 @@ async fn drainer_handler(
         max_read_count,
         stream_name.as_str(),
         jobs_picked,
+        audit_sink.as_deref(),
     ))
     .await;
 
@@ -206,6 +214,7 @@ async fn drainer(
     max_read_count: u64,
     stream_name: &str,
     jobs_picked: Arc<atomic::AtomicU8>,
+    audit_sink: Option<&dyn AuditSink>,
 ) -> errors::DrainerResult<()> {
     let stream_read = match store.read_from_stream(stream_name, max_read_count).await {
         Ok(result) => {
@@ -260,13 +269,29 @@ async fn drainer(
             }
         };
 
-        tracing::Span::current().record("request_id", data.request_id);
-        tracing::Span::current().record("global_id", data.global_id);
+        tracing::Span::current().record("request_id", data.request_id.as_str());
+        tracing::Span::current().record("global_id", data.global_id.as_str());
         logger::debug!("Processing stream entry");
 
+        let entity_type = data.query.entity_type();
+        let operation = data.query.operation();
+
         match data.query.execute_query(&store, data.pushed_at).await {
             Ok(_) => {
                 last_processed_id = entry_id;
+                if let Some(audit_sink) = audit_sink {
+                    audit::audit_applied_entry(
+                        audit_sink,
+                        audit::AuditRecord {
+                            entity_id: data.global_id,
+                            entity_type,
+                            operation,
+                            request_id: data.request_id,
+                            applied_at: common_utils::date_time::now_unix_timestamp(),
+                        },
+                    )
+                    .await;
+                }
             }
             Err(err) => match err.current_context() {
                 // In case of Uniqueviolation we can't really do anything to fix it so just clear
@@ -304,3 +329,93 @@ async fn drainer(
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use std::sync::Mutex;
+
+    use super::*;
+    use crate::settings::{AppState, Settings};
+
+    /// Keeps the serialized audit records in memory
+    #[derive(Default)]
+    struct RecordingAuditSink(Mutex<Vec<serde_json::Value>>);
+
+    #[async_trait::async_trait]
+    impl AuditSink for RecordingAuditSink {
+        async fn record(&self, record: &audit::AuditRecord) -> errors::DrainerResult<()> {
+            let record = serde_json::to_value(record).unwrap();
+            self.0.lock().unwrap().push(record);
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_entries_applied_by_drainer_are_audited() {
+        let state = AppState::new(Settings::new().unwrap()).await;
+        let tenant = state
+            .conf
+            .multitenancy
+            .get_tenants()
+            .values()
+            .next()
+            .unwrap();
+        let store = Arc::new(Store::new(&state.conf, true, tenant).await);
+        let stream_name = format!(
+            "{{{}}}_drainer_audit_test",
+            common_utils::generate_id_with_default_len("shard")
+        );
+        // The query is executed as is, it needs no table to be applied
+        let query = serde_json::json!({
+            "sql": "SELECT 1",
+            "binds": [],
+            "metadata": [],
+            "safe_to_cache_prepared": false,
+            "entity_type": "PaymentAttempt",
+            "operation": "insert",
+        });
+        store
+            .redis_conn
+            .stream_append_entry(
+                &stream_name.as_str().into(),
+                &redis_interface::RedisEntryId::AutoGeneratedID,
+                vec![
+                    ("query", query.to_string()),
+                    ("global_id", "pay_audit_123".to_string()),
+                    ("request_id", "request_audit_123".to_string()),
+                    (
+                        "pushed_at",
+                        common_utils::date_time::now_unix_timestamp().to_string(),
+                    ),
+                ],
+            )
+            .await
+            .unwrap();
+
+        let audit_sink = RecordingAuditSink::default();
+        let drainer_result = drainer(
+            Arc::clone(&store),
+            10,
+            &stream_name,
+            Arc::new(atomic::AtomicU8::new(0)),
+            Some(&audit_sink),
+        )
+        .await;
+        store
+            .redis_conn
+            .delete_key(&stream_name.as_str().into())
+            .await
+            .unwrap();
+
+        drainer_result.unwrap();
+        let records = audit_sink.0.lock().unwrap().clone();
+        assert_eq!(records.len(), 1);
+        let record = records.first().unwrap();
+        assert_eq!(record["entity_id"], "pay_audit_123");
+        assert_eq!(record["entity_type"], "PaymentAttempt");
+        assert_eq!(record["operation"], "insert");
+        assert_eq!(record["request_id"], "request_audit_123");
+    }
+}
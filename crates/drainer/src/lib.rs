@@ -1,3 +1,4 @@
+mod audit;
 mod connection;
 pub mod errors;
 mod handler;
@@ -34,7 +35,8 @@ pub async fn start_drainer(
     stores: HashMap<id_type::TenantId, Arc<Store>>,
     conf: DrainerSettings,
 ) -> errors::DrainerResult<()> {
-    let drainer_handler = handler::Handler::from_conf(conf, stores);
+    let audit_sink = conf.audit_sink.create_sink()?;
+    let drainer_handler = handler::Handler::from_conf(conf, stores, audit_sink);
 
     let (tx, rx) = mpsc::channel::<()>(1);
 
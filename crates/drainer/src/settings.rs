@@ -17,7 +17,7 @@ pub use router_env::config::{Log, LogConsole, LogFile, LogTelemetry};
 use router_env::{env, logger};
 use serde::Deserialize;
 
-use crate::{errors, secrets_transformers};
+use crate::{audit::AuditSinkConfig, errors, secrets_transformers};
 
 #[derive(clap::Parser, Default)]
 #[cfg_attr(feature = "vergen", command(version = router_env::version!()))]
@@ -114,6 +114,7 @@ pub struct DrainerSettings {
     pub max_read_count: u64,
     pub shutdown_interval: u32, // in milliseconds
     pub loop_interval: u32,     // in milliseconds
+    pub audit_sink: AuditSinkConfig,
 }
 
 #[derive(Debug, Deserialize, Clone, Default)]
@@ -224,6 +225,7 @@ impl Default for DrainerSettings {
             max_read_count: 100,
             shutdown_interval: 1000, // in milliseconds
             loop_interval: 100,      // in milliseconds
+            audit_sink: AuditSinkConfig::default(),
         }
     }
 }
@@ -274,7 +276,8 @@ impl DrainerSettings {
             Err(errors::DrainerError::ConfigParsingError(
                 "drainer stream name must not be empty".into(),
             ))
-        })
+        })?;
+        self.audit_sink.validate()
     }
 }
 
@@ -307,7 +310,8 @@ impl Settings<SecuredSecret> {
                     .try_parsing(true)
                     .separator("__")
                     .list_separator(",")
-                    .with_list_parse_key("redis.cluster_urls"),
+                    .with_list_parse_key("redis.cluster_urls")
+                    .with_list_parse_key("drainer.audit_sink.brokers"),
             )
             .build()?;
 
@@ -12,7 +12,10 @@ pub mod client;
 pub mod metrics;
 /// request module
 pub mod request;
-use std::{error::Error, time::Duration};
+use std::{
+    error::Error,
+    time::{Duration, Instant},
+};
 
 pub use common_utils::request::{ContentType, Method, RequestBuilder};
 use common_utils::request::{RequestContent, XmlConfig};
@@ -80,7 +83,7 @@ pub async fn send_request(
         request.ca_certificate,
     )?;
 
-    let headers = request.headers.construct_header_map()?;
+    let headers = request.get_ordered_headers().construct_header_map()?;
     let metrics_tag = router_env::metric_attributes!((
         consts::METRICS_HOST_TAG_NAME,
         url.host_str().unwrap_or_default().to_owned()
@@ -140,28 +143,13 @@ pub async fn send_request(
         None => request_builder,
     };
 
-    let request = request.add_headers(headers).timeout(Duration::from_secs(
-        option_timeout_secs.unwrap_or(consts::REQUEST_TIME_OUT),
-    ));
+    let request_budget =
+        Duration::from_secs(option_timeout_secs.unwrap_or(consts::REQUEST_TIME_OUT));
+    let request = request.add_headers(headers).timeout(request_budget);
 
     // We cannot clone the request type, because it has Form trait which is not cloneable. So we are cloning the request builder here.
-    let cloned_send_request = request.try_clone().map(|cloned_request| async {
-        cloned_request
-            .send()
-            .await
-            .map_err(|error| match error {
-                error if error.is_timeout() => {
-                    metrics::REQUEST_BUILD_FAILURE.add(1, metrics_tag);
-                    HttpClientError::RequestTimeoutReceived
-                }
-                error if is_connection_closed_before_message_could_complete(&error) => {
-                    metrics::REQUEST_BUILD_FAILURE.add(1, metrics_tag);
-                    HttpClientError::ConnectionClosedIncompleteMessage
-                }
-                _ => HttpClientError::RequestNotSent(error.to_string()),
-            })
-            .attach_printable("Unable to send request to connector")
-    });
+    let cloned_request = request.try_clone();
+    let request_start = Instant::now();
 
     let send_request = async {
         request
@@ -202,13 +190,43 @@ pub async fn send_request(
             if error.current_context() == &HttpClientError::ConnectionClosedIncompleteMessage =>
         {
             metrics::AUTO_RETRY_CONNECTION_CLOSED.add(1, metrics_tag);
-            match cloned_send_request {
+            match cloned_request {
                 Some(cloned_request) => {
+                    let Some(remaining_budget) =
+                        get_remaining_budget_for_retry(request_budget, request_start.elapsed())
+                    else {
+                        metrics::AUTO_RETRY_ABORTED_BUDGET_EXCEEDED.add(1, metrics_tag);
+                        logger::info!("Not retrying request due to connection closed before message could complete as the remaining request budget cannot fit another attempt");
+                        return Err(error);
+                    };
                     logger::info!(
                         "Retrying request due to connection closed before message could complete"
                     );
+                    // The retry must not outlive the budget of the original request
+                    let cloned_send_request = async {
+                        cloned_request
+                            .timeout(remaining_budget)
+                            .send()
+                            .await
+                            .map_err(|error| match error {
+                                error if error.is_timeout() => {
+                                    metrics::REQUEST_BUILD_FAILURE.add(1, metrics_tag);
+                                    HttpClientError::RequestTimeoutReceived
+                                }
+                                error
+                                    if is_connection_closed_before_message_could_complete(
+                                        &error,
+                                    ) =>
+                                {
+                                    metrics::REQUEST_BUILD_FAILURE.add(1, metrics_tag);
+                                    HttpClientError::ConnectionClosedIncompleteMessage
+                                }
+                                _ => HttpClientError::RequestNotSent(error.to_string()),
+                            })
+                            .attach_printable("Unable to send request to connector")
+                    };
                     common_utils::metrics::utils::record_operation_time(
-                        cloned_request,
+                        cloned_send_request,
                         &metrics::EXTERNAL_REQUEST_TIME,
                         metrics_tag,
                     )
@@ -224,6 +242,15 @@ pub async fn send_request(
     }
 }
 
+/// Returns the time left in the request budget if it can fit another attempt.
+///
+/// The duration of the attempts made so far is used as the estimate of how long a retry would take.
+fn get_remaining_budget_for_retry(request_budget: Duration, elapsed: Duration) -> Option<Duration> {
+    request_budget
+        .checked_sub(elapsed)
+        .filter(|remaining_budget| *remaining_budget >= elapsed && !remaining_budget.is_zero())
+}
+
 fn is_connection_closed_before_message_could_complete(error: &reqwest::Error) -> bool {
     let mut source = error.source();
     while let Some(err) = source {
@@ -236,3 +263,90 @@ fn is_connection_closed_before_message_could_complete(error: &reqwest::Error) ->
     }
     false
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    };
+
+    use tokio::{io::AsyncReadExt, net::TcpListener};
+
+    use super::*;
+
+    #[test]
+    fn test_retry_fits_in_remaining_budget() {
+        let remaining_budget =
+            get_remaining_budget_for_retry(Duration::from_secs(30), Duration::from_secs(2));
+        assert_eq!(remaining_budget, Some(Duration::from_secs(28)));
+    }
+
+    /// Starts a server which reads each request and closes the connection without responding
+    /// after `delay`, so that every attempt fails with the connection closed before the message
+    /// could complete. Returns the URL of the server and the number of attempts it received.
+    async fn start_connection_closing_server(delay: Duration) -> (String, Arc<AtomicUsize>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        let attempts = Arc::new(AtomicUsize::new(0));
+
+        let server_attempts = Arc::clone(&attempts);
+        tokio::spawn(async move {
+            while let Ok((mut stream, _)) = listener.accept().await {
+                server_attempts.fetch_add(1, Ordering::SeqCst);
+                tokio::spawn(async move {
+                    let mut buffer = [0; 1024];
+                    let _ = stream.read(&mut buffer).await;
+                    tokio::time::sleep(delay).await;
+                });
+            }
+        });
+
+        (format!("http://{address}/"), attempts)
+    }
+
+    #[tokio::test]
+    async fn test_request_retried_when_budget_fits_another_attempt() {
+        let (url, attempts) = start_connection_closing_server(Duration::from_millis(50)).await;
+
+        let error = send_request(&Proxy::default(), Request::new(Method::Get, &url), Some(2))
+            .await
+            .unwrap_err();
+
+        assert_eq!(
+            error.current_context(),
+            &HttpClientError::ConnectionClosedIncompleteMessage
+        );
+        assert_eq!(attempts.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_retry_aborted_when_budget_is_tight() {
+        // The first attempt uses 600 ms of the 1 second budget, which cannot fit another attempt
+        let (url, attempts) = start_connection_closing_server(Duration::from_millis(600)).await;
+        let request_start = Instant::now();
+
+        let error = send_request(&Proxy::default(), Request::new(Method::Get, &url), Some(1))
+            .await
+            .unwrap_err();
+
+        assert_eq!(
+            error.current_context(),
+            &HttpClientError::ConnectionClosedIncompleteMessage
+        );
+        assert_eq!(attempts.load(Ordering::SeqCst), 1);
+        assert!(request_start.elapsed() < Duration::from_secs(1));
+    }
+
+    #[test]
+    fn test_retry_aborted_when_budget_is_exhausted() {
+        assert_eq!(
+            get_remaining_budget_for_retry(Duration::from_secs(5), Duration::from_secs(5)),
+            None
+        );
+        assert_eq!(
+            get_remaining_budget_for_retry(Duration::from_secs(5), Duration::from_secs(6)),
+            None
+        );
+    }
+}
@@ -1,6 +1,5 @@
 use std::str::FromStr;
 
-use common_utils::request::Headers;
 pub use common_utils::{errors::CustomResult, request::ContentType};
 use error_stack::ResultExt;
 use hyperswitch_interfaces::errors::HttpClientError;
@@ -12,7 +11,10 @@ pub trait HeaderExt {
     fn construct_header_map(self) -> CustomResult<reqwest::header::HeaderMap, HttpClientError>;
 }
 
-impl HeaderExt for Headers {
+impl<T> HeaderExt for T
+where
+    T: IntoIterator<Item = (String, Maskable<String>)>,
+{
     fn construct_header_map(self) -> CustomResult<reqwest::header::HeaderMap, HttpClientError> {
         use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
 
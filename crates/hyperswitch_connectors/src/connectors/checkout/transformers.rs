@@ -2411,6 +2411,14 @@ fn convert_to_additional_payment_method_connector_response(
             "avs_result": code.avs_check,
             "card_validation_result": code.cvv_check,
         });
+        let payment_checks = match code
+            .avs_check
+            .as_deref()
+            .and_then(utils::get_avs_match_details_from_code)
+        {
+            Some(avs_match_details) => avs_match_details.add_to_payment_checks(payment_checks),
+            None => payment_checks,
+        };
         AdditionalPaymentMethodConnectorResponse::Card {
             authentication_data: None,
             payment_checks: Some(payment_checks),
@@ -124,6 +124,10 @@ impl ConnectorCommon for Nuvei {
         connectors.nuvei.base_url.as_ref()
     }
 
+    fn get_error_message_path(&self) -> &'static str {
+        "reason"
+    }
+
     fn get_auth_header(
         &self,
         _auth_type: &ConnectorAuthType,
@@ -1830,3 +1834,26 @@ impl ConnectorSpecifications for Nuvei {
         true
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use hyperswitch_interfaces::{api::ConnectorCommon, types::Response};
+
+    use super::Nuvei;
+
+    #[test]
+    fn error_message_is_extracted_from_reason() {
+        let response = Response {
+            headers: None,
+            response: bytes::Bytes::from_static(
+                br#"{"status":"ERROR","errCode":1069,"reason":"Session expired"}"#,
+            ),
+            status_code: 400,
+        };
+
+        let error = Nuvei::new().build_error_response(response, None).unwrap();
+
+        assert_eq!(error.status_code, 400);
+        assert_eq!(error.message, "Session expired");
+    }
+}
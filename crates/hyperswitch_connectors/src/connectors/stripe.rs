@@ -1069,14 +1069,18 @@ impl ConnectorIntegration<Authorize, PaymentsAuthorizeData, PaymentsResponseData
     fn get_request_body(
         &self,
         req: &PaymentsAuthorizeRouterData,
-        _connectors: &Connectors,
+        connectors: &Connectors,
     ) -> CustomResult<RequestContent, ConnectorError> {
         let amount = utils::convert_amount(
             self.amount_converter,
             req.request.minor_amount,
             req.request.currency,
         )?;
-        let connector_req = stripe::PaymentIntentRequest::try_from((req, amount))?;
+        let mut connector_req = stripe::PaymentIntentRequest::try_from((req, amount))?;
+        connector_req.meta_data = stripe::limit_transaction_metadata(
+            connector_req.meta_data,
+            &connectors.stripe.metadata_limits,
+        );
 
         Ok(RequestContent::FormUrlEncoded(Box::new(connector_req)))
     }
@@ -1680,9 +1684,12 @@ impl ConnectorIntegration<SetupMandate, SetupMandateRequestData, PaymentsRespons
     fn get_request_body(
         &self,
         req: &RouterData<SetupMandate, SetupMandateRequestData, PaymentsResponseData>,
-        _connectors: &Connectors,
+        connectors: &Connectors,
     ) -> CustomResult<RequestContent, ConnectorError> {
-        let connector_req = stripe::SetupIntentRequest::try_from(req)?;
+        let mut connector_req = stripe::SetupIntentRequest::try_from(req)?;
+        connector_req.meta_data = connector_req.meta_data.map(|meta_data| {
+            stripe::limit_transaction_metadata(meta_data, &connectors.stripe.metadata_limits)
+        });
         Ok(RequestContent::FormUrlEncoded(Box::new(connector_req)))
     }
 
@@ -2871,9 +2878,7 @@ impl IncomingWebhook for Stripe {
             stripe::WebhookEventType::DisputeUpdated => status
                 .map(Into::into)
                 .unwrap_or(IncomingWebhookEvent::EventNotSupported),
-            stripe::WebhookEventType::DisputeClosed => status
-                .map(Into::into)
-                .unwrap_or(IncomingWebhookEvent::DisputeCancelled),
+            stripe::WebhookEventType::DisputeClosed => stripe::get_dispute_closed_event(status),
             stripe::WebhookEventType::ChargeDisputeFundsWithdrawn => status
                 .map(Into::into)
                 .unwrap_or(IncomingWebhookEvent::DisputeLost),
@@ -1,4 +1,4 @@
-use std::{collections::HashMap, fmt::Debug, ops::Deref};
+use std::{collections::HashMap, fmt::Debug, ops::Deref, str::FromStr};
 
 use api_models::{self, enums as api_enums, payments};
 use common_enums::{enums, AttemptStatus, PaymentChargeType, StripeChargeType};
@@ -16,6 +16,7 @@ use common_utils::{
 };
 use error_stack::ResultExt;
 use hyperswitch_domain_models::{
+    connector_endpoints::ConnectorMetadataLimits,
     mandates,
     payment_method_data::{
         self, BankRedirectData, Card, CardRedirectData, GiftCardData, GooglePayWalletData,
@@ -23,7 +24,8 @@ use hyperswitch_domain_models::{
     },
     router_data::{
         AdditionalPaymentMethodConnectorResponse, ConnectorAuthType, ConnectorResponseData,
-        ExtendedAuthorizationResponseData, PaymentMethodToken, RouterData,
+        ExtendedAuthorizationResponseData, PaymentMethodToken, RecurringMandatePaymentData,
+        RouterData,
     },
     router_flow_types::{Execute, RSync},
     router_request_types::{
@@ -199,8 +201,8 @@ pub struct PaymentIntentRequest {
     pub statement_descriptor: Option<String>,
     #[serde(flatten)]
     pub meta_data: HashMap<String, String>,
-    pub return_url: String,
-    pub confirm: bool,
+    pub return_url: Option<String>,
+    pub confirm: Option<bool>,
     pub payment_method: Option<Secret<String>>,
     pub customer: Option<Secret<String>>,
     #[serde(flatten)]
@@ -230,6 +232,59 @@ pub struct PaymentIntentRequest {
     /// The Stripe account ID that these funds are intended for
     #[serde(skip_serializing_if = "Option::is_none")]
     pub on_behalf_of: Option<String>,
+    /// Order line items, used by Stripe for the risk scoring of pay later payment methods
+    #[serde(flatten)]
+    pub line_items: Option<StripeLineItems>,
+    /// Requests next actions that are handled by the Stripe SDK of native mobile clients, instead
+    /// of redirect URLs
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_stripe_sdk: Option<bool>,
+}
+
+/// Order line items of a payment intent. Stripe expects the items of a form encoded list as
+/// indexed keys, `amount_details[line_items][0][product_name]` and so on, which is why the items
+/// are serialized as a map.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StripeLineItems(Vec<StripeLineItem>);
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StripeLineItem {
+    pub product_name: String,
+    pub unit_cost: MinorUnit,
+    pub quantity: u16,
+    pub total_tax_amount: Option<MinorUnit>,
+}
+
+impl From<&payments::OrderDetailsWithAmount> for StripeLineItem {
+    fn from(order_details: &payments::OrderDetailsWithAmount) -> Self {
+        Self {
+            product_name: order_details.product_name.clone(),
+            unit_cost: order_details.amount,
+            quantity: order_details.quantity,
+            total_tax_amount: order_details.total_tax_amount,
+        }
+    }
+}
+
+impl Serialize for StripeLineItems {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeMap;
+
+        let mut map = serializer.serialize_map(None)?;
+        for (index, line_item) in self.0.iter().enumerate() {
+            let key = |field: &str| format!("amount_details[line_items][{index}]{field}");
+            map.serialize_entry(&key("[product_name]"), &line_item.product_name)?;
+            map.serialize_entry(&key("[unit_cost]"), &line_item.unit_cost)?;
+            map.serialize_entry(&key("[quantity]"), &line_item.quantity)?;
+            if let Some(total_tax_amount) = line_item.total_tax_amount {
+                map.serialize_entry(&key("[tax][total_tax_amount]"), &total_tax_amount)?;
+            }
+        }
+        map.end()
+    }
 }
 
 #[derive(Debug, Eq, PartialEq, Serialize, Clone)]
@@ -255,6 +310,77 @@ pub struct StripeMetadata {
     // it is set as string instead of bool because stripe pass it as string even if we set it as bool
     #[serde(rename(serialize = "metadata[is_refund_id_as_reference]"))]
     pub is_refund_id_as_reference: Option<String>,
+    // always set to the refund_id in refunds, independent of order_id, for reconciliation
+    // merchant refund metadata is not forwarded to stripe, so this key cannot be overwritten
+    #[serde(rename(serialize = "metadata[hyperswitch_refund_id]"))]
+    pub hyperswitch_refund_id: Option<String>,
+}
+
+impl StripeMetadata {
+    fn for_refund(refund_id: &str) -> Self {
+        Self {
+            order_id: Some(refund_id.to_owned()),
+            is_refund_id_as_reference: Some("true".to_string()),
+            hyperswitch_refund_id: Some(refund_id.to_owned()),
+        }
+    }
+}
+
+impl PaymentIntentRequest {
+    /// Creates the payment intent without confirming it, so that the merchant can confirm it
+    /// client-side using the client secret. Stripe accepts these fields only when confirming.
+    fn into_create_only(self) -> Self {
+        Self {
+            return_url: None,
+            confirm: None,
+            setup_mandate_details: None,
+            off_session: None,
+            use_stripe_sdk: None,
+            ..self
+        }
+    }
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct StripeConnectorMetadataObject {
+    /// Create payment intents with `confirm=false`, for merchants confirming them client-side
+    #[serde(default)]
+    pub create_payment_intent_only: bool,
+    /// Send the issuing country of the card as the billing country, when the billing country is missing
+    #[serde(default)]
+    pub infer_billing_country_from_card_bin: bool,
+    /// Card network to process card payments on, for cards supporting multiple networks
+    #[serde(default)]
+    pub preferred_card_network: Option<common_enums::CardNetwork>,
+    /// Fail the payment instead of dropping a preferred card network that Stripe does not support
+    #[serde(default)]
+    pub require_preferred_card_network: bool,
+    /// Do not send the shipping address when it is identical to the billing address
+    #[serde(default)]
+    pub omit_shipping_equal_to_billing: bool,
+    /// Request next actions handled by the Stripe SDK for card payments of native mobile clients,
+    /// for merchants whose mobile apps integrate the Stripe SDK
+    #[serde(default)]
+    pub use_stripe_sdk_for_mobile_clients: bool,
+}
+
+impl TryFrom<&Option<pii::SecretSerdeValue>> for StripeConnectorMetadataObject {
+    type Error = error_stack::Report<ConnectorError>;
+    fn try_from(meta_data: &Option<pii::SecretSerdeValue>) -> Result<Self, Self::Error> {
+        match meta_data {
+            Some(_) => crate::utils::to_connector_meta_from_secret::<Self>(meta_data.clone())
+                .change_context(ConnectorError::InvalidConnectorConfig {
+                    config: "merchant_connector_account.metadata",
+                }),
+            None => Ok(Self::default()),
+        }
+    }
+}
+
+/// Connector metadata of a payment intent created without confirmation
+#[derive(Debug, Serialize, Deserialize)]
+pub struct StripeCreateOnlyPaymentIntentMetadata {
+    pub client_secret: Secret<String>,
 }
 
 #[derive(Debug, Eq, PartialEq, Serialize)]
@@ -710,6 +836,15 @@ pub enum StripeWallet {
     GooglePayPredecryptToken(Box<StripeGooglePayPredecrypt>),
 }
 
+/// Wallet through which a network token was provisioned on the device
+#[derive(Debug, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StripeTokenizationMethod {
+    ApplePay,
+    /// Google Pay device tokens, named after Google Pay's former brand by Stripe
+    AndroidPay,
+}
+
 #[derive(Debug, Eq, PartialEq, Serialize)]
 pub struct StripeApplePayPredecrypt {
     #[serde(rename = "card[number]")]
@@ -723,7 +858,7 @@ pub struct StripeApplePayPredecrypt {
     #[serde(rename = "card[eci]")]
     eci: Option<String>,
     #[serde(rename = "card[tokenization_method]")]
-    tokenization_method: String,
+    tokenization_method: StripeTokenizationMethod,
 }
 
 #[derive(Debug, Eq, PartialEq, Serialize)]
@@ -739,7 +874,7 @@ pub struct StripeGooglePayPredecrypt {
     #[serde(rename = "card[eci]")]
     eci: Option<String>,
     #[serde(rename = "card[tokenization_method]")]
-    tokenization_method: String,
+    tokenization_method: StripeTokenizationMethod,
 }
 
 #[derive(Debug, Eq, PartialEq, Serialize)]
@@ -1091,6 +1226,18 @@ impl From<WebhookEventStatus> for api_models::webhooks::IncomingWebhookEvent {
     }
 }
 
+/// A closed dispute has been resolved, either in favour of the merchant (won) or of the customer
+/// (lost). Disputes closed without an outcome (such as inquiries) are treated as cancelled.
+pub fn get_dispute_closed_event(
+    status: Option<WebhookEventStatus>,
+) -> api_models::webhooks::IncomingWebhookEvent {
+    match status {
+        Some(WebhookEventStatus::Won) => api_models::webhooks::IncomingWebhookEvent::DisputeWon,
+        Some(WebhookEventStatus::Lost) => api_models::webhooks::IncomingWebhookEvent::DisputeLost,
+        _ => api_models::webhooks::IncomingWebhookEvent::DisputeCancelled,
+    }
+}
+
 impl TryFrom<&enums::BankNames> for StripeBankNames {
     type Error = ConnectorError;
     fn try_from(bank: &enums::BankNames) -> Result<Self, Self::Error> {
@@ -1183,10 +1330,62 @@ fn validate_and_get_setup_future_usage(
         Some(common_enums::PaymentMethodType::Affirm)
         | Some(common_enums::PaymentMethodType::AfterpayClearpay)
         | Some(common_enums::PaymentMethodType::Klarna) => Ok(None),
+        // Stripe saves Amazon Pay for reuse only in off session payments, which is what the
+        // payments made with the saved payment method are
+        Some(common_enums::PaymentMethodType::AmazonPay) => {
+            Ok(setup_future_usage.map(|_| common_enums::FutureUsage::OffSession))
+        }
         Some(_) | None => Ok(setup_future_usage),
     }
 }
 
+/// Stripe composes the card statement descriptor from the account prefix and the suffix,
+/// so a full `statement_descriptor` cannot be combined with a suffix for card payments.
+/// For other payment methods the suffix is ignored by Stripe and both can be forwarded.
+fn validate_and_get_statement_descriptors(
+    billing_descriptor: Option<&common_types::payments::BillingDescriptor>,
+    payment_method: enums::PaymentMethod,
+) -> Result<(Option<String>, Option<String>), error_stack::Report<ConnectorError>> {
+    let statement_descriptor =
+        billing_descriptor.and_then(|descriptor| descriptor.statement_descriptor.clone());
+    let statement_descriptor_suffix =
+        billing_descriptor.and_then(|descriptor| descriptor.statement_descriptor_suffix.clone());
+
+    match (
+        payment_method,
+        &statement_descriptor,
+        &statement_descriptor_suffix,
+    ) {
+        (enums::PaymentMethod::Card, Some(_), Some(_)) => Err(ConnectorError::NotSupported {
+            message: "Both statement_descriptor and statement_descriptor_suffix for card payments"
+                .to_string(),
+            connector: "Stripe",
+        }
+        .into()),
+        _ => Ok((statement_descriptor, statement_descriptor_suffix)),
+    }
+}
+
+/// Returns the order line items to send to Stripe, which are only sent for pay later payment
+/// methods where they improve the risk scoring of the payment.
+fn get_stripe_line_items(
+    order_details: Option<&[payments::OrderDetailsWithAmount]>,
+    payment_method_type: Option<&StripePaymentMethodType>,
+) -> Option<StripeLineItems> {
+    match payment_method_type {
+        Some(
+            StripePaymentMethodType::Klarna
+            | StripePaymentMethodType::Affirm
+            | StripePaymentMethodType::AfterpayClearpay,
+        ) => order_details
+            .filter(|order_details| !order_details.is_empty())
+            .map(|order_details| {
+                StripeLineItems(order_details.iter().map(StripeLineItem::from).collect())
+            }),
+        _ => None,
+    }
+}
+
 fn validate_shipping_address_against_payment_method(
     shipping_address: &Option<StripeShippingAddress>,
     payment_method: Option<&StripePaymentMethodType>,
@@ -1217,6 +1416,23 @@ fn validate_shipping_address_against_payment_method(
     }
 }
 
+/// Omit the shipping address when it is identical to the billing address, since sending both is
+/// redundant and can trigger mismatched address risk flags. Payment methods which require the
+/// shipping address always get it.
+fn omit_shipping_address_equal_to_billing(
+    shipping_address: Option<StripeShippingAddress>,
+    billing_address: &StripeBillingAddress,
+    payment_method: Option<&StripePaymentMethodType>,
+) -> Option<StripeShippingAddress> {
+    if matches!(
+        payment_method,
+        Some(StripePaymentMethodType::AfterpayClearpay)
+    ) {
+        return shipping_address;
+    }
+    shipping_address.filter(|shipping_address| !shipping_address.is_same_as(billing_address))
+}
+
 impl TryFrom<&PayLaterData> for StripePaymentMethodType {
     type Error = ConnectorError;
     fn try_from(pay_later_data: &PayLaterData) -> Result<Self, Self::Error> {
@@ -1336,8 +1552,11 @@ impl TryFrom<&payment_method_data::BankDebitData> for StripePaymentMethodType {
 
 fn get_bank_debit_data(
     bank_debit_data: &payment_method_data::BankDebitData,
-) -> (Option<StripePaymentMethodType>, Option<BankDebitData>) {
-    match bank_debit_data {
+) -> Result<
+    (Option<StripePaymentMethodType>, Option<BankDebitData>),
+    error_stack::Report<ConnectorError>,
+> {
+    let bank_debit_data = match bank_debit_data {
         payment_method_data::BankDebitData::AchBankDebit {
             account_number,
             routing_number,
@@ -1351,6 +1570,9 @@ fn get_bank_debit_data(
             (Some(StripePaymentMethodType::Ach), Some(ach_data))
         }
         payment_method_data::BankDebitData::SepaBankDebit { iban, .. } => {
+            if !is_valid_iban(iban.peek()) {
+                return Err(ConnectorError::InvalidDataFormat { field_name: "iban" }.into());
+            }
             let sepa_data: BankDebitData = BankDebitData::Sepa {
                 iban: iban.to_owned(),
             };
@@ -1379,7 +1601,40 @@ fn get_bank_debit_data(
             (Some(StripePaymentMethodType::Bacs), Some(bacs_data))
         }
         _ => (None, None),
+    };
+    Ok(bank_debit_data)
+}
+
+/// Validates the format and the checksum (ISO 13616) of an IBAN, ignoring any spaces in it
+fn is_valid_iban(iban: &str) -> bool {
+    let iban: Vec<char> = iban
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .map(|c| c.to_ascii_uppercase())
+        .collect();
+    let (Some(country_code), Some(check_digits)) = (iban.get(..2), iban.get(2..4)) else {
+        return false;
+    };
+    if !(15..=34).contains(&iban.len())
+        || !country_code.iter().all(char::is_ascii_alphabetic)
+        || !check_digits.iter().all(char::is_ascii_digit)
+        || !iban.iter().all(char::is_ascii_alphanumeric)
+    {
+        return false;
     }
+
+    // The IBAN is valid if, with its first four characters moved to the end and its letters
+    // replaced by two digit numbers (A = 10, ..., Z = 35), it is congruent to 1 modulo 97
+    iban.iter()
+        .cycle()
+        .skip(4)
+        .take(iban.len())
+        .try_fold(0u32, |remainder, c| {
+            let value = c.to_digit(36)?;
+            let multiplier = if value < 10 { 10 } else { 100 };
+            Some((remainder * multiplier + value) % 97)
+        })
+        == Some(1)
 }
 
 pub struct PaymentRequestDetails {
@@ -1391,6 +1646,23 @@ pub struct PaymentRequestDetails {
     pub request_extended_authorization:
         Option<primitive_wrappers::RequestExtendedAuthorizationBool>,
     pub request_overcapture: Option<StripeRequestOvercaptureBool>,
+    pub preferred_card_network: StripePreferredCardNetwork,
+}
+
+/// Card network requested by the merchant, as opposed to the network detected from the card
+#[derive(Debug, Clone, Default)]
+pub struct StripePreferredCardNetwork {
+    pub card_network: Option<common_enums::CardNetwork>,
+    pub is_required: bool,
+}
+
+impl From<&StripeConnectorMetadataObject> for StripePreferredCardNetwork {
+    fn from(connector_metadata: &StripeConnectorMetadataObject) -> Self {
+        Self {
+            card_network: connector_metadata.preferred_card_network.clone(),
+            is_required: connector_metadata.require_preferred_card_network,
+        }
+    }
 }
 
 fn create_stripe_payment_method(
@@ -1419,6 +1691,7 @@ fn create_stripe_payment_method(
                     payment_request_details.request_incremental_authorization,
                     payment_request_details.request_extended_authorization,
                     payment_request_details.request_overcapture,
+                    payment_request_details.preferred_card_network,
                 ))?,
                 Some(StripePaymentMethodType::Card),
                 payment_request_details.billing_address,
@@ -1463,7 +1736,7 @@ fn create_stripe_payment_method(
             ))
         }
         PaymentMethodData::BankDebit(bank_debit_data) => {
-            let (pm_type, bank_debit_data) = get_bank_debit_data(bank_debit_data);
+            let (pm_type, bank_debit_data) = get_bank_debit_data(bank_debit_data)?;
 
             let pm_data = StripePaymentMethodData::BankDebit(StripeBankDebitData {
                 bank_specific_data: bank_debit_data,
@@ -1652,6 +1925,46 @@ fn get_stripe_card_network(card_network: common_enums::CardNetwork) -> Option<St
     }
 }
 
+/// Stripe network to process the card on. The network requested by the merchant takes precedence
+/// over the network detected for the card; a requested network that Stripe does not support is
+/// dropped with a warning, unless the merchant requires it to be honored.
+fn get_preferred_stripe_card_network(
+    detected_card_network: Option<&common_enums::CardNetwork>,
+    preferred_card_network: &StripePreferredCardNetwork,
+) -> Result<Option<StripeCardNetwork>, error_stack::Report<ConnectorError>> {
+    let detected_stripe_card_network = detected_card_network
+        .and_then(|card_network| get_stripe_card_network(card_network.clone()));
+    let Some(card_network) = preferred_card_network.card_network.as_ref() else {
+        return Ok(detected_stripe_card_network);
+    };
+    if let Some(stripe_card_network) = get_stripe_card_network(card_network.clone()) {
+        return Ok(Some(stripe_card_network));
+    }
+
+    router_env::logger::warn!(
+        ?card_network,
+        require_preferred_card_network = preferred_card_network.is_required,
+        "Preferred card network is not supported by Stripe"
+    );
+    crate::metrics::CONNECTOR_PREFERRED_CARD_NETWORK_NOT_HONORED.add(
+        1,
+        router_env::metric_attributes!(
+            ("connector", "stripe"),
+            ("card_network", card_network.to_string()),
+        ),
+    );
+
+    if preferred_card_network.is_required {
+        Err(ConnectorError::NotSupported {
+            message: format!("Preferred card network {card_network}"),
+            connector: "Stripe",
+        }
+        .into())
+    } else {
+        Ok(detected_stripe_card_network)
+    }
+}
+
 impl
     TryFrom<(
         &Card,
@@ -1660,9 +1973,10 @@ impl
         bool,
         Option<primitive_wrappers::RequestExtendedAuthorizationBool>,
         Option<StripeRequestOvercaptureBool>,
+        StripePreferredCardNetwork,
     )> for StripePaymentMethodData
 {
-    type Error = ConnectorError;
+    type Error = error_stack::Report<ConnectorError>;
     fn try_from(
         (
             card,
@@ -1671,6 +1985,7 @@ impl
             request_incremental_authorization,
             request_extended_authorization,
             request_overcapture,
+            preferred_card_network,
         ): (
             &Card,
             Auth3ds,
@@ -1678,8 +1993,18 @@ impl
             bool,
             Option<primitive_wrappers::RequestExtendedAuthorizationBool>,
             Option<StripeRequestOvercaptureBool>,
+            StripePreferredCardNetwork,
         ),
     ) -> Result<Self, Self::Error> {
+        // Stripe rejects out of range months with an error which does not point to the field
+        card.card_exp_month
+            .peek()
+            .parse::<u8>()
+            .ok()
+            .and_then(|month| ::cards::CardExpirationMonth::try_from(month).ok())
+            .ok_or(ConnectorError::InvalidDataFormat {
+                field_name: "card_exp_month",
+            })?;
         let payment_method_auth_type = match authentication_data {
             Some(data) => Some(StripePaymentMethodAuthType::External3ds(
                 StripeExternalThreeDsData {
@@ -1709,10 +2034,10 @@ impl
             payment_method_data_card_exp_year: card.card_exp_year.clone(),
             payment_method_data_card_cvc: Some(card.card_cvc.clone()),
             payment_method_auth_type,
-            payment_method_data_card_preferred_network: card
-                .card_network
-                .clone()
-                .and_then(get_stripe_card_network),
+            payment_method_data_card_preferred_network: get_preferred_stripe_card_network(
+                card.card_network.as_ref(),
+                &preferred_card_network,
+            )?,
             request_incremental_authorization: if request_incremental_authorization {
                 Some(StripeRequestIncrementalAuthorization::IfAvailable)
             } else {
@@ -1742,16 +2067,8 @@ impl TryFrom<(&WalletData, Option<PaymentMethodToken>)> for StripePaymentMethodD
                     if let Some(PaymentMethodToken::ApplePayDecrypt(decrypt_data)) =
                         payment_method_token
                     {
-                        let expiry_year_4_digit = decrypt_data.get_four_digit_expiry_year();
                         Some(Self::Wallet(StripeWallet::ApplePayPredecryptToken(
-                            Box::new(StripeApplePayPredecrypt {
-                                number: decrypt_data.clone().application_primary_account_number,
-                                exp_year: expiry_year_4_digit,
-                                exp_month: decrypt_data.application_expiration_month,
-                                eci: decrypt_data.payment_data.eci_indicator,
-                                cryptogram: decrypt_data.payment_data.online_payment_cryptogram,
-                                tokenization_method: "apple_pay".to_string(),
-                            }),
+                            Box::new(StripeApplePayPredecrypt::from(decrypt_data.as_ref())),
                         )))
                     } else if let Some(PaymentMethodToken::Token(applepay_token)) =
                         payment_method_token
@@ -1930,6 +2247,41 @@ impl TryFrom<&BankRedirectData> for StripePaymentMethodData {
     }
 }
 
+impl From<&common_types::payments::ApplePayPredecryptData> for StripeApplePayPredecrypt {
+    fn from(decrypt_data: &common_types::payments::ApplePayPredecryptData) -> Self {
+        Self {
+            number: decrypt_data.application_primary_account_number.clone(),
+            exp_year: decrypt_data.get_four_digit_expiry_year(),
+            exp_month: decrypt_data.application_expiration_month.clone(),
+            eci: decrypt_data.payment_data.eci_indicator.clone(),
+            cryptogram: decrypt_data.payment_data.online_payment_cryptogram.clone(),
+            tokenization_method: StripeTokenizationMethod::ApplePay,
+        }
+    }
+}
+
+impl TryFrom<&common_types::payments::GPayPredecryptData> for StripeGooglePayPredecrypt {
+    type Error = error_stack::Report<ConnectorError>;
+    fn try_from(
+        predecrypted_data: &common_types::payments::GPayPredecryptData,
+    ) -> Result<Self, Self::Error> {
+        let expiry_year_4_digit = predecrypted_data
+            .get_four_digit_expiry_year()
+            .change_context(ConnectorError::InvalidDataFormat {
+                field_name: "expiry_year_4_digit",
+            })?;
+
+        Ok(Self {
+            number: predecrypted_data.application_primary_account_number.clone(),
+            exp_year: expiry_year_4_digit,
+            exp_month: predecrypted_data.card_exp_month.clone(),
+            eci: predecrypted_data.eci_indicator.clone(),
+            cryptogram: predecrypted_data.cryptogram.clone(),
+            tokenization_method: StripeTokenizationMethod::AndroidPay,
+        })
+    }
+}
+
 impl TryFrom<(&GooglePayWalletData, Option<PaymentMethodToken>)> for StripePaymentMethodData {
     type Error = error_stack::Report<ConnectorError>;
     fn try_from(
@@ -1938,21 +2290,10 @@ impl TryFrom<(&GooglePayWalletData, Option<PaymentMethodToken>)> for StripePayme
         match payment_method_token {
             Some(PaymentMethodToken::GooglePayDecrypt(predecrypted_data)) => {
                 // Use predecrypted token flow
-                let expiry_year_4_digit = predecrypted_data
-                    .get_four_digit_expiry_year()
-                    .change_context(ConnectorError::InvalidDataFormat {
-                        field_name: "expiry_year_4_digit",
-                    })?;
-
                 Ok(Self::Wallet(StripeWallet::GooglePayPredecryptToken(
-                    Box::new(StripeGooglePayPredecrypt {
-                        number: predecrypted_data.application_primary_account_number.clone(),
-                        exp_year: expiry_year_4_digit,
-                        exp_month: predecrypted_data.card_exp_month.clone(),
-                        eci: predecrypted_data.eci_indicator.clone(),
-                        cryptogram: predecrypted_data.cryptogram.clone(),
-                        tokenization_method: "android_pay".to_string(),
-                    }),
+                    Box::new(StripeGooglePayPredecrypt::try_from(
+                        predecrypted_data.as_ref(),
+                    )?),
                 )))
             }
             Some(PaymentMethodToken::Token(gpay_token)) => {
@@ -1990,6 +2331,9 @@ impl TryFrom<(&PaymentsAuthorizeRouterData, MinorUnit)> for PaymentIntentRequest
     type Error = error_stack::Report<ConnectorError>;
     fn try_from(data: (&PaymentsAuthorizeRouterData, MinorUnit)) -> Result<Self, Self::Error> {
         let item = data.0;
+        let connector_metadata =
+            StripeConnectorMetadataObject::try_from(&item.connector_meta_data)?;
+        let create_only = connector_metadata.create_payment_intent_only;
 
         let mandate_metadata = item
             .request
@@ -2050,14 +2394,14 @@ impl TryFrom<(&PaymentsAuthorizeRouterData, MinorUnit)> for PaymentIntentRequest
                 zip: item.get_optional_shipping_zip(),
                 state: item.get_optional_shipping_state(),
                 name: item.get_optional_shipping_full_name(),
-                phone: item.get_optional_shipping_phone_number(),
+                phone: item.get_optional_normalized_shipping_phone_number(),
             })
         };
 
         let billing_address = if payment_method_token.is_some() {
             None
         } else {
-            Some(StripeBillingAddress {
+            let billing_address = StripeBillingAddress {
                 city: item.get_optional_billing_city(),
                 country: item.get_optional_billing_country(),
                 address_line1: item.get_optional_billing_line1(),
@@ -2066,7 +2410,13 @@ impl TryFrom<(&PaymentsAuthorizeRouterData, MinorUnit)> for PaymentIntentRequest
                 state: item.get_optional_billing_state(),
                 name: item.get_optional_billing_full_name(),
                 email: item.get_optional_billing_email(),
-                phone: item.get_optional_billing_phone_number(),
+                phone: item.get_optional_normalized_billing_phone_number(),
+            };
+            Some(if connector_metadata.infer_billing_country_from_card_bin {
+                billing_address
+                    .with_country_inferred_from_card_bin(&item.request.payment_method_data)
+            } else {
+                billing_address
             })
         };
 
@@ -2101,7 +2451,10 @@ impl TryFrom<(&PaymentsAuthorizeRouterData, MinorUnit)> for PaymentIntentRequest
                     None,
                     connector_mandate_ids.get_connector_mandate_id(),
                     StripeBillingAddress::default(),
-                    get_payment_method_type_for_saved_payment_method_payment(item)?,
+                    get_payment_method_type_for_saved_payment_method_payment(
+                        item.payment_method,
+                        item.recurring_mandate_payment_data.as_ref(),
+                    )?,
                     None,
                 ),
                 Some(mandates::MandateReferenceId::NetworkMandateId(network_transaction_id)) => {
@@ -2133,10 +2486,12 @@ impl TryFrom<(&PaymentsAuthorizeRouterData, MinorUnit)> for PaymentIntentRequest
                             payment_method_data_card_cvc: None,
                             payment_method_auth_type: None,
                             payment_method_data_card_preferred_network:
-                                card_details_for_network_transaction_id
-                                    .card_network
-                                    .clone()
-                                    .and_then(get_stripe_card_network),
+                                get_preferred_stripe_card_network(
+                                    card_details_for_network_transaction_id
+                                        .card_network
+                                        .as_ref(),
+                                    &StripePreferredCardNetwork::from(&connector_metadata),
+                                )?,
                             request_incremental_authorization: None,
                             request_extended_authorization: None,
                             request_overcapture: None,
@@ -2203,6 +2558,9 @@ impl TryFrom<(&PaymentsAuthorizeRouterData, MinorUnit)> for PaymentIntentRequest
                             request_overcapture: item.request
                                 .enable_overcapture
                                 .and_then(get_stripe_overcapture_request),
+                            preferred_card_network: StripePreferredCardNetwork::from(
+                                &connector_metadata,
+                            ),
                 }
             , item.request.authentication_data.clone()
             )?;
@@ -2418,26 +2776,48 @@ impl TryFrom<(&PaymentsAuthorizeRouterData, MinorUnit)> for PaymentIntentRequest
             None
         };
 
-        Ok(Self {
+        let (statement_descriptor, statement_descriptor_suffix) =
+            validate_and_get_statement_descriptors(
+                item.request.billing_descriptor.as_ref(),
+                item.payment_method,
+            )?;
+
+        let line_items = get_stripe_line_items(
+            item.request.order_details.as_deref(),
+            payment_method_types.as_ref(),
+        );
+
+        let use_stripe_sdk = get_use_stripe_sdk(
+            &connector_metadata,
+            item.header_payload
+                .as_ref()
+                .and_then(|header_payload| header_payload.x_client_platform.as_ref()),
+            item.payment_method,
+        );
+
+        let shipping_address = if connector_metadata.omit_shipping_equal_to_billing {
+            omit_shipping_address_equal_to_billing(
+                shipping_address,
+                &billing_address,
+                payment_method_types.as_ref(),
+            )
+        } else {
+            shipping_address
+        };
+
+        let request = Self {
             amount,                                      //hopefully we don't loose some cents here
             currency: item.request.currency.to_string(), //we need to copy the value and not transfer ownership
-            statement_descriptor_suffix: item
-                .request
-                .billing_descriptor
-                .as_ref()
-                .and_then(|descriptor| descriptor.statement_descriptor_suffix.clone()),
-            statement_descriptor: item
-                .request
-                .billing_descriptor
-                .as_ref()
-                .and_then(|descriptor| descriptor.statement_descriptor.clone()),
+            statement_descriptor_suffix,
+            statement_descriptor,
             meta_data,
-            return_url: item
-                .request
-                .router_return_url
-                .clone()
-                .unwrap_or_else(|| "https://juspay.in/".to_string()),
-            confirm: true, // Stripe requires confirm to be true if return URL is present
+            return_url: Some(
+                item.request
+                    .router_return_url
+                    .clone()
+                    .unwrap_or_else(|| "https://juspay.in/".to_string()),
+            ),
+            confirm: Some(true), // Stripe requires confirm to be true if return URL is present
             description: item.description.clone(),
             shipping: shipping_address,
             billing: billing_address,
@@ -2465,6 +2845,14 @@ impl TryFrom<(&PaymentsAuthorizeRouterData, MinorUnit)> for PaymentIntentRequest
             charges,
             moto: is_moto,
             on_behalf_of,
+            line_items,
+            use_stripe_sdk,
+        };
+
+        Ok(if create_only {
+            request.into_create_only()
+        } else {
+            request
         })
     }
 }
@@ -2479,12 +2867,13 @@ fn get_stripe_overcapture_request(
 }
 
 fn get_payment_method_type_for_saved_payment_method_payment(
-    item: &PaymentsAuthorizeRouterData,
+    payment_method: api_enums::PaymentMethod,
+    recurring_mandate_payment_data: Option<&RecurringMandatePaymentData>,
 ) -> Result<Option<StripePaymentMethodType>, error_stack::Report<ConnectorError>> {
-    if item.payment_method == api_enums::PaymentMethod::Card {
+    if payment_method == api_enums::PaymentMethod::Card {
         Ok(Some(StripePaymentMethodType::Card)) //stripe takes ["Card"] as default
     } else {
-        let stripe_payment_method_type = match item.recurring_mandate_payment_data.clone() {
+        let stripe_payment_method_type = match recurring_mandate_payment_data {
             Some(recurring_payment_method_data) => {
                 match recurring_payment_method_data.payment_method_type {
                     Some(payment_method_type) => {
@@ -2591,7 +2980,7 @@ impl TryFrom<&TokenizationRouterData> for TokenRequest {
         let billing_address = StripeBillingAddressCardToken {
             name: item.get_optional_billing_full_name(),
             email: item.get_optional_billing_email(),
-            phone: item.get_optional_billing_phone_number(),
+            phone: item.get_optional_normalized_billing_phone_number(),
             address_line1: item.get_optional_billing_line1(),
             address_line2: item.get_optional_billing_line2(),
             city: item.get_optional_billing_city(),
@@ -2621,6 +3010,7 @@ impl TryFrom<&TokenizationRouterData> for TokenRequest {
                         request_incremental_authorization: false,
                         request_extended_authorization: None,
                         request_overcapture: None,
+                        preferred_card_network: StripePreferredCardNetwork::default(),
                     },
                     None,
                 )?
@@ -3086,6 +3476,26 @@ impl From<&AdditionalPaymentMethodDetails> for AdditionalPaymentMethodConnectorR
     }
 }
 
+fn get_avs_match_result(check: Option<&Value>) -> Option<enums::AvsMatchResult> {
+    match check?.as_str()? {
+        "pass" => Some(enums::AvsMatchResult::Match),
+        "fail" => Some(enums::AvsMatchResult::NoMatch),
+        "unavailable" => Some(enums::AvsMatchResult::Unavailable),
+        "unchecked" => Some(enums::AvsMatchResult::NotChecked),
+        _ => None,
+    }
+}
+
+/// Stripe returns the results of the street and postal code checks separately, which are stored
+/// in the payment checks in their normalized form as well
+fn add_avs_match_details(checks: Value) -> Value {
+    let avs_match_details = common_types::payments::AvsMatchDetails {
+        avs_street_match: get_avs_match_result(checks.get("address_line1_check")),
+        avs_postal_code_match: get_avs_match_result(checks.get("address_postal_code_check")),
+    };
+    avs_match_details.add_to_payment_checks(checks)
+}
+
 fn get_extended_authorization_data(
     item: &AdditionalPaymentMethodDetails,
     created_at: Option<PrimitiveDateTime>,
@@ -3121,7 +3531,7 @@ impl StripePaymentMethodDetailsResponse {
     pub fn get_additional_payment_method_data(&self) -> Option<AdditionalPaymentMethodDetails> {
         match self {
             Self::Card { card } => Some(AdditionalPaymentMethodDetails {
-                payment_checks: card.checks.clone(),
+                payment_checks: card.checks.clone().map(add_avs_match_details),
                 authentication_details: card.three_d_secure.clone(),
                 extended_authorization: card.extended_authorization.clone(),
                 capture_before: card.capture_before,
@@ -3326,10 +3736,30 @@ where
             _ => None,
         };
 
-        let connector_metadata =
-            get_connector_metadata(item.response.next_action.as_ref(), item.response.amount)?;
+        let create_only = StripeConnectorMetadataObject::try_from(&item.data.connector_meta_data)?
+            .create_payment_intent_only;
+        let is_unconfirmed = matches!(
+            item.response.status,
+            StripePaymentStatus::RequiresPaymentMethod | StripePaymentStatus::RequiresConfirmation
+        );
 
-        let status = get_stripe_payment_status(item.response.status, item.data.status);
+        let (status, connector_metadata) = if create_only && is_unconfirmed {
+            (
+                match item.response.status {
+                    // The payment method can still be provided when confirming client-side
+                    StripePaymentStatus::RequiresPaymentMethod => {
+                        AttemptStatus::PaymentMethodAwaited
+                    }
+                    _ => AttemptStatus::ConfirmationAwaited,
+                },
+                get_create_only_connector_metadata(&item.response)?,
+            )
+        } else {
+            (
+                get_stripe_payment_status(item.response.status, item.data.status),
+                get_payment_intent_connector_metadata(&item.response)?,
+            )
+        };
 
         let response = if is_payment_failure(status) {
             *get_stripe_payments_response_data(
@@ -3570,6 +4000,57 @@ pub fn get_connector_metadata(
     Ok(next_action_response)
 }
 
+/// Request next actions handled by the Stripe SDK for card payments of native mobile clients, if
+/// the merchant has opted in
+fn get_use_stripe_sdk(
+    connector_metadata: &StripeConnectorMetadataObject,
+    client_platform: Option<&common_enums::ClientPlatform>,
+    payment_method: api_enums::PaymentMethod,
+) -> Option<bool> {
+    let is_mobile_sdk_client = matches!(
+        client_platform,
+        Some(common_enums::ClientPlatform::Ios | common_enums::ClientPlatform::Android)
+    );
+
+    (connector_metadata.use_stripe_sdk_for_mobile_clients
+        && is_mobile_sdk_client
+        && payment_method == api_enums::PaymentMethod::Card)
+        .then_some(true)
+}
+
+/// Get the connector metadata of a confirmed payment intent. A next action to be handled by the
+/// Stripe SDK of the mobile client is returned as the next action of the payment, the client
+/// secret of the payment intent is not stored.
+fn get_payment_intent_connector_metadata(
+    response: &PaymentIntentResponse,
+) -> CustomResult<Option<Value>, ConnectorError> {
+    match response.next_action.as_ref() {
+        Some(StripeNextActionResponse::UseStripeSdk(use_stripe_sdk)) => {
+            payments::ConnectorSdkNextActionData {
+                connector: "stripe".to_string(),
+                sdk_next_action: use_stripe_sdk.clone().expose(),
+            }
+            .encode_to_value()
+            .map(Some)
+            .change_context(ConnectorError::ResponseHandlingFailed)
+        }
+        next_action => get_connector_metadata(next_action, response.amount),
+    }
+}
+
+fn get_create_only_connector_metadata(
+    response: &PaymentIntentResponse,
+) -> CustomResult<Option<Value>, ConnectorError> {
+    response
+        .client_secret
+        .clone()
+        .map(|client_secret| {
+            StripeCreateOnlyPaymentIntentMetadata { client_secret }.encode_to_value()
+        })
+        .transpose()
+        .change_context(ConnectorError::ResponseHandlingFailed)
+}
+
 pub fn get_payment_method_id(
     latest_charge: Option<StripeChargeEnum>,
     payment_method_id_from_intent_root: Secret<String>,
@@ -3644,8 +4125,7 @@ where
                 }
             });
 
-        let connector_metadata =
-            get_connector_metadata(item.response.next_action.as_ref(), item.response.amount)?;
+        let connector_metadata = get_payment_intent_connector_metadata(&item.response)?;
 
         let status = get_stripe_payment_status(item.response.status.to_owned(), item.data.status);
 
@@ -3833,6 +4313,7 @@ pub enum StripeNextActionResponse {
     WechatPayDisplayQrCode(WechatPayRedirectToQr),
     DisplayBankTransferInstructions(StripeBankTransferDetails),
     MultibancoDisplayDetails(MultibancoCreditTansferResponse),
+    UseStripeSdk(Secret<Value>),
     NoNextActionBody,
 }
 
@@ -3849,6 +4330,7 @@ impl StripeNextActionResponse {
             Self::CashappHandleRedirectOrDisplayQrCode(_) => None,
             Self::DisplayBankTransferInstructions(_) => None,
             Self::MultibancoDisplayDetails(_) => None,
+            Self::UseStripeSdk(_) => None,
             Self::NoNextActionBody => None,
         }
     }
@@ -3899,6 +4381,7 @@ impl Serialize for StripeNextActionResponse {
             Self::WechatPayDisplayQrCode(ref i) => Serialize::serialize(i, serializer),
             Self::DisplayBankTransferInstructions(ref i) => Serialize::serialize(i, serializer),
             Self::MultibancoDisplayDetails(ref i) => Serialize::serialize(i, serializer),
+            Self::UseStripeSdk(ref i) => Serialize::serialize(i, serializer),
             Self::NoNextActionBody => Serialize::serialize("NoNextActionBody", serializer),
         }
     }
@@ -4031,16 +4514,29 @@ impl<F> TryFrom<(&RefundsRouterData<F>, MinorUnit)> for RefundRequest {
     ) -> Result<Self, Self::Error> {
         let payment_intent = item.request.connector_transaction_id.clone();
         Ok(Self {
-            amount: Some(refund_amount),
+            amount: get_refund_amount(
+                refund_amount,
+                item.request.minor_refund_amount,
+                item.minor_amount_captured,
+            ),
             payment_intent,
-            meta_data: StripeMetadata {
-                order_id: Some(item.request.refund_id.clone()),
-                is_refund_id_as_reference: Some("true".to_string()),
-            },
+            meta_data: StripeMetadata::for_refund(&item.request.refund_id),
         })
     }
 }
 
+/// Stripe refunds the full remaining amount of the charge when `amount` is not sent, so it is
+/// omitted for full refunds to avoid mismatches from rounding of the converted amount. A refund
+/// is full when it refunds the captured amount, which is less than the payment amount for
+/// partially captured payments.
+fn get_refund_amount(
+    refund_amount: MinorUnit,
+    minor_refund_amount: MinorUnit,
+    minor_amount_captured: Option<MinorUnit>,
+) -> Option<MinorUnit> {
+    (minor_amount_captured != Some(minor_refund_amount)).then_some(refund_amount)
+}
+
 #[derive(Debug, Serialize)]
 pub struct ChargeRefundRequest {
     pub charge: String,
@@ -4077,11 +4573,8 @@ impl<F> TryFrom<&RefundsRouterData<F>> for ChargeRefundRequest {
                         charge: stripe_refund.charge_id.clone(),
                         refund_application_fee,
                         reverse_transfer,
-                        amount: Some(amount),
-                        meta_data: StripeMetadata {
-                            order_id: Some(item.request.refund_id.clone()),
-                            is_refund_id_as_reference: Some("true".to_string()),
-                        },
+                        amount: get_refund_amount(amount, amount, item.minor_amount_captured),
+                        meta_data: StripeMetadata::for_refund(&item.request.refund_id),
                     })
                 }
                 _ => Err(ConnectorError::MissingRequiredField {
@@ -4259,6 +4752,19 @@ pub struct StripeShippingAddress {
     pub phone: Option<Secret<String>>,
 }
 
+impl StripeShippingAddress {
+    fn is_same_as(&self, billing_address: &StripeBillingAddress) -> bool {
+        self.city == billing_address.city
+            && self.country == billing_address.country
+            && self.line1 == billing_address.address_line1
+            && self.line2 == billing_address.address_line2
+            && self.zip == billing_address.zip_code
+            && self.state == billing_address.state
+            && self.name == billing_address.name
+            && self.phone == billing_address.phone
+    }
+}
+
 #[derive(Debug, Clone, Default, Eq, PartialEq, Serialize)]
 pub struct StripeBillingAddress {
     #[serde(rename = "payment_method_data[billing_details][email]")]
@@ -4281,6 +4787,24 @@ pub struct StripeBillingAddress {
     pub phone: Option<Secret<String>>,
 }
 
+impl StripeBillingAddress {
+    /// Fills in the billing country from the issuing country of the card BIN, if the billing
+    /// country is missing. An explicitly provided billing country is always retained.
+    fn with_country_inferred_from_card_bin(self, payment_method_data: &PaymentMethodData) -> Self {
+        if self.country.is_some() {
+            return self;
+        }
+        let PaymentMethodData::Card(card) = payment_method_data else {
+            return self;
+        };
+        let country = card
+            .card_issuing_country_code
+            .as_deref()
+            .and_then(|country_code| api_enums::CountryAlpha2::from_str(country_code).ok());
+        Self { country, ..self }
+    }
+}
+
 #[derive(Debug, Clone, serde::Deserialize, Eq, PartialEq)]
 pub struct StripeRedirectResponse {
     pub payment_intent: Option<String>,
@@ -4326,9 +4850,12 @@ impl TryFrom<&PaymentsUpdateMetadataRouterData> for UpdateMetadataRequest {
 fn format_metadata_for_request(merchant_metadata: Secret<Value>) -> HashMap<String, String> {
     let mut formatted_metadata = HashMap::new();
     if let Value::Object(metadata_map) = merchant_metadata.expose() {
-        for (key, value) in metadata_map {
-            formatted_metadata.insert(format!("metadata[{key}]"), value.to_string());
-        }
+        insert_metadata(
+            &mut formatted_metadata,
+            metadata_map
+                .into_iter()
+                .map(|(key, value)| (key, value.to_string())),
+        );
     }
     formatted_metadata
 }
@@ -4792,6 +5319,9 @@ impl
                     item.request.request_incremental_authorization,
                     None,
                     None,
+                    StripePreferredCardNetwork::from(&StripeConnectorMetadataObject::try_from(
+                        &item.connector_meta_data,
+                    )?),
                 ))?)
             }
             PaymentMethodData::PayLater(_) => Ok(Self::PayLater(StripePayLaterData {
@@ -4805,7 +5335,7 @@ impl
                 item.payment_method_token.clone(),
             ))?),
             PaymentMethodData::BankDebit(bank_debit_data) => {
-                let (_pm_type, bank_data) = get_bank_debit_data(bank_debit_data);
+                let (_pm_type, bank_data) = get_bank_debit_data(bank_debit_data)?;
 
                 Ok(Self::BankDebit(StripeBankDebitData {
                     bank_specific_data: bank_data,
@@ -5119,17 +5649,98 @@ fn get_transaction_metadata(
         let hashmap: HashMap<String, Value> =
             serde_json::from_str(&metadata.peek().to_string()).unwrap_or(HashMap::new());
 
-        for (key, value) in hashmap {
-            let metadata_value = match value {
-                Value::String(string_value) => string_value,
-                value_data => value_data.to_string(),
-            };
-            meta_data.insert(format!("metadata[{key}]"), metadata_value);
-        }
+        insert_metadata(
+            &mut meta_data,
+            hashmap.into_iter().map(|(key, value)| {
+                let metadata_value = match value {
+                    Value::String(string_value) => string_value,
+                    value_data => value_data.to_string(),
+                };
+                (key, metadata_value)
+            }),
+        );
     };
     meta_data
 }
 
+/// Insert the merchant metadata as `metadata[<key>]` parameters, with the keys sanitized. A key
+/// which collides with another key once sanitized, such as `a[b]` and `a_b_`, is suffixed with a
+/// counter instead of overwriting the value of the other key. The keys which do not need to be
+/// sanitized are inserted first, so that they are always sent as is.
+fn insert_metadata(
+    meta_data: &mut HashMap<String, String>,
+    merchant_metadata: impl Iterator<Item = (String, String)>,
+) {
+    let mut merchant_metadata = merchant_metadata.collect::<Vec<_>>();
+    merchant_metadata.sort_by(|(key, _), (other_key, _)| {
+        key.contains(['[', ']'])
+            .cmp(&other_key.contains(['[', ']']))
+            .then_with(|| key.cmp(other_key))
+    });
+
+    for (key, value) in merchant_metadata {
+        let sanitized_key = sanitize_metadata_key(&key);
+        let mut metadata_key = format!("metadata[{sanitized_key}]");
+        if sanitized_key != key {
+            let mut suffix = 1;
+            while meta_data.contains_key(&metadata_key) {
+                suffix += 1;
+                metadata_key = format!("metadata[{sanitized_key}_{suffix}]");
+            }
+            if suffix > 1 {
+                router_env::logger::warn!(
+                    metadata_key = key,
+                    sanitized_metadata_key = metadata_key,
+                    "Sanitized metadata key for Stripe collides with another key, suffixed it"
+                );
+            }
+        }
+        meta_data.insert(metadata_key, value);
+    }
+}
+
+/// Replace the square brackets in a metadata key with underscores. The keys are encoded as
+/// `metadata[<key>]` in the form body, where brackets in the key would be parsed by Stripe as
+/// nested parameters.
+fn sanitize_metadata_key(key: &str) -> String {
+    if !key.contains(['[', ']']) {
+        return key.to_string();
+    }
+
+    let sanitized_key = key.replace(['[', ']'], "_");
+    router_env::logger::info!(
+        metadata_key = key,
+        sanitized_metadata_key = sanitized_key,
+        "Sanitized the metadata key for Stripe"
+    );
+    sanitized_key
+}
+
+/// Truncate the metadata values and drop the metadata keys exceeding the limits of the connector.
+/// The order id is always retained, the remaining keys are retained in sorted order.
+pub fn limit_transaction_metadata(
+    meta_data: HashMap<String, String>,
+    limits: &ConnectorMetadataLimits,
+) -> HashMap<String, String> {
+    let order_id_key = "metadata[order_id]";
+    let mut entries = meta_data.into_iter().collect::<Vec<_>>();
+    entries.sort_by(|(key_a, _), (key_b, _)| {
+        (key_a != order_id_key, key_a).cmp(&(key_b != order_id_key, key_b))
+    });
+
+    entries
+        .into_iter()
+        .take(limits.max_keys)
+        .map(|(key, value)| {
+            let value = match value.char_indices().nth(limits.max_value_length) {
+                Some((index, _)) => value.get(..index).unwrap_or(&value).to_string(),
+                None => value,
+            };
+            (key, value)
+        })
+        .collect()
+}
+
 fn get_stripe_payments_response_data(
     response: &Option<ErrorDetails>,
     http_code: u16,
@@ -5221,16 +5832,127 @@ where
 }
 
 #[cfg(test)]
-mod test_validate_shipping_address_against_payment_method {
-    use common_enums::CountryAlpha2;
+mod tests {
+    use std::{collections::HashMap, marker::PhantomData, net::IpAddr, str::FromStr};
+
+    use api_models::{
+        enums as api_enums,
+        payments::{ConnectorSdkNextActionData, OrderDetailsWithAmount},
+        webhooks::IncomingWebhookEvent,
+    };
+    use common_enums::{
+        enums::{self, AvsMatchResult},
+        CardNetwork, ClientPlatform, CountryAlpha2, FutureUsage, PaymentChargeType, PaymentMethod,
+        PaymentMethodType, StripeChargeType,
+    };
+    use common_types::payments::{
+        AcceptanceType, ApplePayCryptogramData, ApplePayPredecryptData, AvsMatchDetails,
+        BillingDescriptor, CustomerAcceptance, GPayPredecryptData, OnlineMandate,
+        SplitPaymentsRequest, StripeSplitPaymentRequest,
+    };
+    use common_utils::{id_type, pii::Email, types::MinorUnit};
+    use hyperswitch_domain_models::{
+        address::{Address, AddressDetails, PhoneDetails},
+        connector_endpoints::ConnectorMetadataLimits,
+        mandates::MandateData,
+        payment_address::PaymentAddress,
+        payment_method_data::{self, AmazonPayRedirect, Card, PaymentMethodData, WalletData},
+        router_data::{ConnectorAuthType, ErrorResponse, RecurringMandatePaymentData, RouterData},
+        router_flow_types::refunds::Execute,
+        router_request_types::{
+            BrowserInformation, ChargeRefundsOptions, DestinationChargeRefund,
+            PaymentsAuthorizeData, RefundsData, SplitRefundsRequest, StripeSplitRefund,
+        },
+        types::{PaymentsAuthorizeRouterData, RefundsRouterData},
+    };
     use hyperswitch_interfaces::errors::ConnectorError;
-    use hyperswitch_masking::Secret;
+    use hyperswitch_masking::{PeekInterface, Secret};
+    use serde_json::json;
 
     use crate::connectors::stripe::transformers::{
-        validate_shipping_address_against_payment_method, StripePaymentMethodType,
-        StripeShippingAddress,
+        add_avs_match_details, get_bank_debit_data, get_create_only_connector_metadata,
+        get_dispute_closed_event, get_payment_intent_connector_metadata,
+        get_payment_method_type_for_saved_payment_method_payment,
+        get_preferred_stripe_card_network, get_stripe_line_items, get_transaction_metadata,
+        get_use_stripe_sdk, limit_transaction_metadata, omit_shipping_address_equal_to_billing,
+        validate_and_get_setup_future_usage, validate_and_get_statement_descriptors,
+        validate_shipping_address_against_payment_method, AmazonpayPayment, Auth3ds, BankDebitData,
+        ChargeRefundRequest, PaymentIntentRequest, PaymentIntentResponse, RefundRequest,
+        StripeApplePayPredecrypt, StripeBillingAddress, StripeCaptureMethod, StripeCardNetwork,
+        StripeConnectorMetadataObject, StripeCreateOnlyPaymentIntentMetadata,
+        StripeGooglePayPredecrypt, StripeMandateRequest, StripeMandateType, StripeMetadata,
+        StripePayLaterData, StripePaymentMethodData, StripePaymentMethodType, StripePaymentStatus,
+        StripePreferredCardNetwork, StripeShippingAddress, StripeTokenizationMethod, StripeWallet,
+        WebhookEventType, WebhookEventTypeBody,
     };
 
+    /// Router data of a card payment with the request of the flow under test, which the tests
+    /// adjust for their case
+    fn get_router_data<F, Req, Res>(request: Req) -> RouterData<F, Req, Res> {
+        RouterData {
+            flow: PhantomData,
+            merchant_id: id_type::MerchantId::default(),
+            customer_id: None,
+            connector_customer: None,
+            connector: "stripe".to_string(),
+            payment_id: "pay_123".to_string(),
+            attempt_id: "pay_123_1".to_string(),
+            tenant_id: id_type::TenantId::try_from_string("public".to_string()).unwrap(),
+            status: enums::AttemptStatus::Started,
+            payment_method: enums::PaymentMethod::Card,
+            payment_method_type: Some(enums::PaymentMethodType::Credit),
+            connector_auth_type: ConnectorAuthType::HeaderKey {
+                api_key: Secret::new("sk_test_123".to_string()),
+            },
+            description: None,
+            address: PaymentAddress::default(),
+            auth_type: enums::AuthenticationType::NoThreeDs,
+            connector_meta_data: None,
+            connector_wallets_details: None,
+            amount_captured: None,
+            access_token: None,
+            session_token: None,
+            reference_id: None,
+            payment_method_token: None,
+            recurring_mandate_payment_data: None,
+            preprocessing_id: None,
+            payment_method_balance: None,
+            connector_api_version: None,
+            request,
+            response: Err(ErrorResponse::default()),
+            connector_request_reference_id: "pay_123_1".to_string(),
+            #[cfg(feature = "payouts")]
+            payout_method_data: None,
+            #[cfg(feature = "payouts")]
+            quote_id: None,
+            test_mode: None,
+            connector_http_status_code: None,
+            external_latency: None,
+            apple_pay_flow: None,
+            frm_metadata: None,
+            dispute_id: None,
+            refund_id: None,
+            payout_id: None,
+            connector_response: None,
+            payment_method_status: None,
+            minor_amount_captured: None,
+            minor_amount_capturable: None,
+            authorized_amount: None,
+            integrity_check: Ok(()),
+            additional_merchant_data: None,
+            header_payload: None,
+            connector_mandate_request_reference_id: None,
+            l2_l3_data: None,
+            authentication_id: None,
+            psd2_sca_exemption_type: None,
+            raw_connector_response: None,
+            is_payment_id_from_merchant: None,
+            customer_document_details: None,
+            feature_data: None,
+            sender_payment_instrument_id: None,
+        }
+    }
+
     #[test]
     fn should_return_ok() {
         // Arrange
@@ -5379,4 +6101,1208 @@ mod test_validate_shipping_address_against_payment_method {
             phone: Some(Secret::new(String::from("pbone number"))),
         }
     }
+
+    #[test]
+    fn should_return_descriptor_only() {
+        let billing_descriptor = create_billing_descriptor(Some("ACME STORE"), None);
+
+        let result = validate_and_get_statement_descriptors(
+            Some(&billing_descriptor),
+            enums::PaymentMethod::Card,
+        );
+
+        assert_eq!(result.ok(), Some((Some("ACME STORE".to_string()), None)));
+    }
+
+    #[test]
+    fn should_return_suffix_only() {
+        let billing_descriptor = create_billing_descriptor(None, Some("ORDER 42"));
+
+        let result = validate_and_get_statement_descriptors(
+            Some(&billing_descriptor),
+            enums::PaymentMethod::Card,
+        );
+
+        assert_eq!(result.ok(), Some((None, Some("ORDER 42".to_string()))));
+    }
+
+    #[test]
+    fn should_return_err_when_both_are_set_for_card() {
+        let billing_descriptor = create_billing_descriptor(Some("ACME STORE"), Some("ORDER 42"));
+
+        let result = validate_and_get_statement_descriptors(
+            Some(&billing_descriptor),
+            enums::PaymentMethod::Card,
+        );
+
+        assert!(matches!(
+            result.unwrap_err().current_context(),
+            ConnectorError::NotSupported { .. }
+        ));
+    }
+
+    #[test]
+    fn should_return_ok_when_both_are_set_for_non_card() {
+        let billing_descriptor = create_billing_descriptor(Some("ACME STORE"), Some("ORDER 42"));
+
+        let result = validate_and_get_statement_descriptors(
+            Some(&billing_descriptor),
+            enums::PaymentMethod::BankRedirect,
+        );
+
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn should_return_suffix_only_for_manual_capture_authorize() {
+        let mut router_data = create_authorize_router_data();
+        router_data.request.capture_method = Some(enums::CaptureMethod::Manual);
+        router_data.request.billing_descriptor =
+            Some(create_billing_descriptor(None, Some("ORDER 42")));
+
+        let request =
+            PaymentIntentRequest::try_from((&router_data, router_data.request.minor_amount))
+                .unwrap();
+        let encoded = serde_urlencoded::to_string(request).unwrap();
+
+        assert!(encoded.contains("capture_method=manual"));
+        assert!(encoded.contains("statement_descriptor_suffix=ORDER+42"));
+        assert!(!encoded.contains("statement_descriptor="));
+    }
+
+    #[test]
+    fn should_return_err_for_manual_capture_authorize_when_both_are_set_for_card() {
+        let mut router_data = create_authorize_router_data();
+        router_data.request.capture_method = Some(enums::CaptureMethod::Manual);
+        router_data.request.billing_descriptor = Some(create_billing_descriptor(
+            Some("ACME STORE"),
+            Some("ORDER 42"),
+        ));
+
+        let result =
+            PaymentIntentRequest::try_from((&router_data, router_data.request.minor_amount));
+
+        assert!(matches!(
+            result.unwrap_err().current_context(),
+            ConnectorError::NotSupported { .. }
+        ));
+    }
+
+    fn create_billing_descriptor(
+        statement_descriptor: Option<&str>,
+        statement_descriptor_suffix: Option<&str>,
+    ) -> BillingDescriptor {
+        BillingDescriptor {
+            name: None,
+            city: None,
+            phone: None,
+            statement_descriptor: statement_descriptor.map(str::to_string),
+            statement_descriptor_suffix: statement_descriptor_suffix.map(str::to_string),
+            reference: None,
+        }
+    }
+
+    fn card_number() -> cards::CardNumber {
+        "4242424242424242".parse().unwrap()
+    }
+
+    #[test]
+    fn should_use_apple_pay_tokenization_method_for_apple_pay() {
+        let decrypt_data = ApplePayPredecryptData {
+            application_primary_account_number: card_number(),
+            application_expiration_month: Secret::new("12".to_string()),
+            application_expiration_year: Secret::new("30".to_string()),
+            payment_data: ApplePayCryptogramData {
+                online_payment_cryptogram: Secret::new("cryptogram".to_string()),
+                eci_indicator: Some("05".to_string()),
+            },
+        };
+
+        let predecrypt = StripeApplePayPredecrypt::from(&decrypt_data);
+
+        assert_eq!(
+            predecrypt.tokenization_method,
+            StripeTokenizationMethod::ApplePay
+        );
+        let serialized = serde_json::to_value(&predecrypt).unwrap();
+        assert_eq!(serialized["card[tokenization_method]"], "apple_pay");
+    }
+
+    #[test]
+    fn should_use_android_pay_tokenization_method_for_google_pay() {
+        let decrypt_data = GPayPredecryptData {
+            card_exp_month: Secret::new("12".to_string()),
+            card_exp_year: Secret::new("2030".to_string()),
+            application_primary_account_number: card_number(),
+            cryptogram: Some(Secret::new("cryptogram".to_string())),
+            eci_indicator: Some("05".to_string()),
+        };
+
+        let predecrypt = StripeGooglePayPredecrypt::try_from(&decrypt_data).unwrap();
+
+        assert_eq!(
+            predecrypt.tokenization_method,
+            StripeTokenizationMethod::AndroidPay
+        );
+        let serialized = serde_json::to_value(&predecrypt).unwrap();
+        assert_eq!(serialized["card[tokenization_method]"], "android_pay");
+    }
+
+    #[test]
+    fn should_always_include_hyperswitch_refund_id_in_refund_request() {
+        let request = RefundRequest {
+            amount: Some(MinorUnit::new(100)),
+            payment_intent: "pi_123".to_string(),
+            meta_data: StripeMetadata::for_refund("ref_123"),
+        };
+
+        let encoded = serde_urlencoded::to_string(&request).unwrap();
+
+        assert!(encoded.contains("metadata%5Bhyperswitch_refund_id%5D=ref_123"));
+        assert!(encoded.contains("metadata%5Border_id%5D=ref_123"));
+    }
+
+    #[test]
+    fn should_always_include_hyperswitch_refund_id_in_charge_refund_request() {
+        let request = ChargeRefundRequest {
+            charge: "ch_123".to_string(),
+            refund_application_fee: None,
+            reverse_transfer: None,
+            amount: Some(MinorUnit::new(100)),
+            meta_data: StripeMetadata::for_refund("ref_123"),
+        };
+
+        let encoded = serde_urlencoded::to_string(&request).unwrap();
+
+        assert!(encoded.contains("metadata%5Bhyperswitch_refund_id%5D=ref_123"));
+    }
+
+    fn create_refund_router_data(
+        refund_amount: i64,
+        payment_amount: i64,
+        split_refunds: Option<SplitRefundsRequest>,
+    ) -> RefundsRouterData<Execute> {
+        RouterData {
+            status: enums::AttemptStatus::Charged,
+            amount_captured: Some(payment_amount),
+            minor_amount_captured: Some(MinorUnit::new(payment_amount)),
+            refund_id: Some("ref_123".to_string()),
+            ..get_router_data(RefundsData {
+                refund_id: "ref_123".to_string(),
+                connector_transaction_id: "pi_123".to_string(),
+                connector_refund_id: None,
+                currency: enums::Currency::USD,
+                payment_amount,
+                reason: None,
+                webhook_url: None,
+                refund_amount,
+                connector_metadata: None,
+                refund_connector_metadata: None,
+                browser_info: None,
+                split_refunds,
+                minor_payment_amount: MinorUnit::new(payment_amount),
+                minor_refund_amount: MinorUnit::new(refund_amount),
+                integrity_object: None,
+                refund_status: enums::RefundStatus::Pending,
+                merchant_account_id: None,
+                merchant_config_currency: None,
+                capture_method: Some(enums::CaptureMethod::Automatic),
+                additional_payment_method_data: None,
+            })
+        }
+    }
+
+    fn encode_refund_request(refund_amount: i64, payment_amount: i64) -> String {
+        let router_data = create_refund_router_data(refund_amount, payment_amount, None);
+        let request =
+            RefundRequest::try_from((&router_data, router_data.request.minor_refund_amount))
+                .unwrap();
+
+        serde_urlencoded::to_string(&request).unwrap()
+    }
+
+    fn encode_charge_refund_request(refund_amount: i64, payment_amount: i64) -> String {
+        let split_refunds = SplitRefundsRequest::StripeSplitRefund(StripeSplitRefund {
+            charge_id: "ch_123".to_string(),
+            transfer_account_id: "acct_123".to_string(),
+            charge_type: PaymentChargeType::Stripe(StripeChargeType::Destination),
+            options: ChargeRefundsOptions::Destination(DestinationChargeRefund {
+                revert_platform_fee: true,
+                revert_transfer: true,
+            }),
+        });
+        let router_data =
+            create_refund_router_data(refund_amount, payment_amount, Some(split_refunds));
+        let request = ChargeRefundRequest::try_from(&router_data).unwrap();
+
+        serde_urlencoded::to_string(&request).unwrap()
+    }
+
+    #[test]
+    fn should_omit_amount_for_full_refund() {
+        let encoded = encode_refund_request(1000, 1000);
+
+        assert!(!encoded.contains("amount="));
+        assert!(encoded.contains("payment_intent=pi_123"));
+    }
+
+    #[test]
+    fn should_include_amount_for_partial_refund() {
+        let encoded = encode_refund_request(400, 1000);
+
+        assert!(encoded.contains("amount=400"));
+    }
+
+    #[test]
+    fn should_omit_amount_for_full_refund_of_partially_captured_payment() {
+        let mut router_data = create_refund_router_data(600, 1000, None);
+        router_data.minor_amount_captured = Some(MinorUnit::new(600));
+
+        let request =
+            RefundRequest::try_from((&router_data, router_data.request.minor_refund_amount))
+                .unwrap();
+        assert_eq!(request.amount, None);
+
+        router_data.minor_amount_captured = None;
+        let request =
+            RefundRequest::try_from((&router_data, router_data.request.minor_refund_amount))
+                .unwrap();
+        assert_eq!(request.amount, Some(MinorUnit::new(600)));
+    }
+
+    #[test]
+    fn should_omit_amount_for_full_charge_refund() {
+        let encoded = encode_charge_refund_request(1000, 1000);
+
+        assert!(!encoded.contains("amount="));
+        assert!(encoded.contains("charge=ch_123"));
+    }
+
+    #[test]
+    fn should_include_amount_for_partial_charge_refund() {
+        let encoded = encode_charge_refund_request(400, 1000);
+
+        assert!(encoded.contains("amount=400"));
+        assert!(encoded.contains("charge=ch_123"));
+    }
+
+    fn create_payment_intent_request() -> PaymentIntentRequest {
+        PaymentIntentRequest {
+            amount: MinorUnit::new(100),
+            currency: "USD".to_string(),
+            statement_descriptor_suffix: None,
+            statement_descriptor: None,
+            meta_data: HashMap::new(),
+            return_url: Some("https://example.com/return".to_string()),
+            confirm: Some(true),
+            payment_method: Some(Secret::new("pm_123".to_string())),
+            customer: None,
+            setup_mandate_details: Some(StripeMandateRequest {
+                mandate_type: StripeMandateType::Offline,
+            }),
+            description: None,
+            shipping: None,
+            billing: StripeBillingAddress::default(),
+            payment_data: None,
+            capture_method: StripeCaptureMethod::Automatic,
+            payment_method_options: None,
+            setup_future_usage: None,
+            off_session: Some(true),
+            payment_method_types: None,
+            expand: None,
+            browser_info: None,
+            charges: None,
+            moto: None,
+            on_behalf_of: None,
+            line_items: None,
+            use_stripe_sdk: None,
+        }
+    }
+
+    #[test]
+    fn should_confirm_payment_intent_by_default() {
+        let encoded = serde_urlencoded::to_string(create_payment_intent_request()).unwrap();
+
+        assert!(encoded.contains("confirm=true"));
+        assert!(encoded.contains("return_url="));
+    }
+
+    #[test]
+    fn should_omit_confirm_in_create_only_request() {
+        let encoded =
+            serde_urlencoded::to_string(create_payment_intent_request().into_create_only())
+                .unwrap();
+
+        assert!(!encoded.contains("confirm="));
+        assert!(!encoded.contains("return_url="));
+        assert!(!encoded.contains("off_session="));
+        assert!(!encoded.contains("mandate_data"));
+        assert!(encoded.contains("payment_method=pm_123"));
+    }
+
+    #[test]
+    fn should_return_client_secret_for_create_only_response() {
+        let response = PaymentIntentResponse {
+            id: "pi_123".to_string(),
+            status: StripePaymentStatus::RequiresConfirmation,
+            client_secret: Some(Secret::new("pi_123_secret_456".to_string())),
+            ..Default::default()
+        };
+
+        let connector_metadata = get_create_only_connector_metadata(&response)
+            .unwrap()
+            .unwrap();
+        let metadata: StripeCreateOnlyPaymentIntentMetadata =
+            serde_json::from_value(connector_metadata).unwrap();
+
+        assert_eq!(metadata.client_secret.peek(), "pi_123_secret_456");
+    }
+
+    #[test]
+    fn should_request_stripe_sdk_next_actions_for_mobile_sdk_clients() {
+        let mut request = create_payment_intent_request();
+        request.use_stripe_sdk = Some(true);
+
+        let encoded = serde_urlencoded::to_string(&request).unwrap();
+        assert!(encoded.contains("use_stripe_sdk=true"));
+
+        let encoded = serde_urlencoded::to_string(request.into_create_only()).unwrap();
+        assert!(!encoded.contains("use_stripe_sdk"));
+
+        let encoded = serde_urlencoded::to_string(create_payment_intent_request()).unwrap();
+        assert!(!encoded.contains("use_stripe_sdk"));
+    }
+
+    #[test]
+    fn should_request_stripe_sdk_next_actions_only_when_opted_in() {
+        let opted_in = StripeConnectorMetadataObject {
+            use_stripe_sdk_for_mobile_clients: true,
+            ..StripeConnectorMetadataObject::default()
+        };
+
+        assert_eq!(
+            get_use_stripe_sdk(
+                &StripeConnectorMetadataObject::default(),
+                Some(&ClientPlatform::Ios),
+                api_enums::PaymentMethod::Card,
+            ),
+            None
+        );
+        assert_eq!(
+            get_use_stripe_sdk(
+                &opted_in,
+                Some(&ClientPlatform::Android),
+                api_enums::PaymentMethod::Card,
+            ),
+            Some(true)
+        );
+        assert_eq!(
+            get_use_stripe_sdk(
+                &opted_in,
+                Some(&ClientPlatform::Web),
+                api_enums::PaymentMethod::Card,
+            ),
+            None
+        );
+        assert_eq!(
+            get_use_stripe_sdk(
+                &opted_in,
+                Some(&ClientPlatform::Ios),
+                api_enums::PaymentMethod::Wallet,
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn should_return_stripe_sdk_next_action_without_client_secret() {
+        let response: PaymentIntentResponse = serde_json::from_value(serde_json::json!({
+            "id": "pi_123",
+            "amount": 100,
+            "currency": "usd",
+            "status": "requires_action",
+            "client_secret": "pi_123_secret_456",
+            "next_action": {
+                "type": "use_stripe_sdk",
+                "use_stripe_sdk": {
+                    "type": "three_d_secure_redirect",
+                    "stripe_js": "https://hooks.stripe.com/3d_secure_2/hosted",
+                },
+            },
+        }))
+        .unwrap();
+
+        let connector_metadata = get_payment_intent_connector_metadata(&response)
+            .unwrap()
+            .unwrap();
+        assert!(!connector_metadata.to_string().contains("pi_123_secret_456"));
+
+        let sdk_next_action: ConnectorSdkNextActionData =
+            serde_json::from_value(connector_metadata).unwrap();
+        assert_eq!(sdk_next_action.connector, "stripe");
+        assert_eq!(
+            sdk_next_action.sdk_next_action["type"],
+            "three_d_secure_redirect"
+        );
+    }
+
+    fn sepa_bank_debit_data(iban: &str) -> payment_method_data::BankDebitData {
+        payment_method_data::BankDebitData::SepaBankDebit {
+            iban: Secret::new(iban.to_string()),
+            bank_account_holder_name: None,
+        }
+    }
+
+    #[test]
+    fn should_accept_valid_iban() {
+        let (pm_type, bank_debit_data) =
+            get_bank_debit_data(&sepa_bank_debit_data("DE89 3704 0044 0532 0130 00")).unwrap();
+
+        assert_eq!(pm_type, Some(StripePaymentMethodType::Sepa));
+        assert!(matches!(bank_debit_data, Some(BankDebitData::Sepa { .. })));
+    }
+
+    #[test]
+    fn should_reject_iban_with_invalid_checksum() {
+        let error =
+            get_bank_debit_data(&sepa_bank_debit_data("DE88370400440532013000")).unwrap_err();
+
+        assert!(matches!(
+            error.current_context(),
+            ConnectorError::InvalidDataFormat { field_name: "iban" }
+        ));
+    }
+
+    #[test]
+    fn should_reject_malformed_iban() {
+        let error = get_bank_debit_data(&sepa_bank_debit_data("89DE-3704")).unwrap_err();
+
+        assert!(matches!(
+            error.current_context(),
+            ConnectorError::InvalidDataFormat { field_name: "iban" }
+        ));
+    }
+
+    fn dispute_closed_payload(status: &str) -> String {
+        format!(
+            r#"{{
+                "id": "evt_123",
+                "object": "event",
+                "type": "charge.dispute.closed",
+                "data": {{
+                    "object": {{
+                        "id": "dp_123",
+                        "object": "dispute",
+                        "amount": 1000,
+                        "currency": "usd",
+                        "payment_intent": "pi_123",
+                        "reason": "fraudulent",
+                        "status": "{status}"
+                    }}
+                }}
+            }}"#
+        )
+    }
+
+    fn get_event(payload: &str) -> IncomingWebhookEvent {
+        let body: WebhookEventTypeBody = serde_json::from_str(payload).unwrap();
+
+        assert!(matches!(body.event_type, WebhookEventType::DisputeClosed));
+        get_dispute_closed_event(body.event_data.event_object.status)
+    }
+
+    #[test]
+    fn should_map_closed_won_dispute_to_dispute_won() {
+        assert_eq!(
+            get_event(&dispute_closed_payload("won")),
+            IncomingWebhookEvent::DisputeWon
+        );
+    }
+
+    #[test]
+    fn should_map_closed_lost_dispute_to_dispute_lost() {
+        assert_eq!(
+            get_event(&dispute_closed_payload("lost")),
+            IncomingWebhookEvent::DisputeLost
+        );
+    }
+
+    #[test]
+    fn should_map_closed_dispute_without_outcome_to_dispute_cancelled() {
+        assert_eq!(
+            get_event(&dispute_closed_payload("warning_closed")),
+            IncomingWebhookEvent::DisputeCancelled
+        );
+    }
+
+    /// Expected form-encoded body, with one `key=value` pair per line so that changes are easy to
+    /// review. The merchant metadata pairs are compared in sorted order, as their order in the
+    /// serialized request is not stable.
+    ///
+    /// If a change to the serialized form is intended, regenerate the file by running
+    /// `UPDATE_GOLDEN_FILES=1 cargo test --package hyperswitch_connectors -- should_match_golden_file_for_authorize_request`
+    /// and commit the updated file along with the change.
+    const GOLDEN_FILE_PATH: &str = concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/src/connectors/stripe/test_data/authorize_payment_intent_request.txt"
+    );
+
+    const USER_AGENT: &str = "Mozilla/5.0 (X11; Linux x86_64)";
+    const CONNECTED_ACCOUNT_ID: &str = "acct_golden_123";
+
+    fn get_address() -> Address {
+        Address {
+            address: Some(AddressDetails {
+                city: Some("San Francisco".to_string()),
+                country: Some(enums::CountryAlpha2::US),
+                line1: Some(Secret::new("1467".to_string())),
+                line2: Some(Secret::new("Harrison Street".to_string())),
+                zip: Some(Secret::new("94122".to_string())),
+                state: Some(Secret::new("California".to_string())),
+                first_name: Some(Secret::new("John".to_string())),
+                last_name: Some(Secret::new("Doe".to_string())),
+                ..AddressDetails::default()
+            }),
+            phone: Some(PhoneDetails {
+                number: Some(Secret::new("2015550123".to_string())),
+                country_code: Some("+1".to_string()),
+            }),
+            email: Some(Email::from_str("john.doe@example.com").unwrap()),
+        }
+    }
+
+    fn create_authorize_request_data() -> PaymentsAuthorizeData {
+        PaymentsAuthorizeData {
+            payment_method_data: PaymentMethodData::Card(Card {
+                card_number: cards::CardNumber::from_str("4242424242424242").unwrap(),
+                card_exp_month: Secret::new("12".to_string()),
+                card_exp_year: Secret::new("2030".to_string()),
+                card_cvc: Secret::new("123".to_string()),
+                card_network: Some(enums::CardNetwork::Visa),
+                ..Card::default()
+            }),
+            amount: 6540,
+            order_tax_amount: None,
+            email: Some(Email::from_str("john.doe@example.com").unwrap()),
+            customer_name: None,
+            currency: enums::Currency::USD,
+            confirm: true,
+            capture_method: Some(enums::CaptureMethod::Manual),
+            router_return_url: Some("https://hyperswitch.io/return?status=done".to_string()),
+            webhook_url: None,
+            complete_authorize_url: None,
+            setup_future_usage: Some(enums::FutureUsage::OffSession),
+            mandate_id: None,
+            off_session: None,
+            customer_acceptance: None,
+            setup_mandate_details: Some(MandateData {
+                update_mandate_id: None,
+                customer_acceptance: Some(CustomerAcceptance {
+                    acceptance_type: AcceptanceType::Online,
+                    accepted_at: None,
+                    online: Some(OnlineMandate {
+                        ip_address: Some(Secret::new("127.0.0.1".to_string())),
+                        user_agent: USER_AGENT.to_string(),
+                    }),
+                }),
+                mandate_type: None,
+            }),
+            browser_info: Some(BrowserInformation {
+                ip_address: Some(IpAddr::from([127, 0, 0, 1])),
+                user_agent: Some(USER_AGENT.to_string()),
+                ..BrowserInformation::default()
+            }),
+            order_details: None,
+            order_category: None,
+            session_token: None,
+            enrolled_for_3ds: true,
+            related_transaction_id: None,
+            payment_experience: None,
+            payment_method_type: Some(enums::PaymentMethodType::Credit),
+            surcharge_details: None,
+            customer_id: None,
+            request_incremental_authorization: true,
+            metadata: Some(json!({
+                "customer_segment": "gold",
+                "items[0]": 3,
+            })),
+            authentication_data: None,
+            ucs_authentication_data: None,
+            request_extended_authorization: Some(true.into()),
+            split_payments: Some(SplitPaymentsRequest::StripeSplitPayment(
+                StripeSplitPaymentRequest {
+                    charge_type: PaymentChargeType::Stripe(StripeChargeType::Destination),
+                    application_fees: Some(MinorUnit::new(123)),
+                    transfer_account_id: CONNECTED_ACCOUNT_ID.to_string(),
+                    on_behalf_of: Some(CONNECTED_ACCOUNT_ID.to_string()),
+                },
+            )),
+            guest_customer: None,
+            minor_amount: MinorUnit::new(6540),
+            merchant_order_reference_id: None,
+            integrity_object: None,
+            shipping_cost: None,
+            additional_payment_method_data: None,
+            merchant_account_id: None,
+            merchant_config_currency: None,
+            connector_testing_data: None,
+            order_id: None,
+            locale: None,
+            payment_channel: None,
+            enable_partial_authorization: None,
+            enable_overcapture: Some(true.into()),
+            is_stored_credential: None,
+            mit_category: None,
+            billing_descriptor: Some(BillingDescriptor {
+                name: None,
+                city: None,
+                phone: None,
+                statement_descriptor: None,
+                statement_descriptor_suffix: Some("ORDER 1234".to_string()),
+                reference: None,
+            }),
+            tokenization: None,
+            partner_merchant_identifier_details: None,
+            feature_metadata: None,
+            installment_details: None,
+            connector_intent_metadata: None,
+        }
+    }
+
+    fn create_authorize_router_data() -> PaymentsAuthorizeRouterData {
+        RouterData {
+            connector_customer: Some("cus_golden_123".to_string()),
+            payment_id: "pay_golden_123".to_string(),
+            attempt_id: "pay_golden_123_1".to_string(),
+            description: Some("Golden authorize & mandate".to_string()),
+            address: PaymentAddress::new(Some(get_address()), Some(get_address()), None, None),
+            auth_type: enums::AuthenticationType::ThreeDs,
+            connector_request_reference_id: "pay_golden_123".to_string(),
+            ..get_router_data(create_authorize_request_data())
+        }
+    }
+
+    /// Sorts the contiguous merchant metadata pairs, which are serialized from a map
+    fn sort_metadata_pairs<'a>(pairs: impl Iterator<Item = &'a str>) -> Vec<&'a str> {
+        let mut pairs = pairs.collect::<Vec<_>>();
+        let is_metadata_pair = |pair: &&str| pair.starts_with("metadata%5B");
+        if let Some(start) = pairs.iter().position(is_metadata_pair) {
+            let count = pairs
+                .iter()
+                .skip(start)
+                .take_while(|pair| is_metadata_pair(pair))
+                .count();
+            if let Some(metadata_pairs) = pairs.get_mut(start..start + count) {
+                metadata_pairs.sort_unstable();
+            }
+        }
+        pairs
+    }
+
+    #[test]
+    fn should_match_golden_file_for_authorize_request() {
+        let router_data = create_authorize_router_data();
+        let request =
+            PaymentIntentRequest::try_from((&router_data, router_data.request.minor_amount))
+                .unwrap();
+        let encoded = serde_urlencoded::to_string(request).unwrap();
+        let actual = sort_metadata_pairs(encoded.split('&'));
+
+        if std::env::var("UPDATE_GOLDEN_FILES").is_ok() {
+            std::fs::write(GOLDEN_FILE_PATH, format!("{}\n", actual.join("\n"))).unwrap();
+        }
+
+        let golden_file = std::fs::read_to_string(GOLDEN_FILE_PATH).unwrap();
+        let expected = sort_metadata_pairs(golden_file.lines());
+        assert_eq!(
+            actual, expected,
+            "serialized Stripe authorize request does not match {GOLDEN_FILE_PATH}"
+        );
+    }
+
+    fn card_issued_in(country_code: &str) -> PaymentMethodData {
+        PaymentMethodData::Card(Card {
+            card_issuing_country_code: Some(country_code.to_string()),
+            ..Card::default()
+        })
+    }
+
+    #[test]
+    fn test_missing_billing_country_is_inferred_from_card_bin() {
+        let billing_address = StripeBillingAddress::default()
+            .with_country_inferred_from_card_bin(&card_issued_in("DE"));
+        assert_eq!(billing_address.country, Some(CountryAlpha2::DE));
+    }
+
+    #[test]
+    fn test_explicit_billing_country_wins_over_card_bin() {
+        let billing_address = StripeBillingAddress {
+            country: Some(CountryAlpha2::US),
+            ..StripeBillingAddress::default()
+        }
+        .with_country_inferred_from_card_bin(&card_issued_in("DE"));
+        assert_eq!(billing_address.country, Some(CountryAlpha2::US));
+    }
+
+    #[test]
+    fn test_unknown_card_issuing_country_is_not_inferred() {
+        let billing_address = StripeBillingAddress::default()
+            .with_country_inferred_from_card_bin(&card_issued_in("UNKNOWN"));
+        assert_eq!(billing_address.country, None);
+    }
+
+    fn preferred(card_network: CardNetwork, is_required: bool) -> StripePreferredCardNetwork {
+        StripePreferredCardNetwork {
+            card_network: Some(card_network),
+            is_required,
+        }
+    }
+
+    #[test]
+    fn test_detected_network_is_sent_without_preference() {
+        let network = get_preferred_stripe_card_network(
+            Some(&CardNetwork::Visa),
+            &StripePreferredCardNetwork::default(),
+        )
+        .unwrap();
+        assert_eq!(network, Some(StripeCardNetwork::Visa));
+    }
+
+    #[test]
+    fn test_unsupported_detected_network_is_not_treated_as_preference() {
+        let network = get_preferred_stripe_card_network(
+            Some(&CardNetwork::AmericanExpress),
+            &StripePreferredCardNetwork {
+                card_network: None,
+                is_required: true,
+            },
+        )
+        .unwrap();
+        assert_eq!(network, None);
+    }
+
+    #[test]
+    fn test_supported_preferred_network_is_honored() {
+        let network = get_preferred_stripe_card_network(
+            Some(&CardNetwork::Visa),
+            &preferred(CardNetwork::CartesBancaires, true),
+        )
+        .unwrap();
+        assert_eq!(network, Some(StripeCardNetwork::CartesBancaires));
+    }
+
+    #[test]
+    fn test_unsupported_preferred_network_is_dropped_with_warning() {
+        let network = get_preferred_stripe_card_network(
+            Some(&CardNetwork::Visa),
+            &preferred(CardNetwork::AmericanExpress, false),
+        )
+        .unwrap();
+        assert_eq!(network, Some(StripeCardNetwork::Visa));
+    }
+
+    #[test]
+    fn test_unsupported_preferred_network_errors_when_required() {
+        let error = get_preferred_stripe_card_network(
+            Some(&CardNetwork::Visa),
+            &preferred(CardNetwork::AmericanExpress, true),
+        )
+        .unwrap_err();
+        assert!(matches!(
+            error.current_context(),
+            ConnectorError::NotSupported {
+                connector: "Stripe",
+                ..
+            }
+        ));
+    }
+
+    fn create_order_details() -> Vec<OrderDetailsWithAmount> {
+        vec![
+            OrderDetailsWithAmount {
+                product_name: "shirt".to_string(),
+                quantity: 2,
+                amount: MinorUnit::new(1500),
+                total_tax_amount: Some(MinorUnit::new(300)),
+                ..Default::default()
+            },
+            OrderDetailsWithAmount {
+                product_name: "socks".to_string(),
+                quantity: 1,
+                amount: MinorUnit::new(700),
+                ..Default::default()
+            },
+        ]
+    }
+
+    fn create_klarna_authorize_request() -> PaymentIntentRequest {
+        let payment_method_type = StripePaymentMethodType::Klarna;
+        PaymentIntentRequest {
+            amount: MinorUnit::new(4000),
+            currency: "USD".to_string(),
+            statement_descriptor_suffix: None,
+            statement_descriptor: None,
+            meta_data: HashMap::new(),
+            return_url: Some("https://example.com/return".to_string()),
+            confirm: Some(true),
+            payment_method: None,
+            customer: None,
+            setup_mandate_details: None,
+            description: None,
+            shipping: None,
+            billing: StripeBillingAddress::default(),
+            payment_data: Some(StripePaymentMethodData::PayLater(StripePayLaterData {
+                payment_method_data_type: payment_method_type,
+            })),
+            capture_method: StripeCaptureMethod::Automatic,
+            payment_method_options: None,
+            setup_future_usage: None,
+            off_session: None,
+            line_items: get_stripe_line_items(
+                Some(&create_order_details()),
+                Some(&payment_method_type),
+            ),
+            payment_method_types: Some(payment_method_type),
+            expand: None,
+            browser_info: None,
+            charges: None,
+            moto: None,
+            on_behalf_of: None,
+            use_stripe_sdk: None,
+        }
+    }
+
+    #[test]
+    fn should_serialize_line_items_for_klarna_authorize() {
+        let encoded = serde_urlencoded::to_string(create_klarna_authorize_request()).unwrap();
+
+        assert!(encoded.contains(concat!(
+            "amount_details%5Bline_items%5D%5B0%5D%5Bproduct_name%5D=shirt",
+            "&amount_details%5Bline_items%5D%5B0%5D%5Bunit_cost%5D=1500",
+            "&amount_details%5Bline_items%5D%5B0%5D%5Bquantity%5D=2",
+            "&amount_details%5Bline_items%5D%5B0%5D%5Btax%5D%5Btotal_tax_amount%5D=300",
+            "&amount_details%5Bline_items%5D%5B1%5D%5Bproduct_name%5D=socks",
+            "&amount_details%5Bline_items%5D%5B1%5D%5Bunit_cost%5D=700",
+            "&amount_details%5Bline_items%5D%5B1%5D%5Bquantity%5D=1",
+        )));
+        assert!(!encoded.contains("%5B1%5D%5Btax%5D"));
+    }
+
+    #[test]
+    fn should_not_send_line_items_for_card_payments() {
+        assert_eq!(
+            get_stripe_line_items(
+                Some(&create_order_details()),
+                Some(&StripePaymentMethodType::Card),
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn should_not_send_empty_line_items() {
+        assert_eq!(
+            get_stripe_line_items(Some(&[]), Some(&StripePaymentMethodType::Klarna)),
+            None
+        );
+    }
+
+    fn get_metadata() -> HashMap<String, String> {
+        HashMap::from([
+            ("metadata[order_id]".to_string(), "pay_123".to_string()),
+            ("metadata[a]".to_string(), "x".repeat(600)),
+            ("metadata[b]".to_string(), "short".to_string()),
+            ("metadata[c]".to_string(), "ünïcödé".to_string()),
+        ])
+    }
+
+    #[test]
+    fn should_truncate_values_to_stripe_limits_by_default() {
+        let limited =
+            limit_transaction_metadata(get_metadata(), &ConnectorMetadataLimits::default());
+
+        assert_eq!(limited.len(), 4);
+        assert_eq!(limited.get("metadata[a]"), Some(&"x".repeat(500)));
+        assert_eq!(limited.get("metadata[b]"), Some(&"short".to_string()));
+    }
+
+    #[test]
+    fn should_truncate_more_aggressively_for_smaller_configured_limits() {
+        let limits = ConnectorMetadataLimits {
+            max_value_length: 3,
+            max_keys: 3,
+        };
+
+        let limited = limit_transaction_metadata(get_metadata(), &limits);
+
+        assert_eq!(
+            limited,
+            HashMap::from([
+                ("metadata[order_id]".to_string(), "pay".to_string()),
+                ("metadata[a]".to_string(), "xxx".to_string()),
+                ("metadata[b]".to_string(), "sho".to_string()),
+            ])
+        );
+    }
+
+    #[test]
+    fn should_truncate_values_on_character_boundaries() {
+        let limits = ConnectorMetadataLimits {
+            max_value_length: 2,
+            max_keys: 50,
+        };
+
+        let limited = limit_transaction_metadata(get_metadata(), &limits);
+
+        assert_eq!(limited.get("metadata[c]"), Some(&"ün".to_string()));
+    }
+
+    #[test]
+    fn should_add_normalized_street_and_postal_code_checks() {
+        let checks = serde_json::json!({
+            "address_line1_check": "fail",
+            "address_postal_code_check": "pass",
+            "cvc_check": "pass",
+        });
+
+        let checks = add_avs_match_details(checks);
+
+        assert_eq!(
+            AvsMatchDetails::from_payment_checks(&checks),
+            Some(AvsMatchDetails {
+                avs_street_match: Some(AvsMatchResult::NoMatch),
+                avs_postal_code_match: Some(AvsMatchResult::Match),
+            })
+        );
+        assert_eq!(checks["address_line1_check"], "fail");
+    }
+
+    #[test]
+    fn should_not_add_checks_which_were_not_returned() {
+        let checks = serde_json::json!({ "cvc_check": "pass" });
+
+        let checks = add_avs_match_details(checks);
+
+        assert_eq!(checks, serde_json::json!({ "cvc_check": "pass" }));
+        assert_eq!(AvsMatchDetails::from_payment_checks(&checks), None);
+    }
+
+    fn get_card_payment_method_data(
+        card_exp_month: &str,
+    ) -> Result<StripePaymentMethodData, error_stack::Report<ConnectorError>> {
+        let card = Card {
+            card_exp_month: Secret::new(card_exp_month.to_string()),
+            card_exp_year: Secret::new("2030".to_string()),
+            ..Card::default()
+        };
+        StripePaymentMethodData::try_from((
+            &card,
+            Auth3ds::Automatic,
+            None,
+            false,
+            None,
+            None,
+            false,
+        ))
+    }
+
+    #[test]
+    fn should_reject_month_zero() {
+        let error = get_card_payment_method_data("0").unwrap_err();
+        assert_eq!(
+            error.current_context(),
+            &ConnectorError::InvalidDataFormat {
+                field_name: "card_exp_month"
+            }
+        );
+    }
+
+    #[test]
+    fn should_reject_month_thirteen() {
+        let error = get_card_payment_method_data("13").unwrap_err();
+        assert_eq!(
+            error.current_context(),
+            &ConnectorError::InvalidDataFormat {
+                field_name: "card_exp_month"
+            }
+        );
+    }
+
+    #[test]
+    fn should_accept_valid_month() {
+        let card = match get_card_payment_method_data("03").unwrap() {
+            StripePaymentMethodData::Card(card) => Some(card),
+            _ => None,
+        };
+        assert_eq!(
+            card.unwrap().payment_method_data_card_exp_month.peek(),
+            "03"
+        );
+    }
+
+    fn get_shipping_address(line1: &str) -> StripeShippingAddress {
+        StripeShippingAddress {
+            city: Some("San Francisco".to_string()),
+            country: Some(CountryAlpha2::US),
+            line1: Some(Secret::new(line1.to_string())),
+            line2: None,
+            zip: Some(Secret::new("94122".to_string())),
+            state: Some(Secret::new("California".to_string())),
+            name: Some(Secret::new("John Doe".to_string())),
+            phone: Some(Secret::new("+18005550100".to_string())),
+        }
+    }
+
+    fn get_billing_address() -> StripeBillingAddress {
+        StripeBillingAddress {
+            city: Some("San Francisco".to_string()),
+            country: Some(CountryAlpha2::US),
+            address_line1: Some(Secret::new("1467 Harrison Street".to_string())),
+            address_line2: None,
+            zip_code: Some(Secret::new("94122".to_string())),
+            state: Some(Secret::new("California".to_string())),
+            name: Some(Secret::new("John Doe".to_string())),
+            email: None,
+            phone: Some(Secret::new("+18005550100".to_string())),
+        }
+    }
+
+    #[test]
+    fn should_omit_shipping_address_identical_to_billing() {
+        let shipping_address = omit_shipping_address_equal_to_billing(
+            Some(get_shipping_address("1467 Harrison Street")),
+            &get_billing_address(),
+            Some(&StripePaymentMethodType::Card),
+        );
+
+        assert_eq!(shipping_address, None);
+    }
+
+    #[test]
+    fn should_retain_shipping_address_different_from_billing() {
+        let shipping_address = omit_shipping_address_equal_to_billing(
+            Some(get_shipping_address("500 Terry Francine Street")),
+            &get_billing_address(),
+            Some(&StripePaymentMethodType::Card),
+        );
+
+        assert_eq!(
+            shipping_address,
+            Some(get_shipping_address("500 Terry Francine Street"))
+        );
+    }
+
+    #[test]
+    fn should_retain_shipping_address_required_by_payment_method() {
+        let shipping_address = omit_shipping_address_equal_to_billing(
+            Some(get_shipping_address("1467 Harrison Street")),
+            &get_billing_address(),
+            Some(&StripePaymentMethodType::AfterpayClearpay),
+        );
+
+        assert_eq!(
+            shipping_address,
+            Some(get_shipping_address("1467 Harrison Street"))
+        );
+    }
+
+    #[test]
+    fn should_save_amazon_pay_for_off_session_reuse_in_cit() {
+        let wallet_data = WalletData::AmazonPayRedirect(Box::new(AmazonPayRedirect {}));
+
+        let payment_data = StripePaymentMethodData::try_from((&wallet_data, None)).unwrap();
+        let setup_future_usage = validate_and_get_setup_future_usage(
+            Some(FutureUsage::OnSession),
+            Some(PaymentMethodType::AmazonPay),
+        )
+        .unwrap();
+
+        assert_eq!(
+            payment_data,
+            StripePaymentMethodData::Wallet(StripeWallet::AmazonpayPayment(AmazonpayPayment {
+                payment_method_types: StripePaymentMethodType::AmazonPay,
+            }))
+        );
+        assert_eq!(setup_future_usage, Some(FutureUsage::OffSession));
+    }
+
+    #[test]
+    fn should_not_save_amazon_pay_without_future_usage() {
+        let setup_future_usage =
+            validate_and_get_setup_future_usage(None, Some(PaymentMethodType::AmazonPay)).unwrap();
+
+        assert_eq!(setup_future_usage, None);
+    }
+
+    #[test]
+    fn should_reuse_saved_amazon_pay_in_mit() {
+        let recurring_mandate_payment_data = RecurringMandatePaymentData {
+            payment_method_type: Some(PaymentMethodType::AmazonPay),
+            original_payment_authorized_amount: Some(6540),
+            original_payment_authorized_currency: Some(common_enums::Currency::USD),
+            mandate_metadata: None,
+        };
+
+        let payment_method_type = get_payment_method_type_for_saved_payment_method_payment(
+            PaymentMethod::Wallet,
+            Some(&recurring_mandate_payment_data),
+        )
+        .unwrap();
+
+        assert_eq!(
+            payment_method_type,
+            Some(StripePaymentMethodType::AmazonPay)
+        );
+    }
+
+    #[test]
+    fn should_sanitize_brackets_in_metadata_keys() {
+        let merchant_metadata = Secret::new(serde_json::json!({
+            "items[0]": "shoes",
+            "udf1": "value",
+        }));
+
+        let meta_data = get_transaction_metadata(Some(merchant_metadata), "pay_123".to_string());
+
+        assert_eq!(
+            meta_data.get("metadata[items_0_]"),
+            Some(&"shoes".to_string())
+        );
+        assert_eq!(meta_data.get("metadata[udf1]"), Some(&"value".to_string()));
+
+        // Each key is encoded as a single, non nested metadata parameter in the form body
+        let body = serde_urlencoded::to_string(&meta_data).unwrap();
+        let decoded = serde_urlencoded::from_str::<Vec<(String, String)>>(&body).unwrap();
+        assert_eq!(decoded.len(), 3);
+        assert!(decoded.iter().all(|(key, _)| {
+            key.strip_prefix("metadata[")
+                .and_then(|key| key.strip_suffix(']'))
+                .is_some_and(|key| !key.contains(['[', ']']))
+        }));
+    }
+
+    #[test]
+    fn should_suffix_sanitized_metadata_keys_colliding_with_other_keys() {
+        let merchant_metadata = Secret::new(serde_json::json!({
+            "a[b]": "sanitized",
+            "a]b[": "sanitized again",
+            "a_b_": "original",
+        }));
+
+        let meta_data = get_transaction_metadata(Some(merchant_metadata), "pay_123".to_string());
+
+        assert_eq!(meta_data.len(), 4);
+        assert_eq!(
+            meta_data.get("metadata[a_b_]"),
+            Some(&"original".to_string())
+        );
+        assert_eq!(
+            meta_data.get("metadata[a_b__2]"),
+            Some(&"sanitized".to_string())
+        );
+        assert_eq!(
+            meta_data.get("metadata[a_b__3]"),
+            Some(&"sanitized again".to_string())
+        );
+    }
 }
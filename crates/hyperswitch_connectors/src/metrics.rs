@@ -5,3 +5,4 @@ use router_env::{counter_metric, global_meter};
 global_meter!(GLOBAL_METER, "ROUTER_API");
 
 counter_metric!(CONNECTOR_RESPONSE_DESERIALIZATION_FAILURE, GLOBAL_METER);
+counter_metric!(CONNECTOR_PREFERRED_CARD_NETWORK_NOT_HONORED, GLOBAL_METER);
@@ -619,6 +619,7 @@ pub trait RouterData {
     fn get_optional_shipping_full_name(&self) -> Option<Secret<String>>;
     fn get_optional_shipping_phone_number(&self) -> Option<Secret<String>>;
     fn get_optional_shipping_phone_number_without_country_code(&self) -> Option<Secret<String>>;
+    fn get_optional_normalized_shipping_phone_number(&self) -> Option<Secret<String>>;
     fn get_optional_shipping_email(&self) -> Option<Email>;
 
     fn get_required_shipping_full_name(&self) -> Result<Secret<String>, Error>;
@@ -641,6 +642,7 @@ pub trait RouterData {
     fn get_optional_billing_first_name(&self) -> Option<Secret<String>>;
     fn get_optional_billing_last_name(&self) -> Option<Secret<String>>;
     fn get_optional_billing_phone_number(&self) -> Option<Secret<String>>;
+    fn get_optional_normalized_billing_phone_number(&self) -> Option<Secret<String>>;
     fn get_optional_billing_email(&self) -> Option<Email>;
     fn get_optional_l2_l3_data(&self) -> Option<Box<L2L3Data>>;
 }
@@ -786,6 +788,12 @@ impl<Flow, Request, Response> RouterData
             .and_then(|phone_details| phone_details.get_number().ok())
     }
 
+    fn get_optional_normalized_shipping_phone_number(&self) -> Option<Secret<String>> {
+        self.address
+            .get_shipping()
+            .and_then(|shipping_address| get_normalized_phone_number(shipping_address, "shipping"))
+    }
+
     fn get_description(&self) -> Result<String, Error> {
         self.description
             .clone()
@@ -1065,6 +1073,12 @@ impl<Flow, Request, Response> RouterData
             })
     }
 
+    fn get_optional_normalized_billing_phone_number(&self) -> Option<Secret<String>> {
+        self.address
+            .get_payment_method_billing()
+            .and_then(|billing_address| get_normalized_phone_number(billing_address, "billing"))
+    }
+
     fn get_optional_billing_email(&self) -> Option<Email> {
         self.address
             .get_payment_method_billing()
@@ -2383,6 +2397,36 @@ impl PhoneDetailsData for PhoneDetails {
     }
 }
 
+/// Phone number of the address normalized to the E.164 format, using the country of the address
+/// for numbers in the national format. Numbers that cannot be normalized are sent with the
+/// formatting stripped, rather than failing the payment.
+fn get_normalized_phone_number(address: &Address, address_type: &str) -> Option<Secret<String>> {
+    let phone_details = address.phone.as_ref()?;
+    let phone_number = phone_details.number.as_ref()?;
+    let country = address
+        .address
+        .as_ref()
+        .and_then(|address_details| address_details.country);
+
+    let phone_number = match common_utils::validation::normalize_phone_number(
+        phone_number.peek(),
+        phone_details.country_code.as_deref(),
+        country,
+    ) {
+        common_utils::validation::NormalizedPhoneNumber::E164(phone_number) => phone_number,
+        common_utils::validation::NormalizedPhoneNumber::Sanitized(phone_number) => {
+            logger::warn!(
+                address_type,
+                ?country,
+                "Phone number could not be normalized to E.164, sending it without formatting"
+            );
+            phone_number
+        }
+    };
+
+    (!phone_number.is_empty()).then(|| Secret::new(phone_number))
+}
+
 #[cfg(feature = "payouts")]
 pub trait PayoutFulfillRequestData {
     fn get_connector_payout_id(&self) -> Result<String, Error>;
@@ -6763,6 +6807,23 @@ mod tests {
         let qr_image_data_source_url = utils::QrImage::new_from_data("Hyperswitch".to_string());
         assert!(qr_image_data_source_url.is_ok());
     }
+
+    #[test]
+    fn test_get_avs_match_details_from_code() {
+        use common_enums::enums::AvsMatchResult;
+
+        let details = utils::get_avs_match_details_from_code("a");
+        assert_eq!(
+            details.map(|details| (details.avs_street_match, details.avs_postal_code_match)),
+            Some((Some(AvsMatchResult::Match), Some(AvsMatchResult::NoMatch)))
+        );
+        let details = utils::get_avs_match_details_from_code("Z");
+        assert_eq!(
+            details.map(|details| (details.avs_street_match, details.avs_postal_code_match)),
+            Some((Some(AvsMatchResult::NoMatch), Some(AvsMatchResult::Match)))
+        );
+        assert!(utils::get_avs_match_details_from_code("unknown").is_none());
+    }
 }
 
 pub fn get_mandate_details(
@@ -8270,6 +8331,30 @@ where
     }
 }
 
+/// Get the street and postal code results from an AVS result code, as defined by the card networks
+pub fn get_avs_match_details_from_code(
+    avs_code: &str,
+) -> Option<common_types::payments::AvsMatchDetails> {
+    use enums::AvsMatchResult::{Match, NoMatch, NotChecked, Unavailable};
+
+    let (street_match, postal_code_match) = match avs_code.trim().to_uppercase().as_str() {
+        "D" | "F" | "M" | "X" | "Y" => (Match, Match),
+        "A" => (Match, NoMatch),
+        "B" => (Match, NotChecked),
+        "W" | "Z" => (NoMatch, Match),
+        "P" => (NotChecked, Match),
+        "N" => (NoMatch, NoMatch),
+        "C" | "I" => (NotChecked, NotChecked),
+        "E" | "G" | "R" | "S" | "U" => (Unavailable, Unavailable),
+        _ => return None,
+    };
+
+    Some(common_types::payments::AvsMatchDetails {
+        avs_street_match: Some(street_match),
+        avs_postal_code_match: Some(postal_code_match),
+    })
+}
+
 #[macro_export]
 macro_rules! convert_connector_response_to_domain_response {
     ($connector_type:ty, $response_type:ty, $convert_fn:expr) => {
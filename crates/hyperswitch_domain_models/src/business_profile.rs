@@ -84,6 +84,8 @@ pub struct Profile {
     pub external_vault_details: ExternalVaultDetails,
     pub billing_processor_id: Option<common_utils::id_type::MerchantConnectorAccountId>,
     pub surcharge_connector_details: Option<SurchargeConnectorDetails>,
+    pub confirm_time_budget_in_secs: Option<i64>,
+    pub time_zone: Option<String>,
     pub network_tokenization_credentials: OptionalEncryptableValue,
     pub payment_method_blocking: Option<PaymentMethodBlockingConfig>,
     pub default_fallback_routing: Option<pii::SecretSerdeValue>,
@@ -265,6 +267,8 @@ pub struct ProfileSetter {
     pub external_vault_details: ExternalVaultDetails,
     pub billing_processor_id: Option<common_utils::id_type::MerchantConnectorAccountId>,
     pub surcharge_connector_details: Option<SurchargeConnectorDetails>,
+    pub confirm_time_budget_in_secs: Option<i64>,
+    pub time_zone: Option<String>,
     pub network_tokenization_credentials: OptionalEncryptableValue,
     pub payment_method_blocking: Option<PaymentMethodBlockingConfig>,
     pub default_fallback_routing: Option<pii::SecretSerdeValue>,
@@ -337,6 +341,8 @@ impl From<ProfileSetter> for Profile {
             external_vault_details: value.external_vault_details,
             billing_processor_id: value.billing_processor_id,
             surcharge_connector_details: value.surcharge_connector_details,
+            confirm_time_budget_in_secs: value.confirm_time_budget_in_secs,
+            time_zone: value.time_zone,
             network_tokenization_credentials: value.network_tokenization_credentials,
             payment_method_blocking: value.payment_method_blocking,
             default_fallback_routing: value.default_fallback_routing,
@@ -407,6 +413,8 @@ pub struct ProfileDbBuilder {
     pub external_vault_details: ExternalVaultDetails,
     pub billing_processor_id: Option<common_utils::id_type::MerchantConnectorAccountId>,
     pub surcharge_connector_details: Option<SurchargeConnectorDetails>,
+    pub confirm_time_budget_in_secs: Option<i64>,
+    pub time_zone: Option<String>,
     pub network_tokenization_credentials: OptionalEncryptableValue,
     pub payment_method_blocking: Option<PaymentMethodBlockingConfig>,
     pub default_fallback_routing: Option<pii::SecretSerdeValue>,
@@ -479,6 +487,8 @@ impl From<ProfileDbBuilder> for Profile {
             external_vault_details: value.external_vault_details,
             billing_processor_id: value.billing_processor_id,
             surcharge_connector_details: value.surcharge_connector_details,
+            confirm_time_budget_in_secs: value.confirm_time_budget_in_secs,
+            time_zone: value.time_zone,
             network_tokenization_credentials: value.network_tokenization_credentials,
             payment_method_blocking: value.payment_method_blocking,
             default_fallback_routing: value.default_fallback_routing,
@@ -554,6 +564,8 @@ pub struct ProfileGeneralUpdate {
     pub external_vault_connector_details: Option<ExternalVaultConnectorDetails>,
     pub billing_processor_id: Option<common_utils::id_type::MerchantConnectorAccountId>,
     pub surcharge_connector_details: Option<SurchargeConnectorDetails>,
+    pub confirm_time_budget_in_secs: Option<i64>,
+    pub time_zone: Option<String>,
     pub network_tokenization_credentials: OptionalEncryptableValue,
     pub payment_method_blocking: Option<PaymentMethodBlockingConfig>,
 }
@@ -651,6 +663,8 @@ pub struct Profile {
     pub split_txns_enabled: common_enums::SplitTxnsEnabled,
     pub billing_processor_id: Option<common_utils::id_type::MerchantConnectorAccountId>,
     pub surcharge_connector_details: Option<SurchargeConnectorDetails>,
+    pub confirm_time_budget_in_secs: Option<i64>,
+    pub time_zone: Option<String>,
 }
 
 #[cfg(feature = "v2")]
@@ -711,6 +725,8 @@ pub struct ProfileSetter {
     pub split_txns_enabled: common_enums::SplitTxnsEnabled,
     pub billing_processor_id: Option<common_utils::id_type::MerchantConnectorAccountId>,
     pub surcharge_connector_details: Option<SurchargeConnectorDetails>,
+    pub confirm_time_budget_in_secs: Option<i64>,
+    pub time_zone: Option<String>,
 }
 
 #[cfg(feature = "v2")]
@@ -776,6 +792,8 @@ impl From<ProfileSetter> for Profile {
             split_txns_enabled: value.split_txns_enabled,
             billing_processor_id: value.billing_processor_id,
             surcharge_connector_details: value.surcharge_connector_details,
+            confirm_time_budget_in_secs: value.confirm_time_budget_in_secs,
+            time_zone: value.time_zone,
         }
     }
 }
@@ -839,6 +857,8 @@ pub struct ProfileDbBuilder {
     pub split_txns_enabled: common_enums::SplitTxnsEnabled,
     pub billing_processor_id: Option<common_utils::id_type::MerchantConnectorAccountId>,
     pub surcharge_connector_details: Option<SurchargeConnectorDetails>,
+    pub confirm_time_budget_in_secs: Option<i64>,
+    pub time_zone: Option<String>,
 }
 
 #[cfg(feature = "v2")]
@@ -904,6 +924,8 @@ impl From<ProfileDbBuilder> for Profile {
             split_txns_enabled: value.split_txns_enabled,
             billing_processor_id: value.billing_processor_id,
             surcharge_connector_details: value.surcharge_connector_details,
+            confirm_time_budget_in_secs: value.confirm_time_budget_in_secs,
+            time_zone: value.time_zone,
         }
     }
 }
@@ -1207,6 +1229,8 @@ pub struct ProfileGeneralUpdate {
     pub split_txns_enabled: Option<common_enums::SplitTxnsEnabled>,
     pub billing_processor_id: Option<common_utils::id_type::MerchantConnectorAccountId>,
     pub surcharge_connector_details: Option<SurchargeConnectorDetails>,
+    pub confirm_time_budget_in_secs: Option<i64>,
+    pub time_zone: Option<String>,
 }
 
 #[cfg(feature = "v2")]
@@ -243,6 +243,39 @@ pub struct ConnectorParamsWithFileUploadUrl {
     pub base_url: String,
     /// base url for file upload
     pub base_url_file_upload: String,
+    /// limits on the metadata sent to the connector
+    pub metadata_limits: ConnectorMetadataLimits,
+}
+
+/// Limits on the metadata accepted by connectors with Stripe style metadata, defaulting to the
+/// limits of Stripe
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct ConnectorMetadataLimits {
+    /// maximum number of characters in a metadata value
+    pub max_value_length: usize,
+    /// maximum number of metadata keys
+    pub max_keys: usize,
+}
+
+impl Default for ConnectorMetadataLimits {
+    fn default() -> Self {
+        Self {
+            max_value_length: 500,
+            max_keys: 50,
+        }
+    }
+}
+
+impl ConnectorMetadataLimits {
+    /// Validates that the limits allow at least one metadata key with a non empty value
+    pub fn validate(&self, parent_field: &str) -> Result<(), ApplicationError> {
+        common_utils::fp_utils::when(self.max_value_length == 0 || self.max_keys == 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(format!(
+                "max_value_length and max_keys must be greater than zero for {parent_field}"
+            )))
+        })
+    }
 }
 
 /// struct ConnectorParamsWithThreeBaseUrls
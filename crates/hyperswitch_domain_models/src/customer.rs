@@ -72,6 +72,10 @@ pub struct Customer {
     pub document_details: OptionalEncryptableValue,
     pub created_by: Option<CreatedBy>,
     pub last_modified_by: Option<CreatedBy>,
+    /// Keyed hash of the normalized email, used for searching by email
+    pub email_hash: Option<String>,
+    /// Keyed hash of the normalized phone number, used for searching by phone number
+    pub phone_hash: Option<String>,
 }
 
 #[cfg(feature = "v2")]
@@ -146,6 +150,8 @@ impl Customer {
             document_details,
             created_by,
             last_modified_by,
+            email_hash: None,
+            phone_hash: None,
         }
     }
 
@@ -245,6 +251,8 @@ impl behaviour::Conversion for Customer {
                 .last_modified_by
                 .map(|last_modified_by| last_modified_by.to_string()),
             id: self.identifiers.id,
+            email_hash: self.email_hash,
+            phone_hash: self.phone_hash,
         })
     }
 
@@ -329,6 +337,8 @@ impl behaviour::Conversion for Customer {
             last_modified_by: item
                 .last_modified_by
                 .and_then(|last_modified_by| last_modified_by.parse::<CreatedBy>().ok()),
+            email_hash: item.email_hash,
+            phone_hash: item.phone_hash,
         })
     }
 
@@ -357,6 +367,8 @@ impl behaviour::Conversion for Customer {
                 .as_ref()
                 .map(|created_by| created_by.to_string()),
             last_modified_by: self.created_by.map(|created_by| created_by.to_string()), // Same as created_by on creation
+            email_hash: self.email_hash,
+            phone_hash: self.phone_hash,
         })
     }
 }
@@ -687,6 +699,8 @@ pub enum CustomerUpdate {
         tax_registration_id: crypto::OptionalEncryptableSecretString,
         document_details: Box<OptionalEncryptableValue>,
         last_modified_by: Option<String>,
+        email_hash: Option<Option<String>>,
+        phone_hash: Option<Option<String>>,
     },
     ConnectorCustomer {
         connector_customer: Option<pii::SecretSerdeValue>,
@@ -696,6 +710,11 @@ pub enum CustomerUpdate {
         default_payment_method_id: Option<Option<String>>,
         last_modified_by: Option<String>,
     },
+    /// Replaces the search hashes of the customer, when the hashes are recomputed with a new key
+    ContactHashUpdate {
+        email_hash: Option<String>,
+        phone_hash: Option<String>,
+    },
 }
 
 #[cfg(feature = "v1")]
@@ -714,6 +733,8 @@ impl From<CustomerUpdate> for CustomerUpdateInternal {
                 tax_registration_id,
                 document_details,
                 last_modified_by,
+                email_hash,
+                phone_hash,
             } => Self {
                 name: name.map(Encryption::from),
                 email: email.map(Encryption::from),
@@ -729,6 +750,8 @@ impl From<CustomerUpdate> for CustomerUpdateInternal {
                 tax_registration_id: tax_registration_id.map(Encryption::from),
                 document_details: document_details.map(Encryption::from),
                 last_modified_by,
+                email_hash,
+                phone_hash,
             },
             CustomerUpdate::ConnectorCustomer {
                 connector_customer,
@@ -748,6 +771,8 @@ impl From<CustomerUpdate> for CustomerUpdateInternal {
                 tax_registration_id: None,
                 document_details: None,
                 last_modified_by,
+                email_hash: None,
+                phone_hash: None,
             },
             CustomerUpdate::UpdateDefaultPaymentMethod {
                 default_payment_method_id,
@@ -767,6 +792,29 @@ impl From<CustomerUpdate> for CustomerUpdateInternal {
                 tax_registration_id: None,
                 document_details: None,
                 last_modified_by,
+                email_hash: None,
+                phone_hash: None,
+            },
+            CustomerUpdate::ContactHashUpdate {
+                email_hash,
+                phone_hash,
+            } => Self {
+                modified_at: date_time::now(),
+                name: None,
+                email: None,
+                phone: None,
+                description: None,
+                phone_country_code: None,
+                metadata: None,
+                connector_customer: None,
+                address_id: None,
+                default_payment_method_id: None,
+                updated_by: None,
+                tax_registration_id: None,
+                document_details: None,
+                last_modified_by: None,
+                email_hash: Some(email_hash),
+                phone_hash: Some(phone_hash),
             },
         }
     }
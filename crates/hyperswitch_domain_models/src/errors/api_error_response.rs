@@ -316,6 +316,12 @@ pub enum ApiErrorResponse {
     InvalidBasicAuth,
     #[error(error_type = ErrorType::InvalidRequestError, code = "IR_52", message = "Payment Session has expired")]
     PaymentSessionExpired,
+    #[error(error_type = ErrorType::InvalidRequestError, code = "IR_53", message = "The {wallet_name} wallet token has expired")]
+    WalletTokenExpired { wallet_name: String },
+    #[error(error_type = ErrorType::InvalidRequestError, code = "IR_54", message = "{message}")]
+    PaymentCaptureConflict { message: String },
+    #[error(error_type = ErrorType::InvalidRequestError, code = "IR_55", message = "The {quota} quota of the merchant for the current period has been exhausted")]
+    QuotaExceeded { quota: String },
     #[error(error_type = ErrorType::InvalidRequestError, code = "WE_01", message = "Failed to authenticate the webhook")]
     WebhookAuthenticationFailed,
     #[error(error_type = ErrorType::InvalidRequestError, code = "WE_02", message = "Bad request received in webhook")]
@@ -754,6 +760,17 @@ impl ErrorSwitch<api_models::errors::types::ApiErrorResponse> for ApiErrorRespon
                 52,
                 "The provided payment session has expired", None
             )),
+            Self::WalletTokenExpired { wallet_name } => AER::BadRequest(ApiError::new(
+                "IR",
+                53,
+                format!("The {wallet_name} wallet token has expired, fetch a new token and retry the payment"), None
+            )),
+            Self::PaymentCaptureConflict { message } => {
+                AER::Conflict(ApiError::new("IR", 54, message.to_string(), None))
+            }
+            Self::QuotaExceeded { quota } => {
+                AER::Unprocessable(ApiError::new("IR", 55, format!("The {quota} quota of the merchant for the current period has been exhausted"), None))
+            }
         }
     }
 }
@@ -156,6 +156,9 @@ pub struct PaymentIntent {
     pub profile_acquirer_id: Option<id_type::ProfileAcquirerId>,
     pub external_surcharge_strategy: Option<common_enums::SurchargeStrategy>,
     pub external_surcharge_applicable: Option<bool>,
+    pub attribution_tags: Option<common_types::payments::AttributionTags>,
+    pub customer_email_hash: Option<String>,
+    pub customer_phone_hash: Option<String>,
 }
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
@@ -1362,6 +1365,9 @@ pub struct HeaderPayload {
     pub x_app_id: Option<String>,
     pub x_redirect_uri: Option<String>,
     pub x_reference_id: Option<String>,
+    /// The origin of the connector payload the payment status is updated from, set by the flows
+    /// racing with each other to update the status of the payment
+    pub status_update_source: Option<common_enums::StatusUpdateSource>,
 }
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
@@ -1401,6 +1407,16 @@ impl HeaderPayload {
             ..Default::default()
         }
     }
+
+    #[cfg(feature = "v1")]
+    pub fn with_status_update_source(
+        status_update_source: common_enums::StatusUpdateSource,
+    ) -> Self {
+        Self {
+            status_update_source: Some(status_update_source),
+            ..Default::default()
+        }
+    }
 }
 
 #[cfg(feature = "v2")]
@@ -275,6 +275,9 @@ pub struct PaymentIntentUpdateFields {
     pub profile_acquirer_id: Option<id_type::ProfileAcquirerId>,
     pub external_surcharge_strategy: Option<common_enums::SurchargeStrategy>,
     pub external_surcharge_applicable: Option<bool>,
+    pub attribution_tags: Option<common_types::payments::AttributionTags>,
+    pub customer_email_hash: Option<String>,
+    pub customer_phone_hash: Option<String>,
 }
 
 #[cfg(feature = "v1")]
@@ -301,6 +304,8 @@ pub enum PaymentIntentUpdate {
         billing_address_id: Option<String>,
         customer_details: Option<Encryptable<Secret<serde_json::Value>>>,
         updated_by: String,
+        customer_email_hash: Option<String>,
+        customer_phone_hash: Option<String>,
     },
     MerchantStatusUpdate {
         status: common_enums::IntentStatus,
@@ -371,6 +376,19 @@ pub enum PaymentIntentUpdate {
         status: common_enums::IntentStatus,
         updated_by: String,
     },
+    /// Fails the payment on an operator force-closing it, recording the close in the state metadata
+    ForceCloseUpdate {
+        status: common_enums::IntentStatus,
+        state_metadata: common_types::payments::PaymentIntentStateMetadata,
+        updated_by: String,
+    },
+    /// Replaces the search hashes of the customer details, when the hashes are recomputed with a
+    /// new key
+    ContactHashUpdate {
+        customer_email_hash: Option<String>,
+        customer_phone_hash: Option<String>,
+        updated_by: String,
+    },
 }
 
 #[cfg(feature = "v1")]
@@ -508,6 +526,9 @@ pub struct PaymentIntentUpdateInternal {
     pub profile_acquirer_id: Option<id_type::ProfileAcquirerId>,
     pub external_surcharge_strategy: Option<common_enums::SurchargeStrategy>,
     pub external_surcharge_applicable: Option<bool>,
+    pub attribution_tags: Option<common_types::payments::AttributionTags>,
+    pub customer_email_hash: Option<String>,
+    pub customer_phone_hash: Option<String>,
 }
 
 // This conversion is used in the `update_payment_intent` function
@@ -1117,6 +1138,9 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 installment_options: value.installment_options,
                 external_surcharge_strategy: None,
                 external_surcharge_applicable: None,
+                attribution_tags: value.attribution_tags,
+                customer_email_hash: value.customer_email_hash,
+                customer_phone_hash: value.customer_phone_hash,
                 ..Default::default()
             },
             PaymentIntentUpdate::PaymentCreateUpdate {
@@ -1127,6 +1151,8 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 billing_address_id,
                 customer_details,
                 updated_by,
+                customer_email_hash,
+                customer_phone_hash,
             } => Self {
                 return_url,
                 status,
@@ -1136,6 +1162,8 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 customer_details,
                 modified_at: Some(common_utils::date_time::now()),
                 updated_by,
+                customer_email_hash,
+                customer_phone_hash,
                 ..Default::default()
             },
             PaymentIntentUpdate::PGStatusUpdate {
@@ -1343,6 +1371,9 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 profile_acquirer_id: None,
                 external_surcharge_strategy: None,
                 external_surcharge_applicable: None,
+                attribution_tags: None,
+                customer_email_hash: None,
+                customer_phone_hash: None,
             },
             PaymentIntentUpdate::RecurrenceUpdate { status, updated_by } => Self {
                 status: Some(status),
@@ -1400,6 +1431,84 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 profile_acquirer_id: None,
                 external_surcharge_strategy: None,
                 external_surcharge_applicable: None,
+                attribution_tags: None,
+                customer_email_hash: None,
+                customer_phone_hash: None,
+            },
+            PaymentIntentUpdate::ForceCloseUpdate {
+                status,
+                state_metadata,
+                updated_by,
+            } => Self {
+                status: Some(status),
+                updated_by,
+                session_expiry: None,
+                state_metadata: Some(state_metadata),
+                amount: None,
+                currency: None,
+                amount_captured: None,
+                customer_id: None,
+                return_url: None,
+                setup_future_usage: None,
+                off_session: None,
+                metadata: None,
+                connector_metadata: None,
+                billing_address_id: None,
+                shipping_address_id: None,
+                modified_at: None,
+                active_attempt_id: None,
+                business_country: None,
+                business_label: None,
+                description: None,
+                statement_descriptor_name: None,
+                statement_descriptor_suffix: None,
+                billing_descriptor: None,
+                order_details: None,
+                attempt_count: None,
+                merchant_decision: None,
+                payment_confirm_source: None,
+                surcharge_applicable: None,
+                incremental_authorization_allowed: None,
+                authorization_count: None,
+                fingerprint_id: None,
+                request_external_three_ds_authentication: None,
+                frm_metadata: None,
+                customer_details: None,
+                billing_details: None,
+                merchant_order_reference_id: None,
+                shipping_details: None,
+                is_payment_processor_token_flow: None,
+                tax_details: None,
+                force_3ds_challenge: None,
+                is_iframe_redirection_enabled: None,
+                payment_channel: None,
+                feature_metadata: None,
+                tax_status: None,
+                discount_amount: None,
+                order_date: None,
+                shipping_amount_tax: None,
+                duty_amount: None,
+                enable_partial_authorization: None,
+                enable_overcapture: None,
+                shipping_cost: None,
+                installment_options: None,
+                profile_acquirer_id: None,
+                external_surcharge_strategy: None,
+                external_surcharge_applicable: None,
+                attribution_tags: None,
+                customer_email_hash: None,
+                customer_phone_hash: None,
+            },
+            PaymentIntentUpdate::ContactHashUpdate {
+                customer_email_hash,
+                customer_phone_hash,
+                updated_by,
+            } => Self {
+                customer_email_hash,
+                customer_phone_hash,
+                modified_at: Some(common_utils::date_time::now()),
+                updated_by,
+                ..Default::default()
             },
         }
     }
@@ -1509,6 +1618,9 @@ impl From<PaymentIntentUpdate> for DieselPaymentIntentUpdate {
                     profile_acquirer_id: value.profile_acquirer_id,
                     external_surcharge_strategy: None,
                     external_surcharge_applicable: None,
+                    attribution_tags: value.attribution_tags,
+                    customer_email_hash: value.customer_email_hash,
+                    customer_phone_hash: value.customer_phone_hash,
                 }))
             }
             PaymentIntentUpdate::PaymentCreateUpdate {
@@ -1519,6 +1631,8 @@ impl From<PaymentIntentUpdate> for DieselPaymentIntentUpdate {
                 billing_address_id,
                 customer_details,
                 updated_by,
+                customer_email_hash,
+                customer_phone_hash,
             } => Self::PaymentCreateUpdate {
                 return_url,
                 status,
@@ -1527,6 +1641,8 @@ impl From<PaymentIntentUpdate> for DieselPaymentIntentUpdate {
                 billing_address_id,
                 customer_details: customer_details.map(Encryption::from),
                 updated_by,
+                customer_email_hash,
+                customer_phone_hash,
             },
             PaymentIntentUpdate::MerchantStatusUpdate {
                 status,
@@ -1638,6 +1754,24 @@ impl From<PaymentIntentUpdate> for DieselPaymentIntentUpdate {
             PaymentIntentUpdate::RecurrenceUpdate { status, updated_by } => {
                 Self::RecurrenceUpdate { status, updated_by }
             }
+            PaymentIntentUpdate::ForceCloseUpdate {
+                status,
+                state_metadata,
+                updated_by,
+            } => Self::ForceCloseUpdate {
+                status,
+                state_metadata,
+                updated_by,
+            },
+            PaymentIntentUpdate::ContactHashUpdate {
+                customer_email_hash,
+                customer_phone_hash,
+                updated_by,
+            } => Self::ContactHashUpdate {
+                customer_email_hash,
+                customer_phone_hash,
+                updated_by,
+            },
         }
     }
 }
@@ -1702,6 +1836,9 @@ impl From<PaymentIntentUpdateInternal> for diesel_models::PaymentIntentUpdateInt
             profile_acquirer_id,
             external_surcharge_strategy,
             external_surcharge_applicable,
+            attribution_tags,
+            customer_email_hash,
+            customer_phone_hash,
         } = value;
         Self {
             amount,
@@ -1761,6 +1898,9 @@ impl From<PaymentIntentUpdateInternal> for diesel_models::PaymentIntentUpdateInt
             profile_acquirer_id,
             external_surcharge_strategy,
             external_surcharge_applicable,
+            attribution_tags,
+            customer_email_hash,
+            customer_phone_hash,
         }
     }
 }
@@ -1819,7 +1959,11 @@ pub struct PaymentIntentListParams {
     pub card_network: Option<Vec<common_enums::CardNetwork>>,
     pub card_discovery: Option<Vec<common_enums::CardDiscovery>>,
     pub merchant_order_reference_id: Option<String>,
-    pub customer_email: Option<Email>,
+    pub attribution_tags: Option<common_types::payments::AttributionTags>,
+    /// Keyed hash of the customer email to filter by, the email itself is never queried
+    pub customer_email_hash: Option<String>,
+    /// Keyed hash of the customer phone number to filter by
+    pub customer_phone_hash: Option<String>,
 }
 
 #[cfg(feature = "v2")]
@@ -1881,7 +2025,9 @@ impl From<api_models::payments::PaymentListConstraints> for PaymentIntentFetchCo
             card_network: None,
             card_discovery: None,
             merchant_order_reference_id: None,
-            customer_email: None,
+            attribution_tags: None,
+            customer_email_hash: None,
+            customer_phone_hash: None,
         }))
     }
 }
@@ -1973,7 +2119,9 @@ impl From<common_utils::types::TimeRange> for PaymentIntentFetchConstraints {
             card_network: None,
             card_discovery: None,
             merchant_order_reference_id: None,
-            customer_email: None,
+            attribution_tags: None,
+            customer_email_hash: None,
+            customer_phone_hash: None,
         }))
     }
 }
@@ -2010,7 +2158,9 @@ impl From<api_models::payments::PaymentListFilterConstraints> for PaymentIntentF
             first_attempt: _first_attempt,
             card_discovery,
             merchant_order_reference_id,
-            customer_email,
+            customer_email: _customer_email,
+            customer_phone: _customer_phone,
+            attribution_tags,
         } = value;
         if let Some(payment_intent_id) = payment_id {
             Self::Single { payment_intent_id }
@@ -2036,7 +2186,10 @@ impl From<api_models::payments::PaymentListFilterConstraints> for PaymentIntentF
                 card_network,
                 card_discovery,
                 merchant_order_reference_id,
-                customer_email,
+                attribution_tags,
+                // Hashed by the caller, as hashing requires the key of the tenant
+                customer_email_hash: None,
+                customer_phone_hash: None,
             }))
         }
     }
@@ -2636,6 +2789,9 @@ impl behaviour::Conversion for PaymentIntent {
             profile_acquirer_id: self.profile_acquirer_id,
             external_surcharge_strategy: self.external_surcharge_strategy,
             external_surcharge_applicable: self.external_surcharge_applicable,
+            attribution_tags: self.attribution_tags,
+            customer_email_hash: self.customer_email_hash,
+            customer_phone_hash: self.customer_phone_hash,
         })
     }
 
@@ -2755,6 +2911,9 @@ impl behaviour::Conversion for PaymentIntent {
                 profile_acquirer_id: storage_model.profile_acquirer_id,
                 external_surcharge_strategy: storage_model.external_surcharge_strategy,
                 external_surcharge_applicable: storage_model.external_surcharge_applicable,
+                attribution_tags: storage_model.attribution_tags,
+                customer_email_hash: storage_model.customer_email_hash,
+                customer_phone_hash: storage_model.customer_phone_hash,
             })
         }
         .await
@@ -2847,6 +3006,9 @@ impl behaviour::Conversion for PaymentIntent {
             profile_acquirer_id: self.profile_acquirer_id,
             external_surcharge_strategy: self.external_surcharge_strategy,
             external_surcharge_applicable: self.external_surcharge_applicable,
+            attribution_tags: self.attribution_tags,
+            customer_email_hash: self.customer_email_hash,
+            customer_phone_hash: self.customer_phone_hash,
         })
     }
 }
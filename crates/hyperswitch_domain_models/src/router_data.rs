@@ -614,6 +614,29 @@ impl ApplePayPredecryptDataInternal {
                 .to_owned(),
         ))
     }
+
+    /// The token is valid through the application_expiration_date (YYMMDD), so it expires at the
+    /// start of the following day
+    pub fn get_application_expiration_time(
+        &self,
+    ) -> Result<time::PrimitiveDateTime, common_utils::errors::ValidationError> {
+        let get_part = |range: std::ops::Range<usize>| {
+            self.application_expiration_date
+                .get(range)
+                .and_then(|part| part.parse::<u8>().ok())
+        };
+        get_part(0..2)
+            .zip(get_part(2..4).and_then(|month| time::Month::try_from(month).ok()))
+            .zip(get_part(4..6))
+            .and_then(|((year, month), day)| {
+                time::Date::from_calendar_date(2000 + i32::from(year), month, day).ok()
+            })
+            .and_then(|date| date.next_day())
+            .map(|date| date.midnight())
+            .ok_or(common_utils::errors::ValidationError::InvalidValue {
+                message: "Invalid application expiration date".to_string(),
+            })
+    }
 }
 
 #[derive(Debug, Clone, Deserialize)]
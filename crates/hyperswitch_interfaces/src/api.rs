@@ -106,7 +106,7 @@ pub use self::{
 };
 use crate::{
     api::subscriptions::Subscriptions, connector_integration_v2::ConnectorIntegrationV2, consts,
-    errors, events::connector_api_logs::ConnectorEvent, metrics, types, webhooks,
+    errors, events::connector_api_logs::ConnectorEvent, helpers, metrics, types, webhooks,
 };
 
 /// Connector trait
@@ -386,16 +386,27 @@ pub trait ConnectorCommon {
     /// The base URL for interacting with the connector's API.
     fn base_url<'a>(&self, connectors: &'a Connectors) -> &'a str;
 
+    /// Path to the human-readable error message in the connector's JSON error response, with
+    /// object keys separated by `.` and array indices in brackets, e.g. `errors[0].detail`.
+    /// Defaults to `error.message`, and is overridden by the path configured for the connector.
+    fn get_error_message_path(&self) -> &'static str {
+        "error.message"
+    }
+
     /// common error response for a connector if it is same in all case
     fn build_error_response(
         &self,
         res: types::Response,
         _event_builder: Option<&mut ConnectorEvent>,
     ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
+        let message =
+            helpers::get_error_message_from_json_path(&res.response, self.get_error_message_path())
+                .unwrap_or_else(|| consts::NO_ERROR_MESSAGE.to_string());
+
         Ok(ErrorResponse {
             status_code: res.status_code,
             code: consts::NO_ERROR_CODE.to_string(),
-            message: consts::NO_ERROR_MESSAGE.to_string(),
+            message,
             reason: None,
             attempt_status: None,
             connector_transaction_id: None,
@@ -1,5 +1,6 @@
 use std::{
     fmt::Debug,
+    sync::atomic::{AtomicUsize, Ordering},
     time::{Duration, Instant},
 };
 
@@ -22,14 +23,18 @@ use serde_json::json;
 
 use crate::{
     configs,
+    connector_error_severity::{self, ConnectorErrorCategory, ErrorSeverity},
     connector_integration_interface::{
         BoxedConnectorIntegrationInterface, ConnectorEnum, RouterDataConversion,
     },
     consts,
     errors::ConnectorError,
     events,
-    events::connector_api_logs::ConnectorEvent,
-    metrics, types,
+    events::{
+        connector_api_logs::ConnectorEvent,
+        connector_call_outcome::{ConnectorCallOutcome, ConnectorCallStatus},
+    },
+    helpers, metrics, types,
     types::Proxy,
 };
 
@@ -124,6 +129,78 @@ pub trait ApiClientWrapper: Send + Sync {
     fn get_connectors(&self) -> configs::Connectors;
     /// Get the event handler
     fn event_handler(&self) -> &dyn events::EventHandlerInterface;
+    /// Get the locale of the request, if it is to be sent to the connector
+    fn get_connector_request_locale(&self, connector: &str) -> Option<String>;
+    /// Get the order in which the request headers are to be sent to the connector, if the
+    /// connector is sensitive to the order of the headers
+    fn get_connector_request_header_order(&self, connector: &str) -> Option<Vec<String>>;
+    /// Get the path to the error message in the JSON error responses of the connector, if it is
+    /// configured to override the path of the connector
+    fn get_connector_error_message_path(&self, connector: &str) -> Option<String>;
+    /// Send the outcome of a connector call to the internal observability webhook, if it is
+    /// configured, without waiting for it to be delivered
+    fn notify_connector_call_outcome(&self, outcome: ConnectorCallOutcome);
+    /// Get the severity of an error of the connector, which is the default severity of its
+    /// category unless it is overridden for the connector
+    fn get_connector_error_severity(
+        &self,
+        connector: &str,
+        category: ConnectorErrorCategory,
+    ) -> ErrorSeverity;
+}
+
+/// Number of connector calls in flight, which are waited for when the application shuts down
+static IN_FLIGHT_CONNECTOR_CALLS: AtomicUsize = AtomicUsize::new(0);
+
+/// Tracks a connector call as in flight for as long as it is held
+struct InFlightConnectorCallGuard;
+
+impl InFlightConnectorCallGuard {
+    fn track() -> Self {
+        IN_FLIGHT_CONNECTOR_CALLS.fetch_add(1, Ordering::AcqRel);
+        Self
+    }
+}
+
+impl Drop for InFlightConnectorCallGuard {
+    fn drop(&mut self) {
+        IN_FLIGHT_CONNECTOR_CALLS.fetch_sub(1, Ordering::AcqRel);
+    }
+}
+
+/// Get the number of connector calls whose responses are yet to be handled
+pub fn get_in_flight_connector_calls() -> usize {
+    IN_FLIGHT_CONNECTOR_CALLS.load(Ordering::Acquire)
+}
+
+/// Add the `Accept-Language` header to the connector request, unless the connector has already set it
+fn add_accept_language_header(request: &mut Request, locale: String) {
+    let is_header_present = request
+        .headers
+        .iter()
+        .any(|(key, _)| key.eq_ignore_ascii_case(http::header::ACCEPT_LANGUAGE.as_str()));
+    if !is_header_present {
+        request.headers.insert((
+            http::header::ACCEPT_LANGUAGE.to_string(),
+            Maskable::Normal(locale),
+        ));
+    }
+}
+
+/// Replace the message of the error response with the string at the error message path
+/// configured for the connector, keeping the message of the connector if no path is configured or
+/// the path does not resolve to a string in the error response body
+fn apply_configured_error_message_path(
+    mut error: ErrorResponse,
+    error_message_path: Option<&str>,
+    error_response_body: &[u8],
+) -> ErrorResponse {
+    if let Some(message) = error_message_path
+        .and_then(|path| helpers::get_error_message_from_json_path(error_response_body, path))
+    {
+        error.message = message;
+    }
+    error
 }
 
 /// Handle the flow by interacting with connector module
@@ -275,8 +352,17 @@ where
                         ));
                         request_id
                     });
+                    if let Some(locale) = state.get_connector_request_locale(&req.connector) {
+                        add_accept_language_header(&mut request, locale);
+                    }
+                    if let Some(header_order) =
+                        state.get_connector_request_header_order(&req.connector)
+                    {
+                        request.set_header_order(header_order);
+                    }
                     let request_url = request.url.clone();
                     let request_method = request.method;
+                    let _in_flight_connector_call = InFlightConnectorCallGuard::track();
                     let current_time = Instant::now();
                     let response =
                         call_connector_api(state, request, "execute_connector_processing_step")
@@ -309,33 +395,53 @@ where
                         // Direct connector call: a live call, never a shadow mirror.
                         common_enums::EventExecutionMode::Primary,
                     );
+                    state.notify_connector_call_outcome(ConnectorCallOutcome {
+                        connector: req.connector.clone(),
+                        flow: flow_name.clone(),
+                        status: get_connector_call_status(&response),
+                        http_status_code: response.is_ok().then_some(status_code),
+                        latency_ms: u64::try_from(external_latency).unwrap_or(u64::MAX),
+                        request_id: state.get_request_id_str(),
+                    });
 
                     match response {
                         Ok(body) => {
                             let response = match body {
                                 Ok(body) => {
                                     let connector_http_status_code = Some(body.status_code);
-                                    let handle_response_result = connector_integration
-                                        .handle_response(
-                                            req,
-                                            Some(&mut connector_event),
-                                            body.clone(),
-                                        )
-                                        .inspect_err(|error| {
-                                            if error.current_context()
-                                                == &ConnectorError::ResponseDeserializationFailed
-                                            {
-                                                metrics::RESPONSE_DESERIALIZATION_FAILURE.add(
-                                                    1,
-                                                    router_env::metric_attributes!((
-                                                        "connector",
-                                                        req.connector.clone(),
-                                                    )),
-                                                )
-                                            }
-                                        });
+                                    let handle_response_result = decode_connector_response(
+                                        &metrics::CONNECTOR_RESPONSE_DECODE_TIME,
+                                        &req.connector,
+                                        &flow_name,
+                                        || {
+                                            connector_integration.handle_response(
+                                                req,
+                                                Some(&mut connector_event),
+                                                body.clone(),
+                                            )
+                                        },
+                                    )
+                                    .inspect_err(|error| {
+                                        if error.current_context()
+                                            == &ConnectorError::ResponseDeserializationFailed
+                                        {
+                                            metrics::RESPONSE_DESERIALIZATION_FAILURE.add(
+                                                1,
+                                                router_env::metric_attributes!((
+                                                    "connector",
+                                                    req.connector.clone(),
+                                                )),
+                                            )
+                                        }
+                                    });
                                     match handle_response_result {
                                         Ok(mut data) => {
+                                            if let Ok(response) = &data.response {
+                                                ResourceCommonData::set_connector_event_response_details(
+                                                    &mut connector_event,
+                                                    response,
+                                                );
+                                            }
                                             state
                                                 .event_handler()
                                                 .log_connector_event(&connector_event);
@@ -389,6 +495,7 @@ where
                                         &body,
                                     )?;
 
+                                    let error_response_body = body.response.clone();
                                     let error = match body.status_code {
                                         500..=511 => {
                                             let error_res = connector_integration
@@ -416,6 +523,18 @@ where
                                             error_res
                                         }
                                     };
+                                    let error = apply_configured_error_message_path(
+                                        error,
+                                        state
+                                            .get_connector_error_message_path(&req.connector)
+                                            .as_deref(),
+                                        &error_response_body,
+                                    );
+                                    connector_error_severity::record_connector_error(
+                                        state,
+                                        &req.connector,
+                                        ConnectorErrorCategory::from_error_response(&error),
+                                    );
 
                                     router_data.response = Err(error);
 
@@ -427,7 +546,17 @@ where
                         Err(error) => {
                             connector_event.set_error(json!({"error": error.to_string()}));
                             state.event_handler().log_connector_event(&connector_event);
-                            if error.current_context().is_upstream_timeout() {
+                            let is_upstream_timeout = error.current_context().is_upstream_timeout();
+                            connector_error_severity::record_connector_error(
+                                state,
+                                &req.connector,
+                                if is_upstream_timeout {
+                                    ConnectorErrorCategory::Timeout
+                                } else {
+                                    ConnectorErrorCategory::ConnectionFailure
+                                },
+                            );
+                            if is_upstream_timeout {
                                 let error_response = ErrorResponse {
                                     code: consts::REQUEST_TIMEOUT_ERROR_CODE.to_string(),
                                     message: consts::REQUEST_TIMEOUT_ERROR_MESSAGE.to_string(),
@@ -462,6 +591,36 @@ where
     }
 }
 
+fn get_connector_call_status(
+    response: &CustomResult<Result<types::Response, types::Response>, ApiClientError>,
+) -> ConnectorCallStatus {
+    match response {
+        Ok(Ok(_)) => ConnectorCallStatus::Success,
+        Ok(Err(_)) => ConnectorCallStatus::ErrorResponse,
+        Err(error) if error.current_context().is_upstream_timeout() => ConnectorCallStatus::Timeout,
+        Err(_) => ConnectorCallStatus::Failure,
+    }
+}
+
+/// Decode the response of the connector, recording the time taken on the histogram
+fn decode_connector_response<R>(
+    decode_time: &router_env::opentelemetry::metrics::Histogram<f64>,
+    connector: &str,
+    flow: &str,
+    decode: impl FnOnce() -> R,
+) -> R {
+    let start = Instant::now();
+    let decoded_response = decode();
+    decode_time.record(
+        start.elapsed().as_secs_f64(),
+        router_env::metric_attributes!(
+            ("connector", connector.to_string()),
+            ("flow", flow.to_string()),
+        ),
+    );
+    decoded_response
+}
+
 /// Calls the connector API and handles the response
 #[instrument(skip_all)]
 pub async fn call_connector_api(
@@ -611,3 +770,313 @@ pub fn get_flow_name<F>() -> CustomResult<String, api_error_response::ApiErrorRe
         .attach_printable("Flow stringify failed")?
         .to_string())
 }
+
+#[cfg(test)]
+mod tests {
+    use hyperswitch_domain_models::{
+        router_data_v2::flow_common_types::RefundFlowData, router_flow_types::refunds::Execute,
+        router_response_types::RefundsResponseData,
+    };
+
+    use super::*;
+    use crate::connector_integration_interface::ConnectorIntegrationEnum;
+
+    fn accept_language_header(locale: &str) -> (String, Maskable<String>) {
+        (
+            http::header::ACCEPT_LANGUAGE.to_string(),
+            Maskable::Normal(locale.to_string()),
+        )
+    }
+
+    #[test]
+    fn accept_language_header_reflects_request_locale() {
+        let mut request = Request::new(common_utils::request::Method::Post, "https://example.com");
+
+        add_accept_language_header(&mut request, "fr-FR".to_string());
+
+        assert!(request.headers.contains(&accept_language_header("fr-FR")));
+    }
+
+    #[test]
+    fn accept_language_header_set_by_connector_is_not_overridden() {
+        let mut request = Request::new(common_utils::request::Method::Post, "https://example.com");
+        request.add_header("Accept-Language", Maskable::Normal("en".to_string()));
+
+        add_accept_language_header(&mut request, "fr-FR".to_string());
+
+        assert_eq!(request.headers.len(), 1);
+        assert!(!request.headers.contains(&accept_language_header("fr-FR")));
+    }
+
+    #[test]
+    fn configured_error_message_path_overrides_connector_message() {
+        let body = br#"{"errors":[{"code":"invalid_request","detail":"Amount is invalid"}]}"#;
+        let error = ErrorResponse {
+            message: consts::NO_ERROR_MESSAGE.to_string(),
+            ..ErrorResponse::default()
+        };
+
+        let overridden =
+            apply_configured_error_message_path(error.clone(), Some("errors[0].detail"), body);
+        assert_eq!(overridden.message, "Amount is invalid");
+
+        let unresolved =
+            apply_configured_error_message_path(error.clone(), Some("error.message"), body);
+        assert_eq!(unresolved.message, consts::NO_ERROR_MESSAGE);
+
+        let not_configured = apply_configured_error_message_path(error, None, body);
+        assert_eq!(not_configured.message, consts::NO_ERROR_MESSAGE);
+    }
+
+    #[derive(Default)]
+    struct RecordedMeasurements(
+        std::sync::Mutex<Vec<(f64, Vec<router_env::opentelemetry::KeyValue>)>>,
+    );
+
+    impl router_env::opentelemetry::metrics::SyncInstrument<f64> for RecordedMeasurements {
+        fn measure(&self, measurement: f64, attributes: &[router_env::opentelemetry::KeyValue]) {
+            if let Ok(mut measurements) = self.0.lock() {
+                measurements.push((measurement, attributes.to_vec()));
+            }
+        }
+    }
+
+    #[test]
+    fn decode_time_is_recorded_for_decoded_response() {
+        let measurements = std::sync::Arc::new(RecordedMeasurements::default());
+        let decode_time = router_env::opentelemetry::metrics::Histogram::new(measurements.clone());
+
+        let decoded_response =
+            decode_connector_response(&decode_time, "stripe", "Authorize", || {
+                serde_json::from_str::<serde_json::Value>(r#"{"id": "pi_123"}"#)
+            });
+
+        assert!(decoded_response.is_ok());
+        let expected_attributes =
+            router_env::metric_attributes!(("connector", "stripe"), ("flow", "Authorize"));
+        let measurements = measurements
+            .0
+            .lock()
+            .map(|measurements| measurements.clone())
+            .unwrap_or_default();
+        assert_eq!(
+            measurements
+                .iter()
+                .map(|(_, attributes)| attributes.as_slice())
+                .collect::<Vec<_>>(),
+            vec![expected_attributes.as_slice()]
+        );
+    }
+
+    #[derive(Clone)]
+    struct TestApiClient {
+        response_body: &'static str,
+    }
+
+    #[async_trait::async_trait]
+    impl ApiClient for TestApiClient {
+        fn request(
+            &self,
+            _method: Method,
+            _url: String,
+        ) -> CustomResult<Box<dyn RequestBuilder>, ApiClientError> {
+            Err(report!(ApiClientError::UnexpectedState))
+        }
+
+        fn request_with_certificate(
+            &self,
+            _method: Method,
+            _url: String,
+            _certificate: Option<hyperswitch_masking::Secret<String>>,
+            _certificate_key: Option<hyperswitch_masking::Secret<String>>,
+        ) -> CustomResult<Box<dyn RequestBuilder>, ApiClientError> {
+            Err(report!(ApiClientError::UnexpectedState))
+        }
+
+        async fn send_request(
+            &self,
+            _state: &dyn ApiClientWrapper,
+            _request: Request,
+            _option_timeout_secs: Option<u64>,
+            _forward_to_kafka: bool,
+        ) -> CustomResult<reqwest::Response, ApiClientError> {
+            http::Response::builder()
+                .status(200)
+                .body(self.response_body)
+                .map(reqwest::Response::from)
+                .change_context(ApiClientError::UnexpectedState)
+        }
+
+        fn add_request_id(&mut self, _request_id: RequestId) {}
+
+        fn get_request_id(&self) -> Option<RequestId> {
+            None
+        }
+
+        fn get_request_id_str(&self) -> Option<String> {
+            None
+        }
+
+        fn add_flow_name(&mut self, _flow_name: String) {}
+    }
+
+    #[derive(Clone, Default)]
+    struct RecordedConnectorEvents(std::sync::Arc<std::sync::Mutex<Vec<serde_json::Value>>>);
+
+    impl events::EventHandlerInterface for RecordedConnectorEvents {
+        fn log_connector_event(&self, event: &ConnectorEvent) {
+            if let (Ok(mut events), Ok(event)) = (self.0.lock(), serde_json::to_value(event)) {
+                events.push(event);
+            }
+        }
+    }
+
+    struct TestState {
+        api_client: TestApiClient,
+        connector_events: RecordedConnectorEvents,
+    }
+
+    impl ApiClientWrapper for TestState {
+        fn get_api_client(&self) -> &dyn ApiClient {
+            &self.api_client
+        }
+
+        fn get_proxy(&self) -> Proxy {
+            Proxy::default()
+        }
+
+        fn get_request_id_str(&self) -> Option<String> {
+            None
+        }
+
+        fn get_request_id(&self) -> Option<RequestId> {
+            None
+        }
+
+        fn get_tenant(&self) -> configs::Tenant {
+            configs::Tenant {
+                tenant_id: common_utils::id_type::TenantId::get_default_global_tenant_id(),
+                base_url: String::new(),
+                schema: String::new(),
+                accounts_schema: String::new(),
+                redis_key_prefix: String::new(),
+                clickhouse_database: String::new(),
+                user: configs::TenantUserConfig {
+                    control_center_url: String::new(),
+                },
+            }
+        }
+
+        fn get_connectors(&self) -> configs::Connectors {
+            configs::Connectors::default()
+        }
+
+        fn event_handler(&self) -> &dyn events::EventHandlerInterface {
+            &self.connector_events
+        }
+
+        fn get_connector_request_locale(&self, _connector: &str) -> Option<String> {
+            None
+        }
+
+        fn get_connector_request_header_order(&self, _connector: &str) -> Option<Vec<String>> {
+            None
+        }
+
+        fn get_connector_error_message_path(&self, _connector: &str) -> Option<String> {
+            None
+        }
+
+        fn notify_connector_call_outcome(&self, _outcome: ConnectorCallOutcome) {}
+
+        fn get_connector_error_severity(
+            &self,
+            _connector: &str,
+            category: ConnectorErrorCategory,
+        ) -> ErrorSeverity {
+            category.get_default_severity()
+        }
+    }
+
+    struct TestConnector;
+
+    impl crate::api::ConnectorCommon for TestConnector {
+        fn id(&self) -> &'static str {
+            "test_connector"
+        }
+
+        fn base_url<'a>(&self, _connectors: &'a configs::Connectors) -> &'a str {
+            ""
+        }
+    }
+
+    impl crate::api::ConnectorIntegration<Execute, (), RefundsResponseData> for TestConnector {
+        fn handle_response(
+            &self,
+            data: &RouterData<Execute, (), RefundsResponseData>,
+            _event_builder: Option<&mut ConnectorEvent>,
+            res: types::Response,
+        ) -> CustomResult<RouterData<Execute, (), RefundsResponseData>, ConnectorError> {
+            let response: serde_json::Value = serde_json::from_slice(&res.response)
+                .change_context(ConnectorError::ResponseDeserializationFailed)?;
+            Ok(RouterData {
+                response: Ok(RefundsResponseData {
+                    connector_refund_id: response["id"].as_str().unwrap_or_default().to_string(),
+                    refund_status: common_enums::RefundStatus::Pending,
+                }),
+                ..data.clone()
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn refund_connector_event_is_logged_with_refund_response() {
+        let connector_events = RecordedConnectorEvents::default();
+        let state = TestState {
+            api_client: TestApiClient {
+                response_body: r#"{"id":"re_123","status":"pending"}"#,
+            },
+            connector_events: connector_events.clone(),
+        };
+        let connector_integration: BoxedConnectorIntegrationInterface<
+            Execute,
+            RefundFlowData,
+            (),
+            RefundsResponseData,
+        > = Box::new(ConnectorIntegrationEnum::Old(Box::new(&TestConnector)));
+        let mut router_data = crate::conversion_impls::get_default_router_data(
+            common_utils::id_type::TenantId::get_default_global_tenant_id(),
+            "refund",
+            (),
+            Err(ErrorResponse::default()),
+        );
+        router_data.connector = "test_connector".to_string();
+        router_data.refund_id = Some("ref_123".to_string());
+
+        let router_data = execute_connector_processing_step(
+            &state,
+            connector_integration,
+            &router_data,
+            common_enums::CallConnectorAction::Trigger,
+            Some(Request::new(
+                common_utils::request::Method::Post,
+                "https://example.com/refunds",
+            )),
+            None,
+        )
+        .await
+        .expect("connector call failed");
+
+        assert!(router_data.response.is_ok());
+        let logged_events = connector_events
+            .0
+            .lock()
+            .map(|events| events.clone())
+            .unwrap_or_default();
+        assert_eq!(logged_events.len(), 1);
+        let logged_event = logged_events.first().expect("connector event not logged");
+        assert_eq!(logged_event["connector_refund_id"], json!("re_123"));
+        assert_eq!(logged_event["refund_status"], json!("pending"));
+        assert_eq!(logged_event["refund_id"], json!("ref_123"));
+    }
+}
@@ -0,0 +1,139 @@
+//! Classification of connector errors into the severity with which they are to be alerted on.
+//!
+//! A card declined by the issuer is part of the normal operation of a connector, whereas a
+//! connector responding with server errors is an outage. The category of each error is recorded
+//! on a metric along with its severity, which can be overridden per connector.
+
+use common_enums::AttemptStatus;
+use hyperswitch_domain_models::router_data::ErrorResponse;
+use serde::Deserialize;
+
+use crate::{api_client::ApiClientWrapper, metrics};
+
+/// Category of the error of a connector call
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize, strum::Display)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ConnectorErrorCategory {
+    /// The payment was declined by the connector or the issuer
+    Decline,
+    /// The connector rejected the request with a client error other than a decline
+    ClientError,
+    /// The connector responded with a server error
+    ServerError,
+    /// The connector did not respond before the request timed out
+    Timeout,
+    /// The request could not be sent to the connector, or its response could not be received
+    ConnectionFailure,
+}
+
+/// Severity with which an error is to be alerted on
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, strum::Display)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ErrorSeverity {
+    /// Expected in the normal operation of the connector
+    Low,
+    /// Could point to an issue with the integration of the connector
+    Medium,
+    /// The connector is degraded or unavailable
+    High,
+}
+
+impl ConnectorErrorCategory {
+    /// Category of the error response of a connector. Authentication and authorization failures
+    /// point to misconfigured credentials, and are never treated as declines, even if the
+    /// connector marks the attempt as failed.
+    pub fn from_error_response(error_response: &ErrorResponse) -> Self {
+        let is_decline = error_response.status_code == 402
+            || error_response.network_decline_code.is_some()
+            || matches!(
+                error_response.attempt_status,
+                Some(AttemptStatus::Failure | AttemptStatus::AuthorizationFailed)
+            );
+
+        match error_response.status_code {
+            500..=599 => Self::ServerError,
+            401 | 403 => Self::ClientError,
+            _ if is_decline => Self::Decline,
+            _ => Self::ClientError,
+        }
+    }
+
+    /// Severity of the category, unless it is overridden for the connector
+    pub fn get_default_severity(self) -> ErrorSeverity {
+        match self {
+            Self::Decline => ErrorSeverity::Low,
+            Self::ClientError => ErrorSeverity::Medium,
+            Self::ServerError | Self::Timeout | Self::ConnectionFailure => ErrorSeverity::High,
+        }
+    }
+}
+
+/// Record the error of a connector call on the metric, labelled with its category and severity
+pub fn record_connector_error(
+    state: &dyn ApiClientWrapper,
+    connector: &str,
+    category: ConnectorErrorCategory,
+) {
+    let severity = state.get_connector_error_severity(connector, category);
+    metrics::CONNECTOR_ERROR_COUNT.add(
+        1,
+        router_env::metric_attributes!(
+            ("connector", connector.to_string()),
+            ("category", category.to_string()),
+            ("severity", severity.to_string()),
+        ),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decline_maps_to_low_severity() {
+        let error_response = ErrorResponse {
+            code: "card_declined".to_string(),
+            message: "Your card was declined.".to_string(),
+            status_code: 402,
+            ..ErrorResponse::default()
+        };
+
+        let category = ConnectorErrorCategory::from_error_response(&error_response);
+
+        assert_eq!(category, ConnectorErrorCategory::Decline);
+        assert_eq!(category.get_default_severity(), ErrorSeverity::Low);
+    }
+
+    #[test]
+    fn test_server_error_maps_to_high_severity() {
+        let error_response = ErrorResponse {
+            status_code: 503,
+            attempt_status: Some(AttemptStatus::Failure),
+            ..ErrorResponse::default()
+        };
+
+        let category = ConnectorErrorCategory::from_error_response(&error_response);
+
+        assert_eq!(category, ConnectorErrorCategory::ServerError);
+        assert_eq!(category.get_default_severity(), ErrorSeverity::High);
+    }
+
+    #[test]
+    fn test_authentication_failure_is_not_a_decline() {
+        for status_code in [401, 403] {
+            let error_response = ErrorResponse {
+                code: "invalid_api_key".to_string(),
+                status_code,
+                attempt_status: Some(AttemptStatus::Failure),
+                ..ErrorResponse::default()
+            };
+
+            let category = ConnectorErrorCategory::from_error_response(&error_response);
+
+            assert_eq!(category, ConnectorErrorCategory::ClientError);
+            assert_eq!(category.get_default_severity(), ErrorSeverity::Medium);
+        }
+    }
+}
@@ -63,6 +63,18 @@ pub trait RouterDataConversion<T, Req: Clone, Resp: Clone> {
     ) -> CustomResult<RouterData<T, Req, Resp>, errors::ConnectorError>
     where
         Self: Sized;
+    /// Record the details of a successful connector response specific to the flow on the
+    /// connector event
+    ///
+    /// # Arguments
+    ///
+    /// * `connector_event` - The connector event of the connector call
+    /// * `response` - The response of the connector call
+    fn set_connector_event_response_details(_connector_event: &mut ConnectorEvent, _response: &Resp)
+    where
+        Self: Sized,
+    {
+    }
 }
 /// Alias for Box<&'static (dyn Connector + Sync)>
 pub type BoxedConnector = Box<&'static (dyn Connector + Sync)>;
@@ -916,6 +928,13 @@ impl ConnectorCommon for ConnectorEnum {
         }
     }
 
+    fn get_error_message_path(&self) -> &'static str {
+        match self {
+            Self::Old(connector) => connector.get_error_message_path(),
+            Self::New(connector) => connector.get_error_message_path(),
+        }
+    }
+
     fn build_error_response(
         &self,
         res: types::Response,
@@ -22,14 +22,18 @@ use hyperswitch_domain_models::{
         },
         RouterDataV2,
     },
+    router_response_types::RefundsResponseData,
 };
 
-use crate::{connector_integration_interface::RouterDataConversion, errors::ConnectorError};
+use crate::{
+    connector_integration_interface::RouterDataConversion, errors::ConnectorError,
+    events::connector_api_logs::ConnectorEvent,
+};
 
 fn get_irrelevant_id_string(id_name: &str, flow_name: &str) -> String {
     format!("irrelevant {id_name} in {flow_name} flow")
 }
-fn get_default_router_data<F, Req, Resp>(
+pub(crate) fn get_default_router_data<F, Req, Resp>(
     tenant_id: id_type::TenantId,
     flow_name: &str,
     request: Req,
@@ -342,10 +346,10 @@ impl<T, Req: Clone, Resp: Clone> RouterDataConversion<T, Req, Resp> for PaymentF
     }
 }
 
-impl<T, Req: Clone, Resp: Clone> RouterDataConversion<T, Req, Resp> for RefundFlowData {
+impl<T, Req: Clone> RouterDataConversion<T, Req, RefundsResponseData> for RefundFlowData {
     fn from_old_router_data(
-        old_router_data: &RouterData<T, Req, Resp>,
-    ) -> CustomResult<RouterDataV2<T, Self, Req, Resp>, ConnectorError>
+        old_router_data: &RouterData<T, Req, RefundsResponseData>,
+    ) -> CustomResult<RouterDataV2<T, Self, Req, RefundsResponseData>, ConnectorError>
     where
         Self: Sized,
     {
@@ -377,8 +381,8 @@ impl<T, Req: Clone, Resp: Clone> RouterDataConversion<T, Req, Resp> for RefundFl
     }
 
     fn to_old_router_data(
-        new_router_data: RouterDataV2<T, Self, Req, Resp>,
-    ) -> CustomResult<RouterData<T, Req, Resp>, ConnectorError>
+        new_router_data: RouterDataV2<T, Self, Req, RefundsResponseData>,
+    ) -> CustomResult<RouterData<T, Req, RefundsResponseData>, ConnectorError>
     where
         Self: Sized,
     {
@@ -414,6 +418,13 @@ impl<T, Req: Clone, Resp: Clone> RouterDataConversion<T, Req, Resp> for RefundFl
         router_data.refund_id = Some(refund_id);
         Ok(router_data)
     }
+
+    fn set_connector_event_response_details(
+        connector_event: &mut ConnectorEvent,
+        response: &RefundsResponseData,
+    ) {
+        connector_event.set_refund_response_details(response);
+    }
 }
 
 impl<T, Req: Clone, Resp: Clone> RouterDataConversion<T, Req, Resp> for DisputesFlowData {
@@ -1,6 +1,7 @@
 use crate::events::connector_api_logs::ConnectorEvent;
 
 pub mod connector_api_logs;
+pub mod connector_call_outcome;
 pub mod routing_api_logs;
 
 /// Event handling interface
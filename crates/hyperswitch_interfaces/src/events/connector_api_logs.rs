@@ -1,6 +1,7 @@
 //! Connector API logs interface
 
 use common_utils::request::Method;
+use hyperswitch_domain_models::router_response_types::RefundsResponseData;
 use router_env::RequestId;
 use serde::Serialize;
 use serde_json::json;
@@ -27,6 +28,10 @@ pub struct ConnectorEvent {
     destination: common_enums::EventDestination,
     /// Whether this call is the real execution or a shadow mirror.
     execution_mode: common_enums::EventExecutionMode,
+    /// Refund reference returned by the connector, populated for refund flows
+    connector_refund_id: Option<String>,
+    /// Refund status mapped from the connector response, populated for refund flows
+    refund_status: Option<common_enums::RefundStatus>,
     #[serde(flatten)]
     connector_event_type: common_utils::events::ConnectorEventsType,
 }
@@ -77,6 +82,8 @@ impl ConnectorEvent {
             status_code,
             destination,
             execution_mode,
+            connector_refund_id: None,
+            refund_status: None,
             connector_event_type,
         }
     }
@@ -105,4 +112,47 @@ impl ConnectorEvent {
     pub fn set_error(&mut self, error: serde_json::Value) {
         self.error = Some(error.to_string());
     }
+
+    /// fn set_refund_response_details
+    pub fn set_refund_response_details(&mut self, response: &RefundsResponseData) {
+        self.connector_refund_id = Some(response.connector_refund_id.clone());
+        self.refund_status = Some(response.refund_status);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn refund_event_is_enriched_with_refund_response() {
+        let mut connector_event = ConnectorEvent::new(
+            common_utils::id_type::TenantId::get_default_global_tenant_id(),
+            "stripe".to_string(),
+            "hyperswitch_domain_models::router_flow_types::refunds::Execute",
+            json!({}),
+            "https://api.stripe.com/v1/refunds".to_string(),
+            Method::Post,
+            "pay_123".to_string(),
+            common_utils::id_type::MerchantId::default(),
+            None,
+            100,
+            Some("ref_123".to_string()),
+            None,
+            None,
+            200,
+            common_enums::EventDestination::Connector,
+            common_enums::EventExecutionMode::Primary,
+        );
+
+        connector_event.set_refund_response_details(&RefundsResponseData {
+            connector_refund_id: "re_123".to_string(),
+            refund_status: common_enums::RefundStatus::Success,
+        });
+
+        let event = serde_json::to_value(&connector_event).expect("serialization failed");
+        assert_eq!(event["connector_refund_id"], json!("re_123"));
+        assert_eq!(event["refund_status"], json!("success"));
+        assert_eq!(event["refund_id"], json!("ref_123"));
+    }
 }
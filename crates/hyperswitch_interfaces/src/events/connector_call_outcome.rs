@@ -0,0 +1,34 @@
+//! Outcome of connector calls, sent to the internal observability webhook
+
+use serde::Serialize;
+
+/// Status of a connector call
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ConnectorCallStatus {
+    /// The connector responded with a success status code
+    Success,
+    /// The connector responded with an error status code
+    ErrorResponse,
+    /// The connector did not respond before the request timed out
+    Timeout,
+    /// The request could not be sent to the connector, or its response could not be received
+    Failure,
+}
+
+/// Compact summary of a connector call
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ConnectorCallOutcome {
+    /// Name of the connector
+    pub connector: String,
+    /// Name of the flow of the call, such as `Authorize`
+    pub flow: String,
+    /// Status of the call
+    pub status: ConnectorCallStatus,
+    /// HTTP status code of the response of the connector, if it responded
+    pub http_status_code: Option<u16>,
+    /// Time taken by the connector to respond, in milliseconds
+    pub latency_ms: u64,
+    /// ID of the request which resulted in the call
+    pub request_id: Option<String>,
+}
@@ -24,6 +24,29 @@ pub trait ForeignFrom<F>: Sized {
     fn foreign_from(from: F) -> Self;
 }
 
+/// Extract the string at `path` from a JSON error response body. Object keys are separated by
+/// `.` and array indices are given in brackets, e.g. `errors[0].detail`. Returns `None` if the
+/// body is not JSON or the path does not resolve to a string.
+pub fn get_error_message_from_json_path(body: &[u8], path: &str) -> Option<String> {
+    let body: serde_json::Value = serde_json::from_slice(body).ok()?;
+
+    path.split('.')
+        .try_fold(&body, |value, segment| {
+            let mut parts = segment.split('[');
+            let key = parts.next().unwrap_or_default();
+            let value = if key.is_empty() {
+                value
+            } else {
+                value.get(key)?
+            };
+            parts.try_fold(value, |value, index| {
+                value.get(index.strip_suffix(']')?.parse::<usize>().ok()?)
+            })
+        })
+        .and_then(serde_json::Value::as_str)
+        .map(ToOwned::to_owned)
+}
+
 /// Data structure to hold comparison data between Hyperswitch and UCS
 #[derive(serde::Serialize, Debug)]
 pub struct ComparisonData {
@@ -193,3 +216,37 @@ pub async fn send_comparison_data(
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn error_message_is_extracted_from_nested_object() {
+        let body = br#"{"error":{"code":"card_declined","message":"Your card was declined."}}"#;
+
+        assert_eq!(
+            get_error_message_from_json_path(body, "error.message"),
+            Some("Your card was declined.".to_string())
+        );
+    }
+
+    #[test]
+    fn error_message_is_extracted_from_array_element() {
+        let body = br#"{"errors":[{"code":"invalid_request","detail":"Amount is invalid"}]}"#;
+
+        assert_eq!(
+            get_error_message_from_json_path(body, "errors[0].detail"),
+            Some("Amount is invalid".to_string())
+        );
+        assert_eq!(
+            get_error_message_from_json_path(body, "errors[1].detail"),
+            None
+        );
+        assert_eq!(
+            get_error_message_from_json_path(body, "error.message"),
+            None
+        );
+        assert_eq!(get_error_message_from_json_path(b"not json", "error"), None);
+    }
+}
@@ -7,6 +7,7 @@ pub mod api_client;
 pub mod authentication;
 /// Configuration related functionalities
 pub mod configs;
+pub mod connector_error_severity;
 /// Connector integration interface module
 pub mod connector_integration_interface;
 /// definition of the new connector integration trait
@@ -1,6 +1,6 @@
 //! Metrics interface
 
-use router_env::{counter_metric, global_meter};
+use router_env::{counter_metric, global_meter, histogram_metric_f64};
 
 global_meter!(GLOBAL_METER, "ROUTER_API");
 
@@ -9,6 +9,9 @@ counter_metric!(UNIMPLEMENTED_FLOW, GLOBAL_METER);
 counter_metric!(CONNECTOR_CALL_COUNT, GLOBAL_METER); // Attributes needed
 
 counter_metric!(RESPONSE_DESERIALIZATION_FAILURE, GLOBAL_METER);
+histogram_metric_f64!(CONNECTOR_RESPONSE_DECODE_TIME, GLOBAL_METER); // Time in seconds
 counter_metric!(CONNECTOR_ERROR_RESPONSE_COUNT, GLOBAL_METER);
+// Labelled with the category and severity of the error
+counter_metric!(CONNECTOR_ERROR_COUNT, GLOBAL_METER);
 // Connector Level Metric
 counter_metric!(REQUEST_BUILD_FAILURE, GLOBAL_METER);
@@ -292,10 +292,10 @@ pub mod core {
         let mut req_builder = client.request(method, &request.url);
 
         // Add headers
-        for (key, value) in &request.headers {
+        for (key, value) in request.get_ordered_headers() {
             let header_value = match value {
-                hyperswitch_masking::Maskable::Masked(secret) => secret.clone().expose(),
-                hyperswitch_masking::Maskable::Normal(normal) => normal.clone(),
+                hyperswitch_masking::Maskable::Masked(secret) => secret.expose(),
+                hyperswitch_masking::Maskable::Normal(normal) => normal,
             };
             req_builder = req_builder.header(key, header_value);
         }
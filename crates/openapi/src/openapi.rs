@@ -82,26 +82,44 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::payments::payments_cancel,
         routes::payments::payments_cancel_post_capture,
         routes::payments::payments_cancel_post_capture_retrieve,
+        routes::payments::payments_order_cancelled,
+        routes::payments::payments_force_close,
         routes::payments::payments_extend_authorization,
         routes::payments::payments_list,
+        routes::payments::profile_payments_list,
+        routes::payments::payments_retrieve_with_gateway_creds,
+        routes::payments::payments_approve,
+        routes::payments::payments_reject,
+        routes::payments::retrieve_extended_card_info,
         routes::payments::payments_incremental_authorization,
         routes::payment_link::payment_link_retrieve,
+        routes::payment_link::payments_link_list,
         routes::payments::payments_external_authentication,
         routes::payments::payments_complete_authorize,
         routes::payments::payments_post_session_tokens,
         routes::payments::payments_update_metadata,
         routes::payments::payments_submit_eligibility_check,
         routes::payments::payments_submit_eligibility,
+        routes::payments::payments_dynamic_tax_calculation,
+        routes::fraud_check::frm_fulfillment,
 
         // Routes for relay
         routes::relay::relay,
         routes::relay::relay_retrieve,
+        routes::relay::unreferenced_refund,
 
         // Routes for refunds
         routes::refunds::refunds_create,
         routes::refunds::refunds_retrieve,
+        routes::refunds::refunds_retrieve_with_body,
         routes::refunds::refunds_update,
         routes::refunds::refunds_list,
+        routes::refunds::refunds_list_profile,
+        routes::refunds::refunds_filter_list,
+        routes::refunds::get_refunds_filters,
+        routes::refunds::get_refunds_filters_profile,
+        routes::refunds::get_refunds_aggregates,
+        routes::refunds::get_refunds_aggregate_profile,
 
         // Routes for Organization
         routes::organization::organization_create,
@@ -114,16 +132,29 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::merchant_account::update_merchant_account,
         routes::merchant_account::delete_merchant_account,
         routes::merchant_account::merchant_account_kv_status,
+        routes::merchant_account::merchant_takeout_create,
+        routes::merchant_account::merchant_takeout_retrieve,
+        routes::merchant_account::merchant_takeout_file_download,
+        routes::merchant_account::customer_contact_hashes_rehash,
+        routes::merchant_account::merchant_quota_update,
+        routes::merchant_account::merchant_quota_delete,
+        routes::merchant_account::merchant_quota_usage,
 
         // Routes for merchant connector account
         routes::merchant_connector_account::connector_create,
         routes::merchant_connector_account::connector_retrieve,
         routes::merchant_connector_account::connector_list,
+        routes::merchant_account::payment_connector_list_profile,
         routes::merchant_connector_account::connector_update,
         routes::merchant_connector_account::connector_delete,
 
         // Routes for connector webhook configuration
         routes::merchant_connector_account::connector_webhook_register,
+        routes::merchant_connector_account::retrieve_connector_webhook,
+
+        // Routes for Apple Pay domain verification
+        routes::merchant_connector_account::apple_pay_merchant_registration,
+        routes::merchant_connector_account::retrieve_apple_pay_verified_domains,
 
         //Routes for gsm
         routes::gsm::create_gsm_rule,
@@ -131,9 +162,15 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::gsm::update_gsm_rule,
         routes::gsm::delete_gsm_rule,
 
+        // Routes for configs
+        routes::configs::unmasked_headers_retrieve,
+        routes::configs::unmasked_headers_update,
+        routes::configs::unmasked_headers_preview,
+
         // Routes for mandates
         routes::mandates::get_mandate,
         routes::mandates::revoke_mandate,
+        routes::mandates::retrieve_mandates_list,
         routes::mandates::customers_mandates_list,
 
         //Routes for customers
@@ -141,6 +178,9 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::customers::customers_retrieve,
         routes::customers::customers_retrieve_by_merchant_reference_id,
         routes::customers::customers_list,
+        routes::customers::customers_list_with_count,
+        routes::customers::ephemeral_key_create,
+        routes::customers::ephemeral_key_delete,
         routes::customers::customers_update,
         routes::customers::customers_delete,
 
@@ -153,6 +193,11 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::payment_method::payment_method_retrieve_api,
         routes::payment_method::payment_method_update_api,
         routes::payment_method::payment_method_delete_api,
+        routes::payment_method::save_payment_method_api,
+        routes::payment_method::initiate_pm_collect_link_flow,
+        routes::payment_method::link_token_create,
+        routes::payment_method::exchange_token,
+        routes::payment_method::list_payment_methods_for_payments_client,
 
         // Routes for Profile
         routes::profile::profile_create,
@@ -160,6 +205,9 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::profile::profile_retrieve,
         routes::profile::profile_update,
         routes::profile::profile_delete,
+        routes::profile::profiles_list_at_profile_level,
+        routes::profile::toggle_connector_agnostic_mit,
+        routes::profile::issue_embedded_token,
 
         // Routes for disputes
         routes::disputes::retrieve_dispute,
@@ -174,6 +222,19 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::disputes::delete_dispute_evidence,
         routes::disputes::get_disputes_aggregate,
         routes::disputes::get_disputes_aggregate_profile,
+        routes::disputes::fetch_disputes,
+
+        // Routes for files
+        routes::files::files_create,
+        routes::files::files_delete,
+        routes::files::files_retrieve,
+
+        // Routes for forex
+        routes::forex::retrieve_forex,
+        routes::forex::convert_forex,
+
+        // Routes for feature matrix
+        routes::feature_matrix::fetch_feature_matrix,
 
         // Routes for routing
         routes::routing::routing_create_config,
@@ -186,6 +247,17 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::routing::routing_retrieve_linked_config,
         routes::routing::routing_retrieve_default_config_for_profiles,
         routes::routing::routing_update_default_config_for_profile,
+        routes::routing::list_routing_configs_for_profile,
+        routes::routing::payouts_routing_create_config,
+        routes::routing::payouts_list_routing_configs,
+        routes::routing::payouts_list_routing_configs_for_profile,
+        routes::routing::payouts_routing_retrieve_linked_config,
+        routes::routing::payouts_routing_retrieve_default_config,
+        routes::routing::payouts_routing_update_default_config,
+        routes::routing::payouts_routing_link_config,
+        routes::routing::payouts_routing_unlink_config,
+        routes::routing::payouts_routing_retrieve_default_config_for_profiles,
+        routes::routing::payouts_routing_update_default_config_for_profile,
         routes::routing::success_based_routing_update_configs,
         routes::routing::toggle_success_based_routing,
         routes::routing::toggle_elimination_routing,
@@ -193,6 +265,9 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::routing::create_elimination_routing,
         routes::routing::contract_based_routing_setup_config,
         routes::routing::contract_based_routing_update_configs,
+        routes::routing::elimination_routing_update_configs,
+        routes::routing::set_dynamic_routing_volume_split,
+        routes::routing::get_dynamic_routing_volume_split,
         routes::routing::call_decide_gateway_open_router,
         routes::routing::call_update_gateway_score_open_router,
         routes::routing::evaluate_routing_rule,
@@ -213,9 +288,14 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::payouts::payouts_cancel,
         routes::payouts::payouts_fulfill,
         routes::payouts::payouts_list,
+        routes::payouts::payouts_list_profile,
         routes::payouts::payouts_confirm,
         routes::payouts::payouts_list_filters,
+        routes::payouts::payouts_list_filters_profile,
+        routes::payouts::get_payout_filters,
+        routes::payouts::get_payout_filters_profile,
         routes::payouts::payouts_list_by_filter,
+        routes::payouts::payouts_list_by_filter_profile,
 
         // Routes for api keys
         routes::api_keys::api_key_create,
@@ -258,6 +338,7 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::subscriptions::create_subscription,
         routes::subscriptions::confirm_subscription,
         routes::subscriptions::get_subscription,
+        routes::subscriptions::list_subscriptions,
         routes::subscriptions::update_subscription,
         routes::subscriptions::get_subscription_items,
         routes::subscriptions::get_estimate,
@@ -337,6 +418,7 @@ Never share your secret api keys. Keep them guarded and secure.
         common_types::connector_webhook_configuration::WebhookConfigType,
         common_types::payments::PaymentIntentStateMetadata,
         common_types::payments::PostCaptureVoidResponse,
+        common_types::payments::ForceCloseDetails,
         common_types::payments::ExternalSurchargeDetails,
         api_models::enums::PaymentChannel,
         api_models::three_ds_decision_rule::ThreeDsDecisionRuleExecuteRequest,
@@ -374,6 +456,7 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::admin::MerchantConnectorListResponse,
         api_models::admin::AuthenticationConnectorDetails,
         api_models::admin::ExtendedCardInfoConfig,
+        api_models::admin::ConnectorAgnosticMitChoice,
         api_models::admin::BusinessGenericLinkConfig,
         api_models::admin::BusinessCollectLinkConfig,
         api_models::admin::BusinessPayoutLinkConfig,
@@ -433,6 +516,7 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::poll::PollResponse,
         api_models::poll::PollStatus,
         api_models::customers::CustomerResponse,
+        api_models::customers::CustomerListResponse,
         api_models::admin::AcceptedCountries,
         api_models::admin::AcceptedCurrencies,
         api_models::enums::AdyenSplitType,
@@ -461,6 +545,7 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::enums::PaymentMethodIssuerCode,
         api_models::enums::TaxStatus,
         api_models::enums::MandateStatus,
+        api_models::enums::CustomerAcceptanceSource,
         api_models::enums::PaymentExperience,
         api_models::enums::BankNames,
         api_models::enums::BankType,
@@ -544,6 +629,10 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::card_issuer::CardIssuerDeleteResponse,
         api_models::card_issuer::CardIssuerListQuery,
         api_models::card_issuer::CardIssuerListResponse,
+        api_models::configs::UnmaskedHeadersUpdate,
+        api_models::configs::UnmaskedHeadersResponse,
+        api_models::configs::UnmaskedHeadersPreviewRequest,
+        api_models::configs::UnmaskedHeadersPreviewResponse,
         api_models::gsm::GsmCreateRequest,
         api_models::gsm::GsmRetrieveRequest,
         api_models::gsm::GsmUpdateRequest,
@@ -651,6 +740,7 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payments::PaymentsCreateResponseOpenApi,
         api_models::errors::types::GenericErrorResponseOpenApi,
         api_models::payments::PaymentRetrieveBody,
+        api_models::payments::PaymentRetrieveBodyWithCredentials,
         api_models::payments::PaymentsRetrieveRequest,
         api_models::payments::PaymentsCaptureRequest,
         api_models::payments::PaymentsSessionRequest,
@@ -729,6 +819,9 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payments::ApplepayPaymentMethod,
         api_models::payments::PaymentsCancelRequest,
         api_models::payments::PaymentsCancelPostCaptureRequest,
+        api_models::payments::PaymentsOrderCancelledRequest,
+        api_models::payments::PaymentsForceCloseRequest,
+        api_models::payments::PaymentsForceCloseResponse,
         api_models::payments::PaymentListConstraints,
         api_models::payments::PaymentListResponse,
         api_models::payments::CashappQr,
@@ -743,6 +836,7 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payments::MobilePaymentConsent,
         api_models::payments::IframeData,
         api_models::payments::DDCData,
+        api_models::payments::ConnectorSdkNextActionData,
         api_models::payments::ReceiverDetails,
         api_models::payments::AchTransfer,
         api_models::payments::MultibancoTransferInstructions,
@@ -774,6 +868,8 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payments::PaymentsIncrementalAuthorizationRequest,
         api_models::payments::IncrementalAuthorizationResponse,
         api_models::payments::PaymentsCompleteAuthorizeRequest,
+        api_models::payments::PaymentsApproveRequest,
+        api_models::payments::PaymentsRejectRequest,
         api_models::payments::PaymentsExternalAuthenticationRequest,
         api_models::payments::PaymentsExternalAuthenticationResponse,
         api_models::payments::SdkInformation,
@@ -816,6 +912,9 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payments::PaymentsEligibilityResponse,
         api_models::payment_methods::PaymentMethodCollectLinkRequest,
         api_models::payment_methods::PaymentMethodCollectLinkResponse,
+        api_models::pm_auth::LinkTokenCreateRequest,
+        api_models::pm_auth::LinkTokenCreateResponse,
+        api_models::pm_auth::ExchangeTokenCreateRequest,
         api_models::payment_methods::CardType,
         api_models::payment_methods::CardNetworkTokenizeRequest,
         api_models::payment_methods::CardNetworkTokenizeResponse,
@@ -824,6 +923,10 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payment_methods::TokenizePaymentMethodRequest,
         api_models::refunds::RefundListRequest,
         api_models::refunds::RefundListResponse,
+        api_models::refunds::RefundsRetrieveRequest,
+        api_models::refunds::RefundListMetaData,
+        api_models::refunds::RefundListFilters,
+        api_models::refunds::RefundAggregateResponse,
         api_models::relay::RelayRequest,
         api_models::relay::RelayResponse,
         api_models::enums::RelayType,
@@ -835,6 +938,8 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::relay::RelayUnreferencedRefundData,
         api_models::unreferenced_refund::RecipientPaymentMethodData,
         api_models::unreferenced_refund::RecipientCardData,
+        api_models::unreferenced_refund::UnreferencedRefundRequest,
+        api_models::unreferenced_refund::UnreferencedRefundResponse,
         api_models::enums::RelayStatus,
         api_models::relay::RelayError,
         api_models::payments::AmountFilter,
@@ -846,7 +951,13 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::mandates::CardWithLimitedData,
         api_models::mandates::NetworkTransactionIdAndNetworkTokenDetails,
         api_models::mandates::ProcessorPaymentToken,
+        api_models::currency::CurrencyConversionResponse,
+        api_models::ephemeral_key::EphemeralKeyCreateRequest,
         api_models::ephemeral_key::EphemeralKeyCreateResponse,
+        api_models::verifications::ApplepayMerchantVerificationRequest,
+        api_models::verifications::ApplepayMerchantResponse,
+        api_models::verifications::ApplepayVerifiedDomainsResponse,
+        api_models::user::IssueEmbeddedTokenResponse,
         api_models::payments::CustomerDetails,
         api_models::payments::GiftCardData,
         api_models::payments::GiftCardDetails,
@@ -884,6 +995,7 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payouts::PayoutCreateResponse,
         api_models::payouts::PayoutListConstraints,
         api_models::payouts::PayoutListFilters,
+        api_models::payouts::PayoutListFiltersV2,
         api_models::payouts::PayoutListFilterConstraints,
         api_models::payouts::PayoutListResponse,
         api_models::payouts::PayoutRetrieveBody,
@@ -904,6 +1016,20 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payments::FrmMessage,
         api_models::webhooks::OutgoingWebhook,
         api_models::webhooks::OutgoingWebhookContent,
+        api_models::merchant_quota::QuotaThresholdReachedResponse,
+        api_models::merchant_quota::MerchantQuotaUsageResponse,
+        api_models::merchant_quota::MerchantQuota,
+        api_models::merchant_quota::QuotaPeriod,
+        api_models::merchant_quota::VolumeQuota,
+        api_models::merchant_quota::PaymentCountUsage,
+        api_models::merchant_quota::VolumeUsage,
+        api_models::takeout::MerchantTakeoutRequest,
+        api_models::takeout::MerchantTakeoutResponse,
+        api_models::takeout::TakeoutRedactionOptions,
+        api_models::takeout::TakeoutPiiHandling,
+        api_models::takeout::TakeoutResource,
+        api_models::takeout::TakeoutJobStatus,
+        api_models::takeout::TakeoutFile,
         api_models::enums::EventClass,
         api_models::enums::EventType,
         api_models::enums::EventRecipient,
@@ -82,6 +82,8 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::merchant_connector_account::connector_retrieve,
         routes::merchant_connector_account::connector_update,
         routes::merchant_connector_account::connector_delete,
+        routes::merchant_connector_account::apple_pay_merchant_registration,
+        routes::merchant_connector_account::retrieve_apple_pay_verified_domains,
 
         // Routes for merchant account
         routes::merchant_account::merchant_account_create,
@@ -125,6 +127,9 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::customers::customers_delete_v1,
         routes::customers::customers_list,
         routes::customers::customers_list_v1,
+        routes::customers::customers_list_with_count,
+        routes::customers::client_secret_create,
+        routes::customers::client_secret_delete,
 
         //Routes for payments
         routes::payments::payments_create_intent,
@@ -137,6 +142,16 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::payments::list_payment_methods,
         routes::payments::payments_list,
         routes::payments::payments_apply_pm_data,
+        routes::payments::payment_get_intent_using_merchant_reference_id,
+        routes::payments::payments_status_with_gateway_creds,
+        routes::payments::payments_capture,
+        routes::payments::payments_cancel,
+        routes::payments::list_payment_attempts,
+        routes::payments::proxy_confirm_intent,
+        routes::payments::confirm_intent_with_external_vault_proxy,
+        routes::payments::recovery_payments_create,
+        routes::payments::revenue_recovery_invoices_list,
+        routes::payments::revenue_recovery_get_intent,
 
         //Routes for payment methods
         routes::payment_method::create_payment_method_api,
@@ -155,14 +170,21 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::payment_method::payment_method_get_token_details_api,
         routes::payment_method::payment_method_get_token_details_api_v1,
         routes::payment_method::default_payment_method_set_api,
+        routes::payment_method::default_payment_method_set_api_v1,
+        routes::payment_method::get_pm_nt_eligibility_api,
+        routes::payment_method::payment_method_retrieve_olap_api,
+        routes::payment_method::get_payment_method_token_data,
+        routes::payment_method::get_total_payment_method_count,
 
         //Routes for payment method session
         routes::payment_method::payment_method_session_create,
         routes::payment_method::payment_method_session_create_v1,
         routes::payment_method::payment_method_session_retrieve,
         routes::payment_method::payment_method_session_retrieve_v1,
+        routes::payment_method::payment_method_session_update,
         routes::payment_method::payment_method_session_list_payment_methods,
         routes::payment_method::payment_method_session_list_payment_methods_v1,
+        routes::payment_method::payment_method_session_list_enabled_payment_methods,
         routes::payment_method::payment_method_session_update_saved_payment_method,
         routes::payment_method::payment_method_session_update_saved_payment_method_v1,
         routes::payment_method::payment_method_session_delete_saved_payment_method,
@@ -174,8 +196,22 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::refunds::refunds_create,
         routes::refunds::refunds_metadata_update,
         routes::refunds::refunds_retrieve,
+        routes::refunds::refunds_retrieve_with_gateway_creds,
         routes::refunds::refunds_list,
 
+        // Routes for relay
+        routes::relay::relay,
+        routes::relay::relay_retrieve,
+        routes::relay::unreferenced_refund,
+
+        // Routes for 3DS Decision Rule
+        routes::three_ds_decision_rule::three_ds_decision_rule_execute,
+
+        // Routes for configs
+        routes::configs::unmasked_headers_retrieve,
+        routes::configs::unmasked_headers_update,
+        routes::configs::unmasked_headers_preview,
+
         // Routes for Revenue Recovery flow under Process Tracker
         routes::revenue_recovery::revenue_recovery_pt_retrieve_api,
 
@@ -250,6 +286,7 @@ Never share your secret api keys. Keep them guarded and secure.
         common_types::payments::StripeChargeResponseData,
         common_types::payments::PaymentIntentStateMetadata,
         common_types::payments::PostCaptureVoidResponse,
+        common_types::payments::ForceCloseDetails,
         common_types::payments::ExternalSurchargeDetails,
         common_types::three_ds_decision_rule_engine::ThreeDSDecisionRule,
         common_types::three_ds_decision_rule_engine::ThreeDSDecision,
@@ -269,6 +306,7 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::refunds::RefundResponse,
         api_models::refunds::RefundStatus,
         api_models::refunds::RefundMetadataUpdateRequest,
+        api_models::refunds::RefundsRetrievePayload,
         api_models::organization::OrganizationCreateRequest,
         api_models::organization::OrganizationUpdateRequest,
         api_models::organization::OrganizationResponse,
@@ -278,6 +316,9 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::admin::MerchantConnectorDeleteResponse,
         api_models::admin::MerchantConnectorResponse,
         api_models::admin::MerchantConnectorListResponse,
+        api_models::verifications::ApplepayMerchantVerificationRequest,
+        api_models::verifications::ApplepayMerchantResponse,
+        api_models::verifications::ApplepayVerifiedDomainsResponse,
         api_models::admin::AuthenticationConnectorDetails,
         api_models::admin::ExternalVaultConnectorDetails,
         api_models::admin::VaultTokenField,
@@ -292,6 +333,7 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::customers::CustomerUpdateRequest,
         api_models::customers::CustomerDeleteResponse,
         api_models::customers::CustomerDocumentDetails,
+        api_models::customers::CustomerListResponse,
         api_models::ephemeral_key::ResourceId,
         api_models::payment_methods::PaymentMethodCreate,
         api_models::payment_methods::PaymentMethodIntentCreate,
@@ -370,6 +412,7 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::enums::PaymentMethod,
         api_models::enums::PaymentMethodIssuerCode,
         api_models::enums::MandateStatus,
+        api_models::enums::CustomerAcceptanceSource,
         api_models::enums::MerchantProductType,
         api_models::enums::PaymentExperience,
         api_models::enums::BankNames,
@@ -438,6 +481,10 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::admin::TransactionDetailsUiConfiguration,
         api_models::disputes::DisputeResponse,
         api_models::disputes::DisputeResponsePaymentsRetrieve,
+        api_models::configs::UnmaskedHeadersUpdate,
+        api_models::configs::UnmaskedHeadersResponse,
+        api_models::configs::UnmaskedHeadersPreviewRequest,
+        api_models::configs::UnmaskedHeadersPreviewResponse,
         api_models::gsm::GsmCreateRequest,
         api_models::gsm::GsmRetrieveRequest,
         api_models::gsm::GsmUpdateRequest,
@@ -549,6 +596,7 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payments::PaymentsRetrieveRequest,
         api_models::payments::PaymentsStatusRequest,
         api_models::payments::PaymentsCaptureRequest,
+        api_models::payments::PaymentsCaptureResponse,
         api_models::payments::PaymentsSessionRequest,
         api_models::payments::PaymentsSessionResponse,
         api_models::payments::PaymentsCreateIntentRequest,
@@ -616,6 +664,7 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payments::SamsungPayCardBrand,
         api_models::payments::SamsungPayTokenData,
         api_models::payments::PaymentsCancelRequest,
+        api_models::payments::PaymentsCancelResponse,
         api_models::payments::PaymentListResponse,
         api_models::payments::CashappQr,
         api_models::payments::BankTransferData,
@@ -629,6 +678,7 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payments::MobilePaymentConsent,
         api_models::payments::IframeData,
         api_models::payments::DDCData,
+        api_models::payments::ConnectorSdkNextActionData,
         api_models::payments::ReceiverDetails,
         api_models::payments::AchTransfer,
         api_models::payments::MultibancoTransferInstructions,
@@ -653,7 +703,22 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payments::RequestSurchargeDetails,
         api_models::payments::PaymentRevenueRecoveryMetadata,
         api_models::payments::BillingConnectorPaymentDetails,
+        api_models::payments::RecurringPaymentRetryResponse,
         api_models::payments::ApplyPaymentMethodDataRequest,
+        api_models::payments::ProxyPaymentsRequest,
+        api_models::payments::ExternalVaultProxyPaymentsRequest,
+        api_models::payments::ProxyPaymentMethodDataRequest,
+        api_models::payments::ProxyPaymentMethodData,
+        api_models::payments::VaultToken,
+        api_models::payments::RecoveryPaymentsCreate,
+        api_models::payments::RecoveryPaymentsResponse,
+        api_models::payments::CustomRecoveryPaymentMethodData,
+        api_models::payments::AdditionalCardInfo,
+        api_models::payments::RecoveryPaymentListResponse,
+        api_models::payments::RecoveryPaymentsListResponseItem,
+        api_models::payments::RevenueRecoveryGetIntentResponse,
+        api_models::enums::RecoveryStatus,
+        common_types::payments::RecoveryAction,
         api_models::payments::CheckAndApplyPaymentMethodDataResponse,
         api_models::payments::ApplyPaymentMethodDataSurchargeResponseItem,
         api_models::enums::PaymentConnectorTransmission,
@@ -715,6 +780,10 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payment_methods::NetworkTokenDetailsPaymentMethod,
         api_models::payment_methods::NetworkTokenDetailsResponse,
         api_models::payment_methods::TokenDataResponse,
+        api_models::payment_methods::GetTokenDataRequest,
+        api_models::payment_methods::GetNetworkTokenEiligibilityResponse,
+        api_models::payment_methods::PaymentMethodDetailsResponse,
+        api_models::payment_methods::TotalPaymentMethodCountResponse,
         api_models::payment_methods::TokenDetailsResponse,
         api_models::payment_methods::TokenizeCardRequest,
         api_models::payment_methods::TokenizeDataRequest,
@@ -733,6 +802,7 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::mandates::RecurringDetails,
         api_models::mandates::ProcessorPaymentToken,
         api_models::ephemeral_key::ClientSecretResponse,
+        api_models::ephemeral_key::ClientSecretCreateRequest,
         api_models::payments::CustomerDetails,
         api_models::payments::GiftCardData,
         api_models::payments::BalanceCheckPaymentMethodData,
@@ -978,6 +1048,33 @@ Never share your secret api keys. Keep them guarded and secure.
         common_types::payments::SdkDisplayMode,
         common_types::payments::TokenSource,
         common_types::payments::NetworkTransactionIdAndDecryptedWalletTokenDetails,
+        api_models::relay::RelayRequest,
+        api_models::relay::RelayResponse,
+        api_models::enums::RelayType,
+        api_models::relay::RelayData,
+        api_models::relay::RelayRefundRequestData,
+        api_models::relay::RelayCaptureRequestData,
+        api_models::relay::RelayIncrementalAuthorizationRequestData,
+        api_models::relay::RelayVoidRequestData,
+        api_models::relay::RelayUnreferencedRefundData,
+        api_models::unreferenced_refund::RecipientPaymentMethodData,
+        api_models::unreferenced_refund::RecipientCardData,
+        api_models::unreferenced_refund::UnreferencedRefundRequest,
+        api_models::unreferenced_refund::UnreferencedRefundResponse,
+        api_models::enums::RelayStatus,
+        api_models::relay::RelayError,
+        api_models::three_ds_decision_rule::ThreeDsDecisionRuleExecuteRequest,
+        api_models::three_ds_decision_rule::ThreeDsDecisionRuleExecuteResponse,
+        api_models::three_ds_decision_rule::PaymentData,
+        api_models::three_ds_decision_rule::PaymentMethodMetaData,
+        api_models::three_ds_decision_rule::CustomerDeviceData,
+        api_models::three_ds_decision_rule::IssuerData,
+        api_models::three_ds_decision_rule::AcquirerData,
+        api_models::enums::Country,
+        api_models::enums::CardDiscovery,
+        euclid::frontend::dir::enums::CustomerDevicePlatform,
+        euclid::frontend::dir::enums::CustomerDeviceType,
+        euclid::frontend::dir::enums::CustomerDeviceDisplaySize,
     )),
     modifiers(&SecurityAddon)
 )]
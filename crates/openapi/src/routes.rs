@@ -4,8 +4,13 @@ pub mod api_keys;
 pub mod authentication;
 pub mod blocklist;
 pub mod card_issuer;
+pub mod configs;
 pub mod customers;
 pub mod disputes;
+pub mod feature_matrix;
+pub mod files;
+pub mod forex;
+pub mod fraud_check;
 pub mod gsm;
 pub mod mandates;
 pub mod merchant_account;
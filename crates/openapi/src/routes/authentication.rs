@@ -58,7 +58,11 @@ pub async fn authentication_authenticate() {}
 ///
 #[utoipa::path(
     post,
-    path = "/authentication/{authentication_id}/redirect",
+    path = "/authentication/{merchant_id}/{authentication_id}/redirect",
+    params(
+        ("merchant_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("authentication_id" = String, Path, description = "The identifier for the authentication")
+    ),
     request_body = AuthenticationSyncPostUpdateRequest,
     responses(
         (status = 200, description = "Authentication redirect"),
@@ -76,7 +80,11 @@ pub async fn authentication_redirect() {}
 ///
 #[utoipa::path(
     post,
-    path = "/authentication/{authentication_id}/sync",
+    path = "/authentication/{merchant_id}/{authentication_id}/sync",
+    params(
+        ("merchant_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("authentication_id" = String, Path, description = "The identifier for the authentication")
+    ),
     request_body = AuthenticationSyncRequest,
     responses(
         (status = 200, description = "Authentication sync", body = AuthenticationSyncResponse),
@@ -0,0 +1,103 @@
+/// Configs - Retrieve Unmasked Headers
+///
+/// Retrieves the headers of incoming requests which are logged in clear text, and whether they were updated at runtime.
+#[cfg(feature = "v1")]
+#[utoipa::path(
+    get,
+    path = "/configs/unmasked_headers",
+    responses(
+        (status = 200, description = "Unmasked headers retrieved", body = UnmaskedHeadersResponse),
+    ),
+    tag = "Configs",
+    operation_id = "Retrieve Unmasked Headers",
+    security(("admin_api_key" = []))
+)]
+pub async fn unmasked_headers_retrieve() {}
+
+/// Configs - Update Unmasked Headers
+///
+/// Replaces the headers of incoming requests which are logged in clear text. The `unmasked_headers` config is restored if no keys are provided. Credential headers such as `authorization` and `api-key` are always masked, and cannot be unmasked.
+#[cfg(feature = "v1")]
+#[utoipa::path(
+    post,
+    path = "/configs/unmasked_headers",
+    request_body = UnmaskedHeadersUpdate,
+    responses(
+        (status = 200, description = "Unmasked headers updated", body = UnmaskedHeadersResponse),
+        (status = 400, description = "Credential headers cannot be unmasked"),
+    ),
+    tag = "Configs",
+    operation_id = "Update Unmasked Headers",
+    security(("admin_api_key" = []))
+)]
+pub async fn unmasked_headers_update() {}
+
+/// Configs - Preview Unmasked Headers
+///
+/// Renders the given headers the same way as they would be logged for an incoming request.
+#[cfg(feature = "v1")]
+#[utoipa::path(
+    post,
+    path = "/configs/unmasked_headers/preview",
+    request_body = UnmaskedHeadersPreviewRequest,
+    responses(
+        (status = 200, description = "Headers rendered as they would be logged", body = UnmaskedHeadersPreviewResponse),
+        (status = 400, description = "Invalid header name or value"),
+    ),
+    tag = "Configs",
+    operation_id = "Preview Unmasked Headers",
+    security(("admin_api_key" = []))
+)]
+pub async fn unmasked_headers_preview() {}
+
+/// Configs - Retrieve Unmasked Headers
+///
+/// Retrieves the headers of incoming requests which are logged in clear text, and whether they were updated at runtime.
+#[cfg(feature = "v2")]
+#[utoipa::path(
+    get,
+    path = "/v2/configs/unmasked_headers",
+    responses(
+        (status = 200, description = "Unmasked headers retrieved", body = UnmaskedHeadersResponse),
+    ),
+    tag = "Configs",
+    operation_id = "Retrieve Unmasked Headers",
+    security(("admin_api_key" = []))
+)]
+pub async fn unmasked_headers_retrieve() {}
+
+/// Configs - Update Unmasked Headers
+///
+/// Replaces the headers of incoming requests which are logged in clear text. The `unmasked_headers` config is restored if no keys are provided. Credential headers such as `authorization` and `api-key` are always masked, and cannot be unmasked.
+#[cfg(feature = "v2")]
+#[utoipa::path(
+    post,
+    path = "/v2/configs/unmasked_headers",
+    request_body = UnmaskedHeadersUpdate,
+    responses(
+        (status = 200, description = "Unmasked headers updated", body = UnmaskedHeadersResponse),
+        (status = 400, description = "Credential headers cannot be unmasked"),
+    ),
+    tag = "Configs",
+    operation_id = "Update Unmasked Headers",
+    security(("admin_api_key" = []))
+)]
+pub async fn unmasked_headers_update() {}
+
+/// Configs - Preview Unmasked Headers
+///
+/// Renders the given headers the same way as they would be logged for an incoming request.
+#[cfg(feature = "v2")]
+#[utoipa::path(
+    post,
+    path = "/v2/configs/unmasked_headers/preview",
+    request_body = UnmaskedHeadersPreviewRequest,
+    responses(
+        (status = 200, description = "Headers rendered as they would be logged", body = UnmaskedHeadersPreviewResponse),
+        (status = 400, description = "Invalid header name or value"),
+    ),
+    tag = "Configs",
+    operation_id = "Preview Unmasked Headers",
+    security(("admin_api_key" = []))
+)]
+pub async fn unmasked_headers_preview() {}
@@ -108,6 +108,133 @@ pub async fn customers_delete() {}
 #[cfg(feature = "v1")]
 pub async fn customers_list() {}
 
+/// Customers - List With Count
+///
+/// Lists the customers for a particular merchant id, along with the total count of customers matching the constraints.
+#[utoipa::path(
+    get,
+    path = "/customers/list_with_count",
+    params (
+        ("offset" = Option<u32>, Query, description = "Offset for pagination"),
+        ("limit" = Option<u16>, Query, description = "Limit for pagination"),
+        ("customer_id" = Option<String>, Query, description = "The unique identifier for a customer"),
+        ("start_time" = Option<PrimitiveDateTime>, Query, description = "The start of the time range in which the customers were created"),
+        ("end_time" = Option<PrimitiveDateTime>, Query, description = "The end of the time range in which the customers were created"),
+    ),
+    responses(
+        (status = 200, description = "Customers retrieved", body = CustomerListResponse),
+        (status = 400, description = "Invalid Data"),
+    ),
+    tag = "Customers",
+    operation_id = "List all Customers for a Merchant with Count",
+    security(("api_key" = []))
+)]
+#[cfg(feature = "v1")]
+pub async fn customers_list_with_count() {}
+
+/// Customers - List With Count
+///
+/// Lists the customers for a particular merchant id, along with the total count of customers matching the constraints.
+#[utoipa::path(
+    get,
+    path = "/v2/customers/list_with_count",
+    params (
+        ("offset" = Option<u32>, Query, description = "Offset for pagination"),
+        ("limit" = Option<u16>, Query, description = "Limit for pagination"),
+        ("customer_id" = Option<String>, Query, description = "The unique identifier for a customer"),
+        ("start_time" = Option<PrimitiveDateTime>, Query, description = "The start of the time range in which the customers were created"),
+        ("end_time" = Option<PrimitiveDateTime>, Query, description = "The end of the time range in which the customers were created"),
+        (
+            "X-Profile-Id" = String, Header,
+            description = "Profile ID associated to the customer",
+            example = "pro_abcdefghijklmnop"
+        )
+    ),
+    responses(
+        (status = 200, description = "Customers retrieved", body = CustomerListResponse),
+        (status = 400, description = "Invalid Data"),
+    ),
+    tag = "Customers",
+    operation_id = "List all Customers for a Merchant with Count",
+    security(("api_key" = []))
+)]
+#[cfg(feature = "v2")]
+pub async fn customers_list_with_count() {}
+
+/// Customers - Create Ephemeral Key
+///
+/// Creates an ephemeral key for the customer, which can be used to manage the payment methods of the customer from the client.
+#[utoipa::path(
+    post,
+    path = "/ephemeral_keys",
+    request_body = EphemeralKeyCreateRequest,
+    responses(
+        (status = 200, description = "Ephemeral key created"),
+        (status = 400, description = "Invalid Data"),
+    ),
+    tag = "Customers",
+    operation_id = "Create an Ephemeral Key for a Customer",
+    security(("api_key" = []))
+)]
+#[cfg(feature = "v1")]
+pub async fn ephemeral_key_create() {}
+
+/// Customers - Delete Ephemeral Key
+///
+/// Deletes an ephemeral key of a customer.
+#[utoipa::path(
+    delete,
+    path = "/ephemeral_keys/{id}",
+    params (("id" = String, Path, description = "The identifier of the ephemeral key")),
+    responses(
+        (status = 200, description = "Ephemeral key deleted"),
+        (status = 404, description = "Ephemeral key not found")
+    ),
+    tag = "Customers",
+    operation_id = "Delete an Ephemeral Key of a Customer",
+    security(("api_key" = []))
+)]
+#[cfg(feature = "v1")]
+pub async fn ephemeral_key_delete() {}
+
+/// Customers - Create Client Secret
+///
+/// Creates a client secret for the customer, which can be used to manage the payment methods of the customer from the client.
+#[utoipa::path(
+    post,
+    path = "/v2/client-secret",
+    request_body = ClientSecretCreateRequest,
+    responses(
+        (status = 200, description = "Client secret created", body = ClientSecretResponse),
+        (status = 400, description = "Invalid Data"),
+    ),
+    tag = "Customers",
+    operation_id = "Create a Client Secret for a Customer",
+    security(("api_key" = []))
+)]
+#[cfg(feature = "v2")]
+pub async fn client_secret_create() {}
+
+/// Customers - Delete Client Secret
+///
+/// Deletes a client secret of a customer.
+#[utoipa::path(
+    delete,
+    path = "/v2/client-secret/{id}",
+    params (
+        ("id" = String, Path, description = "The unique identifier for the Client Secret"),
+    ),
+    responses(
+        (status = 200, description = "Client secret deleted", body = ClientSecretResponse),
+        (status = 404, description = "Client secret not found")
+    ),
+    tag = "Customers",
+    operation_id = "Delete a Client Secret of a Customer",
+    security(("api_key" = []))
+)]
+#[cfg(feature = "v2")]
+pub async fn client_secret_delete() {}
+
 /// Customers - Create
 ///
 /// Creates a customer object and stores the customer details to be reused for future payments.
@@ -220,3 +220,24 @@ pub async fn get_disputes_filters() {}
     security(("api_key" = []), ("jwt_key" = []))
 )]
 pub async fn get_disputes_filters_profile() {}
+
+/// Disputes - Fetch Disputes from Connector
+/// Fetches the disputes raised within the given time range from the connector, and stores them
+#[utoipa::path(
+    get,
+    path = "/disputes/{merchant_connector_id}/fetch",
+    params(
+        ("merchant_connector_id" = String, Path, description = "The unique identifier for the Merchant Connector Account"),
+        ("fetch_from" = String, Query, description = "The start of the time range in which the disputes were raised"),
+        ("fetch_till" = String, Query, description = "The end of the time range in which the disputes were raised"),
+    ),
+    responses(
+        (status = 200, description = "The disputes were fetched from the connector"),
+        (status = 400, description = "Invalid Data"),
+        (status = 401, description = "Unauthorized request")
+    ),
+    tag = "Disputes",
+    operation_id = "Fetch Disputes from a Connector",
+    security(("api_key" = []))
+)]
+pub async fn fetch_disputes() {}
@@ -0,0 +1,15 @@
+/// Feature Matrix - List
+///
+/// Lists the payment methods, features and regions supported by the connectors. All the connectors are listed when the request body is not passed.
+#[utoipa::path(
+    get,
+    path = "/feature_matrix",
+    request_body(content = Option<FeatureMatrixRequest>, description = "The connectors to list the supported features of"),
+    responses(
+        (status = 200, description = "Features supported by the connectors", body = FeatureMatrixListResponse)
+    ),
+    tag = "Feature Matrix",
+    operation_id = "List the Features Supported by Connectors",
+    security()
+)]
+pub async fn fetch_feature_matrix() {}
@@ -0,0 +1,60 @@
+/// Files - Create
+///
+/// To create a file
+#[utoipa::path(
+    post,
+    path = "/files",
+    request_body(
+        content = String,
+        content_type = "multipart/form-data",
+        description = "A multipart/form-data request with a `file` field containing the file, a `purpose` field \
+            and an optional `dispute_id` field.",
+    ),
+    responses(
+        (status = 200, description = "File created", body = CreateFileResponse),
+        (status = 400, description = "Bad Request")
+    ),
+    tag = "Files",
+    operation_id = "Create a File",
+    security(("api_key" = []))
+)]
+pub async fn files_create() {}
+
+/// Files - Delete
+///
+/// To delete a file
+#[utoipa::path(
+    delete,
+    path = "/files/{file_id}",
+    params(
+        ("file_id" = String, Path, description = "The identifier for file")
+    ),
+    responses(
+        (status = 200, description = "File deleted"),
+        (status = 404, description = "File not found")
+    ),
+    tag = "Files",
+    operation_id = "Delete a File",
+    security(("api_key" = []))
+)]
+pub async fn files_delete() {}
+
+/// Files - Retrieve
+///
+/// To retrieve a file
+#[utoipa::path(
+    get,
+    path = "/files/{file_id}",
+    params(
+        ("file_id" = String, Path, description = "The identifier for file"),
+        ("dispute_id" = Option<String>, Query, description = "The identifier for the dispute the file belongs to")
+    ),
+    responses(
+        (status = 200, description = "File body"),
+        (status = 400, description = "Bad Request")
+    ),
+    tag = "Files",
+    operation_id = "Retrieve a File",
+    security(("api_key" = []))
+)]
+pub async fn files_retrieve() {}
@@ -0,0 +1,36 @@
+/// Forex - Retrieve Rates
+///
+/// Retrieves the latest foreign exchange rates, relative to the base currency.
+#[utoipa::path(
+    get,
+    path = "/forex/rates",
+    responses(
+        (status = 200, description = "Forex rates retrieved"),
+        (status = 404, description = "Forex rates are not available")
+    ),
+    tag = "Forex",
+    operation_id = "Retrieve Forex Rates",
+    security(("api_key" = []))
+)]
+pub async fn retrieve_forex() {}
+
+/// Forex - Convert Amount
+///
+/// Converts an amount in the lowest denomination of a currency to another currency, using the latest foreign exchange rates.
+#[utoipa::path(
+    get,
+    path = "/forex/convert_from_minor",
+    params(
+        ("amount" = i64, Query, description = "The amount to convert, in the lowest denomination of `from_currency`"),
+        ("to_currency" = String, Query, description = "The currency to convert the amount to"),
+        ("from_currency" = String, Query, description = "The currency of the amount")
+    ),
+    responses(
+        (status = 200, description = "Amount converted", body = CurrencyConversionResponse),
+        (status = 400, description = "Invalid Data")
+    ),
+    tag = "Forex",
+    operation_id = "Convert an Amount to Another Currency",
+    security(("api_key" = []))
+)]
+pub async fn convert_forex() {}
@@ -0,0 +1,22 @@
+/// Fraud Check - Fulfillment
+///
+/// Sends the fulfillment details of the order of a payment to the fraud risk management connector of the payment.
+#[cfg(feature = "v1")]
+#[utoipa::path(
+    post,
+    path = "/webhooks/frm_fulfillment",
+    request_body(
+        content = Object,
+        description = "The fulfillment of the order, with the `payment_id` and `order_id` of the payment, the \
+            `fulfillments` with the shipped products and their destination, and optionally the \
+            `fulfillment_status` and the tracking details of the shipment.",
+    ),
+    responses(
+        (status = 200, description = "Fulfillment sent to the fraud risk management connector"),
+        (status = 400, description = "Invalid data")
+    ),
+    tag = "Payments",
+    operation_id = "Send the Fulfillment of a Payment",
+    security(("api_key" = []))
+)]
+pub async fn frm_fulfillment() {}
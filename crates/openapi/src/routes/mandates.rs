@@ -42,6 +42,7 @@ pub async fn revoke_mandate() {}
     path = "/mandates/list",
     params(
         ("limit" = Option<i64>, Query, description = "The maximum number of Mandate Objects to include in the response"),
+        ("offset" = Option<i64>, Query, description = "The number of Mandate Objects to skip"),
         ("mandate_status" = Option<MandateStatus>, Query, description = "The status of mandate"),
         ("connector" = Option<String>, Query, description = "The connector linked to mandate"),
         ("created_time" = Option<PrimitiveDateTime>, Query, description = "The time at which mandate is created"),
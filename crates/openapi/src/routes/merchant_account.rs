@@ -274,23 +274,198 @@ pub async fn delete_merchant_account() {}
 )]
 pub async fn merchant_account_kv_status() {}
 
-/// Merchant Connector - List
+#[cfg(feature = "v1")]
+/// Merchant Account - Takeout Create
+///
+/// Start generating an export of the configuration and transactional data of a merchant account.
+#[utoipa::path(
+    post,
+    path = "/accounts/{account_id}/takeout",
+    request_body (
+        content = MerchantTakeoutRequest,
+        examples (
+            ("Export with the customer details redacted" = (
+                value = json!({})
+            )),
+            ("Export with the customer details included" = (
+                value = json!({
+                    "redaction": {
+                        "customer_pii": "include"
+                    }
+                })
+            ))
+        )
+    ),
+    params (("account_id" = String, Path, description = "The unique identifier for the merchant account")),
+    responses(
+        (status = 200, description = "Takeout started", body = MerchantTakeoutResponse),
+        (status = 400, description = "Invalid data"),
+        (status = 404, description = "Merchant account not found")
+    ),
+    tag = "Merchant Account",
+    operation_id = "Create a Takeout of a Merchant Account",
+    security(("admin_api_key" = []))
+)]
+pub async fn merchant_takeout_create() {}
+
+#[cfg(feature = "v1")]
+/// Merchant Account - Takeout Retrieve
 ///
-/// List Merchant Connector Details for the merchant
+/// Retrieve the status of a takeout, with the download URLs of the files once it is completed.
 #[utoipa::path(
     get,
-    path = "/accounts/{account_id}/profile/connectors",
+    path = "/accounts/{account_id}/takeout/{job_id}",
+    params (
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("job_id" = String, Path, description = "The identifier of the takeout job")
+    ),
+    responses(
+        (status = 200, description = "Takeout retrieved", body = MerchantTakeoutResponse),
+        (status = 404, description = "Takeout not found")
+    ),
+    tag = "Merchant Account",
+    operation_id = "Retrieve a Takeout of a Merchant Account",
+    security(("admin_api_key" = []))
+)]
+pub async fn merchant_takeout_retrieve() {}
+
+#[cfg(feature = "v1")]
+/// Merchant Account - Takeout File Download
+///
+/// Download an exported file of a takeout. The request is authenticated by the signature of the
+/// download URL returned when retrieving the takeout.
+#[utoipa::path(
+    get,
+    path = "/accounts/{account_id}/takeout/{job_id}/files/{file_name}",
+    params (
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("job_id" = String, Path, description = "The identifier of the takeout job"),
+        ("file_name" = String, Path, description = "The name of the exported file"),
+        ("expires" = i64, Query, description = "Unix timestamp at which the download URL expires"),
+        ("signature" = String, Query, description = "Signature of the download URL")
+    ),
+    responses(
+        (status = 200, description = "The exported file, with one JSON record per line", content_type = "application/x-ndjson"),
+        (status = 403, description = "The download URL is invalid or expired"),
+        (status = 404, description = "File not found")
+    ),
+    tag = "Merchant Account",
+    operation_id = "Download a File of a Takeout of a Merchant Account"
+)]
+pub async fn merchant_takeout_file_download() {}
+
+#[cfg(feature = "v1")]
+/// Merchant Account - Customer Contact Hashes Rehash
+///
+/// Start re-computing the hashes of the email and phone number of the customers and payments of a
+/// merchant account, with which payments are searched by customer email or phone number.
+#[utoipa::path(
+    post,
+    path = "/accounts/{account_id}/contact_hashes/rehash",
+    params (("account_id" = String, Path, description = "The unique identifier for the merchant account")),
+    responses(
+        (status = 200, description = "Re-computation of the hashes started"),
+        (status = 404, description = "Merchant account not found")
+    ),
+    tag = "Merchant Account",
+    operation_id = "Rehash the Customer Contacts of a Merchant Account",
+    security(("admin_api_key" = []))
+)]
+pub async fn customer_contact_hashes_rehash() {}
+
+#[cfg(feature = "v1")]
+/// Merchant Account - Quota Update
+///
+/// Set the quota of payments the merchant can create in a period, replacing the existing quota.
+#[utoipa::path(
+    put,
+    path = "/accounts/{account_id}/quota",
+    request_body (
+        content = MerchantQuota,
+        examples (
+            ("Limit the number of payments in a month" = (
+                value = json!({
+                    "period": "monthly",
+                    "payment_count_limit": 1000,
+                    "warning_threshold_percent": 80
+                })
+            )),
+            ("Limit the total amount of payments in a day" = (
+                value = json!({
+                    "period": "daily",
+                    "volume_limit": {
+                        "amount": 10000000,
+                        "currency": "USD"
+                    }
+                })
+            ))
+        )
+    ),
+    params (("account_id" = String, Path, description = "The unique identifier for the merchant account")),
+    responses(
+        (status = 200, description = "Quota updated", body = MerchantQuota),
+        (status = 400, description = "Invalid data"),
+        (status = 404, description = "Merchant account not found")
+    ),
+    tag = "Merchant Account",
+    operation_id = "Update the Quota of a Merchant Account",
+    security(("admin_api_key" = []))
+)]
+pub async fn merchant_quota_update() {}
+
+#[cfg(feature = "v1")]
+/// Merchant Account - Quota Delete
+///
+/// Remove the quota of the merchant, after which its payments are no longer limited.
+#[utoipa::path(
+    delete,
+    path = "/accounts/{account_id}/quota",
+    params (("account_id" = String, Path, description = "The unique identifier for the merchant account")),
+    responses(
+        (status = 200, description = "Quota deleted")
+    ),
+    tag = "Merchant Account",
+    operation_id = "Delete the Quota of a Merchant Account",
+    security(("admin_api_key" = []))
+)]
+pub async fn merchant_quota_delete() {}
+
+#[cfg(feature = "v1")]
+/// Merchant Account - Quota Usage
+///
+/// Retrieve the payments created by the merchant in the current period, against its quota.
+#[utoipa::path(
+    get,
+    path = "/accounts/{account_id}/quota/usage",
+    params (("account_id" = String, Path, description = "The unique identifier for the merchant account")),
+    responses(
+        (status = 200, description = "Quota usage retrieved", body = MerchantQuotaUsageResponse),
+        (status = 404, description = "Quota not found")
+    ),
+    tag = "Merchant Account",
+    operation_id = "Retrieve the Quota Usage of a Merchant Account",
+    security(("admin_api_key" = []))
+)]
+pub async fn merchant_quota_usage() {}
+
+#[cfg(feature = "v1")]
+/// Merchant Connector - List for Profile
+///
+/// List Merchant Connector Details for the profile of the API key, or for all the profiles of the merchant
+#[utoipa::path(
+    get,
+    path = "/account/{account_id}/profile/connectors",
     params(
         ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
     ),
     responses(
-        (status = 200, description = "Merchant Connector list retrieved successfully", body = Vec<MerchantConnectorResponse>),
+        (status = 200, description = "Merchant Connector list retrieved successfully", body = Vec<MerchantConnectorListResponse>),
         (status = 404, description = "Merchant Connector does not exist in records"),
         (status = 401, description = "Unauthorized request")
     ),
     tag = "Merchant Connector Account",
     operation_id = "List all Merchant Connectors for The given Profile",
-    security(("admin_api_key" = []))
+    security(("api_key" = []))
 )]
 pub async fn payment_connector_list_profile() {}
 
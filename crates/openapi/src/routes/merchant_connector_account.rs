@@ -334,7 +334,7 @@ pub async fn connector_delete() {}
 #[cfg(feature = "v1")]
 #[utoipa::path(
     post,
-    path = "/account/{account_id}/webhooks/{merchant_connector_id}",
+    path = "/account/{account_id}/connectors/webhooks/{merchant_connector_id}",
     request_body(
         content = ConnectorWebhookRegisterRequest,
         examples(
@@ -370,13 +370,13 @@ pub async fn connector_delete() {}
 )]
 pub async fn connector_webhook_register() {}
 
-/// Configure Connector Webhook - Register
+/// Configure Connector Webhook - List
 ///
 /// List webhooks configured with hyperswitch at the connector
 #[cfg(feature = "v1")]
 #[utoipa::path(
     get,
-    path = "/account/{account_id}/webhooks/{merchant_connector_id}",
+    path = "/account/{account_id}/connectors/webhooks/{merchant_connector_id}",
     params(
         ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
         ("merchant_connector_id" = String, Path, description = "The unique identifier for the Merchant Connector")
@@ -390,3 +390,94 @@ pub async fn connector_webhook_register() {}
     security(("api_key" = []))
 )]
 pub async fn retrieve_connector_webhook() {}
+
+/// Apple Pay - Register Merchant Domains
+///
+/// Verify the domains of the merchant with Apple Pay through the Apple Pay merchant connector account.
+#[cfg(feature = "v1")]
+#[utoipa::path(
+    post,
+    path = "/verify/apple_pay/{merchant_id}",
+    request_body = ApplepayMerchantVerificationRequest,
+    params(
+        ("merchant_id" = String, Path, description = "The unique identifier for the merchant account")
+    ),
+    responses(
+        (status = 200, description = "Domains verified with Apple Pay", body = ApplepayMerchantResponse),
+        (status = 400, description = "Invalid data"),
+        (status = 401, description = "Unauthorized request")
+    ),
+    tag = "Merchant Connector Account",
+    operation_id = "Verify Domains with Apple Pay",
+    security(("api_key" = []))
+)]
+pub async fn apple_pay_merchant_registration() {}
+
+/// Apple Pay - List Verified Domains
+///
+/// List the domains of the merchant verified with Apple Pay for the merchant connector account.
+#[cfg(feature = "v1")]
+#[utoipa::path(
+    get,
+    path = "/verify/applepay_verified_domains",
+    params(
+        ("merchant_id" = String, Query, description = "The unique identifier for the merchant account"),
+        ("merchant_connector_account_id" = String, Query, description = "The unique identifier for the Apple Pay merchant connector account")
+    ),
+    responses(
+        (status = 200, description = "Domains verified with Apple Pay", body = ApplepayVerifiedDomainsResponse),
+        (status = 401, description = "Unauthorized request")
+    ),
+    tag = "Merchant Connector Account",
+    operation_id = "List Domains Verified with Apple Pay",
+    security(("api_key" = []))
+)]
+pub async fn retrieve_apple_pay_verified_domains() {}
+
+/// Apple Pay - Register Merchant Domains
+///
+/// Verify the domains of the merchant with Apple Pay through the Apple Pay merchant connector account.
+#[cfg(feature = "v2")]
+#[utoipa::path(
+    post,
+    path = "/v2/verify/apple-pay/{merchant_id}",
+    request_body = ApplepayMerchantVerificationRequest,
+    params(
+        ("merchant_id" = String, Path, description = "The unique identifier for the merchant account"),
+        (
+            "X-Profile-Id" = String, Header,
+            description = "Profile ID associated to the merchant connector account",
+            example = "pro_abcdefghijklmnop"
+        )
+    ),
+    responses(
+        (status = 200, description = "Domains verified with Apple Pay", body = ApplepayMerchantResponse),
+        (status = 400, description = "Invalid data"),
+        (status = 401, description = "Unauthorized request")
+    ),
+    tag = "Merchant Connector Account",
+    operation_id = "Verify Domains with Apple Pay",
+    security(("api_key" = []))
+)]
+pub async fn apple_pay_merchant_registration() {}
+
+/// Apple Pay - List Verified Domains
+///
+/// List the domains of the merchant verified with Apple Pay for the merchant connector account.
+#[cfg(feature = "v2")]
+#[utoipa::path(
+    get,
+    path = "/v2/verify/applepay-verified-domains",
+    params(
+        ("merchant_id" = String, Query, description = "The unique identifier for the merchant account"),
+        ("merchant_connector_account_id" = String, Query, description = "The unique identifier for the Apple Pay merchant connector account")
+    ),
+    responses(
+        (status = 200, description = "Domains verified with Apple Pay", body = ApplepayVerifiedDomainsResponse),
+        (status = 401, description = "Unauthorized request")
+    ),
+    tag = "Merchant Connector Account",
+    operation_id = "List Domains Verified with Apple Pay",
+    security(("api_key" = []))
+)]
+pub async fn retrieve_apple_pay_verified_domains() {}
@@ -17,3 +17,27 @@
     security(("api_key" = []), ("publishable_key" = []))
 )]
 pub async fn payment_link_retrieve() {}
+
+/// Payments Link - List
+///
+/// To list the Payment Links created by the merchant, filtered by the time of creation
+#[utoipa::path(
+    post,
+    path = "/payment_link/list",
+    params(
+        ("limit" = Option<i64>, Query, description = "The maximum number of Payment Link Objects to include in the response"),
+        ("created" = Option<PrimitiveDateTime>, Query, description = "The time at which payment link is created"),
+        ("created.lt" = Option<PrimitiveDateTime>, Query, description = "Time less than the payment link created time"),
+        ("created.gt" = Option<PrimitiveDateTime>, Query, description = "Time greater than the payment link created time"),
+        ("created.lte" = Option<PrimitiveDateTime>, Query, description = "Time less than or equals to the payment link created time"),
+        ("created.gte" = Option<PrimitiveDateTime>, Query, description = "Time greater than or equals to the payment link created time"),
+    ),
+    responses(
+        (status = 200, description = "The payment link list was retrieved successfully", body = Vec<RetrievePaymentLinkResponse>),
+        (status = 400, description = "Invalid Data")
+    ),
+    tag = "Payments",
+    operation_id = "List Payment Links",
+    security(("api_key" = []))
+)]
+pub async fn payments_link_list() {}
@@ -180,12 +180,116 @@ pub async fn payment_method_update_api() {}
 #[cfg(feature = "v1")]
 pub async fn payment_method_delete_api() {}
 
+/// Payment Method - Save
+///
+/// Saves the payment method details against an existing payment method, such as one created for a payment.
+#[utoipa::path(
+    post,
+    path = "/payment_methods/{payment_method_id}/save",
+    params (
+        ("payment_method_id" = String, Path, description = "The unique identifier for the Payment Method"),
+    ),
+    request_body = PaymentMethodCreate,
+    responses(
+        (status = 200, description = "Payment Method saved", body = PaymentMethodResponse),
+        (status = 400, description = "Invalid Data")
+    ),
+    tag = "Payment Methods",
+    operation_id = "Save a Payment Method",
+    security(("api_key" = []), ("publishable_key" = []))
+)]
+#[cfg(feature = "v1")]
+pub async fn save_payment_method_api() {}
+
+/// Payment Method - Initiate Collect Link
+///
+/// Creates a link to collect the payment method details of a customer.
+#[utoipa::path(
+    post,
+    path = "/payment_methods/collect",
+    request_body = PaymentMethodCollectLinkRequest,
+    responses(
+        (status = 200, description = "Payment Method collect link created", body = PaymentMethodCollectLinkResponse),
+        (status = 400, description = "Invalid Data")
+    ),
+    tag = "Payment Methods",
+    operation_id = "Initiate a Payment Method Collect Link",
+    security(("api_key" = []))
+)]
+#[cfg(feature = "v1")]
+pub async fn initiate_pm_collect_link_flow() {}
+
+/// Payment Method - Create Link Token
+///
+/// Creates a link token of the payment method authentication connector, to start the payment method authentication flow for a payment.
+#[utoipa::path(
+    post,
+    path = "/payment_methods/auth/link",
+    request_body = LinkTokenCreateRequest,
+    responses(
+        (status = 200, description = "Link token created", body = LinkTokenCreateResponse),
+        (status = 400, description = "Invalid Data")
+    ),
+    tag = "Payment Methods",
+    operation_id = "Create a Link Token",
+    security(("api_key" = []), ("publishable_key" = []))
+)]
+#[cfg(feature = "v1")]
+pub async fn link_token_create() {}
+
+/// Payment Method - Exchange Token
+///
+/// Exchanges the public token received from the payment method authentication connector, and stores the authenticated payment methods for the payment.
+#[utoipa::path(
+    post,
+    path = "/payment_methods/auth/exchange",
+    request_body = ExchangeTokenCreateRequest,
+    responses(
+        (status = 200, description = "Token exchanged"),
+        (status = 400, description = "Invalid Data")
+    ),
+    tag = "Payment Methods",
+    operation_id = "Exchange a Public Token",
+    security(("api_key" = []), ("publishable_key" = []))
+)]
+#[cfg(feature = "v1")]
+pub async fn exchange_token() {}
+
+/// List payment methods for a Payment
+///
+/// Lists the applicable payment methods for a payment, using the client secret and publishable key authorization.
+#[utoipa::path(
+    get,
+    path = "/payments/{payment_id}/client",
+    params (
+        ("payment_id" = String, Path, description = "The identifier for payment"),
+        ("client_secret" = Option<String>, Query, description = "This is a token which expires after 15 minutes, used from the client to authenticate and create sessions from the SDK"),
+        ("accepted_countries" = Option<Vec<CountryAlpha2>>, Query, description = "The two-letter ISO currency code"),
+        ("accepted_currencies" = Option<Vec<Currency>>, Query, description = "The three-letter ISO currency code"),
+        ("amount" = Option<i64>, Query, description = "The amount accepted for processing by the particular payment method."),
+        ("recurring_enabled" = Option<bool>, Query, description = "Indicates whether the payment method is eligible for recurring payments"),
+        ("installment_payment_enabled" = Option<bool>, Query, description = "Indicates whether the payment method is eligible for installment payments"),
+        ("limit" = Option<i64>, Query, description = "Indicates the limit of last used payment methods"),
+        ("card_networks" = Option<Vec<CardNetwork>>, Query, description = "Indicates whether the payment method is eligible for card netwotks"),
+    ),
+    responses(
+        (status = 200, description = "Payment Methods retrieved", body = PaymentMethodListResponse),
+        (status = 400, description = "Invalid Data"),
+        (status = 404, description = "Payment Methods does not exist in records")
+    ),
+    tag = "Payment Methods",
+    operation_id = "List all Payment Methods for a Payment",
+    security(("api_key" = []), ("publishable_key" = []))
+)]
+#[cfg(feature = "v1")]
+pub async fn list_payment_methods_for_payments_client() {}
+
 /// Payment Method - Set Default Payment Method for Customer
 ///
 /// Set the Payment Method as Default for the Customer.
 #[utoipa::path(
     post,
-    path = "/{customer_id}/payment_methods/{payment_method_id}/default",
+    path = "/customers/{customer_id}/payment_methods/{payment_method_id}/default",
     params (
         ("customer_id" = String,Path, description ="The unique identifier for the Customer"),
         ("payment_method_id" = String,Path, description = "The unique identifier for the Payment Method"),
@@ -207,7 +311,7 @@ pub async fn default_payment_method_set_api() {}
 /// Set the Payment Method as Default for the Customer.
 #[utoipa::path(
     post,
-    path = "/v1/{customer_id}/payment-methods/{payment_method_id}/default",
+    path = "/v2/customers/{customer_id}/payment-methods/{payment_method_id}/default",
     params (
         ("customer_id" = String,Path, description ="The unique identifier for the Customer"),
         ("payment_method_id" = String,Path, description = "The unique identifier for the Payment Method"),
@@ -224,6 +328,28 @@ pub async fn default_payment_method_set_api() {}
 #[cfg(feature = "v2")]
 pub async fn default_payment_method_set_api() {}
 
+/// Payment Method - Set Default Payment Method for Customer
+///
+/// Set the Payment Method as Default for the Customer.
+#[utoipa::path(
+    post,
+    path = "/v1/customers/{customer_id}/payment-methods/{payment_method_id}/default",
+    params (
+        ("customer_id" = String,Path, description ="The unique identifier for the Customer"),
+        ("payment_method_id" = String,Path, description = "The unique identifier for the Payment Method"),
+    ),
+    responses(
+        (status = 200, description = "Payment Method has been set as default", body =CustomerDefaultPaymentMethodResponse ),
+        (status = 400, description = "Payment Method has already been set as default for that customer"),
+        (status = 404, description = "Payment Method not found for the customer")
+    ),
+    tag = "Payment Methods",
+    operation_id = "Set the Payment Method as Default",
+    security(("api_key" = []))
+)]
+#[cfg(feature = "v2")]
+pub async fn default_payment_method_set_api_v1() {}
+
 /// Payment Method - Create Intent
 ///
 /// Creates a payment method for customer with billing information and other metadata.
@@ -556,6 +682,105 @@ pub async fn payment_method_get_token_details_api() {}
 )]
 #[cfg(feature = "v2")]
 pub async fn payment_method_get_token_details_api_v1() {}
+
+/// Payment Method - Check Network Token Eligibility
+///
+/// Checks if cards with the given card bin are eligible for network tokenization for the merchant.
+#[utoipa::path(
+    get,
+    path = "/v2/payment-methods/get-network-token-eligibility",
+    params (
+        ("card_bin" = String, Query, description = "The card bin to check the eligibility for"),
+        (
+            "X-Profile-Id" = String, Header,
+            description = "Profile ID associated to the payment method",
+            example = "pro_abcdefghijklmnop"
+        )
+    ),
+    responses(
+        (status = 200, description = "Network Token Eligibility Retrieved", body = GetNetworkTokenEiligibilityResponse),
+        (status = 400, description = "Invalid Data"),
+    ),
+    tag = "Payment Methods",
+    operation_id = "Check Network Token Eligibility",
+    security(("api_key" = []))
+)]
+#[cfg(feature = "v2")]
+pub async fn get_pm_nt_eligibility_api() {}
+
+/// Payment Method - Retrieve with Details
+///
+/// Retrieves a payment method of a customer, along with the other payment methods saved for the customer.
+#[utoipa::path(
+    get,
+    path = "/v2/payment-methods/{id}/details",
+    params (
+        ("id" = String, Path, description = "The unique identifier for the Payment Method"),
+        (
+            "X-Profile-Id" = String, Header,
+            description = "Profile ID associated to the payment method",
+            example = "pro_abcdefghijklmnop"
+        )
+    ),
+    responses(
+        (status = 200, description = "Payment Method Retrieved", body = PaymentMethodDetailsResponse),
+        (status = 404, description = "Payment Method Not Found"),
+    ),
+    tag = "Payment Methods",
+    operation_id = "Retrieve Payment Method with Details",
+    security(("api_key" = []))
+)]
+#[cfg(feature = "v2")]
+pub async fn payment_method_retrieve_olap_api() {}
+
+/// Payment Method - Get Token Data
+///
+/// Retrieves the token data of the given type stored for a payment method, such as its network token.
+#[utoipa::path(
+    get,
+    path = "/v2/payment-methods/{id}/get-token",
+    params (
+        ("id" = String, Path, description = "The unique identifier for the Payment Method"),
+        (
+            "X-Profile-Id" = String, Header,
+            description = "Profile ID associated to the payment method",
+            example = "pro_abcdefghijklmnop"
+        )
+    ),
+    request_body = GetTokenDataRequest,
+    responses(
+        (status = 200, description = "Token Data Retrieved", body = TokenDataResponse),
+        (status = 404, description = "Payment Method Not Found"),
+    ),
+    tag = "Payment Methods",
+    operation_id = "Get Token Data of a Payment Method",
+    security(("api_key" = []))
+)]
+#[cfg(feature = "v2")]
+pub async fn get_payment_method_token_data() {}
+
+/// Payment Method - Total Count for Merchant
+///
+/// Retrieves the total count of the payment methods saved by the customers of the merchant.
+#[utoipa::path(
+    get,
+    path = "/v2/customers/total-payment-methods",
+    params (
+        (
+            "X-Profile-Id" = String, Header,
+            description = "Profile ID associated to the payment method",
+            example = "pro_abcdefghijklmnop"
+        )
+    ),
+    responses(
+        (status = 200, description = "Payment Method Count Retrieved", body = TotalPaymentMethodCountResponse),
+    ),
+    tag = "Payment Methods",
+    operation_id = "Retrieve Total Payment Method Count",
+    security(("api_key" = []))
+)]
+#[cfg(feature = "v2")]
+pub async fn get_total_payment_method_count() {}
 /// Payment Method - List Customer Saved Payment Methods
 ///
 /// List the payment methods saved for a customer
@@ -775,6 +1000,58 @@ pub fn payment_method_session_list_payment_methods() {}
 )]
 pub fn payment_method_session_list_payment_methods_v1() {}
 
+/// Payment Method Session - List Enabled Payment Methods
+///
+/// List payment methods for the given payment method session.
+/// This endpoint lists the enabled payment methods for the profile and the saved payment methods of the customer.
+#[cfg(feature = "v2")]
+#[utoipa::path(
+    get,
+    path = "/v2/payment-methods/{id}/list-enabled-payment-methods",
+    params (
+        ("id" = String, Path, description = "The unique identifier for the Payment Method Session"),
+        (
+            "X-Profile-Id" = String, Header,
+            description = "Profile ID associated to the payment method session",
+            example = "pro_abcdefghijklmnop"
+        )
+    ),
+    responses(
+        (status = 200, description = "The payment method session is retrieved successfully", body = PaymentMethodListResponseForSession),
+        (status = 404, description = "The request is invalid")
+    ),
+    tag = "Payment Method Session",
+    operation_id = "List Enabled Payment methods for a Payment Method Session",
+    security(("publishable_key__client_secret" = []))
+)]
+pub fn payment_method_session_list_enabled_payment_methods() {}
+
+/// Payment Method Session - Update
+///
+/// Update the customer, billing or other details of the payment method session.
+#[cfg(feature = "v2")]
+#[utoipa::path(
+    put,
+    path = "/v2/payment-method-sessions/{id}",
+    params (
+        ("id" = String, Path, description = "The unique identifier for the Payment Method Session"),
+        (
+            "X-Profile-Id" = String, Header,
+            description = "Profile ID associated to the payment method session",
+            example = "pro_abcdefghijklmnop"
+        )
+    ),
+    request_body = PaymentMethodsSessionUpdateRequest,
+    responses(
+        (status = 200, description = "The payment method session is updated successfully", body = PaymentMethodSessionResponse),
+        (status = 404, description = "The request is invalid")
+    ),
+    tag = "Payment Method Session",
+    operation_id = "Update the payment method session",
+    security(("api_key" = []))
+)]
+pub fn payment_method_session_update() {}
+
 /// Payment Method Session - Update a saved payment method
 ///
 /// Update a saved payment method from the given payment method session.
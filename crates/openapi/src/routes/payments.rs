@@ -737,6 +737,7 @@ pub fn payments_confirm() {}
     operation_id = "Capture a Payment",
     security(("api_key" = []))
 )]
+#[cfg(feature = "v1")]
 pub fn payments_capture() {}
 
 #[cfg(feature = "v1")]
@@ -810,6 +811,7 @@ pub fn payments_connector_session() {}
     operation_id = "Cancel a Payment",
     security(("api_key" = []))
 )]
+#[cfg(feature = "v1")]
 pub fn payments_cancel() {}
 
 /// Payments - Cancel Post Capture
@@ -865,6 +867,78 @@ pub fn payments_cancel_post_capture() {}
 )]
 pub fn payments_cancel_post_capture_retrieve() {}
 
+/// Payments - Order Cancelled
+///
+/// Signals that the order of a payment was cancelled, so that the authorization of the payment is voided. Only payments with manual capture in the `requires_capture` status are voided, and voiding on order cancellation must be enabled for the merchant. A conflict is returned if a capture was already initiated for the payment.
+#[utoipa::path(
+    post,
+    path = "/payments/{payment_id}/order_cancelled",
+    request_body (
+        content = PaymentsOrderCancelledRequest,
+        examples(
+            (
+                "Signal the order cancellation with the initiating system" = (
+                    value = json!({"initiated_by": "order_management_system"})
+                )
+            ),
+            (
+                "Signal the order cancellation with cancellation reason" = (
+                    value = json!({
+                        "initiated_by": "order_management_system",
+                        "cancellation_reason": "out_of_stock"
+                    })
+                )
+            ),
+        )
+    ),
+    params(
+        ("payment_id" = String, Path, description = "The identifier for payment")
+    ),
+    responses(
+        (status = 200, description = "Payment voided", body = PaymentsResponse),
+        (status = 400, description = "Missing mandatory fields", body = GenericErrorResponseOpenApi),
+        (status = 409, description = "A capture was initiated for the payment", body = GenericErrorResponseOpenApi)
+    ),
+    tag = "Payments",
+    operation_id = "Void a Payment on Order Cancellation",
+    security(("api_key" = []))
+)]
+pub fn payments_order_cancelled() {}
+
+/// Payments - Force Close
+///
+/// Force-close a payment which is stuck in processing. The payment is synced with the connector first, and the status reported by the connector is applied if the connector has resolved the payment. Otherwise, the payment and its attempt are marked as failed, and the close is recorded with its reason in the `force_close` details of the payment's state metadata.
+#[utoipa::path(
+    post,
+    path = "/payments/{payment_id}/force-close",
+    request_body (
+        content = PaymentsForceCloseRequest,
+        examples(
+            (
+                "Force-close a payment stuck in processing" = (
+                    value = json!({
+                        "merchant_id": "merchant_abc",
+                        "reason": "No response from the connector"
+                    })
+                )
+            ),
+        )
+    ),
+    params(
+        ("payment_id" = String, Path, description = "The identifier for payment"),
+        ("X-Merchant-Id" = String, Header, description = "Merchant ID of the payment")
+    ),
+    responses(
+        (status = 200, description = "Payment force-closed", body = PaymentsForceCloseResponse),
+        (status = 400, description = "Payment is not in processing or missing mandatory fields", body = GenericErrorResponseOpenApi),
+        (status = 404, description = "Payment not found", body = GenericErrorResponseOpenApi)
+    ),
+    tag = "Payments",
+    operation_id = "Force-close a Payment",
+    security(("admin_api_key" = []))
+)]
+pub fn payments_force_close() {}
+
 /// Payments - List
 ///
 /// To list the *payments*
@@ -896,22 +970,23 @@ pub fn payments_list() {}
 /// Profile level Payments - List
 ///
 /// To list the payments
+#[cfg(feature = "v1")]
 #[utoipa::path(
   get,
-  path = "/payments/list",
+  path = "/payments/profile/list",
   params(
-      ("customer_id" = String, Query, description = "The identifier for the customer"),
-      ("starting_after" = String, Query, description = "A cursor for use in pagination, fetch the next list after some object"),
-      ("ending_before" = String, Query, description = "A cursor for use in pagination, fetch the previous list before some object"),
-      ("limit" = i64, Query, description = "Limit on the number of objects to return"),
-      ("created" = PrimitiveDateTime, Query, description = "The time at which payment is created"),
-      ("created_lt" = PrimitiveDateTime, Query, description = "Time less than the payment created time"),
-      ("created_gt" = PrimitiveDateTime, Query, description = "Time greater than the payment created time"),
-      ("created_lte" = PrimitiveDateTime, Query, description = "Time less than or equals to the payment created time"),
-      ("created_gte" = PrimitiveDateTime, Query, description = "Time greater than or equals to the payment created time")
+      ("customer_id" = Option<String>, Query, description = "The identifier for the customer"),
+      ("starting_after" = Option<String>, Query, description = "A cursor for use in pagination, fetch the next list after some object"),
+      ("ending_before" = Option<String>, Query, description = "A cursor for use in pagination, fetch the previous list before some object"),
+      ("limit" = Option<i64>, Query, description = "Limit on the number of objects to return"),
+      ("created" = Option<PrimitiveDateTime>, Query, description = "The time at which payment is created"),
+      ("created_lt" = Option<PrimitiveDateTime>, Query, description = "Time less than the payment created time"),
+      ("created_gt" = Option<PrimitiveDateTime>, Query, description = "Time greater than the payment created time"),
+      ("created_lte" = Option<PrimitiveDateTime>, Query, description = "Time less than or equals to the payment created time"),
+      ("created_gte" = Option<PrimitiveDateTime>, Query, description = "Time greater than or equals to the payment created time")
   ),
   responses(
-      (status = 200, description = "Received payment list"),
+      (status = 200, description = "Received payment list", body = PaymentListResponse),
       (status = 404, description = "No payments found")
   ),
   tag = "Payments",
@@ -920,6 +995,83 @@ pub fn payments_list() {}
 )]
 pub async fn profile_payments_list() {}
 
+/// Payments - Retrieve with Gateway Credentials
+///
+/// To retrieve the status of a payment, using the connector credentials passed in the request
+#[cfg(feature = "v1")]
+#[utoipa::path(
+    post,
+    path = "/payments/sync",
+    request_body=PaymentRetrieveBodyWithCredentials,
+    responses(
+        (status = 200, description = "Gets the payment with final status", body = PaymentsResponse),
+        (status = 404, description = "No payment found")
+    ),
+    tag = "Payments",
+    operation_id = "Retrieve a Payment with Gateway Credentials",
+    security(("api_key" = []))
+)]
+pub fn payments_retrieve_with_gateway_creds() {}
+
+/// Payments - Approve
+///
+/// To approve a payment which is held for a manual review by the fraud and risk management connector
+#[utoipa::path(
+    post,
+    path = "/payments/{payment_id}/approve",
+    request_body=PaymentsApproveRequest,
+    params(
+        ("payment_id" = String, Path, description = "The identifier for payment")
+    ),
+    responses(
+        (status = 200, description = "Payment approved", body = PaymentsResponse),
+        (status = 400, description = "Missing mandatory fields", body = GenericErrorResponseOpenApi)
+    ),
+    tag = "Payments",
+    operation_id = "Approve a Payment",
+    security(("api_key" = []))
+)]
+pub fn payments_approve() {}
+
+/// Payments - Reject
+///
+/// To reject a payment which is held for a manual review by the fraud and risk management connector
+#[utoipa::path(
+    post,
+    path = "/payments/{payment_id}/reject",
+    request_body=PaymentsRejectRequest,
+    params(
+        ("payment_id" = String, Path, description = "The identifier for payment")
+    ),
+    responses(
+        (status = 200, description = "Payment rejected", body = PaymentsResponse),
+        (status = 400, description = "Missing mandatory fields", body = GenericErrorResponseOpenApi)
+    ),
+    tag = "Payments",
+    operation_id = "Reject a Payment",
+    security(("api_key" = []))
+)]
+pub fn payments_reject() {}
+
+/// Payments - Retrieve Extended Card Info
+///
+/// To retrieve the extended card information of a payment, when it is enabled for the profile
+#[utoipa::path(
+    get,
+    path = "/payments/{payment_id}/extended_card_info",
+    params(
+        ("payment_id" = String, Path, description = "The identifier for payment")
+    ),
+    responses(
+        (status = 200, description = "Extended card info retrieved", body = ExtendedCardInfoResponse),
+        (status = 404, description = "Extended card info not found")
+    ),
+    tag = "Payments",
+    operation_id = "Retrieve Extended Card Info for a Payment",
+    security(("api_key" = []))
+)]
+pub fn retrieve_extended_card_info() {}
+
 /// Payments - Incremental Authorization
 ///
 /// Authorized amount for a payment can be incremented if it is in status: requires_capture
@@ -1003,6 +1155,9 @@ pub fn payments_complete_authorize() {}
     post,
     path = "/payments/{payment_id}/calculate_tax",
     request_body=PaymentsDynamicTaxCalculationRequest,
+    params(
+        ("payment_id" = String, Path, description = "The identifier for payment")
+    ),
     responses(
         (status = 200, description = "Tax Calculation is done", body = PaymentsDynamicTaxCalculationResponse),
         (status = 400, description = "Missing mandatory fields", body = GenericErrorResponseOpenApi)
@@ -1011,7 +1166,6 @@ pub fn payments_complete_authorize() {}
     operation_id = "Create Tax Calculation for a Payment",
     security(("publishable_key" = []))
 )]
-
 pub fn payments_dynamic_tax_calculation() {}
 
 /// Payments - Post Session Tokens
@@ -1379,3 +1533,251 @@ pub fn payments_list() {}
     security(("publishable_key__client_secret" = []))
 )]
 pub fn payments_apply_pm_data() {}
+
+/// Payments - Get Intent using Merchant Reference ID
+///
+/// Retrieves a Payment Intent using the merchant supplied reference ID.
+#[utoipa::path(
+    get,
+    path = "/v2/payments/ref/{merchant_reference_id}",
+    params (
+        ("merchant_reference_id" = String, Path, description = "The merchant supplied reference identifier for the Payment"),
+        (
+          "X-Profile-Id" = String, Header,
+          description = "Profile ID associated to the payment intent",
+          example = "pro_abcdefghijklmnop"
+        ),
+    ),
+    responses(
+        (status = 200, description = "Payment Intent retrieved", body = PaymentsIntentResponse),
+        (status = 404, description = "No payment found with the given merchant reference id")
+    ),
+    tag = "Payments",
+    operation_id = "Retrieve a Payment Intent using Merchant Reference ID",
+    security(("api_key" = []))
+)]
+#[cfg(feature = "v2")]
+pub fn payment_get_intent_using_merchant_reference_id() {}
+
+/// Payments - Get with Gateway Credentials
+///
+/// Retrieves a Payment, using the merchant connector details passed in the request to sync the payment status with the connector.
+#[utoipa::path(
+    post,
+    path = "/v2/payments/{id}",
+    params(
+        ("id" = String, Path, description = "The global payment id"),
+        (
+          "X-Profile-Id" = String, Header,
+          description = "Profile ID associated to the payment intent",
+          example = "pro_abcdefghijklmnop"
+        ),
+    ),
+    request_body = PaymentsRetrieveRequest,
+    responses(
+        (status = 200, description = "Gets the payment with final status", body = PaymentsResponse),
+        (status = 404, description = "No payment found with the given id")
+    ),
+    tag = "Payments",
+    operation_id = "Retrieve a Payment with Gateway Credentials",
+    security(("api_key" = []))
+)]
+#[cfg(feature = "v2")]
+pub fn payments_status_with_gateway_creds() {}
+
+/// Payments - Capture
+///
+/// Captures the funds for a previously authorized payment intent where `capture_method` is set to `manual` and `status` is set to `requires_capture`.
+#[utoipa::path(
+    post,
+    path = "/v2/payments/{id}/capture",
+    params(
+        ("id" = String, Path, description = "The global payment id"),
+        (
+          "X-Profile-Id" = String, Header,
+          description = "Profile ID associated to the payment intent",
+          example = "pro_abcdefghijklmnop"
+        ),
+    ),
+    request_body = PaymentsCaptureRequest,
+    responses(
+        (status = 200, description = "Payment captured", body = PaymentsCaptureResponse),
+        (status = 400, description = "Missing mandatory fields", body = GenericErrorResponseOpenApi)
+    ),
+    tag = "Payments",
+    operation_id = "Capture a Payment",
+    security(("api_key" = []))
+)]
+#[cfg(feature = "v2")]
+pub fn payments_capture() {}
+
+/// Payments - Cancel
+///
+/// A Payment could can be cancelled when it is in one of these statuses: `requires_payment_method`, `requires_capture`, `requires_confirmation`, `requires_customer_action`.
+#[utoipa::path(
+    post,
+    path = "/v2/payments/{id}/cancel",
+    params(
+        ("id" = String, Path, description = "The global payment id"),
+        (
+          "X-Profile-Id" = String, Header,
+          description = "Profile ID associated to the payment intent",
+          example = "pro_abcdefghijklmnop"
+        ),
+    ),
+    request_body = PaymentsCancelRequest,
+    responses(
+        (status = 200, description = "Payment cancelled", body = PaymentsCancelResponse),
+        (status = 400, description = "Missing mandatory fields", body = GenericErrorResponseOpenApi)
+    ),
+    tag = "Payments",
+    operation_id = "Cancel a Payment",
+    security(("api_key" = []))
+)]
+#[cfg(feature = "v2")]
+pub fn payments_cancel() {}
+
+/// Payments - List Attempts
+///
+/// Lists the payment attempts made for a payment intent.
+#[utoipa::path(
+    get,
+    path = "/v2/payments/{id}/list-attempts",
+    params(
+        ("id" = String, Path, description = "The global payment id"),
+        (
+          "X-Profile-Id" = String, Header,
+          description = "Profile ID associated to the payment intent",
+          example = "pro_abcdefghijklmnop"
+        ),
+    ),
+    responses(
+        (status = 200, description = "Payment attempts retrieved", body = PaymentAttemptListResponse),
+        (status = 404, description = "No payment found with the given id")
+    ),
+    tag = "Payments",
+    operation_id = "List Attempts of a Payment",
+    security(("api_key" = []))
+)]
+#[cfg(feature = "v2")]
+pub fn list_payment_attempts() {}
+
+/// Payments - Proxy Confirm Intent
+///
+/// Confirms a payment intent with a payment method token stored at the processor, for a payment which is routed to the given connector.
+#[utoipa::path(
+    post,
+    path = "/v2/payments/{id}/proxy-confirm-intent",
+    params(
+        ("id" = String, Path, description = "The global payment id"),
+        (
+          "X-Profile-Id" = String, Header,
+          description = "Profile ID associated to the payment intent",
+          example = "pro_abcdefghijklmnop"
+        ),
+    ),
+    request_body = ProxyPaymentsRequest,
+    responses(
+        (status = 200, description = "Payment confirmed", body = PaymentsResponse),
+        (status = 400, description = "Missing mandatory fields", body = GenericErrorResponseOpenApi)
+    ),
+    tag = "Payments",
+    operation_id = "Proxy Confirm Payment Intent",
+    security(("api_key" = []))
+)]
+#[cfg(feature = "v2")]
+pub fn proxy_confirm_intent() {}
+
+/// Payments - Confirm Intent with External Vault Proxy
+///
+/// Confirms a payment intent with payment method data tokenized by an external vault, which is proxied through the vault to the connector.
+#[utoipa::path(
+    post,
+    path = "/v2/payments/{id}/confirm-intent/external-vault-proxy",
+    params(
+        ("id" = String, Path, description = "The global payment id"),
+        (
+          "X-Profile-Id" = String, Header,
+          description = "Profile ID associated to the payment intent",
+          example = "pro_abcdefghijklmnop"
+        ),
+    ),
+    request_body = ExternalVaultProxyPaymentsRequest,
+    responses(
+        (status = 200, description = "Payment confirmed", body = PaymentsResponse),
+        (status = 400, description = "Missing mandatory fields", body = GenericErrorResponseOpenApi)
+    ),
+    tag = "Payments",
+    operation_id = "Confirm Payment Intent with External Vault Proxy",
+    security(("api_key" = []), ("publishable_key__client_secret" = []))
+)]
+#[cfg(feature = "v2")]
+pub fn confirm_intent_with_external_vault_proxy() {}
+
+/// Payments - Create Recovery Payment
+///
+/// Records a payment of an invoice at the billing connector, for the payment to be recovered by retrying it.
+#[utoipa::path(
+    post,
+    path = "/v2/payments/recovery",
+    params(
+        (
+          "X-Profile-Id" = String, Header,
+          description = "Profile ID associated to the payment intent",
+          example = "pro_abcdefghijklmnop"
+        ),
+    ),
+    request_body = RecoveryPaymentsCreate,
+    responses(
+        (status = 200, description = "Recovery payment created", body = RecoveryPaymentsResponse),
+        (status = 400, description = "Missing mandatory fields", body = GenericErrorResponseOpenApi)
+    ),
+    tag = "Payments",
+    operation_id = "Create a Recovery Payment",
+    security(("api_key" = []))
+)]
+#[cfg(feature = "v2")]
+pub fn recovery_payments_create() {}
+
+/// Payments - List Recovery Payments
+///
+/// Lists the payments being recovered, along with their recovery status.
+#[utoipa::path(
+    get,
+    path = "/v2/payments/recovery-list",
+    params(api_models::payments::PaymentListConstraints),
+    responses(
+        (status = 200, description = "Successfully retrieved a recovery payment list", body = RecoveryPaymentListResponse),
+        (status = 404, description = "No payments found")
+    ),
+    tag = "Payments",
+    operation_id = "List all Recovery Payments",
+    security(("api_key" = []), ("jwt_key" = []))
+)]
+#[cfg(feature = "v2")]
+pub fn revenue_recovery_invoices_list() {}
+
+/// Payments - Get Revenue Recovery Intent
+///
+/// Retrieves a Payment Intent along with its revenue recovery status.
+#[utoipa::path(
+    get,
+    path = "/v2/payments/{id}/get-revenue-recovery-intent",
+    params(
+        ("id" = String, Path, description = "The global payment id"),
+        (
+          "X-Profile-Id" = String, Header,
+          description = "Profile ID associated to the payment intent",
+          example = "pro_abcdefghijklmnop"
+        ),
+    ),
+    responses(
+        (status = 200, description = "Payment Intent retrieved", body = RevenueRecoveryGetIntentResponse),
+        (status = 404, description = "No payment found with the given id")
+    ),
+    tag = "Payments",
+    operation_id = "Retrieve a Revenue Recovery Payment Intent",
+    security(("api_key" = []), ("publishable_key__client_secret" = []))
+)]
+#[cfg(feature = "v2")]
+pub fn revenue_recovery_get_intent() {}
@@ -33,7 +33,7 @@ pub async fn payouts_retrieve() {}
 
 /// Payouts - Update
 #[utoipa::path(
-    post,
+    put,
     path = "/payouts/{payout_id}",
     params(
         ("payout_id" = String, Path, description = "The identifier for payout")
@@ -143,6 +143,46 @@ pub async fn payouts_list_profile() {}
 )]
 pub async fn payouts_list_filters() {}
 
+/// Payouts - List available filters for the given Profiles
+#[utoipa::path(
+    post,
+    path = "/payouts/profile/filter",
+    request_body=TimeRange,
+    responses(
+        (status = 200, description = "Filters listed", body = PayoutListFilters)
+    ),
+    tag = "Payouts",
+    operation_id = "List available payout filters for the given Profiles",
+    security(("api_key" = []))
+)]
+pub async fn payouts_list_filters_profile() {}
+
+/// Payouts - List filters
+#[utoipa::path(
+    get,
+    path = "/payouts/v2/filter",
+    responses(
+        (status = 200, description = "Filters listed", body = PayoutListFiltersV2)
+    ),
+    tag = "Payouts",
+    operation_id = "List all filters for payouts",
+    security(("api_key" = []))
+)]
+pub async fn get_payout_filters() {}
+
+/// Payouts - List filters for the given Profiles
+#[utoipa::path(
+    get,
+    path = "/payouts/v2/profile/filter",
+    responses(
+        (status = 200, description = "Filters listed", body = PayoutListFiltersV2)
+    ),
+    tag = "Payouts",
+    operation_id = "List all filters for payouts for the given Profiles",
+    security(("api_key" = []))
+)]
+pub async fn get_payout_filters_profile() {}
+
 /// Payouts - List using filters
 #[utoipa::path(
     post,
@@ -161,7 +201,7 @@ pub async fn payouts_list_by_filter() {}
 /// Payouts - List using filters for the given Profiles
 #[utoipa::path(
     post,
-    path = "/payouts/list",
+    path = "/payouts/profile/list",
     request_body=PayoutListFilterConstraints,
     responses(
         (status = 200, description = "Payouts filtered", body = PayoutListResponse),
@@ -130,6 +130,71 @@ pub async fn profile_delete() {}
 )]
 pub async fn profile_list() {}
 
+#[cfg(feature = "v1")]
+/// Profile - List at Profile Level
+///
+/// Lists the *profile* of the API key, or all the *profiles* under the merchant for API keys which are not restricted to a profile
+#[utoipa::path(
+    get,
+    path = "/account/{account_id}/profile",
+    params (
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+    ),
+    responses(
+        (status = 200, description = "Profiles Retrieved", body = Vec<ProfileResponse>)
+    ),
+    tag = "Profile",
+    operation_id = "List Profiles at Profile Level",
+    security(("api_key" = []))
+)]
+pub async fn profiles_list_at_profile_level() {}
+
+#[cfg(feature = "v1")]
+/// Profile - Toggle Connector Agnostic MIT
+///
+/// Enable or disable connector agnostic merchant initiated transactions for the *profile*
+#[utoipa::path(
+    post,
+    path = "/account/{account_id}/business_profile/{profile_id}/toggle_connector_agnostic_mit",
+    request_body = ConnectorAgnosticMitChoice,
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the profile")
+    ),
+    responses(
+        (status = 200, description = "Connector agnostic MIT toggled", body = ConnectorAgnosticMitChoice),
+        (status = 400, description = "Invalid data")
+    ),
+    tag = "Profile",
+    operation_id = "Toggle Connector Agnostic MIT for a Profile",
+    security(("api_key" = []))
+)]
+pub async fn toggle_connector_agnostic_mit() {}
+
+#[cfg(feature = "v1")]
+/// Profile - Issue Embedded Token
+///
+/// Issues a token for embedding the dashboard of the *profile* in the application of the merchant
+#[utoipa::path(
+    get,
+    path = "/embedded/token",
+    params(
+        (
+            "X-Profile-Id" = String, Header,
+            description = "Profile ID to issue the embedded token for",
+            example = "pro_abcdefghijklmnop"
+        ),
+    ),
+    responses(
+        (status = 200, description = "Embedded token issued", body = IssueEmbeddedTokenResponse),
+        (status = 400, description = "Invalid data")
+    ),
+    tag = "Profile",
+    operation_id = "Issue an Embedded Token for a Profile",
+    security(("api_key" = []))
+)]
+pub async fn issue_embedded_token() {}
+
 // ******************************************** V2 profile routes ******************************************** //
 
 #[cfg(feature = "v2")]
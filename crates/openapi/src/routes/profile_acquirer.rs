@@ -4,7 +4,7 @@
 /// Create a new Profile Acquirer for accessing our APIs from your servers.
 #[utoipa::path(
     post,
-    path = "/profile_acquirers",
+    path = "/profile_acquirer",
     request_body = ProfileAcquirerCreate,
     responses(
         (status = 200, description = "Profile Acquirer created", body = ProfileAcquirerResponse),
@@ -23,7 +23,7 @@ pub async fn profile_acquirer_create() { /* … */
 /// Update a Profile Acquirer for accessing our APIs from your servers.
 #[utoipa::path(
     post,
-    path = "/profile_acquirers/{profile_id}/{profile_acquirer_id}",
+    path = "/profile_acquirer/{profile_id}/{profile_acquirer_id}",
     params (
         ("profile_id" = String, Path, description = "The unique identifier for the Profile"),
         ("profile_acquirer_id" = String, Path, description = "The unique identifier for the Profile Acquirer")
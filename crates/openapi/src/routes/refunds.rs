@@ -71,16 +71,18 @@ pub async fn refunds_retrieve() {}
 ///
 /// To retrieve the properties of a Refund. This may be used to get the status of a previously initiated payment or next action for an ongoing payment
 #[utoipa::path(
-    get,
+    post,
     path = "/refunds/sync",
+    request_body=RefundsRetrieveRequest,
     responses(
         (status = 200, description = "Refund retrieved", body = RefundResponse),
         (status = 404, description = "Refund does not exist in our records")
     ),
     tag = "Refunds",
-    operation_id = "Retrieve a Refund",
+    operation_id = "Retrieve a Refund with the Request Body",
     security(("api_key" = []))
 )]
+#[cfg(feature = "v1")]
 pub async fn refunds_retrieve_with_body() {}
 
 /// Refunds - Update
@@ -146,6 +148,7 @@ pub fn refunds_list() {}
     operation_id = "List all Refunds for the given Profiles",
     security(("api_key" = []))
 )]
+#[cfg(feature = "v1")]
 pub fn refunds_list_profile() {}
 
 /// Refunds - Filter
@@ -162,8 +165,81 @@ pub fn refunds_list_profile() {}
     operation_id = "List all filters for Refunds",
     security(("api_key" = []))
 )]
+#[cfg(feature = "v1")]
 pub async fn refunds_filter_list() {}
 
+/// Refunds - Filters
+///
+/// To list the refunds filters associated with the merchant connector accounts, currencies and refund statuses
+#[utoipa::path(
+    get,
+    path = "/refunds/v2/filter",
+    responses(
+        (status = 200, description = "List of filters", body = RefundListFilters),
+    ),
+    tag = "Refunds",
+    operation_id = "List all filters for Refunds using the merchant connector accounts",
+    security(("api_key" = []))
+)]
+#[cfg(feature = "v1")]
+pub async fn get_refunds_filters() {}
+
+/// Refunds - Filters For the Given profiles
+///
+/// To list the refunds filters associated with the merchant connector accounts of the given profiles, currencies and refund statuses
+#[utoipa::path(
+    get,
+    path = "/refunds/v2/profile/filter",
+    responses(
+        (status = 200, description = "List of filters", body = RefundListFilters),
+    ),
+    tag = "Refunds",
+    operation_id = "List all filters for Refunds for the given Profiles",
+    security(("api_key" = []))
+)]
+#[cfg(feature = "v1")]
+pub async fn get_refunds_filters_profile() {}
+
+/// Refunds - Aggregate
+///
+/// Gets a count of refunds grouped by their status for a merchant within a time range
+#[utoipa::path(
+    get,
+    path = "/refunds/aggregate",
+    params(
+        ("start_time" = String, Query, description = "The start time for the aggregate query"),
+        ("end_time" = Option<String>, Query, description = "The end time for the aggregate query")
+    ),
+    responses(
+        (status = 200, description = "Refunds aggregate retrieved successfully", body = RefundAggregateResponse),
+    ),
+    tag = "Refunds",
+    operation_id = "Get Refunds Aggregate",
+    security(("api_key" = []))
+)]
+#[cfg(feature = "v1")]
+pub async fn get_refunds_aggregates() {}
+
+/// Refunds - Aggregate For the Given profiles
+///
+/// Gets a count of refunds grouped by their status for the given profiles within a time range
+#[utoipa::path(
+    get,
+    path = "/refunds/profile/aggregate",
+    params(
+        ("start_time" = String, Query, description = "The start time for the aggregate query"),
+        ("end_time" = Option<String>, Query, description = "The end time for the aggregate query")
+    ),
+    responses(
+        (status = 200, description = "Refunds aggregate retrieved successfully", body = RefundAggregateResponse),
+    ),
+    tag = "Refunds",
+    operation_id = "Get Refunds Aggregate for the given Profiles",
+    security(("api_key" = []))
+)]
+#[cfg(feature = "v1")]
+pub async fn get_refunds_aggregate_profile() {}
+
 /// Refunds - Create
 ///
 /// Creates a refund against an already processed payment. In case of some processors, you can even opt to refund only a partial amount multiple times until the original charge amount has been refunded
@@ -268,6 +344,27 @@ pub async fn refunds_metadata_update() {}
 #[cfg(feature = "v2")]
 pub async fn refunds_retrieve() {}
 
+/// Refunds - Retrieve with Gateway Credentials
+///
+/// Retrieves a Refund, using the merchant connector details passed in the request to sync the refund status with the connector
+#[utoipa::path(
+    post,
+    path = "/v2/refunds/{id}",
+    params(
+        ("id" = String, Path, description = "The identifier for refund")
+    ),
+    request_body = RefundsRetrievePayload,
+    responses(
+        (status = 200, description = "Refund retrieved", body = RefundResponse),
+        (status = 404, description = "Refund does not exist in our records")
+    ),
+    tag = "Refunds",
+    operation_id = "Retrieve a Refund with Gateway Credentials",
+    security(("api_key" = []))
+)]
+#[cfg(feature = "v2")]
+pub async fn refunds_retrieve_with_gateway_creds() {}
+
 /// Refunds - List
 ///
 /// To list the refunds associated with a payment_id or with the merchant, if payment_id is not provided
@@ -54,3 +54,23 @@ pub async fn relay() {}
 )]
 
 pub async fn relay_retrieve() {}
+
+/// Relay - Unreferenced Refund
+///
+/// Creates a refund at the connector which is not linked to a payment, and sends it to the card of the recipient.
+#[utoipa::path(
+    post,
+    path = "/relay/unreferenced_refund",
+    request_body = UnreferencedRefundRequest,
+    responses(
+        (status = 200, description = "Unreferenced refund created", body = UnreferencedRefundResponse),
+        (status = 400, description = "Invalid data")
+    ),
+    params(
+        ("X-Profile-Id" = String, Header, description = "Profile ID for authentication")
+    ),
+    tag = "Relay",
+    operation_id = "Create an Unreferenced Refund",
+    security(("api_key" = []))
+)]
+pub async fn unreferenced_refund() {}
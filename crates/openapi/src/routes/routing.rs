@@ -254,6 +254,242 @@ pub async fn routing_retrieve_default_config_for_profiles() {}
 )]
 pub async fn routing_update_default_config_for_profile() {}
 
+#[cfg(feature = "v1")]
+/// Routing - List for Profile
+///
+/// List the routing configs of the profile of the API key, or of all the profiles of the merchant
+#[utoipa::path(
+    get,
+    path = "/routing/list/profile",
+    params(
+        ("limit" = Option<u16>, Query, description = "The number of records to be returned"),
+        ("offset" = Option<u8>, Query, description = "The record offset from which to start gathering of results"),
+        ("profile_id" = Option<String>, Query, description = "The unique identifier for a merchant profile"),
+    ),
+    responses(
+        (status = 200, description = "Successfully fetched routing configs", body = RoutingKind),
+        (status = 500, description = "Internal server error"),
+        (status = 404, description = "Resource missing")
+    ),
+   tag = "Routing",
+   operation_id = "List routing configs for profile",
+   security(("api_key" = []), ("jwt_key" = []))
+)]
+pub async fn list_routing_configs_for_profile() {}
+
+#[cfg(feature = "v1")]
+/// Routing - Create Payout Config
+///
+/// Create a routing config for payouts
+#[utoipa::path(
+    post,
+    path = "/routing/payouts",
+    request_body = RoutingConfigRequest,
+    responses(
+        (status = 200, description = "Routing config created", body = RoutingDictionaryRecord),
+        (status = 400, description = "Request body is malformed"),
+        (status = 500, description = "Internal server error"),
+        (status = 404, description = "Resource missing"),
+        (status = 422, description = "Unprocessable request"),
+        (status = 403, description = "Forbidden"),
+    ),
+   tag = "Routing",
+   operation_id = "Create a payout routing config",
+   security(("api_key" = []), ("jwt_key" = []))
+)]
+pub async fn payouts_routing_create_config() {}
+
+#[cfg(feature = "v1")]
+/// Routing - List Payout Configs
+///
+/// List all routing configs for payouts
+#[utoipa::path(
+    get,
+    path = "/routing/payouts",
+    params(
+        ("limit" = Option<u16>, Query, description = "The number of records to be returned"),
+        ("offset" = Option<u8>, Query, description = "The record offset from which to start gathering of results"),
+        ("profile_id" = Option<String>, Query, description = "The unique identifier for a merchant profile"),
+    ),
+    responses(
+        (status = 200, description = "Successfully fetched routing configs", body = RoutingKind),
+        (status = 500, description = "Internal server error"),
+        (status = 404, description = "Resource missing")
+    ),
+   tag = "Routing",
+   operation_id = "List payout routing configs",
+   security(("api_key" = []), ("jwt_key" = []))
+)]
+pub async fn payouts_list_routing_configs() {}
+
+#[cfg(feature = "v1")]
+/// Routing - List Payout Configs for Profile
+///
+/// List the payout routing configs of the profile of the API key, or of all the profiles of the merchant
+#[utoipa::path(
+    get,
+    path = "/routing/payouts/list/profile",
+    params(
+        ("limit" = Option<u16>, Query, description = "The number of records to be returned"),
+        ("offset" = Option<u8>, Query, description = "The record offset from which to start gathering of results"),
+        ("profile_id" = Option<String>, Query, description = "The unique identifier for a merchant profile"),
+    ),
+    responses(
+        (status = 200, description = "Successfully fetched routing configs", body = RoutingKind),
+        (status = 500, description = "Internal server error"),
+        (status = 404, description = "Resource missing")
+    ),
+   tag = "Routing",
+   operation_id = "List payout routing configs for profile",
+   security(("api_key" = []), ("jwt_key" = []))
+)]
+pub async fn payouts_list_routing_configs_for_profile() {}
+
+#[cfg(feature = "v1")]
+/// Routing - Retrieve Active Payout Config
+///
+/// Retrieve the active routing config for payouts
+#[utoipa::path(
+    get,
+    path = "/routing/payouts/active",
+    params(
+        ("profile_id" = Option<String>, Query, description = "The unique identifier for a merchant profile"),
+    ),
+    responses(
+        (status = 200, description = "Successfully retrieved active config", body = LinkedRoutingConfigRetrieveResponse),
+        (status = 500, description = "Internal server error"),
+        (status = 404, description = "Resource missing"),
+        (status = 403, description = "Forbidden")
+    ),
+   tag = "Routing",
+   operation_id = "Retrieve active payout config",
+   security(("api_key" = []), ("jwt_key" = []))
+)]
+pub async fn payouts_routing_retrieve_linked_config() {}
+
+#[cfg(feature = "v1")]
+/// Routing - Retrieve Default Payout Config
+///
+/// Retrieve the default fallback config for payouts
+#[utoipa::path(
+    get,
+    path = "/routing/payouts/default",
+    responses(
+        (status = 200, description = "Successfully retrieved default config", body = Vec<RoutableConnectorChoice>),
+        (status = 500, description = "Internal server error")
+    ),
+   tag = "Routing",
+   operation_id = "Retrieve default payout fallback config",
+   security(("api_key" = []), ("jwt_key" = []))
+)]
+pub async fn payouts_routing_retrieve_default_config() {}
+
+#[cfg(feature = "v1")]
+/// Routing - Update Default Payout Config
+///
+/// Update the default fallback config for payouts
+#[utoipa::path(
+    post,
+    path = "/routing/payouts/default",
+    request_body = Vec<RoutableConnectorChoice>,
+    responses(
+        (status = 200, description = "Successfully updated default config", body = Vec<RoutableConnectorChoice>),
+        (status = 500, description = "Internal server error"),
+        (status = 400, description = "Malformed request"),
+        (status = 422, description = "Unprocessable request")
+    ),
+   tag = "Routing",
+   operation_id = "Update default payout fallback config",
+   security(("api_key" = []), ("jwt_key" = []))
+)]
+pub async fn payouts_routing_update_default_config() {}
+
+#[cfg(feature = "v1")]
+/// Routing - Activate Payout Config
+///
+/// Activate a routing config for payouts
+#[utoipa::path(
+    post,
+    path = "/routing/payouts/{routing_algorithm_id}/activate",
+    params(
+        ("routing_algorithm_id" = String, Path, description = "The unique identifier for a config"),
+    ),
+    responses(
+        (status = 200, description = "Routing config activated", body = RoutingDictionaryRecord),
+        (status = 500, description = "Internal server error"),
+        (status = 404, description = "Resource missing"),
+        (status = 400, description = "Bad request")
+    ),
+   tag = "Routing",
+   operation_id = "Activate a payout routing config",
+   security(("api_key" = []), ("jwt_key" = []))
+)]
+pub async fn payouts_routing_link_config() {}
+
+#[cfg(feature = "v1")]
+/// Routing - Deactivate Payout Config
+///
+/// Deactivates a routing config for payouts
+#[utoipa::path(
+    post,
+    path = "/routing/payouts/deactivate",
+    request_body = RoutingConfigRequest,
+    responses(
+        (status = 200, description = "Successfully deactivated routing config", body = RoutingDictionaryRecord),
+        (status = 500, description = "Internal server error"),
+        (status = 400, description = "Malformed request"),
+        (status = 403, description = "Malformed request"),
+        (status = 422, description = "Unprocessable request")
+    ),
+   tag = "Routing",
+   operation_id = "Deactivate a payout routing config",
+   security(("api_key" = []), ("jwt_key" = []))
+)]
+pub async fn payouts_routing_unlink_config() {}
+
+#[cfg(feature = "v1")]
+/// Routing - Retrieve Default Payout Config For Profiles
+///
+/// Retrieve the default payout config for profiles
+#[utoipa::path(
+    get,
+    path = "/routing/payouts/default/profile",
+    responses(
+        (status = 200, description = "Successfully retrieved default config", body = ProfileDefaultRoutingConfig),
+        (status = 500, description = "Internal server error"),
+        (status = 404, description = "Resource missing")
+    ),
+   tag = "Routing",
+   operation_id = "Retrieve default payout configs for all profiles",
+   security(("api_key" = []), ("jwt_key" = []))
+)]
+pub async fn payouts_routing_retrieve_default_config_for_profiles() {}
+
+#[cfg(feature = "v1")]
+/// Routing - Update Default Payout Config For Profile
+///
+/// Update the default payout config for a profile
+#[utoipa::path(
+    post,
+    path = "/routing/payouts/default/profile/{profile_id}",
+    request_body = Vec<RoutableConnectorChoice>,
+    params(
+        ("profile_id" = String, Path, description = "The unique identifier for a profile"),
+    ),
+    responses(
+        (status = 200, description = "Successfully updated default config for profile", body = ProfileDefaultRoutingConfig),
+        (status = 500, description = "Internal server error"),
+        (status = 404, description = "Resource missing"),
+        (status = 400, description = "Malformed request"),
+        (status = 422, description = "Unprocessable request"),
+        (status = 403, description = "Forbidden"),
+    ),
+   tag = "Routing",
+   operation_id = "Update default payout configs for a profile",
+   security(("api_key" = []), ("jwt_key" = []))
+)]
+pub async fn payouts_routing_update_default_config_for_profile() {}
+
 #[cfg(feature = "v1")]
 /// Routing - Toggle success based dynamic routing for profile
 ///
@@ -360,6 +596,81 @@ pub async fn success_based_routing_update_configs() {}
 )]
 pub async fn toggle_elimination_routing() {}
 
+#[cfg(feature = "v1")]
+/// Routing - Update elimination routing config for profile
+///
+/// Update elimination based dynamic routing algorithm
+#[utoipa::path(
+    patch,
+    path = "/account/{account_id}/business_profile/{profile_id}/dynamic_routing/elimination/config/{algorithm_id}",
+    params(
+        ("account_id" = String, Path, description = "Merchant id"),
+        ("profile_id" = String, Path, description = "Profile id under which Dynamic routing needs to be toggled"),
+        ("algorithm_id" = String, Path, description = "Elimination routing algorithm id which was last activated to update the config"),
+    ),
+    request_body = EliminationRoutingConfig,
+    responses(
+        (status = 200, description = "Routing Algorithm updated", body = RoutingDictionaryRecord),
+        (status = 400, description = "Update body is malformed"),
+        (status = 500, description = "Internal server error"),
+        (status = 404, description = "Resource missing"),
+        (status = 422, description = "Unprocessable request"),
+        (status = 403, description = "Forbidden"),
+    ),
+   tag = "Routing",
+   operation_id = "Update elimination routing configs",
+   security(("api_key" = []), ("jwt_key" = []))
+)]
+pub async fn elimination_routing_update_configs() {}
+
+#[cfg(feature = "v1")]
+/// Routing - Set dynamic routing volume split for profile
+///
+/// Set the percentage of payments of the profile which are routed with dynamic routing
+#[utoipa::path(
+    post,
+    path = "/account/{account_id}/business_profile/{profile_id}/dynamic_routing/set_volume_split",
+    params(
+        ("account_id" = String, Path, description = "Merchant id"),
+        ("profile_id" = String, Path, description = "Profile id for which the volume split is set"),
+        ("split" = u8, Query, description = "Percentage of payments to be routed with dynamic routing"),
+    ),
+    responses(
+        (status = 200, description = "Volume split set"),
+        (status = 400, description = "Request is malformed"),
+        (status = 500, description = "Internal server error"),
+        (status = 404, description = "Resource missing"),
+        (status = 403, description = "Forbidden"),
+    ),
+   tag = "Routing",
+   operation_id = "Set dynamic routing volume split",
+   security(("api_key" = []), ("jwt_key" = []))
+)]
+pub async fn set_dynamic_routing_volume_split() {}
+
+#[cfg(feature = "v1")]
+/// Routing - Retrieve dynamic routing volume split for profile
+///
+/// Retrieve the percentage of payments of the profile which are routed with dynamic routing
+#[utoipa::path(
+    get,
+    path = "/account/{account_id}/business_profile/{profile_id}/dynamic_routing/get_volume_split",
+    params(
+        ("account_id" = String, Path, description = "Merchant id"),
+        ("profile_id" = String, Path, description = "Profile id for which the volume split is retrieved"),
+    ),
+    responses(
+        (status = 200, description = "Volume split retrieved", body = RoutingVolumeSplitResponse),
+        (status = 500, description = "Internal server error"),
+        (status = 404, description = "Resource missing"),
+        (status = 403, description = "Forbidden"),
+    ),
+   tag = "Routing",
+   operation_id = "Retrieve dynamic routing volume split",
+   security(("api_key" = []), ("jwt_key" = []))
+)]
+pub async fn get_dynamic_routing_volume_split() {}
+
 #[cfg(feature = "v1")]
 /// Routing - Elimination
 ///
@@ -236,6 +236,27 @@ pub async fn confirm_subscription() {}
 )]
 pub async fn get_subscription() {}
 
+/// Subscription - List
+///
+/// Lists the subscriptions of the profile.
+#[utoipa::path(
+    get,
+    path = "/subscriptions/list",
+    params(
+        ("limit" = Option<i64>, Query, description = "Number of records to return"),
+        ("offset" = Option<i64>, Query, description = "Offset for pagination"),
+        ("X-Profile-Id" = String, Header, description = "Profile ID for authentication")
+    ),
+    responses(
+        (status = 200, description = "Subscriptions retrieved successfully", body = Vec<SubscriptionResponse>),
+        (status = 400, description = "Invalid data")
+    ),
+    tag = "Subscriptions",
+    operation_id = "List Subscriptions",
+    security(("api_key" = []))
+)]
+pub async fn list_subscriptions() {}
+
 /// Subscription - Update
 ///
 /// Updates an existing subscription.
@@ -4,6 +4,7 @@ use router::{
     core::errors::{ApplicationError, ApplicationResult},
     logger,
     routes::metrics,
+    services::graceful_shutdown,
 };
 
 #[tokio::main]
@@ -45,11 +46,22 @@ async fn main() -> ApplicationResult<()> {
     );
 
     #[allow(clippy::expect_used)]
-    let server = Box::pin(router::start_server(conf, router_env::service_name!()))
-        .await
-        .expect("Failed to create the server");
+    let (server, shutdown_task_handle) =
+        Box::pin(router::start_server(conf, router_env::service_name!()))
+            .await
+            .expect("Failed to create the server");
     let _ = server.await;
 
+    // Wait for the requests, connector calls and background tasks in flight to be drained if
+    // the server was stopped by a shutdown signal, the task would otherwise wait indefinitely
+    if graceful_shutdown::is_shutting_down() {
+        if let Err(error) = shutdown_task_handle.await {
+            logger::error!(?error, "Failed to shut down the server gracefully");
+        }
+    } else {
+        shutdown_task_handle.abort();
+    }
+
     Err(error_stack::Report::from(ApplicationError::from(
         std::io::Error::other("Server shut down"),
     )))
@@ -367,6 +367,34 @@ impl ProcessTrackerWorkflows<routes::SessionState> for WorkflowRunner {
                             )
                     }
                 }
+                storage::ProcessTrackerRunner::MerchantTakeoutWorkflow => {
+                    #[cfg(all(feature = "olap", feature = "v1"))]
+                    {
+                        Ok(Box::new(workflows::merchant_takeout::MerchantTakeoutWorkflow))
+                    }
+                    #[cfg(not(all(feature = "olap", feature = "v1")))]
+                    {
+                        Err(error_stack::report!(ProcessTrackerError::UnexpectedFlow))
+                            .attach_printable(
+                                "Cannot run merchant takeout workflow when olap or v1 feature is disabled",
+                            )
+                    }
+                }
+                storage::ProcessTrackerRunner::CustomerContactRehashWorkflow => {
+                    #[cfg(all(feature = "olap", feature = "v1"))]
+                    {
+                        Ok(Box::new(
+                            workflows::customer_contact_rehash::CustomerContactRehashWorkflow,
+                        ))
+                    }
+                    #[cfg(not(all(feature = "olap", feature = "v1")))]
+                    {
+                        Err(error_stack::report!(ProcessTrackerError::UnexpectedFlow))
+                            .attach_printable(
+                                "Cannot run customer contact rehash workflow when olap or v1 feature is disabled",
+                            )
+                    }
+                }
             }
         };
 
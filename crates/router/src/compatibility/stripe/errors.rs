@@ -57,6 +57,18 @@ pub enum StripeErrorCode {
     )]
     InvalidWalletToken { wallet_name: String },
 
+    #[error(
+        error_type = StripeErrorType::InvalidRequestError, code = "wallet_token_expired",
+        message = "The {wallet_name} wallet token has expired"
+    )]
+    WalletTokenExpired { wallet_name: String },
+
+    #[error(
+        error_type = StripeErrorType::InvalidRequestError, code = "quota_exceeded",
+        message = "The {quota} quota of the merchant for the current period has been exhausted"
+    )]
+    QuotaExceeded { quota: String },
+
     #[error(error_type = StripeErrorType::ApiError, code = "refund_failed", message = "refund has failed")]
     RefundFailed, // stripe error code
 
@@ -597,7 +609,8 @@ impl From<errors::ApiErrorResponse> for StripeErrorCode {
             errors::ApiErrorResponse::InvalidRequestData { message } => {
                 Self::InvalidRequestData { message }
             }
-            errors::ApiErrorResponse::PreconditionFailed { message } => {
+            errors::ApiErrorResponse::PreconditionFailed { message }
+            | errors::ApiErrorResponse::PaymentCaptureConflict { message } => {
                 Self::PreconditionFailed { message }
             }
             errors::ApiErrorResponse::InvalidDataValue { field_name } => Self::ParameterMissing {
@@ -605,6 +618,7 @@ impl From<errors::ApiErrorResponse> for StripeErrorCode {
                 param: field_name.to_string(),
             },
             errors::ApiErrorResponse::MaximumRefundCount => Self::MaximumRefundCount,
+            errors::ApiErrorResponse::QuotaExceeded { quota } => Self::QuotaExceeded { quota },
             errors::ApiErrorResponse::PaymentNotSucceeded => Self::PaymentFailed,
             errors::ApiErrorResponse::DuplicateMandate => Self::DuplicateMandate,
             errors::ApiErrorResponse::SuccessfulPaymentNotFound => Self::SuccessfulPaymentNotFound,
@@ -683,6 +697,9 @@ impl From<errors::ApiErrorResponse> for StripeErrorCode {
             errors::ApiErrorResponse::InvalidWalletToken { wallet_name } => {
                 Self::InvalidWalletToken { wallet_name }
             }
+            errors::ApiErrorResponse::WalletTokenExpired { wallet_name } => {
+                Self::WalletTokenExpired { wallet_name }
+            }
             errors::ApiErrorResponse::ExtendedCardInfoNotFound => Self::ExtendedCardInfoNotFound,
             errors::ApiErrorResponse::LinkConfigurationError { message } => {
                 Self::LinkConfigurationError { message }
@@ -731,9 +748,9 @@ impl actix_web::ResponseError for StripeErrorCode {
             | Self::PlatformUnauthorizedRequest
             | Self::ConnectedUnauthorizedRequest => StatusCode::UNAUTHORIZED,
             Self::InvalidRequestUrl | Self::GenericNotFoundError { .. } => StatusCode::NOT_FOUND,
-            Self::ParameterUnknown { .. } | Self::HyperswitchUnprocessableEntity { .. } => {
-                StatusCode::UNPROCESSABLE_ENTITY
-            }
+            Self::ParameterUnknown { .. }
+            | Self::HyperswitchUnprocessableEntity { .. }
+            | Self::QuotaExceeded { .. } => StatusCode::UNPROCESSABLE_ENTITY,
             Self::ParameterMissing { .. }
             | Self::RefundAmountExceedsPaymentAmount { .. }
             | Self::PaymentIntentAuthenticationFailure { .. }
@@ -767,6 +784,7 @@ impl actix_web::ResponseError for StripeErrorCode {
             | Self::SerdeQsError { .. }
             | Self::InvalidRequestData { .. }
             | Self::InvalidWalletToken { .. }
+            | Self::WalletTokenExpired { .. }
             | Self::PreconditionFailed { .. }
             | Self::DuplicateMandate
             | Self::SuccessfulPaymentNotFound
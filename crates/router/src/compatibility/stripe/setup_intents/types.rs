@@ -413,6 +413,9 @@ pub enum StripeNextAction {
     InvokeDdc {
         ddc_data: payments::DDCData,
     },
+    InvokeConnectorSdk {
+        connector_sdk_data: payments::ConnectorSdkNextActionData,
+    },
 }
 
 pub(crate) fn into_stripe_next_action(
@@ -502,6 +505,9 @@ pub(crate) fn into_stripe_next_action(
         payments::NextActionData::InvokeDdc { ddc_data } => {
             StripeNextAction::InvokeDdc { ddc_data }
         }
+        payments::NextActionData::InvokeConnectorSdk { connector_sdk_data } => {
+            StripeNextAction::InvokeConnectorSdk { connector_sdk_data }
+        }
     })
 }
 
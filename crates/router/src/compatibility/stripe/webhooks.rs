@@ -91,6 +91,7 @@ pub enum StripeWebhookObject {
     #[cfg(feature = "payouts")]
     Payout(StripePayoutResponse),
     Subscriptions,
+    QuotaUsage(Box<api_models::merchant_quota::QuotaThresholdReachedResponse>),
 }
 
 #[derive(Serialize, Debug)]
@@ -291,6 +292,13 @@ fn get_stripe_event_type(event_type: api_models::enums::EventType) -> &'static s
         api_models::enums::EventType::MandateRevoked => "mandate.revoked",
         api_models::enums::EventType::SurchargePaymentSucceeded => "surcharge_payment.succeeded",
         api_models::enums::EventType::SurchargeRefundSucceeded => "surcharge_refund.succeeded",
+        api_models::enums::EventType::RecurringPaymentRetryScheduled => {
+            "recurring_payment.retry_scheduled"
+        }
+        api_models::enums::EventType::RecurringPaymentRecoveryExhausted => {
+            "recurring_payment.recovery_exhausted"
+        }
+        api_models::enums::EventType::QuotaThresholdReached => "quota.threshold_reached",
 
         // as per this doc https://stripe.com/docs/api/events/types#event_types-payment_intent.amount_capturable_updated
         api_models::enums::EventType::PaymentAuthorized => {
@@ -351,6 +359,9 @@ impl From<api::OutgoingWebhookContent> for StripeWebhookObject {
             api_models::webhooks::OutgoingWebhookContent::SubscriptionDetails(_) => {
                 Self::Subscriptions
             }
+            api::OutgoingWebhookContent::QuotaUsageDetails(quota_usage) => {
+                Self::QuotaUsage(quota_usage)
+            }
         }
     }
 }
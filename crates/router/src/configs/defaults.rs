@@ -96,6 +96,28 @@ impl Default for super::settings::Refund {
     }
 }
 
+impl Default for super::settings::ConnectorSmokeTestConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            interval_in_secs: None,
+            timeout_in_secs: 5,
+            connectors: Vec::new(),
+        }
+    }
+}
+
+impl Default for super::settings::ConnectorWarmupConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            interval_in_secs: 60,
+            timeout_in_secs: 5,
+            connectors: Vec::new(),
+        }
+    }
+}
+
 impl Default for super::settings::EphemeralConfig {
     fn default() -> Self {
         Self { validity: 1 }
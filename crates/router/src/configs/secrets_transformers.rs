@@ -92,6 +92,25 @@ impl SecretsHandler for settings::ForexApi {
     }
 }
 
+#[async_trait::async_trait]
+impl SecretsHandler for settings::MerchantTakeoutConfig {
+    async fn convert_to_raw_secret(
+        value: SecretStateContainer<Self, SecuredSecret>,
+        secret_management_client: &dyn SecretManagementInterface,
+    ) -> CustomResult<SecretStateContainer<Self, RawSecret>, SecretsManagementError> {
+        let merchant_takeout = value.get_inner();
+
+        let download_url_signing_key = secret_management_client
+            .get_secret(merchant_takeout.download_url_signing_key.clone())
+            .await?;
+
+        Ok(value.transition_state(|merchant_takeout| Self {
+            download_url_signing_key,
+            ..merchant_takeout
+        }))
+    }
+}
+
 #[async_trait::async_trait]
 impl SecretsHandler for settings::ApiKeys {
     async fn convert_to_raw_secret(
@@ -261,16 +280,18 @@ impl SecretsHandler for settings::Secrets {
         secret_management_client: &dyn SecretManagementInterface,
     ) -> CustomResult<SecretStateContainer<Self, RawSecret>, SecretsManagementError> {
         let secrets = value.get_inner();
-        let (jwt_secret, admin_api_key, master_enc_key) = tokio::try_join!(
+        let (jwt_secret, admin_api_key, master_enc_key, search_hash_key) = tokio::try_join!(
             secret_management_client.get_secret(secrets.jwt_secret.clone()),
             secret_management_client.get_secret(secrets.admin_api_key.clone()),
-            secret_management_client.get_secret(secrets.master_enc_key.clone())
+            secret_management_client.get_secret(secrets.master_enc_key.clone()),
+            secret_management_client.get_secret(secrets.search_hash_key.clone())
         )?;
 
         Ok(value.transition_state(|_| Self {
             jwt_secret,
             admin_api_key,
             master_enc_key,
+            search_hash_key,
         }))
     }
 }
@@ -472,6 +493,14 @@ pub(crate) async fn fetch_raw_secrets(
             .await
             .expect("Failed to decrypt forex api configs");
 
+    #[allow(clippy::expect_used)]
+    let merchant_takeout = settings::MerchantTakeoutConfig::convert_to_raw_secret(
+        conf.merchant_takeout,
+        secret_management_client,
+    )
+    .await
+    .expect("Failed to decrypt merchant takeout configs");
+
     #[allow(clippy::expect_used)]
     let jwekey = settings::Jwekey::convert_to_raw_secret(conf.jwekey, secret_management_client)
         .await
@@ -636,6 +665,11 @@ pub(crate) async fn fetch_raw_secrets(
         required_fields: conf.required_fields,
         delayed_session_response: conf.delayed_session_response,
         webhook_source_verification_call: conf.webhook_source_verification_call,
+        connector_request_locale: conf.connector_request_locale,
+        connector_request_header_order: conf.connector_request_header_order,
+        connector_error_message_path: conf.connector_error_message_path,
+        connector_call_outcome_webhook: conf.connector_call_outcome_webhook,
+        connector_error_severity: conf.connector_error_severity,
         billing_connectors_payment_sync: conf.billing_connectors_payment_sync,
         billing_connectors_invoice_sync: conf.billing_connectors_invoice_sync,
         payment_method_auth,
@@ -696,11 +730,16 @@ pub(crate) async fn fetch_raw_secrets(
         enhancement: conf.enhancement,
         proxy_status_mapping: conf.proxy_status_mapping,
         trace_header: conf.trace_header,
+        wallet_token_expiry: conf.wallet_token_expiry,
+        merchant_takeout,
         internal_services: conf.internal_services,
         micro_services: conf.micro_services,
         superposition,
         comparison_service: conf.comparison_service,
         authentication_service_enabled_connectors: conf.authentication_service_enabled_connectors,
         save_payment_method_on_session: conf.save_payment_method_on_session,
+        connector_smoke_test: conf.connector_smoke_test,
+        connector_warmup: conf.connector_warmup,
+        redirect_completion: conf.redirect_completion,
     }
 }
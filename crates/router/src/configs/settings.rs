@@ -136,6 +136,15 @@ pub struct Settings<S: SecretState> {
     pub required_fields: RequiredFields,
     pub delayed_session_response: DelayedSessionConfig,
     pub webhook_source_verification_call: WebhookSourceVerificationCall,
+    pub connector_request_locale: ConnectorRequestLocaleConfig,
+    #[serde(default)]
+    pub connector_request_header_order: ConnectorRequestHeaderOrderConfig,
+    #[serde(default)]
+    pub connector_error_message_path: ConnectorErrorMessagePathConfig,
+    #[serde(default)]
+    pub connector_call_outcome_webhook: ConnectorCallOutcomeWebhook,
+    #[serde(default)]
+    pub connector_error_severity: ConnectorErrorSeverityConfig,
     pub billing_connectors_payment_sync: BillingConnectorPaymentsSyncCall,
     pub billing_connectors_invoice_sync: BillingConnectorInvoiceSyncCall,
     pub payment_method_auth: SecretStateContainer<PaymentMethodAuth, S>,
@@ -196,12 +205,20 @@ pub struct Settings<S: SecretState> {
     pub superposition: SecretStateContainer<SuperpositionClientConfig, S>,
     pub proxy_status_mapping: ProxyStatusMapping,
     pub trace_header: TraceHeaderConfig,
+    pub wallet_token_expiry: WalletTokenExpiryConfig,
+    pub merchant_takeout: SecretStateContainer<MerchantTakeoutConfig, S>,
     pub internal_services: InternalServicesConfig,
     #[serde(default)]
     pub micro_services: MicroServicesConfig,
     pub comparison_service: Option<ComparisonServiceConfig>,
     pub authentication_service_enabled_connectors: AuthenticationServiceEnabledConnectors,
     pub save_payment_method_on_session: OnSessionConfig,
+    #[serde(default)]
+    pub connector_smoke_test: ConnectorSmokeTestConfig,
+    #[serde(default)]
+    pub connector_warmup: ConnectorWarmupConfig,
+    #[serde(default)]
+    pub redirect_completion: RedirectCompletionConfig,
 }
 
 #[cfg(feature = "deja")]
@@ -392,6 +409,41 @@ pub struct OnSessionConfig {
         HashMap<enums::PaymentMethod, HashSet<enums::PaymentMethodType>>,
 }
 
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct ConnectorSmokeTestConfig {
+    /// Check that the connector base URLs resolve and complete a TLS handshake on startup
+    pub enabled: bool,
+    /// Interval at which the smoke test is repeated, runs only once on startup if not set
+    pub interval_in_secs: Option<u64>,
+    /// Timeout for checking a single connector base URL
+    pub timeout_in_secs: u64,
+    /// Connectors to be checked, all connectors are checked if empty
+    pub connectors: Vec<enums::Connector>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct ConnectorWarmupConfig {
+    /// Periodically send requests to connector base URLs to keep pooled connections warm
+    pub enabled: bool,
+    /// Interval at which the connector base URLs are requested
+    pub interval_in_secs: u64,
+    /// Timeout for a single warmup request
+    pub timeout_in_secs: u64,
+    /// Connectors whose connections are to be kept warm
+    pub connectors: Vec<enums::Connector>,
+}
+
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct RedirectCompletionConfig {
+    /// Skip the connector call on redirect completion if the attempt is already in a terminal
+    /// status (for example, when a webhook arrived before the customer's browser returned), and
+    /// return the final status right away
+    pub short_circuit_terminal_attempts: bool,
+}
+
 #[derive(Debug, Deserialize, Clone, Default)]
 pub struct DebitRoutingConfig {
     #[serde(deserialize_with = "deserialize_hashmap")]
@@ -910,6 +962,10 @@ pub struct Secrets {
     pub jwt_secret: Secret<String>,
     pub admin_api_key: Secret<String>,
     pub master_enc_key: Secret<String>,
+    /// Key from which the keys used for hashing customer emails and phone numbers for search are
+    /// derived, one per tenant. Searching payments by customer email or phone number is disabled
+    /// if not configured.
+    pub search_hash_key: Secret<String>,
 }
 
 #[derive(Debug, Default, Deserialize, Clone)]
@@ -1148,6 +1204,59 @@ impl Default for TraceHeaderConfig {
     }
 }
 
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct WalletTokenExpiryConfig {
+    /// Tolerance for the clock skew between the wallet and the application, applied when
+    /// checking whether a wallet token has expired
+    pub clock_skew_tolerance_in_secs: u32,
+}
+
+impl Default for WalletTokenExpiryConfig {
+    fn default() -> Self {
+        Self {
+            clock_skew_tolerance_in_secs: 60,
+        }
+    }
+}
+
+impl WalletTokenExpiryConfig {
+    pub fn get_clock_skew_tolerance(&self) -> time::Duration {
+        time::Duration::seconds(i64::from(self.clock_skew_tolerance_in_secs))
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct MerchantTakeoutConfig {
+    /// Key used for signing the download URLs of takeout files
+    pub download_url_signing_key: Secret<String>,
+    /// Validity of the download URLs of takeout files
+    pub download_url_expiry_in_secs: u32,
+    /// Maximum number of records exported in a single takeout file
+    pub records_per_file: u16,
+}
+
+impl Default for MerchantTakeoutConfig {
+    fn default() -> Self {
+        Self {
+            download_url_signing_key: Secret::default(),
+            download_url_expiry_in_secs: 3600,
+            records_per_file: 1000,
+        }
+    }
+}
+
+impl MerchantTakeoutConfig {
+    fn validate(&self) -> Result<(), ApplicationError> {
+        common_utils::fp_utils::when(self.records_per_file == 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "merchant takeout records per file must be greater than zero".into(),
+            ))
+        })
+    }
+}
+
 #[derive(Debug, Clone, Default, Deserialize)]
 #[serde(default)]
 pub struct WebhooksSettings {
@@ -1196,6 +1305,52 @@ pub struct WebhookSourceVerificationCall {
     pub connectors_with_webhook_source_verification_call: HashSet<enums::Connector>,
 }
 
+#[derive(Debug, Deserialize, Clone, Default)]
+pub struct ConnectorRequestLocaleConfig {
+    #[serde(deserialize_with = "deserialize_hashset")]
+    pub connectors_with_locale_header: HashSet<enums::Connector>,
+}
+
+/// Order of the request headers for the connectors which are sensitive to it, such as connectors
+/// whose signature schemes depend on the order of the headers
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(transparent)]
+pub struct ConnectorRequestHeaderOrderConfig {
+    #[serde(deserialize_with = "deserialize_header_order")]
+    pub header_order: HashMap<enums::Connector, Vec<String>>,
+}
+
+/// Path to the human-readable error message in the JSON error responses of the connectors, for
+/// the connectors for which it differs from the path the connector extracts the message from
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(transparent)]
+pub struct ConnectorErrorMessagePathConfig {
+    pub paths: HashMap<enums::Connector, String>,
+}
+
+/// Severity of the categories of connector errors, for the connectors for which it differs from
+/// the default severity of the category
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(transparent)]
+pub struct ConnectorErrorSeverityConfig {
+    pub severity_overrides: HashMap<
+        enums::Connector,
+        HashMap<
+            hyperswitch_interfaces::connector_error_severity::ConnectorErrorCategory,
+            hyperswitch_interfaces::connector_error_severity::ErrorSeverity,
+        >,
+    >,
+}
+
+/// Internal webhook to which a summary of the outcome of each connector call is posted, for
+/// piping the outcomes into external observability pipelines
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct ConnectorCallOutcomeWebhook {
+    pub enabled: bool,
+    pub url: String,
+}
+
 #[derive(Debug, Deserialize, Clone, Default)]
 pub struct BillingConnectorPaymentsSyncCall {
     #[serde(deserialize_with = "deserialize_hashset")]
@@ -1422,6 +1577,7 @@ impl Settings<SecuredSecret> {
         #[cfg(feature = "kv_store")]
         self.drainer.validate()?;
         self.api_keys.get_inner().validate()?;
+        self.merchant_takeout.get_inner().validate()?;
 
         self.file_storage
             .validate()
@@ -1479,6 +1635,8 @@ impl Settings<SecuredSecret> {
 
         self.open_router.validate()?;
 
+        self.connector_call_outcome_webhook.validate()?;
+
         // Validate gRPC client settings
         #[cfg(feature = "revenue_recovery")]
         self.grpc_client
@@ -1671,6 +1829,35 @@ where
         .map_err(D::Error::custom)
 }
 
+fn deserialize_header_order<'a, D>(
+    deserializer: D,
+) -> Result<HashMap<enums::Connector, Vec<String>>, D::Error>
+where
+    D: serde::Deserializer<'a>,
+{
+    use serde::de::Error;
+
+    <HashMap<String, String>>::deserialize(deserializer)?
+        .into_iter()
+        .map(|(connector, header_order)| {
+            let connector = connector
+                .trim()
+                .parse::<enums::Connector>()
+                .map_err(|error| {
+                    D::Error::custom(format!(
+                        "Unable to deserialize `{connector}` as `Connector`: {error}"
+                    ))
+                })?;
+            let header_order = header_order
+                .split(',')
+                .map(|header_name| header_name.trim().to_string())
+                .filter(|header_name| !header_name.is_empty())
+                .collect();
+            Ok((connector, header_order))
+        })
+        .collect()
+}
+
 fn deserialize_hashset_inner<T>(value: impl AsRef<str>) -> Result<HashSet<T>, String>
 where
     T: Eq + std::str::FromStr + std::hash::Hash,
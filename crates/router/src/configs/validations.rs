@@ -370,3 +370,15 @@ impl super::settings::SageSettings {
         )
     }
 }
+
+impl super::settings::ConnectorCallOutcomeWebhook {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.enabled && self.url.trim().is_empty(), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "connector call outcome webhook url must be set if the webhook is enabled".into(),
+            ))
+        })
+    }
+}
@@ -29,6 +29,9 @@ pub const REQUEST_TIME_OUT: u64 = 30;
 pub const REQUEST_TIMEOUT_PAYMENT_NOT_FOUND: &str = "Timed out ,payment not found";
 pub const REQUEST_TIMEOUT_ERROR_MESSAGE_FROM_PSYNC: &str =
     "This Payment has been moved to failed as there is no response from the connector";
+pub const FORCE_CLOSED_PAYMENT_ERROR_CODE: &str = "operator_closed";
+pub const FORCE_CLOSED_PAYMENT_ERROR_MESSAGE: &str =
+    "This Payment has been moved to failed by an operator as it was not resolved by the connector";
 
 ///Payment intent fulfillment default timeout (in seconds)
 pub const DEFAULT_FULFILLMENT_TIME: i64 = 15 * 60;
@@ -59,6 +62,9 @@ pub const ROUTING_CONFIG_ID_LENGTH: usize = 10;
 pub const LOCKER_REDIS_PREFIX: &str = "LOCKER_PM_TOKEN";
 pub const LOCKER_REDIS_EXPIRY_SECONDS: u32 = 60 * 15; // 15 minutes
 
+/// Prefix of the redis key locking the creation of the connector customer of a customer
+pub const CONNECTOR_CUSTOMER_LOCK_PREFIX: &str = "CONNECTOR_CUSTOMER_LOCK";
+
 pub const JWT_TOKEN_TIME_IN_SECS: u64 = 60 * 60 * 24 * 2; // 2 days
 pub const JWT_EMBEDDED_TOKEN_TIME_IN_SECS: u64 = 60 * 60 * 3; // 3 hours
 
@@ -79,6 +85,7 @@ pub const EMAIL_TOKEN_TIME_IN_SECS: u64 = 60 * 60 * 24; // 1 day
 pub const EMAIL_TOKEN_BLACKLIST_PREFIX: &str = "BET_";
 
 pub const EMAIL_SUBJECT_API_KEY_EXPIRY: &str = "API Key Expiry Notice";
+pub const EMAIL_SUBJECT_RECURRING_PAYMENT_FAILED: &str = "Your recurring payment has failed";
 pub const EMAIL_SUBJECT_DASHBOARD_FEATURE_REQUEST: &str = "Dashboard Pro Feature Request by";
 pub const EMAIL_SUBJECT_APPROVAL_RECON_REQUEST: &str =
     "Approval of Recon Request - Access Granted to Recon Dashboard";
@@ -126,6 +133,13 @@ pub const OUTGOING_CALL_URL: &str = "https://api.stripe.com/healthcheck";
 // 15 minutes = 900 seconds
 pub const POLL_ID_TTL: i64 = 900;
 
+/// Time for which the trace context of an authorize pending a redirect is retained, for the
+/// redirect completion to be linked to it
+// 15 minutes = 900 seconds
+pub const AUTHORIZE_TRACE_CONTEXT_TTL: i64 = 900;
+
+pub const AUTHORIZE_TRACE_CONTEXT_PREFIX: &str = "authorize_trace_context";
+
 // 15 minutes = 900 seconds
 pub const AUTHENTICATION_ELIGIBILITY_CHECK_DATA_TTL: i64 = 900;
 
@@ -139,6 +153,10 @@ pub const AUTHENTICATION_ELIGIBILITY_CHECK_DATA_KEY: &str = "AUTH_ELIGIBILITY_CH
 pub const DEFAULT_POLL_DELAY_IN_SECS: i8 = 2;
 pub const DEFAULT_POLL_FREQUENCY: i8 = 5;
 
+// Poll config for payments returned as processing once their confirm time budget is used up
+pub const CONFIRM_TIME_BUDGET_POLL_DELAY_IN_SECS: u16 = 5;
+pub const CONFIRM_TIME_BUDGET_POLL_FREQUENCY: u16 = 12;
+
 // Number of seconds to subtract from access token expiry
 pub(crate) const REDUCE_ACCESS_TOKEN_EXPIRY_TIME: u8 = 15;
 pub const CONNECTOR_CREDS_TOKEN_TTL: i64 = 900;
@@ -227,6 +245,9 @@ pub const CLICK_TO_PAY: &str = "click_to_pay";
 pub const AUTHENTICATION_SERVICE_ELIGIBLE_CONFIG: &str =
     "merchants_eligible_for_authentication_service";
 
+/// Config key of the headers logged in clear text, when updated at runtime
+pub const UNMASKED_HEADERS_CONFIG: &str = "unmasked_headers";
+
 /// Payment flow identifier used for performing GSM operations
 pub const PAYMENT_FLOW_STR: &str = "Payment";
 
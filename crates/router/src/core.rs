@@ -17,9 +17,13 @@ pub mod conditional_config;
 pub mod configs;
 #[cfg(feature = "olap")]
 pub mod connector_onboarding;
+pub mod connector_smoke_test;
 pub mod connector_validation;
+pub mod connector_warmup;
 #[cfg(any(feature = "olap", feature = "oltp"))]
 pub mod currency;
+#[cfg(feature = "v1")]
+pub mod customer_search;
 pub mod customers;
 #[cfg(feature = "v1")]
 pub mod debit_routing;
@@ -34,6 +38,10 @@ pub mod gsm;
 pub mod health_check;
 pub mod mandate;
 pub mod merchant_connector_webhook_management;
+#[cfg(feature = "v1")]
+pub mod merchant_quota;
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub mod merchant_takeout;
 pub mod metrics;
 pub mod payment_link;
 #[cfg(feature = "v2")]
@@ -3483,6 +3483,10 @@ impl ProfileCreateBridge for api::ProfileCreate {
             helpers::validate_session_expiry(session_expiry.to_owned())?;
         }
 
+        if let Some(time_zone) = &self.time_zone {
+            helpers::validate_time_zone(time_zone)?;
+        }
+
         if let Some(intent_fulfillment_expiry) = self.intent_fulfillment_time {
             helpers::validate_intent_fulfillment_expiry(intent_fulfillment_expiry)?;
         }
@@ -3703,6 +3707,8 @@ impl ProfileCreateBridge for api::ProfileCreate {
             surcharge_connector_details: self
                 .surcharge_connector_details
                 .map(ForeignInto::foreign_into),
+            confirm_time_budget_in_secs: self.confirm_time_budget_in_secs.map(i64::from),
+            time_zone: self.time_zone,
         }))
     }
 
@@ -3717,6 +3723,10 @@ impl ProfileCreateBridge for api::ProfileCreate {
             helpers::validate_session_expiry(session_expiry.to_owned())?;
         }
 
+        if let Some(time_zone) = &self.time_zone {
+            helpers::validate_time_zone(time_zone)?;
+        }
+
         // Generate a unique profile id
         // TODO: the profile_id should be generated from the profile_name
         let profile_id = common_utils::generate_profile_id_of_default_length();
@@ -3863,6 +3873,8 @@ impl ProfileCreateBridge for api::ProfileCreate {
             surcharge_connector_details: self
                 .surcharge_connector_details
                 .map(ForeignInto::foreign_into),
+            confirm_time_budget_in_secs: self.confirm_time_budget_in_secs.map(i64::from),
+            time_zone: self.time_zone,
         }))
     }
 }
@@ -4063,6 +4075,10 @@ impl ProfileUpdateBridge for api::ProfileUpdate {
             helpers::validate_session_expiry(session_expiry.to_owned())?;
         }
 
+        if let Some(time_zone) = &self.time_zone {
+            helpers::validate_time_zone(time_zone)?;
+        }
+
         if let Some(intent_fulfillment_expiry) = self.intent_fulfillment_time {
             helpers::validate_intent_fulfillment_expiry(intent_fulfillment_expiry)?;
         }
@@ -4288,6 +4304,8 @@ impl ProfileUpdateBridge for api::ProfileUpdate {
                 surcharge_connector_details: self
                     .surcharge_connector_details
                     .map(ForeignInto::foreign_into),
+                confirm_time_budget_in_secs: self.confirm_time_budget_in_secs.map(i64::from),
+                time_zone: self.time_zone,
             },
         )))
     }
@@ -4306,6 +4324,10 @@ impl ProfileUpdateBridge for api::ProfileUpdate {
             helpers::validate_session_expiry(session_expiry.to_owned())?;
         }
 
+        if let Some(time_zone) = &self.time_zone {
+            helpers::validate_time_zone(time_zone)?;
+        }
+
         let webhook_details = self.webhook_details.map(ForeignInto::foreign_into);
 
         let payment_link_config = self
@@ -4442,6 +4464,8 @@ impl ProfileUpdateBridge for api::ProfileUpdate {
                 surcharge_connector_details: self
                     .surcharge_connector_details
                     .map(ForeignInto::foreign_into),
+                confirm_time_budget_in_secs: self.confirm_time_budget_in_secs.map(i64::from),
+                time_zone: self.time_zone,
             },
         )))
     }
@@ -1,5 +1,6 @@
 pub mod dimension_config;
 pub mod dimension_state;
+pub mod unmasked_headers;
 use common_utils::errors::CustomResult;
 pub use dimension_config::{
     EnableExtendedCardBin, ImplicitCustomerUpdate, RequiresCvv, ShouldCallGsm,
@@ -0,0 +1,314 @@
+//! Headers of incoming requests that are logged in clear text.
+//!
+//! The headers are read from the `unmasked_headers` config, and can be replaced at runtime through
+//! the configs table. Headers carrying credentials are always masked, irrespective of either.
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use actix_web::http::header::{HeaderMap, HeaderName, HeaderValue};
+use error_stack::ResultExt;
+use hyperswitch_masking::PeekInterface;
+use router_env::logger;
+
+use crate::{
+    configs::settings::UnmaskedHeaders,
+    consts,
+    core::errors::{self, RouterResponse, RouterResult},
+    db::GlobalStorageInterface,
+    events::audit_events::{AuditEvent, AuditEventType},
+    headers,
+    routes::{app::ReqState, SessionState},
+    services::ApplicationResponse,
+    types::{api, storage},
+};
+
+pub const MASKED_HEADER_VALUE: &str = "**MASKED**";
+
+/// Headers carrying credentials, which are never logged in clear text
+const ALWAYS_MASKED_HEADERS: [&str; 9] = [
+    headers::AUTHORIZATION,
+    headers::API_KEY,
+    headers::APIKEY,
+    headers::X_API_KEY,
+    headers::X_INTERNAL_API_KEY,
+    headers::X_CLIENT_SECRET,
+    headers::STRIPE_COMPATIBLE_WEBHOOK_SIGNATURE,
+    "cookie",
+    "proxy-authorization",
+];
+
+// Cached when the headers were not updated at runtime, so that the configs table is not queried
+// for every incoming request.
+const NOT_UPDATED_AT_RUNTIME: &str = "null";
+
+fn is_always_masked(key: &str) -> bool {
+    ALWAYS_MASKED_HEADERS
+        .iter()
+        .any(|header| header.eq_ignore_ascii_case(key))
+}
+
+/// Mask the values of the headers that are not allowed to be logged in clear text
+pub fn mask_headers(
+    headers: &HeaderMap,
+    unmasked_keys: &HashSet<String>,
+) -> HashMap<String, HeaderValue> {
+    headers
+        .iter()
+        .fold(HashMap::new(), |mut acc, (key, value)| {
+            let key = key.to_string();
+            if unmasked_keys.contains(&key.to_lowercase()) && !is_always_masked(&key) {
+                acc.insert(key, value.clone());
+            } else {
+                acc.insert(key, HeaderValue::from_static(MASKED_HEADER_VALUE));
+            }
+            acc
+        })
+}
+
+/// Get the headers to be logged in clear text. The headers updated at runtime take precedence over
+/// the config, which is also used if the headers updated at runtime could not be fetched.
+pub async fn get_unmasked_header_keys(
+    store: &dyn GlobalStorageInterface,
+    config: &UnmaskedHeaders,
+) -> HashSet<String> {
+    find_runtime_unmasked_header_keys(store)
+        .await
+        .unwrap_or_else(|error| {
+            logger::error!(
+                ?error,
+                "Failed to fetch the unmasked headers, falling back to the config"
+            );
+            None
+        })
+        .unwrap_or_else(|| config.keys.clone())
+}
+
+async fn find_runtime_unmasked_header_keys(
+    store: &dyn GlobalStorageInterface,
+) -> RouterResult<Option<HashSet<String>>> {
+    let config = store
+        .find_config_by_key_unwrap_or(
+            consts::UNMASKED_HEADERS_CONFIG,
+            Some(NOT_UPDATED_AT_RUNTIME.to_string()),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the unmasked headers config")?;
+
+    serde_json::from_str(&config.config)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to deserialize the unmasked headers config")
+}
+
+fn validate_unmasked_header_keys(keys: Vec<String>) -> RouterResult<HashSet<String>> {
+    keys.into_iter()
+        .map(|key| -> RouterResult<String> {
+            let key = key.trim().to_lowercase();
+            if is_always_masked(&key) {
+                Err(errors::ApiErrorResponse::InvalidRequestData {
+                    message: format!("`{key}` header cannot be logged in clear text"),
+                })?
+            }
+            HeaderName::try_from(key.as_str()).change_context(
+                errors::ApiErrorResponse::InvalidRequestData {
+                    message: format!("`{key}` is not a valid header name"),
+                },
+            )?;
+            Ok(key)
+        })
+        .collect()
+}
+
+fn sorted_keys(keys: &HashSet<String>) -> Vec<String> {
+    let mut keys = keys.iter().cloned().collect::<Vec<_>>();
+    keys.sort();
+    keys
+}
+
+pub async fn retrieve_unmasked_headers(
+    state: SessionState,
+) -> RouterResponse<api::UnmaskedHeadersResponse> {
+    let runtime_keys = find_runtime_unmasked_header_keys(state.global_store.as_ref()).await?;
+    let is_runtime_override = runtime_keys.is_some();
+    let keys = runtime_keys.unwrap_or_else(|| state.conf.unmasked_headers.keys.clone());
+
+    Ok(ApplicationResponse::Json(api::UnmaskedHeadersResponse {
+        keys: sorted_keys(&keys),
+        is_runtime_override,
+    }))
+}
+
+pub async fn update_unmasked_headers(
+    state: SessionState,
+    req_state: ReqState,
+    request: api::UnmaskedHeadersUpdate,
+) -> RouterResponse<api::UnmaskedHeadersResponse> {
+    let store = state.global_store.as_ref();
+    let runtime_keys = request
+        .keys
+        .map(validate_unmasked_header_keys)
+        .transpose()?;
+    let previous_keys = get_unmasked_header_keys(store, &state.conf.unmasked_headers).await;
+
+    match &runtime_keys {
+        Some(keys) => {
+            let config = serde_json::to_string(&sorted_keys(keys))
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to serialize the unmasked headers config")?;
+            let update = store
+                .update_config_by_key(
+                    consts::UNMASKED_HEADERS_CONFIG,
+                    storage::ConfigUpdate::Update {
+                        config: Some(config.clone()),
+                    },
+                )
+                .await;
+            match update {
+                Err(error) if error.current_context().is_db_not_found() => store
+                    .insert_config(storage::ConfigNew {
+                        key: consts::UNMASKED_HEADERS_CONFIG.to_string(),
+                        config,
+                    })
+                    .await
+                    .map(|_| ()),
+                result => result.map(|_| ()),
+            }
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to update the unmasked headers config")?;
+        }
+        None => {
+            if let Err(error) = store
+                .delete_config_by_key(consts::UNMASKED_HEADERS_CONFIG)
+                .await
+            {
+                if !error.current_context().is_db_not_found() {
+                    return Err(error
+                        .change_context(errors::ApiErrorResponse::InternalServerError)
+                        .attach_printable("Failed to delete the unmasked headers config"));
+                }
+            }
+        }
+    }
+
+    let is_runtime_override = runtime_keys.is_some();
+    let keys = runtime_keys.unwrap_or_else(|| state.conf.unmasked_headers.keys.clone());
+    if keys != previous_keys {
+        logger::info!(
+            previous_keys = ?sorted_keys(&previous_keys),
+            keys = ?sorted_keys(&keys),
+            "Updated the headers logged in clear text"
+        );
+        req_state
+            .event_context
+            .event(AuditEvent::new(AuditEventType::UnmaskedHeadersUpdated {
+                previous_keys: sorted_keys(&previous_keys),
+                keys: sorted_keys(&keys),
+            }))
+            .emit();
+    }
+
+    Ok(ApplicationResponse::Json(api::UnmaskedHeadersResponse {
+        keys: sorted_keys(&keys),
+        is_runtime_override,
+    }))
+}
+
+/// Render the headers the same way as they would be logged for an incoming request
+pub async fn preview_unmasked_headers(
+    state: SessionState,
+    request: api::UnmaskedHeadersPreviewRequest,
+) -> RouterResponse<api::UnmaskedHeadersPreviewResponse> {
+    let mut headers = HeaderMap::new();
+    for (key, value) in request.headers {
+        let name = HeaderName::try_from(key.as_str()).change_context(
+            errors::ApiErrorResponse::InvalidRequestData {
+                message: format!("`{key}` is not a valid header name"),
+            },
+        )?;
+        let value = HeaderValue::try_from(value.peek().as_str()).change_context(
+            errors::ApiErrorResponse::InvalidRequestData {
+                message: format!("Value of the `{key}` header is invalid"),
+            },
+        )?;
+        headers.insert(name, value);
+    }
+
+    let unmasked_keys =
+        get_unmasked_header_keys(state.global_store.as_ref(), &state.conf.unmasked_headers).await;
+    let headers = render_masked_headers(&headers, &unmasked_keys);
+
+    Ok(ApplicationResponse::Json(
+        api::UnmaskedHeadersPreviewResponse { headers },
+    ))
+}
+
+fn render_masked_headers(
+    headers: &HeaderMap,
+    unmasked_keys: &HashSet<String>,
+) -> BTreeMap<String, String> {
+    mask_headers(headers, unmasked_keys)
+        .into_iter()
+        .map(|(key, value)| {
+            let value = value
+                .to_str()
+                .map(ToOwned::to_owned)
+                .unwrap_or_else(|_| format!("{value:?}"));
+            (key, value)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    fn header_map(headers: &[(&'static str, &'static str)]) -> HeaderMap {
+        let mut header_map = HeaderMap::new();
+        for &(key, value) in headers {
+            header_map.insert(
+                HeaderName::from_static(key),
+                HeaderValue::from_static(value),
+            );
+        }
+        header_map
+    }
+
+    #[test]
+    fn test_render_masked_headers_never_unmasks_credentials() {
+        let headers = header_map(&[
+            ("api-key", "dev_secret"),
+            ("authorization", "Bearer secret"),
+            ("user-agent", "curl/8.0"),
+            ("x-profile-id", "pro_123"),
+        ]);
+        let unmasked_keys = HashSet::from([
+            "api-key".to_string(),
+            "authorization".to_string(),
+            "user-agent".to_string(),
+        ]);
+
+        let rendered = render_masked_headers(&headers, &unmasked_keys);
+
+        assert_eq!(
+            rendered,
+            BTreeMap::from([
+                ("api-key".to_string(), MASKED_HEADER_VALUE.to_string()),
+                ("authorization".to_string(), MASKED_HEADER_VALUE.to_string()),
+                ("user-agent".to_string(), "curl/8.0".to_string()),
+                ("x-profile-id".to_string(), MASKED_HEADER_VALUE.to_string()),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_validate_unmasked_header_keys() {
+        assert_eq!(
+            validate_unmasked_header_keys(vec![" User-Agent ".to_string()]).unwrap(),
+            HashSet::from(["user-agent".to_string()])
+        );
+        assert!(validate_unmasked_header_keys(vec!["Authorization".to_string()]).is_err());
+        assert!(validate_unmasked_header_keys(vec!["invalid header".to_string()]).is_err());
+    }
+}
@@ -0,0 +1,309 @@
+//! Reachability checks against configured connector base URLs.
+//!
+//! The smoke test resolves the host of the base URL of each connector, connects to it, and
+//! completes a TLS handshake if the base URL is served over HTTPS. No request is sent over the
+//! connection, so no connector API is called. The connection is made directly, and not through
+//! the outbound proxy.
+//!
+//! The smoke test never blocks startup. Failures are logged and recorded in the
+//! `CONNECTOR_SMOKE_TEST_FAILURE` gauge, and the results of the latest run for the tenant are
+//! exposed through the deep health check endpoint.
+
+use std::{
+    collections::HashMap,
+    sync::{Arc, RwLock},
+    time::Duration,
+};
+
+use api_models::{
+    enums,
+    health_check::{ConnectorSmokeTestReport, ConnectorSmokeTestResult},
+};
+use common_utils::{date_time, id_type};
+use hyperswitch_interfaces::{api::ConnectorCommon, configs::Connectors};
+use router_env::logger;
+use rustls::pki_types::ServerName;
+use strum::IntoEnumIterator;
+use tokio_rustls::TlsConnector;
+
+use crate::{
+    configs::settings::ConnectorSmokeTestConfig,
+    routes::{metrics, AppState, SessionState},
+    types::api,
+};
+
+/// Results of the latest smoke test run of each tenant
+pub type ConnectorSmokeTestReports =
+    Arc<RwLock<HashMap<id_type::TenantId, ConnectorSmokeTestReport>>>;
+
+#[derive(Debug, thiserror::Error)]
+enum ConnectorSmokeTestError {
+    #[error("Invalid base URL")]
+    InvalidBaseUrl,
+    #[error("Failed to resolve the host: {0}")]
+    DnsResolutionFailed(std::io::Error),
+    #[error("Failed to connect to the host: {0}")]
+    ConnectionFailed(std::io::Error),
+    #[error("TLS handshake failed: {0}")]
+    TlsHandshakeFailed(std::io::Error),
+    #[error("Timed out")]
+    TimedOut,
+}
+
+/// Get the results of the latest smoke test run for the tenant, if any
+pub fn get_latest_report(state: &SessionState) -> Option<ConnectorSmokeTestReport> {
+    state
+        .connector_smoke_test_reports
+        .read()
+        .ok()
+        .and_then(|reports| reports.get(&state.tenant.tenant_id).cloned())
+}
+
+/// Spawn the smoke test in the background. It runs once on startup for every tenant, and
+/// periodically if an interval is configured.
+pub fn spawn_connector_smoke_test(state: AppState) {
+    let config = state.conf.connector_smoke_test.clone();
+    if !config.enabled {
+        return;
+    }
+
+    tokio::spawn(async move {
+        loop {
+            for tenant_id in state.conf.multitenancy.get_tenant_ids() {
+                let report =
+                    run_connector_smoke_test(&tenant_id, &state.conf.connectors, &config).await;
+                logger::info!(
+                    ?tenant_id,
+                    connector_smoke_test_failure_count = report.failure_count,
+                    "Connector smoke test completed"
+                );
+                if let Ok(mut latest_reports) = state.connector_smoke_test_reports.write() {
+                    latest_reports.insert(tenant_id, report);
+                }
+            }
+
+            match config.interval_in_secs {
+                Some(interval) => tokio::time::sleep(Duration::from_secs(interval)).await,
+                None => break,
+            }
+        }
+    });
+}
+
+/// Check every configured connector base URL of the tenant
+pub async fn run_connector_smoke_test(
+    tenant_id: &id_type::TenantId,
+    connectors: &Connectors,
+    config: &ConnectorSmokeTestConfig,
+) -> ConnectorSmokeTestReport {
+    let tls_connector = get_tls_connector();
+    let checks =
+        get_connector_base_urls(config, connectors)
+            .into_iter()
+            .map(|(connector, base_url)| {
+                check_connector_base_url(
+                    tenant_id,
+                    &tls_connector,
+                    connector,
+                    base_url,
+                    config.timeout_in_secs,
+                )
+            });
+    let results = futures::future::join_all(checks).await;
+
+    ConnectorSmokeTestReport {
+        completed_at: date_time::now(),
+        failure_count: results
+            .iter()
+            .filter(|result| result.error.is_some())
+            .count(),
+        results,
+    }
+}
+
+fn get_connector_base_urls(
+    config: &ConnectorSmokeTestConfig,
+    connectors: &Connectors,
+) -> Vec<(String, String)> {
+    let connector_names = if config.connectors.is_empty() {
+        enums::Connector::iter().collect()
+    } else {
+        config.connectors.clone()
+    };
+
+    connector_names
+        .into_iter()
+        .filter_map(|connector_name| {
+            let connector_name = connector_name.to_string();
+            api::ConnectorData::convert_connector(&connector_name)
+                .ok()
+                .map(|connector| {
+                    let base_url = connector.base_url(connectors).to_owned();
+                    (connector_name, base_url)
+                })
+        })
+        .filter(|(_, base_url)| !base_url.is_empty())
+        .collect()
+}
+
+fn get_tls_connector() -> TlsConnector {
+    let mut root_store = rustls::RootCertStore::empty();
+    root_store.extend(webpki_roots::TLS_SERVER_ROOTS.iter().cloned());
+    let config = rustls::ClientConfig::builder()
+        .with_root_certificates(root_store)
+        .with_no_client_auth();
+    TlsConnector::from(Arc::new(config))
+}
+
+async fn check_connector_base_url(
+    tenant_id: &id_type::TenantId,
+    tls_connector: &TlsConnector,
+    connector: String,
+    base_url: String,
+    timeout_in_secs: u64,
+) -> ConnectorSmokeTestResult {
+    let result = tokio::time::timeout(
+        Duration::from_secs(timeout_in_secs),
+        connect_to_base_url(tls_connector, &base_url),
+    )
+    .await
+    .unwrap_or(Err(ConnectorSmokeTestError::TimedOut));
+
+    let error = result.err().map(|error| {
+        logger::warn!(
+            connector = %connector,
+            base_url = %base_url,
+            ?error,
+            "Connector smoke test failed"
+        );
+        error.to_string()
+    });
+
+    metrics::CONNECTOR_SMOKE_TEST_FAILURE.record(
+        u64::from(error.is_some()),
+        router_env::metric_attributes!(
+            ("tenant", tenant_id.get_string_repr().to_owned()),
+            ("connector", connector.clone()),
+        ),
+    );
+
+    ConnectorSmokeTestResult {
+        connector,
+        base_url,
+        error,
+    }
+}
+
+async fn connect_to_base_url(
+    tls_connector: &TlsConnector,
+    base_url: &str,
+) -> Result<(), ConnectorSmokeTestError> {
+    let url = url::Url::parse(base_url).map_err(|_| ConnectorSmokeTestError::InvalidBaseUrl)?;
+    let host = url
+        .host_str()
+        .map(|host| host.trim_start_matches('[').trim_end_matches(']'))
+        .ok_or(ConnectorSmokeTestError::InvalidBaseUrl)?;
+    let port = url
+        .port_or_known_default()
+        .ok_or(ConnectorSmokeTestError::InvalidBaseUrl)?;
+
+    let addresses = tokio::net::lookup_host((host, port))
+        .await
+        .map_err(ConnectorSmokeTestError::DnsResolutionFailed)?
+        .collect::<Vec<_>>();
+    let stream = tokio::net::TcpStream::connect(addresses.as_slice())
+        .await
+        .map_err(ConnectorSmokeTestError::ConnectionFailed)?;
+
+    if url.scheme() == "https" {
+        let server_name = ServerName::try_from(host)
+            .map_err(|_| ConnectorSmokeTestError::InvalidBaseUrl)?
+            .to_owned();
+        tls_connector
+            .connect(server_name, stream)
+            .await
+            .map_err(ConnectorSmokeTestError::TlsHandshakeFailed)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    fn get_config(connectors: Vec<enums::Connector>) -> ConnectorSmokeTestConfig {
+        ConnectorSmokeTestConfig {
+            enabled: true,
+            interval_in_secs: None,
+            timeout_in_secs: 5,
+            connectors,
+        }
+    }
+
+    #[test]
+    fn test_connectors_without_base_url_are_not_checked() {
+        let mut connectors = Connectors::default();
+        connectors.stripe.base_url = "https://api.stripe.com/".to_string();
+
+        let base_urls = get_connector_base_urls(
+            &get_config(vec![enums::Connector::Stripe, enums::Connector::Adyen]),
+            &connectors,
+        );
+
+        assert_eq!(
+            base_urls,
+            vec![("stripe".to_string(), "https://api.stripe.com/".to_string())]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_smoke_test_only_connects_to_the_base_url() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        // Accept the connections and close them right away, without reading the request
+        tokio::spawn(async move {
+            while let Ok((stream, _)) = listener.accept().await {
+                drop(stream);
+            }
+        });
+
+        let mut connectors = Connectors::default();
+        connectors.stripe.base_url = format!("http://{address}/");
+        // The listener does not speak TLS, the handshake fails
+        connectors.adyen.base_url = format!("https://{address}/");
+        // Nothing listens on port 1, the connection is refused
+        connectors.checkout.base_url = "http://127.0.0.1:1/".to_string();
+
+        let report = run_connector_smoke_test(
+            &id_type::TenantId::try_from_string("public".to_string()).unwrap(),
+            &connectors,
+            &get_config(vec![
+                enums::Connector::Stripe,
+                enums::Connector::Adyen,
+                enums::Connector::Checkout,
+            ]),
+        )
+        .await;
+
+        assert_eq!(report.failure_count, 2);
+        let error_of = |connector: &str| {
+            report
+                .results
+                .iter()
+                .find(|result| result.connector == connector)
+                .unwrap()
+                .error
+                .clone()
+        };
+        assert_eq!(error_of("stripe"), None);
+        assert!(error_of("adyen")
+            .unwrap()
+            .starts_with("TLS handshake failed"));
+        assert!(error_of("checkout")
+            .unwrap()
+            .starts_with("Failed to connect to the host"));
+    }
+}
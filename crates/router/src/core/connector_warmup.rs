@@ -0,0 +1,191 @@
+//! Periodic requests to connector base URLs to keep pooled connections warm.
+//!
+//! Idle connections in the HTTP client pool are closed after the configured idle timeout, after
+//! which the first payment has to pay for a fresh TCP connection and TLS handshake. The warmup
+//! task periodically requests the base URL of each configured connector through the same client
+//! used for connector calls, so that the pool always holds a live connection. The response
+//! status is irrelevant, failures are only logged.
+
+use std::{sync::Arc, time::Duration};
+
+use common_utils::{
+    errors::CustomResult,
+    request::{Method, RequestBuilder},
+};
+use external_services::http_client;
+use hyperswitch_interfaces::{
+    api::ConnectorCommon, configs::Connectors, errors::HttpClientError, types::Proxy,
+};
+use router_env::logger;
+use tokio::task::JoinHandle;
+
+use crate::{configs::settings::ConnectorWarmupConfig, types::api};
+
+/// Client used to send warmup requests
+#[async_trait::async_trait]
+pub trait WarmupClient: Send + Sync {
+    async fn ping(&self, url: &str, timeout: Duration) -> CustomResult<(), HttpClientError>;
+}
+
+/// Sends warmup requests through the pooled HTTP client used for connector calls
+#[derive(Clone, Debug)]
+pub struct ProxyWarmupClient {
+    proxy: Proxy,
+}
+
+impl ProxyWarmupClient {
+    pub fn new(proxy: Proxy) -> Self {
+        Self { proxy }
+    }
+}
+
+#[async_trait::async_trait]
+impl WarmupClient for ProxyWarmupClient {
+    async fn ping(&self, url: &str, timeout: Duration) -> CustomResult<(), HttpClientError> {
+        let request = RequestBuilder::new().method(Method::Get).url(url).build();
+        http_client::send_request(&self.proxy, request, Some(timeout.as_secs()))
+            .await
+            .map(|_| ())
+    }
+}
+
+/// Spawn the warmup task in the background if it is enabled and at least one connector is
+/// configured
+pub fn spawn_connector_warmup<C: WarmupClient + 'static>(
+    config: &ConnectorWarmupConfig,
+    connectors: &Connectors,
+    client: C,
+) -> Option<JoinHandle<()>> {
+    if !config.enabled {
+        return None;
+    }
+
+    if config.interval_in_secs == 0 {
+        logger::warn!("Connector warmup is enabled but the interval is zero, skipping warmup");
+        return None;
+    }
+
+    let base_urls = get_connector_base_urls(config, connectors);
+    if base_urls.is_empty() {
+        logger::warn!("Connector warmup is enabled but no connector base URLs are configured");
+        return None;
+    }
+
+    Some(tokio::spawn(run_connector_warmup(
+        Arc::new(client),
+        base_urls,
+        Duration::from_secs(config.interval_in_secs),
+        Duration::from_secs(config.timeout_in_secs),
+    )))
+}
+
+async fn run_connector_warmup<C: WarmupClient>(
+    client: Arc<C>,
+    base_urls: Vec<(String, String)>,
+    interval: Duration,
+    timeout: Duration,
+) {
+    let mut ticker = tokio::time::interval(interval);
+    ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+    loop {
+        ticker.tick().await;
+
+        let pings = base_urls.iter().map(|(connector, base_url)| {
+            let client = Arc::clone(&client);
+            async move {
+                if let Err(error) = client.ping(base_url, timeout).await {
+                    logger::debug!(%connector, ?error, "Connector warmup request failed");
+                }
+            }
+        });
+        futures::future::join_all(pings).await;
+    }
+}
+
+fn get_connector_base_urls(
+    config: &ConnectorWarmupConfig,
+    connectors: &Connectors,
+) -> Vec<(String, String)> {
+    config
+        .connectors
+        .iter()
+        .filter_map(|connector_name| {
+            let connector_name = connector_name.to_string();
+            api::ConnectorData::convert_connector(&connector_name)
+                .ok()
+                .map(|connector| {
+                    let base_url = connector.base_url(connectors).to_owned();
+                    (connector_name, base_url)
+                })
+        })
+        .filter(|(_, base_url)| !base_url.is_empty())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex,
+    };
+
+    use super::*;
+
+    #[derive(Clone, Default)]
+    struct MockWarmupClient {
+        requested_urls: Arc<Mutex<Vec<String>>>,
+        request_count: Arc<AtomicUsize>,
+    }
+
+    #[async_trait::async_trait]
+    impl WarmupClient for MockWarmupClient {
+        async fn ping(&self, url: &str, _timeout: Duration) -> CustomResult<(), HttpClientError> {
+            self.request_count.fetch_add(1, Ordering::SeqCst);
+            self.requested_urls.lock().unwrap().push(url.to_owned());
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn warmup_task_issues_periodic_requests() {
+        let client = MockWarmupClient::default();
+        let base_urls = vec![
+            ("stripe".to_string(), "https://api.stripe.com/".to_string()),
+            (
+                "adyen".to_string(),
+                "https://checkout-test.adyen.com/".to_string(),
+            ),
+        ];
+
+        let handle = tokio::spawn(run_connector_warmup(
+            Arc::new(client.clone()),
+            base_urls,
+            Duration::from_millis(10),
+            Duration::from_secs(1),
+        ));
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        handle.abort();
+
+        let request_count = client.request_count.load(Ordering::SeqCst);
+        assert!(request_count >= 4, "only {request_count} warmup requests");
+        let requested_urls = client.requested_urls.lock().unwrap();
+        assert!(requested_urls.contains(&"https://api.stripe.com/".to_string()));
+        assert!(requested_urls.contains(&"https://checkout-test.adyen.com/".to_string()));
+    }
+
+    #[tokio::test]
+    async fn warmup_task_is_not_spawned_when_disabled() {
+        let client = MockWarmupClient::default();
+        let config = ConnectorWarmupConfig {
+            enabled: false,
+            connectors: vec![api_models::enums::Connector::Stripe],
+            ..Default::default()
+        };
+
+        let handle = spawn_connector_warmup(&config, &Connectors::default(), client.clone());
+
+        assert!(handle.is_none());
+        assert_eq!(client.request_count.load(Ordering::SeqCst), 0);
+    }
+}
@@ -0,0 +1,482 @@
+//! Hashes of the email and phone number of customers, with which payments are searched without
+//! decrypting the customer details stored on the payments and customers.
+//!
+//! The values are normalized before hashing, so that the same email or phone number written
+//! differently results in the same hash. The hashes are keyed per tenant, with keys derived from
+//! the `search_hash_key` secret.
+
+#[cfg(feature = "olap")]
+use common_utils::types::TimeRange;
+use common_utils::{
+    crypto::{HmacSha256, SignMessage},
+    errors::{CryptoError, CustomResult},
+    validation::normalize_phone_number,
+};
+use error_stack::ResultExt;
+#[cfg(feature = "olap")]
+use hyperswitch_domain_models::payments::payment_intent::{
+    PaymentIntentFetchConstraints, PaymentIntentListParams,
+};
+use hyperswitch_masking::{PeekInterface, Secret};
+#[cfg(feature = "olap")]
+use scheduler::utils as pt_utils;
+
+#[cfg(feature = "olap")]
+use crate::{core::customers::REDACTED, logger, services};
+use crate::{
+    core::errors,
+    routes::SessionState,
+    types::{api, domain, storage},
+};
+
+#[cfg(feature = "olap")]
+const CUSTOMER_CONTACT_REHASH_TASK: &str = "CUSTOMER_CONTACT_REHASH";
+#[cfg(feature = "olap")]
+const CUSTOMER_CONTACT_REHASH_TAGS: [&str; 2] = ["CUSTOMER", "CONTACT_REHASH"];
+/// Number of records rehashed per page, after which the progress of the rehash is persisted
+#[cfg(feature = "olap")]
+pub const CUSTOMER_CONTACT_REHASH_BATCH_SIZE: u16 = 500;
+
+/// Hashes of the email and phone number of a customer
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ContactHashes {
+    pub email_hash: Option<String>,
+    pub phone_hash: Option<String>,
+}
+
+pub struct ContactHasher {
+    key: Secret<Vec<u8>>,
+}
+
+impl ContactHasher {
+    fn new(search_hash_key: &str, tenant_id: &str) -> CustomResult<Self, CryptoError> {
+        let key = HmacSha256.sign_message(search_hash_key.as_bytes(), tenant_id.as_bytes())?;
+        Ok(Self {
+            key: Secret::new(key),
+        })
+    }
+
+    /// Get the hasher of the tenant of the request, `None` if the search hash key is not configured
+    pub fn from_state(state: &SessionState) -> CustomResult<Option<Self>, CryptoError> {
+        let search_hash_key = state.conf.secrets.get_inner().search_hash_key.peek();
+        if search_hash_key.is_empty() {
+            return Ok(None);
+        }
+
+        Self::new(search_hash_key, state.tenant.tenant_id.get_string_repr()).map(Some)
+    }
+
+    fn hash(&self, value: &str) -> CustomResult<String, CryptoError> {
+        HmacSha256
+            .sign_message(self.key.peek(), value.as_bytes())
+            .map(hex::encode)
+    }
+
+    pub fn hash_email(&self, email: &str) -> CustomResult<String, CryptoError> {
+        self.hash(&email.trim().to_lowercase())
+    }
+
+    pub fn hash_phone(
+        &self,
+        phone: &str,
+        phone_country_code: Option<&str>,
+    ) -> CustomResult<String, CryptoError> {
+        self.hash(&normalize_phone_number(phone, phone_country_code, None).into_inner())
+    }
+
+    pub fn hash_contact(
+        &self,
+        email: Option<&str>,
+        phone: Option<&str>,
+        phone_country_code: Option<&str>,
+    ) -> CustomResult<ContactHashes, CryptoError> {
+        Ok(ContactHashes {
+            email_hash: email.map(|email| self.hash_email(email)).transpose()?,
+            phone_hash: phone
+                .map(|phone| self.hash_phone(phone, phone_country_code))
+                .transpose()?,
+        })
+    }
+}
+
+/// Get the hashes of the email and phone number, which are not computed if the search hash key is
+/// not configured
+pub fn get_contact_hashes(
+    state: &SessionState,
+    email: Option<&str>,
+    phone: Option<&str>,
+    phone_country_code: Option<&str>,
+) -> CustomResult<ContactHashes, CryptoError> {
+    match ContactHasher::from_state(state)? {
+        Some(hasher) => hasher.hash_contact(email, phone, phone_country_code),
+        None => Ok(ContactHashes::default()),
+    }
+}
+
+/// Hash the email and phone number of the customer
+pub fn hash_customer_contact(
+    hasher: &ContactHasher,
+    customer: &domain::Customer,
+) -> CustomResult<ContactHashes, CryptoError> {
+    hasher.hash_contact(
+        customer
+            .email
+            .as_ref()
+            .map(|email| email.get_inner().peek().as_str()),
+        customer
+            .phone
+            .as_ref()
+            .map(|phone| phone.get_inner().peek().as_str()),
+        customer.phone_country_code.as_deref(),
+    )
+}
+
+/// Set the hashes of the email and phone number of the customer, which are not set if the search
+/// hash key is not configured
+pub fn set_customer_contact_hashes(
+    state: &SessionState,
+    customer: &mut domain::Customer,
+) -> CustomResult<(), CryptoError> {
+    if let Some(hasher) = ContactHasher::from_state(state)? {
+        let contact_hashes = hash_customer_contact(&hasher, customer)?;
+        customer.email_hash = contact_hashes.email_hash;
+        customer.phone_hash = contact_hashes.phone_hash;
+    }
+
+    Ok(())
+}
+
+/// Get the hashes of the email and phone number of the customer once updated with the values
+/// provided, which are not computed if the search hash key is not configured
+pub fn get_updated_customer_contact_hashes(
+    state: &SessionState,
+    customer: &domain::Customer,
+    email: Option<&str>,
+    phone: Option<&str>,
+    phone_country_code: Option<&str>,
+) -> CustomResult<ContactHashes, CryptoError> {
+    get_contact_hashes(
+        state,
+        email.or(customer
+            .email
+            .as_ref()
+            .map(|email| email.get_inner().peek().as_str())),
+        phone.or(customer
+            .phone
+            .as_ref()
+            .map(|phone| phone.get_inner().peek().as_str())),
+        phone_country_code.or(customer.phone_country_code.as_deref()),
+    )
+}
+
+/// Get the hashes of the email and phone number in the customer details of the payment, which are
+/// not computed if the search hash key is not configured
+pub fn get_intent_contact_hashes(
+    state: &SessionState,
+    payment_intent: &storage::PaymentIntent,
+) -> errors::RouterResult<ContactHashes> {
+    match ContactHasher::from_state(state)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to derive the search hash key of the tenant")?
+    {
+        Some(hasher) => hash_intent_customer_details(&hasher, payment_intent),
+        None => Ok(ContactHashes::default()),
+    }
+}
+
+/// Hash the email and phone number in the customer details of the payment
+pub fn hash_intent_customer_details(
+    hasher: &ContactHasher,
+    payment_intent: &storage::PaymentIntent,
+) -> errors::RouterResult<ContactHashes> {
+    let customer_details = payment_intent
+        .get_intent_customer_details()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse customer data from payment intent")?;
+
+    customer_details
+        .map(|customer_details| {
+            hasher.hash_contact(
+                customer_details
+                    .email
+                    .as_ref()
+                    .map(|email| email.peek().as_str()),
+                customer_details
+                    .phone
+                    .as_ref()
+                    .map(|phone| phone.peek().as_str()),
+                customer_details.phone_country_code.as_deref(),
+            )
+        })
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to hash the customer details of the payment intent")
+        .map(Option::unwrap_or_default)
+}
+
+/// Get the hashes of the customer email and phone number with which the payments list is filtered
+pub fn get_payment_list_contact_hashes(
+    state: &SessionState,
+    constraints: &api::PaymentListFilterConstraints,
+) -> errors::RouterResult<ContactHashes> {
+    if constraints.customer_email.is_none() && constraints.customer_phone.is_none() {
+        return Ok(ContactHashes::default());
+    }
+
+    let hasher = ContactHasher::from_state(state)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to derive the search hash key of the tenant")?
+        .ok_or(errors::ApiErrorResponse::NotSupported {
+            message: "Searching payments by customer email or phone number".to_string(),
+        })?;
+
+    hasher
+        .hash_contact(
+            constraints
+                .customer_email
+                .as_ref()
+                .map(|email| email.peek().as_str()),
+            constraints
+                .customer_phone
+                .as_ref()
+                .map(|phone| phone.peek().as_str()),
+            None,
+        )
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to hash the customer email and phone number")
+}
+
+/// Start re-computing the hashes of the customers and payments of the merchant, so that the
+/// records written before the search hash key was configured or rotated can be searched
+#[cfg(feature = "olap")]
+pub async fn start_customer_contact_rehash(
+    state: SessionState,
+    platform: domain::Platform,
+) -> errors::RouterResponse<()> {
+    let merchant_id = platform.get_processor().get_account().get_id();
+
+    if state
+        .conf
+        .secrets
+        .get_inner()
+        .search_hash_key
+        .peek()
+        .is_empty()
+    {
+        return Err(errors::ApiErrorResponse::NotSupported {
+            message: "Searching payments by customer email or phone number".to_string(),
+        }
+        .into());
+    }
+
+    let job_id = common_utils::generate_id(crate::consts::ID_LENGTH, "rehash");
+    let now = common_utils::date_time::now();
+    let tracking_data = storage::CustomerContactRehashTrackingData {
+        job_id: job_id.clone(),
+        merchant_id: merchant_id.clone(),
+        snapshot_at: now,
+        stage: storage::CustomerContactRehashStage::Customers,
+        offset: 0,
+    };
+
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        pt_utils::get_process_tracker_id(
+            storage::ProcessTrackerRunner::CustomerContactRehashWorkflow,
+            CUSTOMER_CONTACT_REHASH_TASK,
+            &job_id,
+            merchant_id,
+        ),
+        CUSTOMER_CONTACT_REHASH_TASK,
+        storage::ProcessTrackerRunner::CustomerContactRehashWorkflow,
+        CUSTOMER_CONTACT_REHASH_TAGS,
+        tracking_data,
+        None,
+        now,
+        common_types::consts::API_VERSION,
+        common_enums::ApplicationSource::Main,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to create ProcessTrackerNew for customer contact rehash")?;
+
+    state
+        .store
+        .insert_process(process_tracker_entry)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to enqueue customer contact rehash ProcessTracker task")?;
+
+    logger::info!(job_id = %job_id, "Customer contact rehash initiated");
+
+    Ok(services::ApplicationResponse::StatusOk)
+}
+
+/// Re-compute the hashes of a page of the customers created before the snapshot, returning the
+/// number of customers in the page
+#[cfg(feature = "olap")]
+pub async fn rehash_customers_page(
+    state: &SessionState,
+    hasher: &ContactHasher,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    tracking_data: &storage::CustomerContactRehashTrackingData,
+) -> errors::RouterResult<usize> {
+    let db = &*state.store;
+    let constraints = hyperswitch_domain_models::customer::CustomerListConstraints {
+        limit: CUSTOMER_CONTACT_REHASH_BATCH_SIZE,
+        offset: Some(tracking_data.offset),
+        customer_id: None,
+        time_range: Some(TimeRange {
+            start_time: merchant_account.created_at,
+            end_time: Some(tracking_data.snapshot_at),
+        }),
+    };
+    let customers = db
+        .list_customers_by_merchant_id(merchant_account.get_id(), key_store, constraints)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list customers for contact rehash")?;
+    let customer_count = customers.len();
+
+    for customer in customers {
+        // Redacted customers are not searchable
+        let contact_hashes = if customer.phone_country_code.as_deref() == Some(REDACTED) {
+            ContactHashes::default()
+        } else {
+            hash_customer_contact(hasher, &customer)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to hash the contact details of the customer")?
+        };
+        if customer.email_hash == contact_hashes.email_hash
+            && customer.phone_hash == contact_hashes.phone_hash
+        {
+            continue;
+        }
+
+        db.update_customer_by_customer_id_merchant_id(
+            customer.get_id().to_owned(),
+            merchant_account.get_id().to_owned(),
+            customer,
+            storage::CustomerUpdate::ContactHashUpdate {
+                email_hash: contact_hashes.email_hash,
+                phone_hash: contact_hashes.phone_hash,
+            },
+            key_store,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update the contact hashes of the customer")?;
+    }
+
+    Ok(customer_count)
+}
+
+/// Re-compute the hashes of a page of the payments created before the snapshot, returning the
+/// number of payments in the page
+#[cfg(feature = "olap")]
+pub async fn rehash_payments_page(
+    state: &SessionState,
+    hasher: &ContactHasher,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    tracking_data: &storage::CustomerContactRehashTrackingData,
+) -> errors::RouterResult<usize> {
+    let db = &*state.store;
+    let constraints = PaymentIntentFetchConstraints::List(Box::new(PaymentIntentListParams {
+        offset: tracking_data.offset,
+        starting_at: None,
+        ending_at: Some(tracking_data.snapshot_at),
+        amount_filter: None,
+        connector: None,
+        currency: None,
+        status: None,
+        payment_method: None,
+        payment_method_type: None,
+        authentication_type: None,
+        merchant_connector_id: None,
+        profile_id: None,
+        customer_id: None,
+        starting_after_id: None,
+        ending_before_id: None,
+        limit: Some(u32::from(CUSTOMER_CONTACT_REHASH_BATCH_SIZE)),
+        order: Default::default(),
+        card_network: None,
+        card_discovery: None,
+        merchant_order_reference_id: None,
+        attribution_tags: None,
+        customer_email_hash: None,
+        customer_phone_hash: None,
+    }));
+    let payment_intents = db
+        .filter_payment_intent_by_constraints(
+            merchant_account.get_id(),
+            &constraints,
+            key_store,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list payments for contact rehash")?;
+    let payment_count = payment_intents.len();
+
+    for payment_intent in payment_intents {
+        let contact_hashes = hash_intent_customer_details(hasher, &payment_intent)?;
+        if payment_intent.customer_email_hash == contact_hashes.email_hash
+            && payment_intent.customer_phone_hash == contact_hashes.phone_hash
+        {
+            continue;
+        }
+
+        db.update_payment_intent(
+            payment_intent,
+            storage::PaymentIntentUpdate::ContactHashUpdate {
+                customer_email_hash: contact_hashes.email_hash,
+                customer_phone_hash: contact_hashes.phone_hash,
+                updated_by: merchant_account.storage_scheme.to_string(),
+            },
+            key_store,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update the contact hashes of the payment intent")?;
+    }
+
+    Ok(payment_count)
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    #[test]
+    fn test_hashes_are_computed_on_normalized_values() {
+        let hasher = ContactHasher::new("search_hash_key", "public").unwrap();
+
+        assert_eq!(
+            hasher.hash_email(" John.Doe@Example.com ").unwrap(),
+            hasher.hash_email("john.doe@example.com").unwrap()
+        );
+        assert_eq!(
+            hasher.hash_phone("(415) 555-2671", Some("+1")).unwrap(),
+            hasher.hash_phone("+1 415 555 2671", None).unwrap()
+        );
+        assert_ne!(
+            hasher.hash_email("john.doe@example.com").unwrap(),
+            hasher.hash_email("jane.doe@example.com").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_hashes_are_keyed_per_tenant() {
+        let public_hasher = ContactHasher::new("search_hash_key", "public").unwrap();
+        let other_hasher = ContactHasher::new("search_hash_key", "other").unwrap();
+
+        assert_ne!(
+            public_hasher.hash_email("john.doe@example.com").unwrap(),
+            other_hasher.hash_email("john.doe@example.com").unwrap()
+        );
+    }
+}
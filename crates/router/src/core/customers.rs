@@ -20,6 +20,8 @@ use hyperswitch_masking::{ExposeInterface, Secret, SwitchStrategy};
 use payment_methods::controller::PaymentMethodsController;
 use router_env::{instrument, tracing};
 
+#[cfg(feature = "v1")]
+use crate::core::customer_search;
 #[cfg(feature = "v2")]
 use crate::core::payment_methods::delete_payment_method_by_record;
 #[cfg(feature = "v2")]
@@ -255,7 +257,7 @@ impl CustomerCreateBridge for customers::CustomerRequest {
             pii::SecretSerdeValue::new(serde_json::Value::Object(map))
         });
 
-        Ok(domain::Customer::new(
+        let mut customer = domain::Customer::new(
             merchant_reference_id
                 .to_owned()
                 .ok_or(errors::CustomersErrorResponse::InternalServerError)?,
@@ -279,7 +281,12 @@ impl CustomerCreateBridge for customers::CustomerRequest {
             initiator.and_then(|initiator| initiator.to_created_by()),
             initiator.and_then(|initiator| initiator.to_created_by()),
             id_type::GlobalCustomerId::generate(&state.conf.cell_information.id),
-        ))
+        );
+        customer_search::set_customer_contact_hashes(state, &mut customer)
+            .change_context(errors::CustomersErrorResponse::InternalServerError)
+            .attach_printable("Failed to hash the contact details of the customer")?;
+
+        Ok(customer)
     }
 
     fn generate_response<'a>(
@@ -1119,6 +1126,9 @@ impl CustomerDeleteBridge for id_type::CustomerId {
             address_id: None,
             tax_registration_id: Some(redacted_encrypted_value.clone()),
             document_details: Box::new(None),
+            // The redacted customer is no longer found when searching by email or phone number
+            email_hash: Some(None),
+            phone_hash: Some(None),
             last_modified_by: initiator
                 .and_then(|initiator| initiator.to_created_by())
                 .map(|last_modified_by| last_modified_by.to_string()),
@@ -1135,6 +1145,28 @@ impl CustomerDeleteBridge for id_type::CustomerId {
         .await
         .switch()?;
 
+        // The connector customers of the redacted customer are not to be reused by its payments
+        match db
+            .delete_connector_customer_mappings_by_merchant_id_customer_id(
+                provider.get_account().get_id(),
+                self,
+            )
+            .await
+        {
+            Ok(_) => Ok(()),
+            Err(error) => {
+                if error.current_context().is_db_not_found() {
+                    Ok(())
+                } else {
+                    Err(error)
+                        .change_context(errors::CustomersErrorResponse::InternalServerError)
+                        .attach_printable(
+                            "failed delete_connector_customer_mappings_by_merchant_id_customer_id",
+                        )
+                }
+            }
+        }?;
+
         let response = customers::CustomerDeleteResponse {
             customer_id: self.clone(),
             customer_deleted: true,
@@ -1444,6 +1476,16 @@ impl CustomerUpdateBridge for customers::CustomerUpdateRequest {
                 .attach_printable(e)
         })?;
 
+        let contact_hashes = customer_search::get_updated_customer_contact_hashes(
+            state,
+            domain_customer,
+            self.email.as_ref().map(|email| email.peek().as_str()),
+            self.phone.as_ref().map(|phone| phone.peek().as_str()),
+            self.phone_country_code.as_deref(),
+        )
+        .change_context(errors::CustomersErrorResponse::InternalServerError)
+        .attach_printable("Failed to hash the contact details of the customer")?;
+
         let response = db
             .update_customer_by_customer_id_merchant_id(
                 domain_customer.get_id().to_owned(),
@@ -1467,6 +1509,8 @@ impl CustomerUpdateBridge for customers::CustomerUpdateRequest {
                     description: self.description.clone(),
                     connector_customer: Box::new(None),
                     address_id: address.clone().map(|addr| addr.address_id),
+                    email_hash: contact_hashes.email_hash.map(Some),
+                    phone_hash: contact_hashes.phone_hash.map(Some),
                     last_modified_by: initiator.and_then(|initiator| {
                         initiator
                             .to_created_by()
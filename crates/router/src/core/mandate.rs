@@ -218,6 +218,42 @@ pub async fn update_connector_mandate_id(
     }
     Ok(services::ApplicationResponse::StatusOk)
 }
+/// Record the customer acceptance captured when the customer completed the redirection on a
+/// mandate that was accepted on the bank page. The acceptance provided at confirm is retained if
+/// the redirect completion details are incomplete.
+#[cfg(feature = "v1")]
+#[instrument(skip_all)]
+pub async fn update_customer_acceptance_on_redirect_completion(
+    db: &dyn StorageInterface,
+    merchant_id: &id_type::MerchantId,
+    mandate_id: &str,
+    redirect_completion_details: &crate::core::payments::RedirectCompletionDetails,
+    storage_scheme: MerchantStorageScheme,
+) -> errors::RouterResult<()> {
+    let mandate = db
+        .find_mandate_by_merchant_id_mandate_id(merchant_id, mandate_id, storage_scheme)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MandateNotFound)?;
+
+    let Some(mandate_update) =
+        helpers::get_redirect_completion_acceptance_update(&mandate, redirect_completion_details)
+    else {
+        return Ok(());
+    };
+
+    db.update_mandate_by_merchant_id_mandate_id(
+        merchant_id,
+        mandate_id,
+        mandate_update,
+        mandate,
+        storage_scheme,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::MandateUpdateFailed)?;
+
+    Ok(())
+}
+
 #[cfg(feature = "v1")]
 #[instrument(skip(state))]
 pub async fn get_customer_mandates(
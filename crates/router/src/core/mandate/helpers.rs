@@ -8,7 +8,11 @@ use hyperswitch_domain_models::mandates::MandateData;
 
 use crate::{core::errors, types::api};
 #[cfg(feature = "v1")]
-use crate::{core::payments, routes::SessionState, types::domain};
+use crate::{
+    core::payments,
+    routes::SessionState,
+    types::{domain, storage},
+};
 
 #[cfg(feature = "v1")]
 pub async fn get_profile_id_for_mandate(
@@ -95,3 +99,129 @@ pub struct MandateGenericData {
     pub mandate_connector: Option<payments::MandateConnectorDetails>,
     pub payment_method_info: Option<domain::PaymentMethod>,
 }
+
+/// Build the update recording the customer acceptance captured at redirect completion. No update
+/// is needed if the connector has not confirmed the mandate, if the acceptance was already
+/// recorded at redirect completion, or if the customer's IP address could not be captured.
+#[cfg(feature = "v1")]
+pub fn get_redirect_completion_acceptance_update(
+    mandate: &Mandate,
+    redirect_completion_details: &payments::RedirectCompletionDetails,
+) -> Option<storage::MandateUpdate> {
+    if mandate.connector_mandate_id.is_none()
+        || mandate.customer_acceptance_source
+            == Some(enums::CustomerAcceptanceSource::RedirectCompletion)
+    {
+        return None;
+    }
+
+    let Some(ip_address) = redirect_completion_details.ip_address.clone() else {
+        router_env::logger::info!(
+            mandate_id = %mandate.mandate_id,
+            "IP address not captured at redirect completion, retaining customer acceptance provided at confirm"
+        );
+        return None;
+    };
+
+    Some(storage::MandateUpdate::CustomerAcceptanceUpdate {
+        customer_accepted_at: Some(redirect_completion_details.completed_at),
+        customer_ip_address: Some(ip_address),
+        customer_user_agent_extended: redirect_completion_details.user_agent.clone(),
+        customer_acceptance_source: enums::CustomerAcceptanceSource::RedirectCompletion,
+    })
+}
+
+#[cfg(all(test, feature = "v1"))]
+mod tests {
+    use common_utils::{date_time, id_type};
+    use hyperswitch_masking::Secret;
+
+    use super::*;
+
+    fn get_mandate(connector_mandate_id: Option<&str>) -> Mandate {
+        Mandate {
+            mandate_id: "man_123".to_string(),
+            customer_id: id_type::CustomerId::try_from(std::borrow::Cow::from("cus_123")).unwrap(),
+            merchant_id: id_type::MerchantId::default(),
+            payment_method_id: "pm_123".to_string(),
+            mandate_status: enums::MandateStatus::Active,
+            mandate_type: diesel_models::enums::MandateType::MultiUse,
+            customer_accepted_at: Some(date_time::now()),
+            customer_ip_address: Some(Secret::new("127.0.0.1".to_string())),
+            customer_user_agent: None,
+            network_transaction_id: None,
+            previous_attempt_id: None,
+            created_at: date_time::now(),
+            mandate_amount: None,
+            mandate_currency: None,
+            amount_captured: None,
+            connector: "stripe".to_string(),
+            connector_mandate_id: connector_mandate_id.map(ToOwned::to_owned),
+            start_date: None,
+            end_date: None,
+            metadata: None,
+            connector_mandate_ids: None,
+            original_payment_id: None,
+            merchant_connector_id: None,
+            updated_by: None,
+            customer_user_agent_extended: Some("confirm-agent".to_string()),
+            network_transaction_link_id: None,
+            customer_acceptance_source: Some(enums::CustomerAcceptanceSource::Confirm),
+        }
+    }
+
+    fn get_redirect_completion_details(
+        ip_address: Option<&str>,
+    ) -> payments::RedirectCompletionDetails {
+        payments::RedirectCompletionDetails {
+            completed_at: date_time::now(),
+            ip_address: ip_address.map(|ip| Secret::new(ip.to_string())),
+            user_agent: Some("redirect-agent".to_string()),
+        }
+    }
+
+    #[test]
+    fn records_acceptance_captured_at_redirect_completion() {
+        let mandate = get_mandate(Some("src_generated_sepa_debit"));
+        let redirect_completion_details = get_redirect_completion_details(Some("10.0.0.1"));
+
+        let mandate_update =
+            get_redirect_completion_acceptance_update(&mandate, &redirect_completion_details);
+
+        assert!(matches!(
+            mandate_update,
+            Some(storage::MandateUpdate::CustomerAcceptanceUpdate {
+                customer_accepted_at: Some(accepted_at),
+                customer_ip_address: Some(ref ip_address),
+                customer_user_agent_extended: Some(ref user_agent),
+                customer_acceptance_source: enums::CustomerAcceptanceSource::RedirectCompletion,
+            }) if accepted_at == redirect_completion_details.completed_at
+                && hyperswitch_masking::PeekInterface::peek(ip_address) == "10.0.0.1"
+                && user_agent == "redirect-agent"
+        ));
+    }
+
+    #[test]
+    fn retains_confirm_acceptance_when_ip_address_is_missing() {
+        let mandate = get_mandate(Some("src_generated_sepa_debit"));
+
+        let mandate_update = get_redirect_completion_acceptance_update(
+            &mandate,
+            &get_redirect_completion_details(None),
+        );
+
+        assert!(mandate_update.is_none());
+    }
+
+    #[test]
+    fn skips_update_when_connector_has_not_confirmed_mandate() {
+        let mandate = get_mandate(None);
+
+        let mandate_update = get_redirect_completion_acceptance_update(
+            &mandate,
+            &get_redirect_completion_details(Some("10.0.0.1")),
+        );
+
+        assert!(mandate_update.is_none());
+    }
+}
@@ -0,0 +1,1024 @@
+//! Quotas of payments imposed on merchants by the platform hosting them.
+//!
+//! The quota of a merchant is stored in the configs table, and limits the number and the total
+//! amount of the payments it can create in a period. The usage of the current period is accounted
+//! in redis when a payment is created, and is reset at the period boundary. The periods start at
+//! midnight in the time zone of the default profile of the merchant, or in UTC if it has none.
+
+use std::collections::HashMap;
+
+use api_models::merchant_quota::{
+    MerchantQuota, MerchantQuotaUsageResponse, PaymentCountUsage, QuotaPeriod,
+    QuotaThresholdReachedResponse, VolumeUsage,
+};
+use chrono::{Datelike, Days, Months, NaiveDate, TimeZone};
+use chrono_tz::Tz;
+use common_enums::enums;
+use common_utils::{date_time, types::MinorUnit};
+use currency_conversion::types::ExchangeRates;
+use error_stack::ResultExt;
+use router_env::{logger, tracing::Instrument};
+use rust_decimal::{prelude::ToPrimitive, Decimal};
+use storage_impl::platform_wrapper;
+use time::{Duration, OffsetDateTime, PrimitiveDateTime};
+
+use crate::{
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        payments::helpers,
+        webhooks,
+    },
+    routes::SessionState,
+    services::ApplicationResponse,
+    types::{api, domain, storage},
+    utils::currency,
+};
+
+// Cached when the merchant has no quota, so that the configs table is not queried for every
+// payment created.
+const NO_QUOTA_CONFIGURED: &str = "null";
+
+const PAYMENT_COUNT_FIELD: &str = "payment_count";
+const VOLUME_FIELD: &str = "volume";
+
+/// The usage is retained for a day past the end of the period, for it to be retrieved around the
+/// period boundary
+const USAGE_RETENTION_AFTER_PERIOD_END: Duration = Duration::days(1);
+
+/// Start and end of the period which is in progress at a point in time, in UTC
+#[derive(Debug, PartialEq, Eq)]
+struct QuotaPeriodBounds {
+    start: PrimitiveDateTime,
+    end: PrimitiveDateTime,
+}
+
+impl QuotaPeriodBounds {
+    fn get(period: QuotaPeriod, time_zone: Tz, now: PrimitiveDateTime) -> Option<Self> {
+        let today = chrono::DateTime::from_timestamp(now.assume_utc().unix_timestamp(), 0)?
+            .with_timezone(&time_zone)
+            .date_naive();
+
+        let (start, end) = match period {
+            QuotaPeriod::Daily => (today, today.succ_opt()?),
+            QuotaPeriod::Weekly => {
+                let start = today
+                    .checked_sub_days(Days::new(today.weekday().num_days_from_monday().into()))?;
+                (start, start.checked_add_days(Days::new(7))?)
+            }
+            QuotaPeriod::Monthly => {
+                let start = today.with_day(1)?;
+                (start, start.checked_add_months(Months::new(1))?)
+            }
+        };
+
+        let start_of_day_in_utc = |date: NaiveDate| {
+            // Midnight is skipped on the days a daylight saving transition happens at midnight, in
+            // which case the day starts at the first hour which is not skipped
+            let start_of_day = (0..24).find_map(|hour| {
+                time_zone
+                    .from_local_datetime(&date.and_hms_opt(hour, 0, 0)?)
+                    .earliest()
+            })?;
+            let start_of_day =
+                OffsetDateTime::from_unix_timestamp(start_of_day.timestamp()).ok()?;
+            Some(PrimitiveDateTime::new(
+                start_of_day.date(),
+                start_of_day.time(),
+            ))
+        };
+        Some(Self {
+            start: start_of_day_in_utc(start)?,
+            end: start_of_day_in_utc(end)?,
+        })
+    }
+
+    fn get_usage_key(&self, merchant_id: &common_utils::id_type::MerchantId) -> String {
+        merchant_id.get_merchant_quota_usage_key(self.start.assume_utc().unix_timestamp())
+    }
+}
+
+/// Payments created by the merchant in a period, the volume is in the currency of the volume limit
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct QuotaUsage {
+    payment_count: u64,
+    volume: i64,
+}
+
+impl QuotaUsage {
+    fn from_hash_fields(fields: &HashMap<String, String>) -> Self {
+        Self {
+            payment_count: fields
+                .get(PAYMENT_COUNT_FIELD)
+                .and_then(|value| value.parse().ok())
+                .unwrap_or_default(),
+            volume: fields
+                .get(VOLUME_FIELD)
+                .and_then(|value| value.parse().ok())
+                .unwrap_or_default(),
+        }
+    }
+
+    /// Name of the first limit of the quota that the usage exceeds
+    fn get_exceeded_limit(&self, quota: &MerchantQuota) -> Option<&'static str> {
+        if quota
+            .payment_count_limit
+            .is_some_and(|limit| self.payment_count > limit)
+        {
+            Some("payment count")
+        } else if quota
+            .volume_limit
+            .as_ref()
+            .is_some_and(|limit| self.volume > limit.amount.get_amount_as_i64())
+        {
+            Some("payment volume")
+        } else {
+            None
+        }
+    }
+
+    /// Whether any of the limits of the quota reached the warning threshold with this usage, having
+    /// been below it with the previous usage
+    fn has_crossed_warning_threshold(&self, previous: &Self, quota: &MerchantQuota) -> bool {
+        let Some(percent) = quota.warning_threshold_percent else {
+            return false;
+        };
+        let get_threshold = |limit: i128| limit * i128::from(percent) / 100;
+        let has_crossed = |previous: i128, current: i128, limit: i128| {
+            previous < get_threshold(limit) && current >= get_threshold(limit)
+        };
+
+        quota.payment_count_limit.is_some_and(|limit| {
+            has_crossed(
+                previous.payment_count.into(),
+                self.payment_count.into(),
+                limit.into(),
+            )
+        }) || quota.volume_limit.as_ref().is_some_and(|limit| {
+            has_crossed(
+                previous.volume.into(),
+                self.volume.into(),
+                limit.amount.get_amount_as_i64().into(),
+            )
+        })
+    }
+}
+
+fn build_quota_usage_response(
+    merchant_id: common_utils::id_type::MerchantId,
+    quota: MerchantQuota,
+    bounds: &QuotaPeriodBounds,
+    usage: QuotaUsage,
+) -> MerchantQuotaUsageResponse {
+    MerchantQuotaUsageResponse {
+        merchant_id,
+        payment_count: quota.payment_count_limit.map(|limit| PaymentCountUsage {
+            used: usage.payment_count,
+            limit,
+        }),
+        volume: quota.volume_limit.as_ref().map(|limit| VolumeUsage {
+            used: MinorUnit::new(usage.volume),
+            limit: limit.amount,
+            currency: limit.currency,
+        }),
+        quota,
+        period_start: bounds.start,
+        period_end: bounds.end,
+    }
+}
+
+async fn find_merchant_quota(
+    state: &SessionState,
+    merchant_id: &common_utils::id_type::MerchantId,
+) -> RouterResult<Option<MerchantQuota>> {
+    let config = state
+        .store
+        .find_config_by_key_unwrap_or(
+            &merchant_id.get_merchant_quota_key(),
+            Some(NO_QUOTA_CONFIGURED.to_string()),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the merchant quota config")?;
+
+    serde_json::from_str(&config.config)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to deserialize the merchant quota config")
+}
+
+fn validate_merchant_quota(quota: &MerchantQuota) -> RouterResult<()> {
+    let invalid_request = |message: &str| errors::ApiErrorResponse::InvalidRequestData {
+        message: message.to_string(),
+    };
+
+    if quota.payment_count_limit.is_none() && quota.volume_limit.is_none() {
+        Err(invalid_request(
+            "Either `payment_count_limit` or `volume_limit` must be provided",
+        ))?
+    }
+    if quota
+        .volume_limit
+        .as_ref()
+        .is_some_and(|limit| limit.amount.get_amount_as_i64() < 0)
+    {
+        Err(invalid_request("`volume_limit.amount` cannot be negative"))?
+    }
+    if quota
+        .warning_threshold_percent
+        .is_some_and(|percent| !(1..=100).contains(&percent))
+    {
+        Err(invalid_request(
+            "`warning_threshold_percent` must be between 1 and 100",
+        ))?
+    }
+    Ok(())
+}
+
+pub async fn update_merchant_quota(
+    state: SessionState,
+    platform: domain::Platform,
+    quota: MerchantQuota,
+) -> RouterResponse<MerchantQuota> {
+    validate_merchant_quota(&quota)?;
+
+    let db = state.store.as_ref();
+    let key = platform
+        .get_processor()
+        .get_account()
+        .get_id()
+        .get_merchant_quota_key();
+    let config = serde_json::to_string(&quota)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to serialize the merchant quota config")?;
+
+    let update = db
+        .update_config_by_key(
+            &key,
+            storage::ConfigUpdate::Update {
+                config: Some(config.clone()),
+            },
+        )
+        .await;
+    match update {
+        Err(error) if error.current_context().is_db_not_found() => db
+            .insert_config(storage::ConfigNew { key, config })
+            .await
+            .map(|_| ()),
+        result => result.map(|_| ()),
+    }
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to update the merchant quota config")?;
+
+    Ok(ApplicationResponse::Json(quota))
+}
+
+pub async fn delete_merchant_quota(
+    state: SessionState,
+    platform: domain::Platform,
+) -> RouterResponse<()> {
+    let key = platform
+        .get_processor()
+        .get_account()
+        .get_id()
+        .get_merchant_quota_key();
+    if let Err(error) = state.store.delete_config_by_key(&key).await {
+        if !error.current_context().is_db_not_found() {
+            return Err(error
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to delete the merchant quota config"));
+        }
+    }
+
+    Ok(ApplicationResponse::StatusOk)
+}
+
+async fn get_quota_usage(
+    state: &SessionState,
+    merchant_id: &common_utils::id_type::MerchantId,
+    bounds: &QuotaPeriodBounds,
+) -> RouterResult<QuotaUsage> {
+    let fields = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?
+        .get_hash_fields::<HashMap<String, String>>(&bounds.get_usage_key(merchant_id).into())
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the merchant quota usage")?;
+
+    Ok(QuotaUsage::from_hash_fields(&fields))
+}
+
+/// Time zone in which the quota periods of the merchant start, that of its default profile.
+///
+/// The default profile is not fetched again if it is the `business_profile` already fetched.
+async fn get_quota_time_zone(
+    state: &SessionState,
+    processor: &domain::Processor,
+    business_profile: Option<&domain::Profile>,
+) -> RouterResult<Tz> {
+    let Some(default_profile_id) = processor.get_account().get_default_profile() else {
+        return Ok(Tz::UTC);
+    };
+    let time_zone = match business_profile.filter(|profile| profile.get_id() == default_profile_id)
+    {
+        Some(business_profile) => business_profile.time_zone.clone(),
+        None => {
+            platform_wrapper::business_profile::find_business_profile_by_profile_id(
+                state.store.as_ref(),
+                processor,
+                default_profile_id,
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+                id: default_profile_id.get_string_repr().to_owned(),
+            })?
+            .time_zone
+        }
+    };
+
+    time_zone
+        .as_deref()
+        .map(helpers::validate_time_zone)
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Invalid time zone in the default profile of the merchant")
+        .map(|time_zone| time_zone.unwrap_or(Tz::UTC))
+}
+
+/// Retrieve the payments created by the merchant in the current period, against its quota
+pub async fn retrieve_merchant_quota_usage(
+    state: SessionState,
+    platform: domain::Platform,
+) -> RouterResponse<MerchantQuotaUsageResponse> {
+    let merchant_id = platform.get_processor().get_account().get_id();
+    let quota = find_merchant_quota(&state, merchant_id).await?.ok_or(
+        errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Quota is not configured for the merchant".to_string(),
+        },
+    )?;
+    let time_zone = get_quota_time_zone(&state, platform.get_processor(), None).await?;
+    let bounds = QuotaPeriodBounds::get(quota.period, time_zone, date_time::now())
+        .ok_or(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to compute the bounds of the current quota period")?;
+    let usage = get_quota_usage(&state, merchant_id, &bounds).await?;
+
+    Ok(ApplicationResponse::Json(build_quota_usage_response(
+        merchant_id.clone(),
+        quota,
+        &bounds,
+        usage,
+    )))
+}
+
+/// Convert an amount in the minor unit of its currency to the minor unit of the reporting currency
+/// of the volume limit
+fn convert_to_quota_currency(
+    rates: &ExchangeRates,
+    amount: MinorUnit,
+    currency: enums::Currency,
+    quota_currency: enums::Currency,
+) -> RouterResult<i64> {
+    if currency == quota_currency {
+        return Ok(amount.get_amount_as_i64());
+    }
+
+    // The converted amount is in the major unit of the reporting currency
+    let converted_amount = currency_conversion::conversion::convert(
+        rates,
+        currency,
+        quota_currency,
+        amount.get_amount_as_i64(),
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable_lazy(|| format!("Failed to convert {currency} to {quota_currency}"))?;
+    let minor_units_per_major_unit =
+        Decimal::from(10_u32.pow(quota_currency.number_of_digits_after_decimal_point().into()));
+
+    (converted_amount * minor_units_per_major_unit)
+        .round()
+        .to_i64()
+        .ok_or(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Converted amount does not fit in i64")
+}
+
+async fn get_amount_in_quota_currency(
+    state: &SessionState,
+    amount: MinorUnit,
+    currency: enums::Currency,
+    quota_currency: enums::Currency,
+) -> RouterResult<i64> {
+    if currency == quota_currency {
+        return Ok(amount.get_amount_as_i64());
+    }
+
+    let forex_api = state.conf.forex_api.get_inner();
+    let rates = currency::get_forex_rates(state, forex_api.data_expiration_delay_in_seconds)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the forex rates")?;
+    convert_to_quota_currency(&rates.data, amount, currency, quota_currency)
+}
+
+/// Usage of the quota of the merchant reserved for a payment being created.
+///
+/// The reservation is released if the payment is not created, and is completed once it is.
+#[derive(Debug)]
+pub struct QuotaReservation {
+    merchant_id: common_utils::id_type::MerchantId,
+    bounds: QuotaPeriodBounds,
+    increments: Vec<(&'static str, i64)>,
+    /// Usage of the quota including the payment, if the payment took it past the warning threshold
+    threshold_reached_usage: Option<MerchantQuotaUsageResponse>,
+}
+
+/// Reserve the usage of a payment being created against the quota of the merchant, rejecting the
+/// payment if it exceeds the quota.
+///
+/// Failures to account the payment are logged and the payment is allowed, so that the payments of
+/// the merchant are not blocked when redis or the forex rates are unavailable. The volume of the
+/// payment is not accounted if it cannot be converted to the reporting currency of the quota.
+pub async fn reserve_payment_quota(
+    state: &SessionState,
+    platform: &domain::Platform,
+    business_profile: &domain::Profile,
+    amount: MinorUnit,
+    currency: enums::Currency,
+) -> RouterResult<Option<QuotaReservation>> {
+    let merchant_id = platform.get_processor().get_account().get_id();
+    let quota = match find_merchant_quota(state, merchant_id).await {
+        Ok(Some(quota)) => quota,
+        Ok(None) => return Ok(None),
+        Err(error) => {
+            logger::error!(
+                ?error,
+                "Failed to fetch the merchant quota, allowing the payment"
+            );
+            return Ok(None);
+        }
+    };
+    let time_zone =
+        match get_quota_time_zone(state, platform.get_processor(), Some(business_profile)).await {
+            Ok(time_zone) => time_zone,
+            Err(error) => {
+                logger::error!(
+                    ?error,
+                    "Failed to get the time zone of the merchant quota, using UTC"
+                );
+                Tz::UTC
+            }
+        };
+    let Some(bounds) = QuotaPeriodBounds::get(quota.period, time_zone, date_time::now()) else {
+        logger::error!("Failed to compute the bounds of the current quota period");
+        return Ok(None);
+    };
+
+    let mut increments = vec![(PAYMENT_COUNT_FIELD, 1)];
+    if let Some(volume_limit) = &quota.volume_limit {
+        match get_amount_in_quota_currency(state, amount, currency, volume_limit.currency).await {
+            Ok(amount) => increments.push((VOLUME_FIELD, amount)),
+            Err(error) => logger::error!(
+                ?error,
+                "Failed to convert the payment amount, not accounting it in the quota volume"
+            ),
+        }
+    }
+
+    match reserve_quota_usage(state, merchant_id, quota, bounds, increments).await {
+        Ok(reservation) => Ok(Some(reservation)),
+        Err(error)
+            if matches!(
+                error.current_context(),
+                errors::ApiErrorResponse::QuotaExceeded { .. }
+            ) =>
+        {
+            Err(error)
+        }
+        Err(error) => {
+            logger::error!(
+                ?error,
+                "Failed to account the payment in the merchant quota"
+            );
+            Ok(None)
+        }
+    }
+}
+
+async fn reserve_quota_usage(
+    state: &SessionState,
+    merchant_id: &common_utils::id_type::MerchantId,
+    quota: MerchantQuota,
+    bounds: QuotaPeriodBounds,
+    increments: Vec<(&'static str, i64)>,
+) -> RouterResult<QuotaReservation> {
+    let usage = increment_quota_usage(state, merchant_id, &bounds, &increments).await?;
+    let previous_usage = QuotaUsage {
+        payment_count: usage.payment_count.saturating_sub(1),
+        volume: increments
+            .iter()
+            .find(|(field, _)| *field == VOLUME_FIELD)
+            .map_or(usage.volume, |(_, amount)| {
+                usage.volume.saturating_sub(*amount)
+            }),
+    };
+    let reservation = QuotaReservation {
+        merchant_id: merchant_id.clone(),
+        bounds,
+        increments,
+        threshold_reached_usage: None,
+    };
+
+    if let Some(exceeded_limit) = usage.get_exceeded_limit(&quota) {
+        release_payment_quota(state, reservation).await;
+        return Err(errors::ApiErrorResponse::QuotaExceeded {
+            quota: exceeded_limit.to_string(),
+        }
+        .into());
+    }
+
+    let threshold_reached_usage = usage
+        .has_crossed_warning_threshold(&previous_usage, &quota)
+        .then(|| {
+            build_quota_usage_response(merchant_id.clone(), quota, &reservation.bounds, usage)
+        });
+    Ok(QuotaReservation {
+        threshold_reached_usage,
+        ..reservation
+    })
+}
+
+/// Give back the usage reserved for a payment which was not created
+pub async fn release_payment_quota(state: &SessionState, reservation: QuotaReservation) {
+    let decrements = reservation
+        .increments
+        .iter()
+        .map(|(field, increment)| (*field, -increment))
+        .collect::<Vec<_>>();
+    if let Err(error) = increment_quota_usage(
+        state,
+        &reservation.merchant_id,
+        &reservation.bounds,
+        &decrements,
+    )
+    .await
+    {
+        logger::error!(?error, "Failed to release the reserved merchant quota");
+    }
+}
+
+/// Complete the reservation of the usage of a created payment, notifying the merchant if the
+/// payment took the usage past the warning threshold
+pub async fn complete_payment_quota_reservation(
+    state: &SessionState,
+    platform: &domain::Platform,
+    business_profile: &domain::Profile,
+    payment_id: &common_utils::id_type::PaymentId,
+    reservation: QuotaReservation,
+) {
+    if let Some(usage) = reservation.threshold_reached_usage {
+        trigger_quota_threshold_reached_webhook(
+            state,
+            platform,
+            business_profile,
+            payment_id,
+            usage,
+        )
+        .await;
+    }
+}
+
+async fn increment_quota_usage(
+    state: &SessionState,
+    merchant_id: &common_utils::id_type::MerchantId,
+    bounds: &QuotaPeriodBounds,
+    increments: &[(&str, i64)],
+) -> RouterResult<QuotaUsage> {
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+    let key = bounds.get_usage_key(merchant_id).into();
+
+    let values = redis_conn
+        .increment_fields_in_hash(&key, increments)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to increment the merchant quota usage")?;
+    let expire_at = bounds.end.assume_utc() + USAGE_RETENTION_AFTER_PERIOD_END;
+    redis_conn
+        .set_expire_at(&key, expire_at.unix_timestamp())
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to set the expiry of the merchant quota usage")?;
+
+    let fields = increments
+        .iter()
+        .zip(values)
+        .map(|((field, _), value)| (field.to_string(), value.to_string()))
+        .collect();
+    Ok(QuotaUsage::from_hash_fields(&fields))
+}
+
+fn get_quota_threshold_reached_webhook_content(
+    payment_id: common_utils::id_type::PaymentId,
+    usage: MerchantQuotaUsageResponse,
+) -> api::OutgoingWebhookContent {
+    api::OutgoingWebhookContent::QuotaUsageDetails(Box::new(QuotaThresholdReachedResponse {
+        payment_id,
+        usage,
+    }))
+}
+
+async fn trigger_quota_threshold_reached_webhook(
+    state: &SessionState,
+    platform: &domain::Platform,
+    business_profile: &domain::Profile,
+    payment_id: &common_utils::id_type::PaymentId,
+    usage: MerchantQuotaUsageResponse,
+) {
+    let webhook_recipient = match webhooks::utils::resolve_webhook_recipient_from_created_by(
+        state,
+        platform,
+        business_profile,
+        None,
+    )
+    .await
+    {
+        Ok(webhook_recipient) => webhook_recipient,
+        Err(error) => {
+            logger::error!(?error, "Failed to resolve the quota webhook recipient");
+            return;
+        }
+    };
+
+    let state = state.clone();
+    let platform = platform.clone();
+    let business_profile = business_profile.clone();
+    let payment_id = payment_id.clone();
+    tokio::spawn(
+        async move {
+            Box::pin(webhooks::create_event_and_trigger_outgoing_webhook(
+                state,
+                platform,
+                enums::EventType::QuotaThresholdReached,
+                enums::EventClass::Payments,
+                payment_id.get_string_repr().to_owned(),
+                enums::EventObjectType::PaymentDetails,
+                get_quota_threshold_reached_webhook_content(payment_id, usage),
+                Some(date_time::now()),
+                webhook_recipient,
+                None,
+                business_profile,
+            ))
+            .await
+        }
+        .in_current_span(),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use std::{borrow::Cow, sync::Arc};
+
+    use api_models::merchant_quota::VolumeQuota;
+    use currency_conversion::types::CurrencyFactors;
+    use time::macros::datetime;
+
+    use super::*;
+    use crate::{
+        configs::settings::Settings, db::StorageImpl, routes, services,
+        types::transformers::ForeignFrom,
+    };
+
+    fn get_quota(period: QuotaPeriod) -> MerchantQuota {
+        MerchantQuota {
+            period,
+            payment_count_limit: Some(100),
+            volume_limit: Some(VolumeQuota {
+                amount: MinorUnit::new(10_000),
+                currency: enums::Currency::USD,
+            }),
+            warning_threshold_percent: Some(80),
+        }
+    }
+
+    async fn get_session_state() -> SessionState {
+        let conf = Settings::new().expect("invalid settings");
+        let tx: tokio::sync::oneshot::Sender<()> = tokio::sync::oneshot::channel().0;
+        let api_client = services::MockApiClient;
+        let app_state = Box::pin(routes::AppState::with_storage(
+            conf,
+            StorageImpl::Mock,
+            tx,
+            Box::new(api_client),
+            env!("CARGO_PKG_NAME"),
+        ))
+        .await;
+        Arc::new(app_state)
+            .get_session_state(
+                &common_utils::id_type::TenantId::try_from_string("public".to_string()).unwrap(),
+                None,
+                || {},
+            )
+            .unwrap()
+    }
+
+    fn get_test_merchant_id() -> common_utils::id_type::MerchantId {
+        // The usage is kept in redis, a new merchant is used for every run of the test
+        common_utils::id_type::MerchantId::try_from(Cow::from(
+            common_utils::generate_id_with_default_len("merchant_quota"),
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_monthly_period_starts_in_the_time_zone_of_the_profile() {
+        // 31st of January 20:00 UTC is already the 1st of February in Asia/Kolkata (UTC+05:30)
+        let bounds = QuotaPeriodBounds::get(
+            QuotaPeriod::Monthly,
+            Tz::Asia__Kolkata,
+            datetime!(2026-01-31 20:00),
+        )
+        .unwrap();
+
+        assert_eq!(
+            bounds,
+            QuotaPeriodBounds {
+                start: datetime!(2026-01-31 18:30),
+                end: datetime!(2026-02-28 18:30),
+            }
+        );
+    }
+
+    #[test]
+    fn test_monthly_period_rolls_over_the_year() {
+        let bounds = QuotaPeriodBounds::get(
+            QuotaPeriod::Monthly,
+            Tz::America__New_York,
+            datetime!(2026-12-15 12:00),
+        )
+        .unwrap();
+
+        assert_eq!(bounds.start, datetime!(2026-12-01 05:00));
+        assert_eq!(bounds.end, datetime!(2027-01-01 05:00));
+    }
+
+    #[test]
+    fn test_period_bounds_follow_daylight_saving_time() {
+        // Clocks in London go forward on the 29th of March 2026 and back on the 25th of October
+        let march = QuotaPeriodBounds::get(
+            QuotaPeriod::Monthly,
+            Tz::Europe__London,
+            datetime!(2026-03-15 12:00),
+        )
+        .unwrap();
+        assert_eq!(march.start, datetime!(2026-03-01 00:00));
+        assert_eq!(march.end, datetime!(2026-03-31 23:00));
+
+        let october = QuotaPeriodBounds::get(
+            QuotaPeriod::Weekly,
+            Tz::Europe__London,
+            datetime!(2026-10-22 12:00),
+        )
+        .unwrap();
+        assert_eq!(october.start, datetime!(2026-10-18 23:00));
+        assert_eq!(october.end, datetime!(2026-10-26 00:00));
+
+        // Clocks in New York go forward on the 8th of March 2026
+        let new_york = QuotaPeriodBounds::get(
+            QuotaPeriod::Daily,
+            Tz::America__New_York,
+            datetime!(2026-03-08 03:00),
+        )
+        .unwrap();
+        assert_eq!(new_york.start, datetime!(2026-03-07 05:00));
+        assert_eq!(new_york.end, datetime!(2026-03-08 05:00));
+        let new_york = QuotaPeriodBounds::get(
+            QuotaPeriod::Daily,
+            Tz::America__New_York,
+            datetime!(2026-03-08 12:00),
+        )
+        .unwrap();
+        assert_eq!(new_york.start, datetime!(2026-03-08 05:00));
+        assert_eq!(new_york.end, datetime!(2026-03-09 04:00));
+    }
+
+    #[test]
+    fn test_day_starting_after_a_skipped_midnight() {
+        // Clocks in Santiago go forward from midnight to 01:00 on the 6th of September 2026
+        let bounds = QuotaPeriodBounds::get(
+            QuotaPeriod::Daily,
+            Tz::America__Santiago,
+            datetime!(2026-09-06 12:00),
+        )
+        .unwrap();
+
+        assert_eq!(bounds.start, datetime!(2026-09-06 04:00));
+        assert_eq!(bounds.end, datetime!(2026-09-07 03:00));
+    }
+
+    #[test]
+    fn test_weekly_period_starts_on_monday() {
+        // 15th of October 2026 is a Thursday
+        let bounds =
+            QuotaPeriodBounds::get(QuotaPeriod::Weekly, Tz::UTC, datetime!(2026-10-15 09:00))
+                .unwrap();
+
+        assert_eq!(bounds.start, datetime!(2026-10-12 00:00));
+        assert_eq!(bounds.end, datetime!(2026-10-19 00:00));
+    }
+
+    #[test]
+    fn test_amounts_are_converted_to_the_minor_unit_of_the_reporting_currency() {
+        // 1 USD is 100 JPY and 0.5 KWD
+        let rates = ExchangeRates::new(
+            enums::Currency::USD,
+            HashMap::from([
+                (
+                    enums::Currency::JPY,
+                    CurrencyFactors::new(Decimal::new(100, 0), Decimal::new(1, 2)),
+                ),
+                (
+                    enums::Currency::KWD,
+                    CurrencyFactors::new(Decimal::new(5, 1), Decimal::new(2, 0)),
+                ),
+            ]),
+        );
+        let convert = |amount: i64, currency, quota_currency| {
+            convert_to_quota_currency(&rates, MinorUnit::new(amount), currency, quota_currency)
+                .unwrap()
+        };
+
+        // $10.00
+        assert_eq!(
+            convert(1000, enums::Currency::USD, enums::Currency::USD),
+            1000
+        );
+        assert_eq!(
+            convert(1000, enums::Currency::USD, enums::Currency::JPY),
+            1000
+        );
+        assert_eq!(
+            convert(1000, enums::Currency::USD, enums::Currency::KWD),
+            5000
+        );
+        // ¥1000 and 1.500 KWD
+        assert_eq!(
+            convert(1000, enums::Currency::JPY, enums::Currency::USD),
+            1000
+        );
+        assert_eq!(
+            convert(1500, enums::Currency::KWD, enums::Currency::USD),
+            300
+        );
+        assert!(convert_to_quota_currency(
+            &rates,
+            MinorUnit::new(1000),
+            enums::Currency::EUR,
+            enums::Currency::USD
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_usage_exceeding_a_limit_is_reported() {
+        let quota = get_quota(QuotaPeriod::Monthly);
+        let within_quota = QuotaUsage {
+            payment_count: 100,
+            volume: 10_000,
+        };
+
+        assert_eq!(within_quota.get_exceeded_limit(&quota), None);
+        assert_eq!(
+            QuotaUsage {
+                payment_count: 101,
+                ..within_quota
+            }
+            .get_exceeded_limit(&quota),
+            Some("payment count")
+        );
+        assert_eq!(
+            QuotaUsage {
+                volume: 10_001,
+                ..within_quota
+            }
+            .get_exceeded_limit(&quota),
+            Some("payment volume")
+        );
+    }
+
+    #[test]
+    fn test_warning_threshold_is_crossed_only_once() {
+        let quota = get_quota(QuotaPeriod::Monthly);
+        let below_threshold = QuotaUsage {
+            payment_count: 79,
+            volume: 1000,
+        };
+        let at_threshold = QuotaUsage {
+            payment_count: 80,
+            volume: 1000,
+        };
+        let above_threshold = QuotaUsage {
+            payment_count: 81,
+            volume: 1000,
+        };
+
+        assert!(at_threshold.has_crossed_warning_threshold(&below_threshold, &quota));
+        assert!(!above_threshold.has_crossed_warning_threshold(&at_threshold, &quota));
+    }
+
+    #[tokio::test]
+    async fn test_payments_exceeding_the_quota_are_rejected_until_usage_is_released() {
+        let state = get_session_state().await;
+        let merchant_id = get_test_merchant_id();
+        let quota = MerchantQuota {
+            payment_count_limit: Some(1),
+            volume_limit: None,
+            warning_threshold_percent: None,
+            ..get_quota(QuotaPeriod::Daily)
+        };
+        let get_bounds =
+            || QuotaPeriodBounds::get(quota.period, Tz::UTC, date_time::now()).unwrap();
+        let reserve = || {
+            reserve_quota_usage(
+                &state,
+                &merchant_id,
+                quota.clone(),
+                get_bounds(),
+                vec![(PAYMENT_COUNT_FIELD, 1)],
+            )
+        };
+
+        let reservation = reserve().await.unwrap();
+        let error = reserve().await.unwrap_err();
+        assert!(matches!(
+            error.current_context(),
+            errors::ApiErrorResponse::QuotaExceeded { .. }
+        ));
+        // The rejected payment is not accounted
+        let usage = get_quota_usage(&state, &merchant_id, &get_bounds())
+            .await
+            .unwrap();
+        assert_eq!(usage.payment_count, 1);
+
+        // Usage given back by a payment which was not created is available to the next payment
+        release_payment_quota(&state, reservation).await;
+        assert!(reserve().await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_only_the_payment_crossing_the_warning_threshold_notifies_the_merchant() {
+        let state = get_session_state().await;
+        let merchant_id = get_test_merchant_id();
+        let quota = MerchantQuota {
+            payment_count_limit: Some(5),
+            ..get_quota(QuotaPeriod::Daily)
+        };
+        let bounds = || QuotaPeriodBounds::get(quota.period, Tz::UTC, date_time::now()).unwrap();
+
+        let mut threshold_reached_usages = Vec::new();
+        for _ in 0..5 {
+            let reservation = reserve_quota_usage(
+                &state,
+                &merchant_id,
+                quota.clone(),
+                bounds(),
+                vec![(PAYMENT_COUNT_FIELD, 1), (VOLUME_FIELD, 100)],
+            )
+            .await
+            .unwrap();
+            threshold_reached_usages.push(reservation.threshold_reached_usage);
+        }
+
+        // The fourth payment takes the usage to 80% of the payment count limit
+        let usage = threshold_reached_usages
+            .iter()
+            .enumerate()
+            .filter_map(|(index, usage)| usage.as_ref().map(|usage| (index, usage)))
+            .collect::<Vec<_>>();
+        let [(index, usage)] = usage.as_slice() else {
+            panic!("exactly one payment should cross the warning threshold, got {usage:?}");
+        };
+        assert_eq!(*index, 3);
+        assert_eq!(
+            usage.payment_count,
+            Some(PaymentCountUsage { used: 4, limit: 5 })
+        );
+        assert_eq!(
+            usage.volume.as_ref().map(|volume| volume.used),
+            Some(MinorUnit::new(400))
+        );
+
+        let payment_id =
+            common_utils::id_type::PaymentId::generate_test_payment_id_for_sample_data();
+        let content =
+            get_quota_threshold_reached_webhook_content(payment_id.clone(), (*usage).clone());
+        assert!(matches!(
+            storage::EventMetadata::foreign_from(&content),
+            storage::EventMetadata::Payment { payment_id: id } if id == payment_id
+        ));
+    }
+}
@@ -0,0 +1,688 @@
+//! Merchant data takeout helpers.
+use api_models::takeout::{self as api_takeout, TakeoutPiiHandling, TakeoutResource};
+use common_utils::{
+    crypto::{self, SignMessage, VerifySignature},
+    date_time, id_type,
+    types::TimeRange,
+};
+use error_stack::ResultExt;
+use futures::future::join_all;
+use hyperswitch_domain_models::payments::payment_intent::{
+    PaymentIntentFetchConstraints, PaymentIntentListParams,
+};
+use hyperswitch_masking::{PeekInterface, Secret};
+use router_env::{instrument, tracing};
+use scheduler::utils as pt_utils;
+
+use crate::{
+    core::errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+    logger,
+    routes::SessionState,
+    services,
+    types::{
+        api::{self, customers},
+        domain, storage,
+        transformers::{ForeignFrom, ForeignInto, ForeignTryFrom, ForeignTryInto},
+    },
+};
+
+const MERCHANT_TAKEOUT_TASK: &str = "MERCHANT_TAKEOUT";
+const MERCHANT_TAKEOUT_TAGS: [&str; 2] = ["MERCHANT", "TAKEOUT"];
+const TAKEOUT_FILE_CONTENT_TYPE: &str = "application/x-ndjson";
+
+/// Returns the name of a part of the export of a resource, parts are numbered from 1.
+pub(crate) fn takeout_file_name(resource: TakeoutResource, part: usize) -> String {
+    format!("{resource}_{part:04}.jsonl")
+}
+
+/// Returns the file storage key for an exported file of a takeout.
+pub(crate) fn takeout_file_key(merchant_id: &str, job_id: &str, file_name: &str) -> String {
+    format!("takeout/{merchant_id}/{job_id}/{file_name}")
+}
+
+fn get_process_tracker_id(merchant_id: &id_type::MerchantId, job_id: &str) -> String {
+    pt_utils::get_process_tracker_id(
+        storage::ProcessTrackerRunner::MerchantTakeoutWorkflow,
+        MERCHANT_TAKEOUT_TASK,
+        job_id,
+        merchant_id,
+    )
+}
+
+fn get_download_url_message(
+    merchant_id: &id_type::MerchantId,
+    job_id: &str,
+    file_name: &str,
+    expires: i64,
+) -> String {
+    format!(
+        "{}:{job_id}:{file_name}:{expires}",
+        merchant_id.get_string_repr()
+    )
+}
+
+/// Signs the download URL of a takeout file, binding it to the file and its expiry.
+fn sign_download_url(
+    signing_key: &Secret<String>,
+    merchant_id: &id_type::MerchantId,
+    job_id: &str,
+    file_name: &str,
+    expires: i64,
+) -> RouterResult<String> {
+    crypto::HmacSha256
+        .sign_message(
+            signing_key.peek().as_bytes(),
+            get_download_url_message(merchant_id, job_id, file_name, expires).as_bytes(),
+        )
+        .map(hex::encode)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to sign takeout file download URL")
+}
+
+/// Verifies the signature of the download URL of a takeout file. Malformed signatures are
+/// treated as invalid.
+fn verify_download_url(
+    signing_key: &Secret<String>,
+    merchant_id: &id_type::MerchantId,
+    job_id: &str,
+    file_name: &str,
+    expires: i64,
+    signature: &Secret<String>,
+) -> RouterResult<bool> {
+    let Ok(signature) = hex::decode(signature.peek()) else {
+        return Ok(false);
+    };
+    crypto::HmacSha256
+        .verify_signature(
+            signing_key.peek().as_bytes(),
+            &signature,
+            get_download_url_message(merchant_id, job_id, file_name, expires).as_bytes(),
+        )
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to verify takeout file download URL")
+}
+
+fn get_download_url(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    job_id: &str,
+    file_name: &str,
+    expires: i64,
+) -> RouterResult<Secret<String>> {
+    let signature = sign_download_url(
+        &state
+            .conf
+            .merchant_takeout
+            .get_inner()
+            .download_url_signing_key,
+        merchant_id,
+        job_id,
+        file_name,
+        expires,
+    )?;
+    Ok(Secret::new(format!(
+        "{}/accounts/{}/takeout/{job_id}/files/{file_name}?expires={expires}&signature={signature}",
+        state.base_url,
+        merchant_id.get_string_repr(),
+    )))
+}
+
+fn get_takeout_status(
+    process: &storage::ProcessTracker,
+    tracking_data: &storage::MerchantTakeoutTrackingData,
+) -> api_takeout::TakeoutJobStatus {
+    match process.status {
+        common_enums::ProcessTrackerStatus::Finish
+            if process.business_status == storage::business_status::COMPLETED_BY_PT =>
+        {
+            api_takeout::TakeoutJobStatus::Completed
+        }
+        common_enums::ProcessTrackerStatus::Finish => api_takeout::TakeoutJobStatus::Failed,
+        common_enums::ProcessTrackerStatus::ProcessStarted => {
+            api_takeout::TakeoutJobStatus::Processing
+        }
+        _ if !tracking_data.files.is_empty() => api_takeout::TakeoutJobStatus::Processing,
+        _ => api_takeout::TakeoutJobStatus::Pending,
+    }
+}
+
+async fn find_takeout_process(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    job_id: &str,
+) -> RouterResult<(
+    storage::ProcessTracker,
+    storage::MerchantTakeoutTrackingData,
+)> {
+    use common_utils::ext_traits::ValueExt;
+
+    let process = state
+        .store
+        .as_scheduler()
+        .find_process_by_id(&get_process_tracker_id(merchant_id, job_id))
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch merchant takeout process")?
+        .ok_or(errors::ApiErrorResponse::GenericNotFoundError {
+            message: format!("Merchant takeout `{job_id}` not found"),
+        })?;
+
+    let tracking_data = process
+        .tracking_data
+        .clone()
+        .parse_value::<storage::MerchantTakeoutTrackingData>("MerchantTakeoutTrackingData")
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+    Ok((process, tracking_data))
+}
+
+/// Enqueues a process tracker job generating the takeout of the merchant.
+#[instrument(skip_all, fields(flow = ?router_env::Flow::MerchantTakeoutCreate))]
+pub async fn create_merchant_takeout(
+    state: SessionState,
+    platform: domain::Platform,
+    req: api_takeout::MerchantTakeoutRequest,
+) -> RouterResponse<api_takeout::MerchantTakeoutResponse> {
+    let merchant_id = platform.get_processor().get_account().get_id();
+
+    if state
+        .conf
+        .merchant_takeout
+        .get_inner()
+        .download_url_signing_key
+        .peek()
+        .is_empty()
+    {
+        return Err(errors::ApiErrorResponse::NotSupported {
+            message: "Merchant data takeout is not configured".to_string(),
+        }
+        .into());
+    }
+
+    let job_id = common_utils::generate_id(crate::consts::ID_LENGTH, "takeout");
+    let now = date_time::now();
+    let tracking_data = storage::MerchantTakeoutTrackingData {
+        job_id: job_id.clone(),
+        merchant_id: merchant_id.clone(),
+        redaction: req.redaction,
+        snapshot_at: now,
+        completed_resources: Vec::new(),
+        resource_offset: 0,
+        files: Vec::new(),
+    };
+
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        get_process_tracker_id(merchant_id, &job_id),
+        MERCHANT_TAKEOUT_TASK,
+        storage::ProcessTrackerRunner::MerchantTakeoutWorkflow,
+        MERCHANT_TAKEOUT_TAGS,
+        tracking_data.clone(),
+        None,
+        now,
+        common_types::consts::API_VERSION,
+        common_enums::ApplicationSource::Main,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to create ProcessTrackerNew for merchant takeout")?;
+
+    state
+        .store
+        .insert_process(process_tracker_entry)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to enqueue merchant takeout ProcessTracker task")?;
+
+    logger::info!(
+        job_id = %job_id,
+        redaction = ?tracking_data.redaction,
+        "Merchant takeout initiated"
+    );
+
+    Ok(services::ApplicationResponse::Json(
+        api_takeout::MerchantTakeoutResponse {
+            job_id,
+            merchant_id: merchant_id.clone(),
+            status: api_takeout::TakeoutJobStatus::Pending,
+            redaction: tracking_data.redaction,
+            files: Vec::new(),
+            download_urls_expire_at: None,
+            created_at: now,
+        },
+    ))
+}
+
+/// Fetches the status of a takeout, with signed download URLs of the files once it is completed.
+#[instrument(skip_all, fields(flow = ?router_env::Flow::MerchantTakeoutRetrieve))]
+pub async fn retrieve_merchant_takeout(
+    state: SessionState,
+    platform: domain::Platform,
+    job_id: String,
+) -> RouterResponse<api_takeout::MerchantTakeoutResponse> {
+    let merchant_id = platform.get_processor().get_account().get_id();
+    let (process, tracking_data) = find_takeout_process(&state, merchant_id, &job_id).await?;
+    let status = get_takeout_status(&process, &tracking_data);
+
+    let takeout_config = state.conf.merchant_takeout.get_inner();
+    let download_urls_expire_at = (status == api_takeout::TakeoutJobStatus::Completed).then(|| {
+        date_time::now().saturating_add(time::Duration::seconds(i64::from(
+            takeout_config.download_url_expiry_in_secs,
+        )))
+    });
+
+    let files = tracking_data
+        .files
+        .into_iter()
+        .map(|file| {
+            let download_url = download_urls_expire_at
+                .map(|expire_at| {
+                    get_download_url(
+                        &state,
+                        merchant_id,
+                        &job_id,
+                        &file.file_name,
+                        expire_at.assume_utc().unix_timestamp(),
+                    )
+                })
+                .transpose()?;
+            Ok(api_takeout::TakeoutFile {
+                resource: file.resource,
+                file_name: file.file_name,
+                record_count: file.record_count,
+                download_url,
+            })
+        })
+        .collect::<RouterResult<Vec<_>>>()?;
+
+    Ok(services::ApplicationResponse::Json(
+        api_takeout::MerchantTakeoutResponse {
+            job_id,
+            merchant_id: merchant_id.clone(),
+            status,
+            redaction: tracking_data.redaction,
+            files,
+            download_urls_expire_at,
+            created_at: process.created_at,
+        },
+    ))
+}
+
+/// Serves an exported file of a completed takeout, if the signature of its download URL is valid.
+#[instrument(skip_all, fields(flow = ?router_env::Flow::MerchantTakeoutFileDownload))]
+pub async fn download_merchant_takeout_file(
+    state: SessionState,
+    merchant_id: id_type::MerchantId,
+    job_id: String,
+    file_name: String,
+    query: api_takeout::TakeoutFileDownloadQuery,
+) -> RouterResponse<()> {
+    let forbidden = || errors::ApiErrorResponse::AccessForbidden {
+        resource: "takeout file".to_string(),
+    };
+
+    let is_valid_signature = verify_download_url(
+        &state
+            .conf
+            .merchant_takeout
+            .get_inner()
+            .download_url_signing_key,
+        &merchant_id,
+        &job_id,
+        &file_name,
+        query.expires,
+        &query.signature,
+    )?;
+    if !is_valid_signature || query.expires < date_time::now_unix_timestamp() {
+        return Err(forbidden().into());
+    }
+
+    let (process, tracking_data) = find_takeout_process(&state, &merchant_id, &job_id).await?;
+    if get_takeout_status(&process, &tracking_data) != api_takeout::TakeoutJobStatus::Completed {
+        return Err(forbidden().into());
+    }
+
+    let file = tracking_data
+        .files
+        .into_iter()
+        .find(|file| file.file_name == file_name)
+        .ok_or(errors::ApiErrorResponse::FileNotFound)?;
+
+    let file_data = state
+        .file_storage_client
+        .retrieve_file(&file.file_key)
+        .await
+        .change_context(errors::ApiErrorResponse::FileNotAvailable)
+        .attach_printable_lazy(|| format!("Failed to retrieve takeout file {file_name}"))?;
+
+    let content_type = TAKEOUT_FILE_CONTENT_TYPE
+        .parse::<mime::Mime>()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse takeout file content type")?;
+
+    Ok(services::ApplicationResponse::FileData((
+        file_data,
+        content_type,
+    )))
+}
+
+/// Serializes the exported records of a resource as JSON lines.
+pub(crate) fn records_to_jsonl(records: &[serde_json::Value]) -> RouterResult<Vec<u8>> {
+    let mut bytes = Vec::new();
+    for record in records {
+        serde_json::to_writer(&mut bytes, record)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to serialize takeout record")?;
+        bytes.push(b'\n');
+    }
+    Ok(bytes)
+}
+
+fn to_records<T: serde::Serialize>(items: Vec<T>) -> RouterResult<Vec<serde_json::Value>> {
+    items
+        .into_iter()
+        .map(|item| {
+            serde_json::to_value(item)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to serialize takeout record")
+        })
+        .collect()
+}
+
+/// Returns the page of a non-paginated list of records, which are all loaded at once.
+fn get_page<T>(items: impl IntoIterator<Item = T>, offset: u32, limit: u16) -> Vec<T> {
+    items
+        .into_iter()
+        .skip(usize::try_from(offset).unwrap_or(usize::MAX))
+        .take(usize::from(limit))
+        .collect()
+}
+
+/// Exports a page of the records of a resource created before the snapshot time of the takeout,
+/// with the secrets and the redacted customer details left out.
+#[instrument(skip_all, fields(resource = %resource))]
+pub(crate) async fn export_resource_page(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    tracking_data: &storage::MerchantTakeoutTrackingData,
+    resource: TakeoutResource,
+    offset: u32,
+    limit: u16,
+) -> RouterResult<Vec<serde_json::Value>> {
+    let db = &*state.store;
+    let merchant_id = merchant_account.get_id();
+    let storage_scheme = merchant_account.storage_scheme;
+    let redact_customer_pii = tracking_data.redaction.customer_pii == TakeoutPiiHandling::Redact;
+    let time_range = TimeRange {
+        start_time: merchant_account.created_at,
+        end_time: Some(tracking_data.snapshot_at),
+    };
+
+    match resource {
+        TakeoutResource::Profiles => {
+            let profiles = db
+                .list_profile_by_merchant_id(key_store, merchant_id)
+                .await
+                .to_not_found_response(errors::ApiErrorResponse::InternalServerError)?;
+            let profiles = get_page(profiles, offset, limit)
+                .into_iter()
+                .map(|profile| {
+                    let mut profile = api_models::admin::ProfileResponse::foreign_try_from(profile)
+                        .change_context(errors::ApiErrorResponse::InternalServerError)
+                        .attach_printable("Failed to parse business profile details")?;
+                    profile.payment_response_hash_key = None;
+                    if let Some(webhook_details) = profile.webhook_details.as_mut() {
+                        webhook_details.webhook_password = None;
+                    }
+                    Ok(profile)
+                })
+                .collect::<RouterResult<Vec<_>>>()?;
+            to_records(profiles)
+        }
+        TakeoutResource::MerchantConnectorAccounts => {
+            let merchant_connector_accounts = db
+                .find_merchant_connector_account_without_encrypted_by_merchant_id_and_disabled_list(
+                    merchant_id,
+                    true,
+                )
+                .await
+                .to_not_found_response(errors::ApiErrorResponse::InternalServerError)?;
+            let merchant_connector_accounts = get_page(merchant_connector_accounts, offset, limit)
+                .into_iter()
+                .map(ForeignTryInto::foreign_try_into)
+                .collect::<RouterResult<Vec<api_models::admin::MerchantConnectorListResponse>>>()?;
+            to_records(merchant_connector_accounts)
+        }
+        TakeoutResource::Payments => {
+            let constraints =
+                PaymentIntentFetchConstraints::List(Box::new(PaymentIntentListParams {
+                    offset,
+                    starting_at: None,
+                    ending_at: Some(tracking_data.snapshot_at),
+                    amount_filter: None,
+                    connector: None,
+                    currency: None,
+                    status: None,
+                    payment_method: None,
+                    payment_method_type: None,
+                    authentication_type: None,
+                    merchant_connector_id: None,
+                    profile_id: None,
+                    customer_id: None,
+                    starting_after_id: None,
+                    ending_before_id: None,
+                    limit: Some(u32::from(limit)),
+                    order: Default::default(),
+                    card_network: None,
+                    card_discovery: None,
+                    merchant_order_reference_id: None,
+                    attribution_tags: None,
+                    customer_email_hash: None,
+                    customer_phone_hash: None,
+                }));
+            let payment_intents = db
+                .filter_payment_intent_by_constraints(
+                    merchant_id,
+                    &constraints,
+                    key_store,
+                    storage_scheme,
+                )
+                .await
+                .to_not_found_response(errors::ApiErrorResponse::InternalServerError)?;
+
+            let payment_attempt_futures = payment_intents.into_iter().map(|payment_intent| async {
+                db.find_payment_attempt_by_payment_id_processor_merchant_id_attempt_id(
+                    &payment_intent.payment_id,
+                    merchant_id,
+                    &payment_intent.active_attempt.get_id(),
+                    // since OLAP doesn't have KV. Force to get the data from PSQL.
+                    storage::enums::MerchantStorageScheme::PostgresOnly,
+                    key_store,
+                )
+                .await
+                .map(|payment_attempt| (payment_intent, payment_attempt))
+            });
+            let payments = join_all(payment_attempt_futures)
+                .await
+                .into_iter()
+                .collect::<Result<Vec<_>, _>>()
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to fetch payment attempts for takeout")?
+                .into_iter()
+                .map(|payment| {
+                    let mut payment = api::PaymentsResponse::foreign_from(payment);
+                    payment.client_secret = None;
+                    if redact_customer_pii {
+                        payment.customer = None;
+                        payment.email = None;
+                        payment.name = None;
+                        payment.phone = None;
+                        payment.billing = None;
+                        payment.shipping = None;
+                        if let Some(payment_method_data) = payment.payment_method_data.as_mut() {
+                            payment_method_data.billing = None;
+                        }
+                    }
+                    payment
+                })
+                .collect::<Vec<_>>();
+            to_records(payments)
+        }
+        TakeoutResource::Refunds => {
+            let constraints = hyperswitch_domain_models::refunds::RefundListConstraints {
+                payment_id: None,
+                refund_id: None,
+                profile_id: None,
+                limit: None,
+                offset: None,
+                time_range: Some(time_range),
+                amount_filter: None,
+                connector: None,
+                merchant_connector_id: None,
+                currency: None,
+                refund_status: None,
+            };
+            let refunds = db
+                .filter_refund_by_constraints(
+                    merchant_id,
+                    &constraints,
+                    storage_scheme,
+                    i64::from(limit),
+                    i64::from(offset),
+                )
+                .await
+                .to_not_found_response(errors::ApiErrorResponse::InternalServerError)?
+                .into_iter()
+                .map(ForeignInto::foreign_into)
+                .collect::<Vec<api_models::refunds::RefundResponse>>();
+            to_records(refunds)
+        }
+        TakeoutResource::Disputes => {
+            let constraints = hyperswitch_domain_models::disputes::DisputeListConstraints {
+                dispute_id: None,
+                payment_id: None,
+                limit: Some(u32::from(limit)),
+                offset: Some(offset),
+                profile_id: None,
+                dispute_status: None,
+                dispute_stage: None,
+                reason: None,
+                connector: None,
+                merchant_connector_id: None,
+                currency: None,
+                time_range: Some(time_range),
+            };
+            let disputes = db
+                .find_disputes_by_constraints(merchant_id, &constraints, storage_scheme)
+                .await
+                .to_not_found_response(errors::ApiErrorResponse::InternalServerError)?
+                .into_iter()
+                .map(api_models::disputes::DisputeResponse::foreign_from)
+                .collect::<Vec<_>>();
+            to_records(disputes)
+        }
+        TakeoutResource::Customers => {
+            let constraints = hyperswitch_domain_models::customer::CustomerListConstraints {
+                limit,
+                offset: Some(offset),
+                customer_id: None,
+                time_range: Some(time_range),
+            };
+            let customers = db
+                .list_customers_by_merchant_id(merchant_id, key_store, constraints)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to list customers for takeout")?
+                .into_iter()
+                .map(|customer| {
+                    let mut customer = customers::CustomerResponse::try_from((customer, None))
+                        .change_context(errors::ApiErrorResponse::InternalServerError)
+                        .attach_printable("Failed to convert domain customer to CustomerResponse")?
+                        .0;
+                    if redact_customer_pii {
+                        customer.name = None;
+                        customer.email = None;
+                        customer.phone = None;
+                        customer.phone_country_code = None;
+                        customer.address = None;
+                        customer.tax_registration_id = None;
+                        customer.document_details = None;
+                    }
+                    Ok(customer)
+                })
+                .collect::<RouterResult<Vec<_>>>()?;
+            to_records(customers)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_download_url_signature_is_bound_to_file_and_expiry() {
+        let signing_key = Secret::new("takeout_signing_key".to_string());
+        let merchant_id = id_type::MerchantId::try_from(std::borrow::Cow::from("merchant_1"))
+            .expect("valid merchant id");
+        let signature = Secret::new(
+            sign_download_url(
+                &signing_key,
+                &merchant_id,
+                "takeout_1",
+                "payments_0001.jsonl",
+                100,
+            )
+            .expect("signing succeeds"),
+        );
+        let verify = |key: &Secret<String>, file_name: &str, expires: i64, signature| {
+            verify_download_url(
+                key,
+                &merchant_id,
+                "takeout_1",
+                file_name,
+                expires,
+                signature,
+            )
+            .expect("verification succeeds")
+        };
+
+        assert!(verify(&signing_key, "payments_0001.jsonl", 100, &signature));
+        assert!(!verify(
+            &signing_key,
+            "customers_0001.jsonl",
+            100,
+            &signature
+        ));
+        assert!(!verify(
+            &signing_key,
+            "payments_0001.jsonl",
+            200,
+            &signature
+        ));
+        assert!(!verify(
+            &Secret::new("another_key".to_string()),
+            "payments_0001.jsonl",
+            100,
+            &signature
+        ));
+        assert!(!verify(
+            &signing_key,
+            "payments_0001.jsonl",
+            100,
+            &Secret::new("not-hex".to_string())
+        ));
+    }
+
+    #[test]
+    fn test_records_to_jsonl_writes_one_record_per_line() {
+        let records = vec![
+            serde_json::json!({ "payment_id": "pay_1" }),
+            serde_json::json!({ "payment_id": "pay_2" }),
+        ];
+        let jsonl = records_to_jsonl(&records).expect("serialization succeeds");
+        assert_eq!(
+            String::from_utf8(jsonl).expect("valid utf-8"),
+            "{\"payment_id\":\"pay_1\"}\n{\"payment_id\":\"pay_2\"}\n"
+        );
+    }
+}
@@ -20,9 +20,12 @@ use super::{
     NetworkTokenizationResponse, State, StoreLockerResponse, TransitionTo,
 };
 use crate::{
-    core::payment_methods::{
-        cards::{add_card_to_vault, PmCards},
-        transformers as pm_transformers,
+    core::{
+        customer_search,
+        payment_methods::{
+            cards::{add_card_to_vault, PmCards},
+            transformers as pm_transformers,
+        },
     },
     errors::{self, RouterResult},
     types::{api, domain},
@@ -420,7 +423,7 @@ impl CardNetworkTokenizeExecutor<'_, domain::TokenizeCardRequest> {
                 .attach_printable("Failed to form EncryptableCustomer")?;
 
         let new_customer_id = generate_customer_id_of_default_length();
-        let domain_customer = domain::Customer::new(
+        let mut domain_customer = domain::Customer::new(
             new_customer_id.clone(),
             self.merchant_account.get_id().clone(),
             encryptable_customer.name,
@@ -442,6 +445,9 @@ impl CardNetworkTokenizeExecutor<'_, domain::TokenizeCardRequest> {
             initiator.and_then(|initiator| initiator.to_created_by()),
             id_type::GlobalCustomerId::generate(&self.state.conf.cell_information.id),
         );
+        customer_search::set_customer_contact_hashes(self.state, &mut domain_customer)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to hash the contact details of the customer")?;
 
         db.insert_customer(
             domain_customer,
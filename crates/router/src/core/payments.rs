@@ -1,11 +1,15 @@
 use hyperswitch_domain_models::mandates;
 pub mod access_token;
+#[cfg(feature = "v1")]
+pub mod avs_decision;
 pub mod conditional_configs;
 pub mod customers;
 pub mod flows;
 pub mod gateway;
 pub mod helpers;
 pub mod operations;
+#[cfg(feature = "v1")]
+pub mod order_cancellation;
 pub mod session_token;
 
 pub mod client_session;
@@ -14,6 +18,7 @@ pub mod retry;
 pub mod routing;
 #[cfg(feature = "v2")]
 pub mod session_operation;
+pub mod state_machine;
 pub mod tokenization;
 pub mod transformers;
 pub mod types;
@@ -77,7 +82,7 @@ use hyperswitch_masking::{ExposeInterface, PeekInterface, Secret};
 #[cfg(feature = "v2")]
 use operations::ValidateStatusForOperation;
 use redis_interface::errors::RedisError;
-use router_env::{instrument, tracing};
+use router_env::{instrument, tracing, tracing::Instrument};
 #[cfg(feature = "olap")]
 use router_types::transformers::ForeignFrom;
 use routing::{RoutingStage, StraightThroughRoutingInput, StraightThroughRoutingStage};
@@ -116,6 +121,8 @@ use super::{
 use crate::core::blocklist::utils as blocklist_utils;
 #[cfg(feature = "v1")]
 use crate::core::card_testing_guard::utils as card_testing_guard_utils;
+#[cfg(all(feature = "olap", feature = "v1"))]
+use crate::core::customer_search;
 #[cfg(feature = "v1")]
 use crate::core::debit_routing;
 #[cfg(feature = "frm")]
@@ -126,6 +133,8 @@ use crate::core::payment_methods::vault;
 use crate::core::revenue_recovery::map_to_recovery_payment_item;
 #[cfg(feature = "v1")]
 use crate::core::routing::helpers as routing_helpers;
+#[cfg(all(feature = "olap", feature = "v1"))]
+use crate::events::audit_events;
 #[cfg(all(feature = "v1", feature = "dynamic_routing"))]
 use crate::types::api::convert_connector_data_to_routable_connectors;
 use crate::{
@@ -678,6 +687,9 @@ where
     PaymentResponse: Operation<F, FData, Data = D>,
     FData: Send + Sync + Clone + router_types::Capturable + 'static + serde::Serialize,
 {
+    // The time budget of the confirm also covers the steps performed before the connector call
+    #[cfg(all(feature = "retry", feature = "v1"))]
+    let confirm_started_at = std::time::Instant::now();
     let operation: BoxedOperation<'_, F, Req, D> = Box::new(operation);
 
     tracing::Span::current().record(
@@ -1331,6 +1343,10 @@ where
                                 &business_profile,
                                 &feature_config,
                                 &dimensions,
+                                retry::ConfirmTimeBudget::new(
+                                    confirm_started_at,
+                                    &business_profile,
+                                ),
                             )
                             .await?;
                         };
@@ -4441,6 +4457,18 @@ pub struct PaymentsRedirectResponseData {
     pub resource_id: api::PaymentIdType,
     pub force_sync: bool,
     pub creds_identifier: Option<String>,
+    #[serde(skip)]
+    pub redirect_completion_details: Option<RedirectCompletionDetails>,
+}
+
+/// Details of the customer's browser captured when the customer is redirected back from the
+/// connector
+#[cfg(feature = "v1")]
+#[derive(Clone, Debug)]
+pub struct RedirectCompletionDetails {
+    pub completed_at: time::PrimitiveDateTime,
+    pub ip_address: Option<Secret<String, common_utils::pii::IpAddress>>,
+    pub user_agent: Option<String>,
 }
 
 #[cfg(feature = "v2")]
@@ -4491,6 +4519,16 @@ pub trait PaymentRedirectFlow: Sync {
         state: &SessionState,
     ) -> RouterResult<services::ApplicationResponse<api::RedirectionResponse>>;
 
+    /// Get the mandate set up by this payment if the customer accepted it on the bank page and
+    /// the connector confirmed its creation on redirect completion
+    #[cfg(feature = "v1")]
+    fn get_bank_page_accepted_mandate_id(
+        &self,
+        _payment_flow_response: &Self::PaymentFlowResponse,
+    ) -> Option<String> {
+        None
+    }
+
     #[cfg(feature = "v2")]
     fn generate_response(
         &self,
@@ -4551,6 +4589,46 @@ pub trait PaymentRedirectFlow: Sync {
             .change_context(errors::ApiErrorResponse::InternalServerError)
             .attach_printable("Failed to decide the response flow")?;
 
+        // A webhook may have moved the attempt to a terminal status before the customer's
+        // browser returned, the final status is returned right away without a connector call
+        if state
+            .conf
+            .redirect_completion
+            .short_circuit_terminal_attempts
+        {
+            let attempt_status =
+                get_active_attempt_status(&state, platform.get_processor(), &resource_id).await?;
+            if attempt_status.is_terminal_status() {
+                logger::info!(
+                    ?attempt_status,
+                    "Attempt is already in a terminal status, skipping connector call on redirect completion"
+                );
+                let payment_sync_flow = PaymentRedirectSync;
+                let payment_flow_response = payment_sync_flow
+                    .call_payment_flow(
+                        &state,
+                        req_state,
+                        platform,
+                        req,
+                        CallConnectorAction::Avoid,
+                        connector.clone(),
+                        resource_id.clone(),
+                    )
+                    .await?;
+                return payment_sync_flow.generate_response(
+                    &payment_flow_response,
+                    resource_id,
+                    connector,
+                    &state,
+                );
+            }
+        }
+
+        let merchant_id = platform.get_processor().get_account().get_id().clone();
+        let storage_scheme = platform.get_processor().get_account().storage_scheme;
+        let redirect_completion_span =
+            get_redirect_completion_span(&state, platform.get_processor(), &resource_id).await;
+
         let payment_flow_response = self
             .call_payment_flow(
                 &state,
@@ -4561,8 +4639,32 @@ pub trait PaymentRedirectFlow: Sync {
                 connector.clone(),
                 resource_id.clone(),
             )
+            .instrument(redirect_completion_span)
             .await?;
 
+        if let Some((mandate_id, redirect_completion_details)) = self
+            .get_bank_page_accepted_mandate_id(&payment_flow_response)
+            .zip(req.redirect_completion_details.as_ref())
+        {
+            // Failing to record the acceptance must not fail the redirection, the acceptance
+            // provided at confirm is retained on the mandate
+            crate::core::mandate::update_customer_acceptance_on_redirect_completion(
+                state.store.as_ref(),
+                &merchant_id,
+                &mandate_id,
+                redirect_completion_details,
+                storage_scheme,
+            )
+            .await
+            .map_err(|error| {
+                logger::error!(
+                    ?error,
+                    "Failed to record customer acceptance captured at redirect completion"
+                )
+            })
+            .ok();
+        }
+
         self.generate_response(&payment_flow_response, resource_id, connector, &state)
     }
 
@@ -4592,6 +4694,91 @@ pub trait PaymentRedirectFlow: Sync {
     }
 }
 
+#[cfg(feature = "v1")]
+async fn get_active_attempt_status(
+    state: &SessionState,
+    processor: &domain::Processor,
+    payment_id: &id_type::PaymentId,
+) -> RouterResult<storage_enums::AttemptStatus> {
+    let payment_intent = state
+        .store
+        .find_payment_intent_by_payment_id_processor_merchant_id(
+            payment_id,
+            processor.get_account().get_id(),
+            processor.get_key_store(),
+            processor.get_account().storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+    let payment_attempt = state
+        .store
+        .find_payment_attempt_by_payment_id_processor_merchant_id_attempt_id(
+            &payment_intent.payment_id,
+            processor.get_account().get_id(),
+            &payment_intent.active_attempt.get_id(),
+            processor.get_account().storage_scheme,
+            processor.get_key_store(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    Ok(payment_attempt.status)
+}
+
+/// Span of the connector call on redirect completion, linked to the span of the authorize which
+/// was pending the redirect. The trace context of the authorize is looked up only if the traces
+/// are exported.
+#[cfg(feature = "v1")]
+async fn get_redirect_completion_span(
+    state: &SessionState,
+    processor: &domain::Processor,
+    payment_id: &id_type::PaymentId,
+) -> tracing::Span {
+    let span = tracing::info_span!(
+        "redirect_completion_sync",
+        payment_id = payment_id.get_string_repr()
+    );
+    if router_env::trace_link::get_current_trace_context().is_none() {
+        return span;
+    }
+
+    let key =
+        core_utils::get_authorize_trace_context_key(processor.get_account().get_id(), payment_id);
+    let authorize_trace_context = match state.store.get_redis_conn() {
+        Ok(redis_conn) => redis_conn.get_key::<Option<String>>(&key.into()).await,
+        Err(error) => Err(error),
+    }
+    .map_err(|error| {
+        logger::error!(
+            ?error,
+            "Failed to fetch the trace context of the authorize to link the redirect completion"
+        )
+    })
+    .ok()
+    .flatten();
+
+    if let Some(authorize_trace_context) = authorize_trace_context {
+        router_env::trace_link::link_span_to_trace_context(&span, &authorize_trace_context);
+    }
+    span
+}
+
+/// The customer accepts the mandate on the bank page for bank redirect payment methods (for
+/// example iDEAL or Bancontact set up as SEPA Direct Debit mandates). The connector confirms the
+/// mandate once the payment succeeds or is being processed on redirect completion.
+#[cfg(feature = "v1")]
+fn get_bank_page_accepted_mandate_id(payments_response: &api::PaymentsResponse) -> Option<String> {
+    let is_mandate_confirmed = matches!(
+        payments_response.status,
+        storage_enums::IntentStatus::Succeeded | storage_enums::IntentStatus::Processing
+    );
+
+    (is_mandate_confirmed
+        && payments_response.payment_method == Some(storage_enums::PaymentMethod::BankRedirect))
+    .then(|| payments_response.mandate_id.clone())
+    .flatten()
+}
+
 #[derive(Clone, Debug)]
 pub struct PaymentRedirectCompleteAuthorize;
 
@@ -4652,7 +4839,7 @@ impl PaymentRedirectFlow for PaymentRedirectCompleteAuthorize {
             connector_action,
             None,
             None,
-            HeaderPayload::default(),
+            HeaderPayload::with_status_update_source(state_machine::StatusUpdateSource::Redirect),
             None,
         ))
         .await?;
@@ -4686,6 +4873,13 @@ impl PaymentRedirectFlow for PaymentRedirectCompleteAuthorize {
         services::PaymentAction::CompleteAuthorize
     }
 
+    fn get_bank_page_accepted_mandate_id(
+        &self,
+        payment_flow_response: &Self::PaymentFlowResponse,
+    ) -> Option<String> {
+        get_bank_page_accepted_mandate_id(&payment_flow_response.payments_response)
+    }
+
     fn generate_response(
         &self,
         payment_flow_response: &Self::PaymentFlowResponse,
@@ -4718,6 +4912,7 @@ impl PaymentRedirectFlow for PaymentRedirectCompleteAuthorize {
                         api_models::payments::NextActionData::InvokeUpiIntentSdk{ .. } => None,
                         api_models::payments::NextActionData::InvokeUpiQrFlow{ .. } => None,
                         api_models::payments::NextActionData::InvokeDdc { .. } => None,
+                        api_models::payments::NextActionData::InvokeConnectorSdk { .. } => None,
                     })
                     .ok_or(errors::ApiErrorResponse::InternalServerError)
                     .attach_printable(
@@ -4846,7 +5041,9 @@ impl PaymentRedirectFlow for PaymentRedirectSync {
                 connector_action,
                 None,
                 None,
-                HeaderPayload::default(),
+                HeaderPayload::with_status_update_source(
+                    state_machine::StatusUpdateSource::Redirect,
+                ),
                 None,
             ),
         )
@@ -4945,6 +5142,13 @@ impl PaymentRedirectFlow for PaymentRedirectSync {
     fn get_payment_action(&self) -> services::PaymentAction {
         services::PaymentAction::PSync
     }
+
+    fn get_bank_page_accepted_mandate_id(
+        &self,
+        payment_flow_response: &Self::PaymentFlowResponse,
+    ) -> Option<String> {
+        get_bank_page_accepted_mandate_id(&payment_flow_response.payments_response)
+    }
 }
 
 #[cfg(feature = "v2")]
@@ -5302,7 +5506,9 @@ impl PaymentRedirectFlow for PaymentAuthenticateCompleteAuthorize {
                     connector_action,
                     None,
                     None,
-                    HeaderPayload::default(),
+                    HeaderPayload::with_status_update_source(
+                        state_machine::StatusUpdateSource::Redirect,
+                    ),
                     None,
                 ),
             )
@@ -5512,7 +5718,7 @@ where
         let payment_method_token = match decide_wallet_flow {
             Some(payment_price_data) => {
                 let token = wallet
-                    .decrypt_wallet_token(&payment_price_data, payment_data)
+                    .decrypt_wallet_token(state, &payment_price_data, payment_data)
                     .await
                     .attach_printable("Failed to decrypt Wallet token")?;
 
@@ -7350,6 +7556,7 @@ where
 
     async fn decrypt_wallet_token(
         &self,
+        state: &SessionState,
         wallet_flow: &DecideWalletFlow,
         payment_data: &D,
     ) -> CustomResult<PaymentMethodToken, errors::ApiErrorResponse>;
@@ -7383,6 +7590,7 @@ where
 
     async fn decrypt_wallet_token(
         &self,
+        _state: &SessionState,
         wallet_flow: &DecideWalletFlow,
         payment_data: &D,
     ) -> CustomResult<PaymentMethodToken, errors::ApiErrorResponse> {
@@ -7494,6 +7702,7 @@ where
 
     async fn decrypt_wallet_token(
         &self,
+        state: &SessionState,
         wallet_flow: &DecideWalletFlow,
         payment_data: &D,
     ) -> CustomResult<PaymentMethodToken, errors::ApiErrorResponse> {
@@ -7532,6 +7741,19 @@ where
                 "failed to parse decrypted apple pay response to ApplePayPredecryptData",
             )?;
 
+        let application_expiration_time = apple_pay_predecrypt_internal
+            .get_application_expiration_time()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("failed to parse apple pay application expiration date")?;
+        if application_expiration_time + state.conf.wallet_token_expiry.get_clock_skew_tolerance()
+            <= common_utils::date_time::now()
+        {
+            return Err(helpers::get_wallet_token_expired_error(
+                storage_enums::PaymentMethodType::ApplePay,
+            )
+            .into());
+        }
+
         let apple_pay_predecrypt =
             common_types::payments::ApplePayPredecryptData::try_from(apple_pay_predecrypt_internal)
                 .change_context(errors::ApiErrorResponse::InternalServerError)
@@ -7604,6 +7826,7 @@ where
 
     async fn decrypt_wallet_token(
         &self,
+        state: &SessionState,
         wallet_flow: &DecideWalletFlow,
         payment_data: &D,
     ) -> CustomResult<PaymentMethodToken, errors::ApiErrorResponse> {
@@ -7645,8 +7868,16 @@ where
                     .change_context(errors::ApiErrorResponse::InternalServerError)?
                     .clone(),
                 false,
+                state.conf.wallet_token_expiry.get_clock_skew_tolerance(),
             )
-            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .map_err(|error| match error.current_context() {
+                errors::GooglePayDecryptionError::DecryptedTokenExpired => {
+                    error.change_context(helpers::get_wallet_token_expired_error(
+                        storage_enums::PaymentMethodType::GooglePay,
+                    ))
+                }
+                _ => error.change_context(errors::ApiErrorResponse::InternalServerError),
+            })
             .attach_printable("failed to decrypt google pay token")?;
         let google_pay_data =
             common_types::payments::GPayPredecryptData::from(google_pay_data_internal);
@@ -8373,25 +8604,45 @@ where
                 &label,
             ) {
                 customers::ConnectorCustomerAction::CreateCustomer => {
+                    let merchant_id = payment_data.get_payment_intent().merchant_id.clone();
+                    let customer_id = payment_data.get_payment_intent().customer_id.clone();
+
                     // Create customer at connector and update the customer table to store this data
-                    let mut customer_router_data = payment_data
-                        .construct_router_data(
-                            state,
-                            connector.connector.id(),
-                            processor,
-                            merchant_connector_account,
-                            None,
-                            None,
-                            payment_data.get_payment_attempt().payment_method,
-                            payment_data.get_payment_attempt().payment_method_type,
-                        )
-                        .await?;
+                    let create_connector_customer = || async {
+                        let mut customer_router_data = payment_data
+                            .construct_router_data(
+                                state,
+                                connector.connector.id(),
+                                processor,
+                                merchant_connector_account,
+                                None,
+                                None,
+                                payment_data.get_payment_attempt().payment_method,
+                                payment_data.get_payment_attempt().payment_method_type,
+                            )
+                            .await?;
 
-                    customer_router_data.access_token = access_token.cloned();
+                        customer_router_data.access_token = access_token.cloned();
 
-                    let connector_customer_id = customer_router_data
-                        .create_connector_customer(state, &connector, gateway_context)
-                        .await?;
+                        customer_router_data
+                            .create_connector_customer(state, &connector, gateway_context)
+                            .await
+                    };
+
+                    // Concurrent payments for the same customer reuse a single connector customer
+                    let connector_customer_id = match customer_id {
+                        Some(customer_id) => {
+                            customers::get_or_create_connector_customer_id(
+                                state,
+                                &merchant_id,
+                                &customer_id,
+                                &label,
+                                create_connector_customer,
+                            )
+                            .await?
+                        }
+                        None => create_connector_customer().await?,
+                    };
 
                     let customer_update: Option<
                         hyperswitch_domain_models::customer::CustomerUpdate,
@@ -10425,7 +10676,16 @@ pub async fn apply_filters_on_payments(
             let limit = &constraints.limit;
             helpers::validate_payment_list_request_for_joins(*limit)?;
             let db: &dyn StorageInterface = state.store.as_ref();
-            let pi_fetch_constraints = (constraints.clone(), profile_id_list.clone()).try_into()?;
+            let mut pi_fetch_constraints =
+                (constraints.clone(), profile_id_list.clone()).try_into()?;
+            if let payments::payment_intent::PaymentIntentFetchConstraints::List(params) =
+                &mut pi_fetch_constraints
+            {
+                let contact_hashes =
+                    customer_search::get_payment_list_contact_hashes(&state, &constraints)?;
+                params.customer_email_hash = contact_hashes.email_hash;
+                params.customer_phone_hash = contact_hashes.phone_hash;
+            }
             let list: Vec<(storage::PaymentIntent, storage::PaymentAttempt)> = db
                 .get_filtered_payment_intents_attempt(
                     platform.get_processor().get_account().get_id(),
@@ -13421,6 +13681,235 @@ pub async fn payments_manual_status_update(
     ))
 }
 
+/// Only payments waiting on the connector to resolve them may be force-closed
+#[cfg(all(feature = "olap", feature = "v1"))]
+fn is_force_closable_status(status: storage_enums::IntentStatus) -> bool {
+    status == storage_enums::IntentStatus::Processing
+}
+
+/// Force-close a payment stuck in processing.
+///
+/// The payment is synced with the connector first, and is moved to failed only if the connector
+/// does not resolve it either. If the connector reports a status, that status is applied to the
+/// payment instead. The close is recorded in the state metadata of the payment intent.
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub async fn payments_force_close(
+    state: SessionState,
+    req_state: ReqState,
+    req: api_models::payments::PaymentsForceCloseRequest,
+) -> RouterResponse<api_models::payments::PaymentsForceCloseResponse> {
+    let api_models::payments::PaymentsForceCloseRequest {
+        payment_id,
+        merchant_id,
+        reason,
+    } = req;
+
+    utils::when(reason.trim().is_empty(), || {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "reason must not be empty".to_string(),
+        })
+    })?;
+
+    let key_store = state
+        .store
+        .get_merchant_key_store_by_merchant_id(
+            &merchant_id,
+            &state.store.get_master_key().to_vec().into(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)
+        .attach_printable("Error while fetching the key store by merchant_id")?;
+    let merchant_account = state
+        .store
+        .find_merchant_account_by_merchant_id(&merchant_id, &key_store)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)
+        .attach_printable("Error while fetching the merchant_account by merchant_id")?;
+    let payment_intent = state
+        .store
+        .find_payment_intent_by_payment_id_processor_merchant_id(
+            &payment_id,
+            merchant_account.get_id(),
+            &key_store,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)
+        .attach_printable("Error while fetching the payment_intent by payment_id, merchant_id")?;
+
+    utils::when(!is_force_closable_status(payment_intent.status), || {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "Payment in status '{}' cannot be force-closed",
+                payment_intent.status
+            ),
+        })
+    })?;
+
+    let platform = domain::Platform::new(
+        merchant_account.clone(),
+        key_store.clone(),
+        merchant_account.clone(),
+        key_store.clone(),
+        None,
+    );
+    let dimensions = Dimensions::new()
+        .with_processor_merchant_id(platform.get_processor().get_processor_merchant_id())
+        .with_provider_merchant_id(platform.get_provider().get_provider_merchant_id());
+    let sync_request = api::PaymentsRetrieveRequest {
+        resource_id: api::PaymentIdType::PaymentIntentId(payment_id.clone()),
+        merchant_id: Some(merchant_id.clone()),
+        force_sync: true,
+        ..Default::default()
+    };
+    let (mut payment_data, _, _, _) = Box::pin(payments_operation_core::<
+        api::PSync,
+        _,
+        _,
+        _,
+        PaymentData<api::PSync>,
+    >(
+        &state,
+        req_state.clone(),
+        &platform,
+        None,
+        operations::PaymentStatus,
+        sync_request,
+        CallConnectorAction::Trigger,
+        None,
+        services::AuthFlow::Merchant,
+        None,
+        HeaderPayload::with_status_update_source(state_machine::StatusUpdateSource::Sync),
+        &dimensions,
+        None,
+    ))
+    .await?;
+
+    // The connector has resolved the payment, the synced status has already been applied
+    if !is_force_closable_status(payment_data.payment_intent.status) {
+        logger::info!(
+            intent_status = ?payment_data.payment_intent.status,
+            "Not force-closing payment resolved by the connector"
+        );
+        return Ok(services::ApplicationResponse::Json(
+            api_models::payments::PaymentsForceCloseResponse {
+                payment_id,
+                attempt_id: payment_data.payment_attempt.attempt_id,
+                merchant_id,
+                force_closed: false,
+                intent_status: payment_data.payment_intent.status,
+                attempt_status: payment_data.payment_attempt.status,
+            },
+        ));
+    }
+
+    let payment_attempt_update = storage::PaymentAttemptUpdate::ErrorUpdate {
+        connector: None,
+        status: storage_enums::AttemptStatus::Failure,
+        error_code: Some(Some(consts::FORCE_CLOSED_PAYMENT_ERROR_CODE.to_string())),
+        error_message: Some(Some(consts::FORCE_CLOSED_PAYMENT_ERROR_MESSAGE.to_string())),
+        error_reason: Some(Some(reason.clone())),
+        amount_capturable: Some(MinorUnit::zero()),
+        updated_by: merchant_account.storage_scheme.to_string(),
+        unified_code: None,
+        unified_message: None,
+        standardised_code: None,
+        description: None,
+        user_guidance_message: None,
+        connector_transaction_id: None,
+        connector_response_reference_id: None,
+        payment_method_data: None,
+        authentication_type: None,
+        issuer_error_code: None,
+        issuer_error_message: None,
+        network_details: None,
+        network_error_message: None,
+        advice_message: None,
+        encrypted_payment_method_data: None,
+        recommended_action: None,
+        card_network: payment_data.payment_attempt.extract_card_network(),
+    };
+    payment_data.payment_attempt = state
+        .store
+        .update_payment_attempt_with_attempt_id(
+            payment_data.payment_attempt,
+            payment_attempt_update,
+            merchant_account.storage_scheme,
+            &key_store,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)
+        .attach_printable("Error while updating the payment_attempt")?;
+
+    let payment_intent_update = storage::PaymentIntentUpdate::ForceCloseUpdate {
+        status: storage_enums::IntentStatus::Failed,
+        state_metadata: payment_data
+            .payment_intent
+            .state_metadata
+            .clone()
+            .unwrap_or_default()
+            .set_force_close_details(reason.clone()),
+        updated_by: merchant_account.storage_scheme.to_string(),
+    };
+    payment_data.payment_intent = state
+        .store
+        .update_payment_intent(
+            payment_data.payment_intent,
+            payment_intent_update,
+            &key_store,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)
+        .attach_printable("Error while updating payment_intent")?;
+
+    req_state
+        .event_context
+        .event(audit_events::AuditEvent::new(
+            audit_events::AuditEventType::PaymentForceClosed { reason },
+        ))
+        .with(payment_data.to_event())
+        .emit();
+
+    let response = api_models::payments::PaymentsForceCloseResponse {
+        payment_id,
+        attempt_id: payment_data.payment_attempt.attempt_id.clone(),
+        merchant_id,
+        force_closed: true,
+        intent_status: payment_data.payment_intent.status,
+        attempt_status: payment_data.payment_attempt.status,
+    };
+
+    let profile_id = payment_data
+        .payment_intent
+        .profile_id
+        .as_ref()
+        .get_required_value("profile_id")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Could not find profile_id in payment intent")?;
+    let business_profile = state
+        .store
+        .find_business_profile_by_profile_id(&key_store, profile_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+            id: profile_id.get_string_repr().to_owned(),
+        })?;
+
+    // Notify the merchant about the failed payment
+    Box::pin(utils::trigger_payments_webhook(
+        &platform,
+        business_profile,
+        payment_data,
+        &state,
+        operations::PaymentStatus,
+    ))
+    .await
+    .map_err(|error| logger::warn!(payments_outgoing_webhook_error=?error))
+    .ok();
+
+    Ok(services::ApplicationResponse::Json(response))
+}
+
 // Trait for Eligibility Checks
 #[cfg(feature = "v1")]
 #[async_trait::async_trait]
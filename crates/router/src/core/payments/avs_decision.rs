@@ -0,0 +1,347 @@
+//! Voids authorized payments whose address verification (AVS) results match the rules configured
+//! by the merchant, for merchants which decide whether to capture a payment based on the street
+//! and postal code results separately.
+//!
+//! The rules are applied to the response of the payments create and confirm APIs only. Payments
+//! which are authorized later, when they are synced with the connector or on an incoming webhook,
+//! are not voided.
+
+use common_enums::enums;
+use common_types::payments::AvsMatchDetails;
+use error_stack::ResultExt;
+use hyperswitch_domain_models::payments::HeaderPayload;
+use router_env::logger;
+use serde::Deserialize;
+
+use crate::{
+    core::{
+        errors::{self, RouterResponse, RouterResult},
+        payments,
+    },
+    events::audit_events::{AuditEvent, AuditEventType},
+    routes::{app::ReqState, SessionState},
+    services,
+    types::{api, domain},
+};
+
+// Cached when the merchant has not configured any rules, so that the configs table is not queried
+// for every authorized payment.
+const NO_RULES_CONFIGURED: &str = "null";
+
+/// Rules configured by the merchant, which void an authorized payment when any of them matches
+#[derive(Debug, Deserialize)]
+struct AvsDecisionRules {
+    rules: Vec<AvsDecisionRule>,
+    /// Payments authenticated with 3DS are not voided unless enabled, as the liability for them
+    /// is usually shifted to the issuer
+    #[serde(default)]
+    apply_to_three_ds_authenticated: bool,
+}
+
+/// A rule matches when the results of all of its conditions are among the configured results. A
+/// condition without any results is not considered.
+#[derive(Debug, Deserialize)]
+struct AvsDecisionRule {
+    name: String,
+    #[serde(default)]
+    avs_street_match: Vec<enums::AvsMatchResult>,
+    #[serde(default)]
+    avs_postal_code_match: Vec<enums::AvsMatchResult>,
+}
+
+impl AvsDecisionRule {
+    fn is_matched(&self, avs_match_details: &AvsMatchDetails) -> bool {
+        let is_condition_matched =
+            |results: &[enums::AvsMatchResult], result: Option<enums::AvsMatchResult>| {
+                results.is_empty() || result.is_some_and(|result| results.contains(&result))
+            };
+
+        !(self.avs_street_match.is_empty() && self.avs_postal_code_match.is_empty())
+            && is_condition_matched(&self.avs_street_match, avs_match_details.avs_street_match)
+            && is_condition_matched(
+                &self.avs_postal_code_match,
+                avs_match_details.avs_postal_code_match,
+            )
+    }
+}
+
+impl AvsDecisionRules {
+    fn find_matched_rule(
+        &self,
+        avs_match_details: &AvsMatchDetails,
+        is_three_ds_authenticated: bool,
+    ) -> Option<&AvsDecisionRule> {
+        if is_three_ds_authenticated && !self.apply_to_three_ds_authenticated {
+            return None;
+        }
+
+        self.rules
+            .iter()
+            .find(|rule| rule.is_matched(avs_match_details))
+    }
+}
+
+fn is_three_ds_authenticated(payments_response: &api::PaymentsResponse) -> bool {
+    payments_response.authentication_type == Some(enums::AuthenticationType::ThreeDs)
+        || payments_response.external_3ds_authentication_attempted == Some(true)
+}
+
+fn get_avs_match_details(payments_response: &api::PaymentsResponse) -> Option<AvsMatchDetails> {
+    match payments_response
+        .payment_method_data
+        .as_ref()?
+        .payment_method_data
+        .as_ref()?
+    {
+        api::PaymentMethodDataResponse::Card(card) => card
+            .payment_checks
+            .as_ref()
+            .and_then(AvsMatchDetails::from_payment_checks),
+        _ => None,
+    }
+}
+
+async fn find_avs_decision_rules(
+    state: &SessionState,
+    merchant_id: &common_utils::id_type::MerchantId,
+) -> RouterResult<Option<AvsDecisionRules>> {
+    let config = state
+        .store
+        .find_config_by_key_unwrap_or(
+            &merchant_id.get_avs_decision_rules_key(),
+            Some(NO_RULES_CONFIGURED.to_string()),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the AVS decision rules config")?;
+
+    serde_json::from_str(&config.config)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to deserialize the AVS decision rules config")
+}
+
+/// Void the payment if it was authorized and its AVS results match any of the rules configured by
+/// the merchant. The authorize response is returned as is if the rules could not be applied.
+pub async fn apply_avs_decision_rules(
+    state: SessionState,
+    req_state: ReqState,
+    platform: domain::Platform,
+    profile_id: Option<common_utils::id_type::ProfileId>,
+    header_payload: HeaderPayload,
+    response: services::ApplicationResponse<api::PaymentsResponse>,
+) -> RouterResponse<api::PaymentsResponse> {
+    let payments_response = match &response {
+        services::ApplicationResponse::Json(payments_response)
+        | services::ApplicationResponse::JsonWithHeaders((payments_response, _)) => {
+            payments_response
+        }
+        _ => return Ok(response),
+    };
+    if payments_response.status != enums::IntentStatus::RequiresCapture {
+        return Ok(response);
+    }
+    let Some(avs_match_details) = get_avs_match_details(payments_response) else {
+        return Ok(response);
+    };
+
+    let merchant_id = platform.get_processor().get_account().get_id();
+    let rules = match find_avs_decision_rules(&state, merchant_id).await {
+        Ok(rules) => rules,
+        Err(error) => {
+            logger::error!(?error, "Failed to apply the AVS decision rules");
+            return Ok(response);
+        }
+    };
+    let Some(rule) = rules.as_ref().and_then(|rules| {
+        rules.find_matched_rule(
+            &avs_match_details,
+            is_three_ds_authenticated(payments_response),
+        )
+    }) else {
+        return Ok(response);
+    };
+
+    logger::info!(
+        payment_id = %payments_response.payment_id.get_string_repr(),
+        rule_name = %rule.name,
+        ?avs_match_details,
+        "Voiding the payment as its AVS results matched a decision rule"
+    );
+    let cancel_request = api::PaymentsCancelRequest {
+        payment_id: payments_response.payment_id.clone(),
+        cancellation_reason: Some(format!(
+            "Voided as the AVS results matched the decision rule {}",
+            rule.name
+        )),
+        merchant_connector_details: None,
+        all_keys_required: None,
+    };
+
+    let void_response = Box::pin(payments::payments_core::<
+        api::Void,
+        api::PaymentsResponse,
+        _,
+        _,
+        _,
+        payments::PaymentData<api::Void>,
+    >(
+        state,
+        req_state.clone(),
+        platform,
+        profile_id,
+        payments::PaymentCancel,
+        cancel_request,
+        services::AuthFlow::Merchant,
+        payments::CallConnectorAction::Trigger,
+        None,
+        None,
+        header_payload,
+        None,
+    ))
+    .await;
+
+    match get_void_failure_reason(&void_response) {
+        None => {
+            req_state
+                .event_context
+                .event(AuditEvent::new(AuditEventType::PaymentAvsAutoVoided {
+                    rule_name: rule.name.clone(),
+                    avs_street_match: avs_match_details.avs_street_match,
+                    avs_postal_code_match: avs_match_details.avs_postal_code_match,
+                }))
+                .emit();
+            void_response
+        }
+        Some(error_message) => {
+            logger::error!(
+                %error_message,
+                "Failed to void the payment whose AVS results matched a decision rule"
+            );
+            req_state
+                .event_context
+                .event(AuditEvent::new(AuditEventType::PaymentAvsAutoVoidFailed {
+                    rule_name: rule.name.clone(),
+                    avs_street_match: avs_match_details.avs_street_match,
+                    avs_postal_code_match: avs_match_details.avs_postal_code_match,
+                    error_message,
+                }))
+                .emit();
+            Ok(response)
+        }
+    }
+}
+
+/// The reason the payment could not be voided, absent if the payment was voided
+fn get_void_failure_reason(
+    void_response: &RouterResponse<api::PaymentsResponse>,
+) -> Option<String> {
+    match void_response {
+        Ok(
+            services::ApplicationResponse::Json(payments_response)
+            | services::ApplicationResponse::JsonWithHeaders((payments_response, _)),
+        ) => get_void_status_failure_reason(
+            payments_response.status,
+            payments_response.error_message.as_deref(),
+        ),
+        Ok(_) => Some("Unexpected response for the void of the payment".to_string()),
+        Err(error) => Some(error.current_context().to_string()),
+    }
+}
+
+fn get_void_status_failure_reason(
+    status: enums::IntentStatus,
+    error_message: Option<&str>,
+) -> Option<String> {
+    (status != enums::IntentStatus::Cancelled).then(|| match error_message {
+        Some(error_message) => error_message.to_string(),
+        None => format!("The payment has a status of {status} after the void"),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    fn get_rules(rules: serde_json::Value) -> AvsDecisionRules {
+        serde_json::from_value(rules).unwrap()
+    }
+
+    #[test]
+    fn test_rule_is_matched_only_when_all_conditions_match() {
+        let rules = get_rules(serde_json::json!({
+            "rules": [{
+                "name": "street_and_postal_code_mismatch",
+                "avs_street_match": ["no_match"],
+                "avs_postal_code_match": ["no_match", "unavailable"],
+            }],
+        }));
+
+        let avs_match_details = AvsMatchDetails {
+            avs_street_match: Some(enums::AvsMatchResult::NoMatch),
+            avs_postal_code_match: Some(enums::AvsMatchResult::Unavailable),
+        };
+        assert_eq!(
+            rules
+                .find_matched_rule(&avs_match_details, false)
+                .map(|rule| rule.name.as_str()),
+            Some("street_and_postal_code_mismatch")
+        );
+
+        let avs_match_details = AvsMatchDetails {
+            avs_street_match: Some(enums::AvsMatchResult::NoMatch),
+            avs_postal_code_match: Some(enums::AvsMatchResult::Match),
+        };
+        assert!(rules.find_matched_rule(&avs_match_details, false).is_none());
+    }
+
+    #[test]
+    fn test_three_ds_authenticated_payments_are_excluded_by_default() {
+        let avs_match_details = AvsMatchDetails {
+            avs_street_match: Some(enums::AvsMatchResult::NoMatch),
+            avs_postal_code_match: None,
+        };
+
+        let rules = get_rules(serde_json::json!({
+            "rules": [{ "name": "street_mismatch", "avs_street_match": ["no_match"] }],
+        }));
+        assert!(rules.find_matched_rule(&avs_match_details, true).is_none());
+
+        let rules = get_rules(serde_json::json!({
+            "rules": [{ "name": "street_mismatch", "avs_street_match": ["no_match"] }],
+            "apply_to_three_ds_authenticated": true,
+        }));
+        assert!(rules.find_matched_rule(&avs_match_details, true).is_some());
+    }
+
+    #[test]
+    fn test_void_failure_is_reported() {
+        let void_response: RouterResponse<api::PaymentsResponse> =
+            Err(errors::ApiErrorResponse::PaymentNotFound.into());
+        assert_eq!(
+            get_void_failure_reason(&void_response),
+            Some(errors::ApiErrorResponse::PaymentNotFound.to_string())
+        );
+
+        assert_eq!(
+            get_void_status_failure_reason(
+                enums::IntentStatus::RequiresCapture,
+                Some("Authorization already expired")
+            ),
+            Some("Authorization already expired".to_string())
+        );
+        assert_eq!(
+            get_void_status_failure_reason(enums::IntentStatus::Processing, None),
+            Some("The payment has a status of processing after the void".to_string())
+        );
+    }
+
+    #[test]
+    fn test_cancelled_payment_is_voided() {
+        assert_eq!(
+            get_void_status_failure_reason(enums::IntentStatus::Cancelled, None),
+            None
+        );
+    }
+}
@@ -1,10 +1,18 @@
+#[cfg(feature = "v1")]
+use error_stack::ResultExt;
 pub use hyperswitch_domain_models::customer::update_connector_customer_in_customers;
+#[cfg(feature = "v1")]
+use hyperswitch_domain_models::ext_traits::OptionExt;
 use hyperswitch_interfaces::api::{gateway, ConnectorSpecifications};
 use hyperswitch_masking::PeekInterface;
+#[cfg(feature = "v1")]
+use redis_interface as redis;
 use router_env::{instrument, tracing};
 
 #[cfg(feature = "v2")]
 use crate::types::domain;
+#[cfg(feature = "v1")]
+use crate::{consts, core::errors, types::storage};
 use crate::{
     core::{
         errors::{ConnectorErrorExt, RouterResult},
@@ -79,6 +87,257 @@ pub async fn create_connector_customer<F: Clone, T: Clone>(
     Ok(connector_customer_id)
 }
 
+#[cfg(feature = "v1")]
+fn get_connector_customer_key(
+    prefix: &str,
+    merchant_id: &common_utils::id_type::MerchantId,
+    customer_id: &common_utils::id_type::CustomerId,
+    connector_label: &str,
+) -> String {
+    format!(
+        "{}_{}_{}_{}",
+        prefix,
+        merchant_id.get_string_repr(),
+        customer_id.get_string_repr(),
+        connector_label
+    )
+}
+
+/// Lock on the creation of the connector customer of a customer, held by a single payment
+#[cfg(feature = "v1")]
+#[derive(Clone, Debug)]
+pub struct ConnectorCustomerLock {
+    redis_key: String,
+    holder: String,
+}
+
+/// Outcome of reserving the creation of the connector customer of a customer
+#[cfg(feature = "v1")]
+#[derive(Clone, Debug)]
+pub enum ConnectorCustomerReservation {
+    /// The connector customer is to be created by the caller. The lock is absent when redis is
+    /// unavailable, in which case only the uniqueness of the stored connector customer holds.
+    Reserved(Option<ConnectorCustomerLock>),
+    /// The connector customer was already created and stored by a concurrent payment
+    Stored(String),
+}
+
+/// Fetches the connector customer mapped to the customer by a payment which created it, which may
+/// not have been stored in the connector customer map of the customer yet.
+#[cfg(feature = "v1")]
+#[instrument(skip_all)]
+pub async fn find_stored_connector_customer_id(
+    state: &SessionState,
+    merchant_id: &common_utils::id_type::MerchantId,
+    customer_id: &common_utils::id_type::CustomerId,
+    connector_label: &str,
+) -> RouterResult<Option<String>> {
+    match state
+        .store
+        .find_connector_customer_mapping_by_merchant_id_customer_id_connector_label(
+            merchant_id,
+            customer_id,
+            connector_label,
+        )
+        .await
+    {
+        Ok(mapping) => Ok(Some(mapping.connector_customer_id)),
+        Err(error) if error.current_context().is_db_not_found() => Ok(None),
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch the connector customer mapping"),
+    }
+}
+
+/// Reserves the creation of the connector customer of the customer before the connector is called,
+/// so that concurrent payments for the same customer do not create a connector customer each.
+///
+/// A payment finding the reservation held by a concurrent payment waits until the connector
+/// customer created by the concurrent payment is stored, which is then reused, or until the
+/// reservation is released or expires without a connector customer stored, and is taken over.
+#[cfg(feature = "v1")]
+#[instrument(skip_all)]
+pub async fn reserve_connector_customer_creation(
+    state: &SessionState,
+    merchant_id: &common_utils::id_type::MerchantId,
+    customer_id: &common_utils::id_type::CustomerId,
+    connector_label: &str,
+) -> RouterResult<ConnectorCustomerReservation> {
+    let redis_conn = match state.store.get_redis_conn() {
+        Ok(redis_conn) => redis_conn,
+        Err(error) => {
+            logger::error!(?error, "Failed to get redis connection");
+            return Ok(ConnectorCustomerReservation::Reserved(None));
+        }
+    };
+
+    let lock = ConnectorCustomerLock {
+        redis_key: get_connector_customer_key(
+            consts::CONNECTOR_CUSTOMER_LOCK_PREFIX,
+            merchant_id,
+            customer_id,
+            connector_label,
+        ),
+        holder: uuid::Uuid::new_v4().to_string(),
+    };
+    let lock_settings = &state.conf.lock_settings;
+
+    loop {
+        let lock_acquired = match redis_conn
+            .set_key_if_not_exists_with_expiry(
+                &lock.redis_key.as_str().into(),
+                lock.holder.clone(),
+                Some(i64::from(lock_settings.redis_lock_expiry_seconds)),
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)?
+        {
+            redis::SetnxReply::KeySet => true,
+            redis::SetnxReply::KeyNotSet => false,
+        };
+
+        // The connector customer may have been stored by the payment which held the lock, which
+        // could still be holding it if its connector call outlived the expiry of the lock
+        let stored_connector_customer_id =
+            find_stored_connector_customer_id(state, merchant_id, customer_id, connector_label)
+                .await?;
+
+        match (stored_connector_customer_id, lock_acquired) {
+            (Some(stored_connector_customer_id), lock_acquired) => {
+                if lock_acquired {
+                    release_connector_customer_reservation(state, lock).await;
+                }
+                return Ok(ConnectorCustomerReservation::Stored(
+                    stored_connector_customer_id,
+                ));
+            }
+            (None, true) => return Ok(ConnectorCustomerReservation::Reserved(Some(lock))),
+            (None, false) => {
+                logger::info!("Connector customer is being created by a concurrent payment");
+                tokio::time::sleep(std::time::Duration::from_millis(u64::from(
+                    lock_settings.delay_between_retries_in_milliseconds,
+                )))
+                .await;
+            }
+        }
+    }
+}
+
+/// Releases the reservation on the creation of the connector customer, if still held by the caller
+#[cfg(feature = "v1")]
+#[instrument(skip_all)]
+pub async fn release_connector_customer_reservation(
+    state: &SessionState,
+    lock: ConnectorCustomerLock,
+) {
+    let Ok(redis_conn) = state
+        .store
+        .get_redis_conn()
+        .inspect_err(|error| logger::error!(?error, "Failed to get redis connection"))
+    else {
+        return;
+    };
+    let redis_key = lock.redis_key.as_str().into();
+
+    match redis_conn.get_key::<Option<String>>(&redis_key).await {
+        Ok(Some(holder)) if holder == lock.holder => {
+            redis_conn
+                .delete_key(&redis_key)
+                .await
+                .map_err(|error| {
+                    logger::error!(?error, "Failed to release the connector customer lock")
+                })
+                .ok();
+        }
+        Ok(_) => logger::warn!("Connector customer lock expired before being released"),
+        Err(error) => logger::error!(?error, "Failed to fetch the connector customer lock"),
+    }
+}
+
+/// Maps the connector customer created for the customer to the customer. The mapping is unique for
+/// the customer and connector, so that the connector customer mapped by a concurrent payment is
+/// returned instead when the reservation could not be held for the whole connector call.
+#[cfg(feature = "v1")]
+#[instrument(skip_all)]
+pub async fn store_connector_customer_id(
+    state: &SessionState,
+    merchant_id: &common_utils::id_type::MerchantId,
+    customer_id: &common_utils::id_type::CustomerId,
+    connector_label: &str,
+    connector_customer_id: String,
+) -> RouterResult<String> {
+    match state
+        .store
+        .insert_connector_customer_mapping(storage::ConnectorCustomerMappingNew {
+            merchant_id: merchant_id.to_owned(),
+            customer_id: customer_id.to_owned(),
+            connector_label: connector_label.to_owned(),
+            connector_customer_id: connector_customer_id.clone(),
+        })
+        .await
+    {
+        Ok(mapping) => Ok(mapping.connector_customer_id),
+        Err(error) if error.current_context().is_db_unique_violation() => {
+            let stored_connector_customer_id =
+                find_stored_connector_customer_id(state, merchant_id, customer_id, connector_label)
+                    .await?
+                    .get_required_value("stored_connector_customer_id")?;
+            logger::info!(
+                discarded_connector_customer_id = %connector_customer_id,
+                "Connector customer already mapped by a concurrent payment, reusing {stored_connector_customer_id}"
+            );
+            Ok(stored_connector_customer_id)
+        }
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to store the connector customer mapping"),
+    }
+}
+
+/// Returns the connector customer of the customer, creating it at the connector only if no
+/// concurrent payment for the customer has created it.
+#[cfg(feature = "v1")]
+#[instrument(skip_all)]
+pub async fn get_or_create_connector_customer_id<F, Fut>(
+    state: &SessionState,
+    merchant_id: &common_utils::id_type::MerchantId,
+    customer_id: &common_utils::id_type::CustomerId,
+    connector_label: &str,
+    create_connector_customer: F,
+) -> RouterResult<Option<String>>
+where
+    F: FnOnce() -> Fut,
+    Fut: std::future::Future<Output = RouterResult<Option<String>>>,
+{
+    let lock =
+        match reserve_connector_customer_creation(state, merchant_id, customer_id, connector_label)
+            .await?
+        {
+            ConnectorCustomerReservation::Stored(stored_connector_customer_id) => {
+                return Ok(Some(stored_connector_customer_id));
+            }
+            ConnectorCustomerReservation::Reserved(lock) => lock,
+        };
+
+    let connector_customer_id = match create_connector_customer().await {
+        Ok(Some(connector_customer_id)) => store_connector_customer_id(
+            state,
+            merchant_id,
+            customer_id,
+            connector_label,
+            connector_customer_id,
+        )
+        .await
+        .map(Some),
+        result => result,
+    };
+
+    if let Some(lock) = lock {
+        release_connector_customer_reservation(state, lock).await;
+    }
+    connector_customer_id
+}
+
 #[cfg(feature = "v1")]
 #[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
 pub enum ConnectorCustomerAction<'a> {
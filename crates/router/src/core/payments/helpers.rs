@@ -129,7 +129,10 @@ use crate::{
 #[cfg(feature = "v2")]
 use crate::{core::admin as core_admin, headers, types::ConnectorAuthType};
 #[cfg(feature = "v1")]
-use crate::{core::utils::create_encrypted_data, types::storage::CustomerUpdate::Update};
+use crate::{
+    core::{customer_search, utils::create_encrypted_data},
+    types::storage::CustomerUpdate::Update,
+};
 
 #[instrument(skip_all)]
 #[allow(clippy::too_many_arguments)]
@@ -2117,6 +2120,21 @@ pub async fn create_customer_if_not_exist<'a, F: Clone, R, D>(
                             | request_customer_details.tax_registration_id.is_some())
                             | request_customer_details.document_details.is_some()
                     {
+                        let contact_hashes = customer_search::get_updated_customer_contact_hashes(
+                            state,
+                            &c,
+                            request_customer_details
+                                .email
+                                .as_ref()
+                                .map(|email| email.peek().as_str()),
+                            request_customer_details
+                                .phone
+                                .as_ref()
+                                .map(|phone| phone.peek().as_str()),
+                            request_customer_details.phone_country_code.as_deref(),
+                        )
+                        .change_context(errors::StorageError::EncryptionError)
+                        .attach_printable("Failed to hash the contact details of the customer")?;
                         let customer_update = Update {
                             name: encryptable_customer.name,
                             email: encryptable_customer.email.map(|email| {
@@ -2136,6 +2154,8 @@ pub async fn create_customer_if_not_exist<'a, F: Clone, R, D>(
                             address_id: None,
                             tax_registration_id: encryptable_customer.tax_registration_id,
                             document_details: Box::new(document_details),
+                            email_hash: contact_hashes.email_hash.map(Some),
+                            phone_hash: contact_hashes.phone_hash.map(Some),
                             last_modified_by: initiator
                                 .and_then(|initiator| initiator.to_created_by())
                                 .map(|last_modified_by| last_modified_by.to_string()),
@@ -2181,7 +2201,7 @@ pub async fn create_customer_if_not_exist<'a, F: Clone, R, D>(
                         ))))?
                     }
 
-                    let new_customer = domain::Customer::new(
+                    let mut new_customer = domain::Customer::new(
                         customer_id,
                         merchant_id.to_owned(),
                         encryptable_customer.name,
@@ -2206,6 +2226,9 @@ pub async fn create_customer_if_not_exist<'a, F: Clone, R, D>(
                         initiator.and_then(|initiator| initiator.to_created_by()),
                         id_type::GlobalCustomerId::generate(&state.conf.cell_information.id),
                     );
+                    customer_search::set_customer_contact_hashes(state, &mut new_customer)
+                        .change_context(errors::StorageError::EncryptionError)
+                        .attach_printable("Failed to hash the contact details of the customer")?;
                     metrics::CUSTOMER_CREATED.add(1, &[]);
                     db.insert_customer(new_customer, key_store, storage_scheme)
                         .await
@@ -4609,6 +4632,9 @@ pub fn generate_mandate(
                 )
                 .set_customer_user_agent_extended(customer_acceptance.get_user_agent())
                 .set_customer_accepted_at(Some(customer_acceptance.get_accepted_at()))
+                .set_customer_acceptance_source(Some(
+                    storage_enums::CustomerAcceptanceSource::Confirm,
+                ))
                 .set_metadata(payment_method_data_option.map(|payment_method_data| {
                     pii::SecretSerdeValue::new(
                         serde_json::to_value(payment_method_data).unwrap_or_default(),
@@ -4942,6 +4968,9 @@ mod tests {
             profile_acquirer_id: None,
             external_surcharge_strategy: None,
             external_surcharge_applicable: None,
+            attribution_tags: None,
+            customer_email_hash: None,
+            customer_phone_hash: None,
         };
         let req_cs = Some("1".to_string());
         assert!(authenticate_client_secret(req_cs.as_ref(), &payment_intent).is_ok());
@@ -5036,6 +5065,9 @@ mod tests {
             profile_acquirer_id: None,
             external_surcharge_strategy: None,
             external_surcharge_applicable: None,
+            attribution_tags: None,
+            customer_email_hash: None,
+            customer_phone_hash: None,
         };
         let req_cs = Some("1".to_string());
         assert!(authenticate_client_secret(req_cs.as_ref(), &payment_intent,).is_err())
@@ -5128,6 +5160,9 @@ mod tests {
             profile_acquirer_id: None,
             external_surcharge_strategy: None,
             external_surcharge_applicable: None,
+            attribution_tags: None,
+            customer_email_hash: None,
+            customer_phone_hash: None,
         };
         let req_cs = Some("1".to_string());
         assert!(authenticate_client_secret(req_cs.as_ref(), &payment_intent).is_err())
@@ -5862,6 +5897,24 @@ mod test {
             super::get_payment_id_from_client_secret(client_secret3).unwrap()
         );
     }
+
+    #[test]
+    fn test_expiration_date_clock_skew_tolerance() {
+        let expired_ten_seconds_ago =
+            (time::OffsetDateTime::now_utc().unix_timestamp_nanos() / 1_000_000 - 10_000)
+                .to_string();
+
+        assert!(!super::check_expiration_date_is_valid(
+            &expired_ten_seconds_ago,
+            time::Duration::ZERO
+        )
+        .unwrap());
+        assert!(super::check_expiration_date_is_valid(
+            &expired_ten_seconds_ago,
+            time::Duration::seconds(60)
+        )
+        .unwrap());
+    }
 }
 
 #[instrument(skip_all)]
@@ -6675,6 +6728,30 @@ pub fn validate_customer_access(
     Ok(())
 }
 
+/// Attribution tags are used for billing, so they cannot be changed once the payment has been
+/// confirmed
+#[cfg(feature = "v1")]
+pub fn validate_attribution_tags_update(
+    payment_intent: &PaymentIntent,
+    attribution_tags: Option<&common_types::payments::AttributionTags>,
+) -> Result<(), errors::ApiErrorResponse> {
+    let is_changed = attribution_tags.is_some_and(|attribution_tags| {
+        payment_intent.attribution_tags.as_ref() != Some(attribution_tags)
+    });
+    let is_confirmed = !matches!(
+        payment_intent.status,
+        storage_enums::IntentStatus::RequiresPaymentMethod
+            | storage_enums::IntentStatus::RequiresConfirmation
+    );
+
+    fp_utils::when(is_changed && is_confirmed, || {
+        Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: "attribution_tags cannot be updated after the payment has been confirmed"
+                .to_string(),
+        })
+    })
+}
+
 pub fn is_apple_pay_simplified_flow(
     connector_metadata: Option<pii::SecretSerdeValue>,
     connector_name: Option<&String>,
@@ -7271,9 +7348,10 @@ pub enum GooglePayProtocolVersion {
     EcProtocolVersion2,
 }
 
-// Check expiration date validity
+// Check expiration date validity, allowing for the given clock skew
 fn check_expiration_date_is_valid(
     expiration: &str,
+    clock_skew_tolerance: time::Duration,
 ) -> CustomResult<bool, errors::GooglePayDecryptionError> {
     let expiration_ms = expiration
         .parse::<i128>()
@@ -7284,7 +7362,21 @@ fn check_expiration_date_is_valid(
             .change_context(errors::GooglePayDecryptionError::InvalidExpirationTime)?;
     let now = time::OffsetDateTime::now_utc();
 
-    Ok(expiration_time > now)
+    Ok(expiration_time + clock_skew_tolerance > now)
+}
+
+/// Error for a wallet token which expired before being sent to the connector, so that the SDK
+/// fetches a new token from the wallet
+pub fn get_wallet_token_expired_error(
+    wallet_type: storage_enums::PaymentMethodType,
+) -> errors::ApiErrorResponse {
+    metrics::WALLET_TOKEN_EXPIRED.add(
+        1,
+        router_env::metric_attributes!(("wallet_type", wallet_type.to_string())),
+    );
+    errors::ApiErrorResponse::WalletTokenExpired {
+        wallet_name: wallet_type.to_string(),
+    }
 }
 
 // Construct little endian format of u32
@@ -7301,12 +7393,11 @@ fn filter_root_signing_keys(
         .filter(|key| {
             key.protocol_version == GooglePayProtocolVersion::EcProtocolVersion2
                 && matches!(
-                    check_expiration_date_is_valid(&key.key_expiration).inspect_err(
-                        |err| logger::warn!(
+                    check_expiration_date_is_valid(&key.key_expiration, time::Duration::ZERO)
+                        .inspect_err(|err| logger::warn!(
                             "Failed to check expirattion due to invalid format: {:?}",
                             err
-                        )
-                    ),
+                        )),
                     Ok(true)
                 )
         })
@@ -7361,6 +7452,7 @@ impl GooglePayTokenDecryptor {
         &self,
         data: String,
         should_verify_signature: bool,
+        clock_skew_tolerance: time::Duration,
     ) -> CustomResult<
         hyperswitch_domain_models::router_data::GooglePayPredecryptDataInternal,
         errors::GooglePayDecryptionError,
@@ -7403,7 +7495,10 @@ impl GooglePayTokenDecryptor {
         // check the expiration date of the decrypted data
 
         if matches!(
-            check_expiration_date_is_valid(&decrypted_data.message_expiration),
+            check_expiration_date_is_valid(
+                &decrypted_data.message_expiration,
+                clock_skew_tolerance
+            ),
             Ok(true)
         ) {
             Ok(decrypted_data)
@@ -7524,7 +7619,7 @@ impl GooglePayTokenDecryptor {
             .parse_struct("GooglePaySignedKey")
             .change_context(errors::GooglePayDecryptionError::SignedKeyParsingFailure)?;
         if !matches!(
-            check_expiration_date_is_valid(&signed_key.key_expiration),
+            check_expiration_date_is_valid(&signed_key.key_expiration, time::Duration::ZERO),
             Ok(true)
         ) {
             Err(errors::GooglePayDecryptionError::SignedKeyExpired)?;
@@ -8115,6 +8210,15 @@ pub fn validate_session_expiry(session_expiry: u32) -> Result<(), errors::ApiErr
     }
 }
 
+// This function validates the time zone of the profile set by the merchant in the request
+pub fn validate_time_zone(time_zone: &str) -> Result<chrono_tz::Tz, errors::ApiErrorResponse> {
+    time_zone
+        .parse()
+        .map_err(|_| errors::ApiErrorResponse::InvalidDataValue {
+            field_name: "time_zone",
+        })
+}
+
 pub fn get_recipient_id_for_open_banking(
     merchant_data: &AdditionalMerchantData,
 ) -> Result<Option<String>, errors::ApiErrorResponse> {
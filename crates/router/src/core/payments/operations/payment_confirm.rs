@@ -39,6 +39,7 @@ use crate::{
         blocklist::utils as blocklist_utils,
         card_testing_guard::utils as card_testing_guard_utils,
         configs::dimension_state,
+        customer_search,
         errors::{self, CustomResult, RouterResult, StorageErrorExt},
         mandate::helpers as m_helpers,
         metrics,
@@ -164,6 +165,11 @@ impl<F: Send + Clone + Sync> GetTracker<F, PaymentData<F>, api::PaymentsRequest>
             )?;
         }
 
+        helpers::validate_attribution_tags_update(
+            &payment_intent,
+            request.attribution_tags.as_ref(),
+        )?;
+
         helpers::authenticate_client_secret(request.client_secret.as_ref(), &payment_intent)?;
 
         let customer_details =
@@ -496,6 +502,10 @@ impl<F: Send + Clone + Sync> GetTracker<F, PaymentData<F>, api::PaymentsRequest>
             .map(hyperswitch_masking::Secret::new)
             .or(payment_intent.feature_metadata);
         payment_intent.metadata = request.metadata.clone().or(payment_intent.metadata);
+        payment_intent.attribution_tags = request
+            .attribution_tags
+            .clone()
+            .or(payment_intent.attribution_tags);
         payment_intent.frm_metadata = request.frm_metadata.clone().or(payment_intent.frm_metadata);
         payment_intent.request_incremental_authorization = request
             .request_incremental_authorization
@@ -2650,6 +2660,8 @@ impl<F: Clone + Sync> UpdateTracker<F, PaymentData<F>, api::PaymentsRequest> for
             .attach_printable("Failed to encode additional pm data")?;
 
         let customer_details = payment_data.payment_intent.customer_details.clone();
+        let contact_hashes =
+            customer_search::get_intent_contact_hashes(state, &payment_data.payment_intent)?;
         let business_sub_label = payment_data.payment_attempt.business_sub_label.clone();
         let authentication_type = payment_data.payment_attempt.authentication_type;
 
@@ -2955,6 +2967,9 @@ impl<F: Clone + Sync> UpdateTracker<F, PaymentData<F>, api::PaymentsRequest> for
                         external_surcharge_applicable: payment_data
                             .payment_intent
                             .external_surcharge_applicable,
+                        attribution_tags: payment_data.payment_intent.attribution_tags.clone(),
+                        customer_email_hash: contact_hashes.email_hash,
+                        customer_phone_hash: contact_hashes.phone_hash,
                     })),
                     &m_key_store,
                     storage_scheme,
@@ -34,9 +34,10 @@ use crate::{
     consts,
     core::{
         configs::dimension_state,
+        customer_search,
         errors::{self, CustomResult, RouterResult, StorageErrorExt},
         mandate::helpers as m_helpers,
-        payment_link,
+        merchant_quota, payment_link,
         payment_methods::transformers as pm_transformers,
         payments::{
             self, client_session::ClientSessionManager, helpers, operations, CustomerDetails,
@@ -147,6 +148,7 @@ impl<F: Send + Clone + Sync> GetTracker<F, PaymentData<F>, api::PaymentsRequest>
                 id: profile_id.get_string_repr().to_owned(),
             })?
         };
+
         let customer_acceptance = request.customer_acceptance.clone();
 
         let recurring_details = request.recurring_details.clone();
@@ -365,7 +367,18 @@ impl<F: Send + Clone + Sync> GetTracker<F, PaymentData<F>, api::PaymentsRequest>
         )
         .await?;
 
-        let payment_intent = platform_wrapper::payment_intent::insert_payment_intent(
+        // The usage of the quota is reserved right before the payment intent is stored, and given
+        // back if it is not stored, so that rejected and duplicate payments are not accounted
+        let quota_reservation = merchant_quota::reserve_payment_quota(
+            state,
+            platform,
+            &business_profile,
+            MinorUnit::from(amount),
+            currency,
+        )
+        .await?;
+
+        let payment_intent = match platform_wrapper::payment_intent::insert_payment_intent(
             state.store.as_ref(),
             platform.get_processor(),
             payment_intent_new,
@@ -373,7 +386,26 @@ impl<F: Send + Clone + Sync> GetTracker<F, PaymentData<F>, api::PaymentsRequest>
         .await
         .to_duplicate_response(errors::ApiErrorResponse::DuplicatePayment {
             payment_id: payment_id.clone(),
-        })?;
+        }) {
+            Ok(payment_intent) => payment_intent,
+            Err(error) => {
+                if let Some(quota_reservation) = quota_reservation {
+                    merchant_quota::release_payment_quota(state, quota_reservation).await;
+                }
+                return Err(error);
+            }
+        };
+
+        if let Some(quota_reservation) = quota_reservation {
+            merchant_quota::complete_payment_quota_reservation(
+                state,
+                platform,
+                &business_profile,
+                &payment_id,
+                quota_reservation,
+            )
+            .await;
+        }
 
         if let Some(order_details) = &request.order_details {
             helpers::validate_order_details_amount(
@@ -1170,6 +1202,8 @@ impl<F: Clone + Sync> UpdateTracker<F, PaymentData<F>, api::PaymentsRequest> for
         let customer_id = payment_data.payment_intent.customer_id.clone();
 
         let customer_details = payment_data.payment_intent.customer_details.clone();
+        let contact_hashes =
+            customer_search::get_intent_contact_hashes(state, &payment_data.payment_intent)?;
 
         payment_data.payment_intent = state
             .store
@@ -1182,6 +1216,8 @@ impl<F: Clone + Sync> UpdateTracker<F, PaymentData<F>, api::PaymentsRequest> for
                     shipping_address_id: None,
                     billing_address_id: None,
                     customer_details,
+                    customer_email_hash: contact_hashes.email_hash,
+                    customer_phone_hash: contact_hashes.phone_hash,
                     updated_by: storage_scheme.to_string(),
                 },
                 key_store,
@@ -1866,6 +1902,21 @@ impl PaymentCreate {
             .change_context(errors::ApiErrorResponse::InternalServerError)
             .attach_printable("Unable to encode billing details to serde_json::Value")?;
 
+        let contact_hashes = raw_customer_details
+            .as_ref()
+            .map(|customer| {
+                customer_search::get_contact_hashes(
+                    state,
+                    customer.email.as_ref().map(|email| email.peek().as_str()),
+                    customer.phone.as_ref().map(|phone| phone.peek().as_str()),
+                    customer.phone_country_code.as_deref(),
+                )
+            })
+            .transpose()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to hash the customer details")?
+            .unwrap_or_default();
+
         let customer_details_encoded = raw_customer_details
             .map(|customer| Encode::encode_to_value(&customer).map(Secret::new))
             .transpose()
@@ -2005,6 +2056,9 @@ impl PaymentCreate {
             profile_acquirer_id: request.profile_acquirer_id.clone(),
             external_surcharge_strategy: request.external_surcharge_strategy,
             external_surcharge_applicable: None,
+            attribution_tags: request.attribution_tags.clone(),
+            customer_email_hash: contact_hashes.email_hash,
+            customer_phone_hash: contact_hashes.phone_hash,
         })
     }
 }
@@ -38,6 +38,8 @@ use super::payment_update::PaymentUpdate;
 use super::{Operation, OperationSessionSetters, PostUpdateTracker};
 #[cfg(feature = "v1")]
 use crate::core::payment_methods::transformers::call_modular_payment_method_update;
+#[cfg(feature = "v1")]
+use crate::core::payments::state_machine;
 #[cfg(all(feature = "v1", feature = "dynamic_routing"))]
 use crate::core::routing::helpers as routing_helpers;
 #[cfg(feature = "v2")]
@@ -476,6 +478,10 @@ impl<F: Send + Clone> PostUpdateTracker<F, PaymentData<F>, types::PaymentsAuthor
         ))
         .await?;
 
+        if payment_data.payment_attempt.status == enums::AttemptStatus::AuthenticationPending {
+            store_authorize_trace_context(db, processor, &payment_data.payment_intent).await;
+        }
+
         Ok(payment_data)
     }
 
@@ -2220,6 +2226,20 @@ async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
 
     let payment_method_status = router_data.payment_method_status;
 
+    // Flows racing with each other to update the status pass the origin of the connector payload
+    let status_update_source = router_data
+        .header_payload
+        .as_ref()
+        .and_then(|header_payload| header_payload.status_update_source)
+        .unwrap_or(state_machine::StatusUpdateSource::Api);
+    let current_attempt_status = state_machine::get_current_attempt_status(
+        state,
+        &payment_data.payment_attempt,
+        processor,
+        status_update_source,
+    )
+    .await;
+
     // TODO: refactor of gsm_error_category with respective feature flag
     #[allow(unused_variables)]
     let (capture_update, mut payment_attempt_update, gsm_error_category) = match router_data
@@ -2354,6 +2374,12 @@ async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
                             }
                         }
                     };
+                    let status = state_machine::resolve_attempt_status(
+                        current_attempt_status,
+                        status,
+                        status_update_source,
+                        &router_data.connector,
+                    );
                     (
                         None,
                         Some(storage::PaymentAttemptUpdate::ErrorUpdate {
@@ -2477,6 +2503,12 @@ async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
                                 .map(MinorUnit::get_amount_as_i64),
                         )?,
                     };
+                    let updated_attempt_status = state_machine::resolve_attempt_status(
+                        current_attempt_status,
+                        updated_attempt_status,
+                        status_update_source,
+                        &router_data.connector,
+                    );
                     match payments_response {
                         types::PaymentsResponseData::PreProcessingResponse {
                             pre_processing_id,
@@ -2961,6 +2993,7 @@ async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
         &router_data,
         processor,
         amount_captured,
+        status_update_source,
     );
 
     let m_db = state.clone().store;
@@ -3119,18 +3152,57 @@ async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
     }
 }
 
+/// Store the trace context of the authorize pending a redirect, so that the connector call on
+/// redirect completion can be linked to it. Nothing is stored if the traces are not exported.
+#[cfg(feature = "v1")]
+async fn store_authorize_trace_context(
+    state: &SessionState,
+    processor: &domain::Processor,
+    payment_intent: &storage::PaymentIntent,
+) {
+    let Some(trace_context) = router_env::trace_link::get_current_trace_context() else {
+        return;
+    };
+    let key = core_utils::get_authorize_trace_context_key(
+        processor.get_account().get_id(),
+        &payment_intent.payment_id,
+    );
+
+    // Failing to store the trace context must not fail the authorize
+    let result = match state.store.get_redis_conn() {
+        Ok(redis_conn) => {
+            redis_conn
+                .set_key_with_expiry(
+                    &key.into(),
+                    trace_context,
+                    consts::AUTHORIZE_TRACE_CONTEXT_TTL,
+                )
+                .await
+        }
+        Err(error) => Err(error),
+    };
+    if let Err(error) = result {
+        logger::error!(?error, "Failed to store the trace context of the authorize");
+    }
+}
+
 #[cfg(feature = "v1")]
 fn get_payment_intent_update_data<F: Clone, T: types::Capturable>(
     payment_data: PaymentData<F>,
     router_data: &types::RouterData<F, T, types::PaymentsResponseData>,
     processor: &domain::Processor,
     amount_captured: Option<MinorUnit>,
+    status_update_source: state_machine::StatusUpdateSource,
 ) -> storage::PaymentIntentUpdate {
+    let status = state_machine::resolve_intent_status(
+        payment_data.payment_intent.status,
+        api_models::enums::IntentStatus::foreign_from(payment_data.payment_attempt.status),
+        status_update_source,
+        &router_data.connector,
+    );
     match &router_data.response {
         Err(_) => storage::PaymentIntentUpdate::PGStatusUpdate {
-            status: api_models::enums::IntentStatus::foreign_from(
-                payment_data.payment_attempt.status,
-            ),
+            status,
             updated_by: processor.get_account().storage_scheme.to_string(),
             incremental_authorization_allowed: Some(false),
             feature_metadata: payment_data.payment_intent.feature_metadata.clone(),
@@ -3159,9 +3231,7 @@ fn get_payment_intent_update_data<F: Clone, T: types::Capturable>(
             }
         }
         Ok(_) => storage::PaymentIntentUpdate::ResponseUpdate {
-            status: api_models::enums::IntentStatus::foreign_from(
-                payment_data.payment_attempt.status,
-            ),
+            status,
             amount_captured,
             updated_by: processor.get_account().storage_scheme.to_string(),
             fingerprint_id: payment_data.payment_attempt.fingerprint_id.clone(),
@@ -23,6 +23,7 @@ use super::{BoxedOperation, Domain, GetTracker, Operation, UpdateTracker, Valida
 use crate::{
     core::{
         configs::dimension_state,
+        customer_search,
         errors::{self, CustomResult, RouterResult, StorageErrorExt},
         mandate::helpers as m_helpers,
         payments::{
@@ -124,6 +125,10 @@ impl<F: Send + Clone + Sync> GetTracker<F, PaymentData<F>, api::PaymentsRequest>
             ],
             "update",
         )?;
+        helpers::validate_attribution_tags_update(
+            &payment_intent,
+            request.attribution_tags.as_ref(),
+        )?;
         helpers::authenticate_client_secret(request.client_secret.as_ref(), &payment_intent)?;
 
         payment_intent.order_details = request
@@ -132,6 +137,11 @@ impl<F: Send + Clone + Sync> GetTracker<F, PaymentData<F>, api::PaymentsRequest>
             .attach_printable("Failed to convert order details to value")?
             .or(payment_intent.order_details);
 
+        payment_intent.attribution_tags = request
+            .attribution_tags
+            .clone()
+            .or(payment_intent.attribution_tags);
+
         payment_attempt = db
             .find_payment_attempt_by_payment_id_processor_merchant_id_attempt_id(
                 &payment_intent.payment_id,
@@ -1203,6 +1213,8 @@ impl PaymentUpdate {
         );
 
         let customer_details = payment_data.payment_intent.customer_details.clone();
+        let contact_hashes =
+            customer_search::get_intent_contact_hashes(state, &payment_data.payment_intent)?;
 
         let return_url = payment_data.payment_intent.return_url.clone();
         let setup_future_usage = payment_data.payment_intent.setup_future_usage;
@@ -1359,6 +1371,9 @@ impl PaymentUpdate {
                 external_surcharge_applicable: payment_data
                     .payment_intent
                     .external_surcharge_applicable,
+                attribution_tags: payment_data.payment_intent.attribution_tags.clone(),
+                customer_email_hash: contact_hashes.email_hash,
+                customer_phone_hash: contact_hashes.phone_hash,
             }));
 
         Ok((payment_data, attempt_update, intent_update))
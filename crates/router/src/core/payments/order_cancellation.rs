@@ -0,0 +1,454 @@
+//! Voids the authorization of a payment when the merchant signals that the order of the payment
+//! was cancelled, so that the funds held on the customer's payment method are released.
+
+use common_enums::enums;
+use error_stack::ResultExt;
+use hyperswitch_domain_models::payments::HeaderPayload;
+use router_env::logger;
+
+use crate::{
+    core::{
+        api_locking,
+        errors::{self, utils::StorageErrorExt, RouterResponse, RouterResult},
+        payments,
+    },
+    events::audit_events::{AuditEvent, AuditEventType},
+    routes::{app::ReqState, lock_utils, SessionState},
+    services,
+    types::{api, domain},
+};
+
+/// Void the authorization of a manual capture payment whose order was cancelled.
+///
+/// A capture being processed for the payment at the same time, or a capture which was already
+/// initiated, results in a conflict, as the authorization can no longer be voided.
+pub async fn payments_order_cancelled(
+    state: SessionState,
+    req_state: ReqState,
+    platform: domain::Platform,
+    profile_id: Option<common_utils::id_type::ProfileId>,
+    request: api::PaymentsOrderCancelledRequest,
+    header_payload: HeaderPayload,
+) -> RouterResponse<api::PaymentsResponse> {
+    let processor_merchant_id = platform.get_processor().get_account().get_id().to_owned();
+    ensure_void_on_order_cancellation_enabled(&state, &processor_merchant_id).await?;
+
+    let lock_action =
+        lock_payment_for_order_cancellation(&state, &processor_merchant_id, &request.payment_id)
+            .await?;
+
+    let response = Box::pin(void_payment_for_cancelled_order(
+        state.clone(),
+        req_state.clone(),
+        platform,
+        profile_id,
+        request.clone(),
+        header_payload,
+    ))
+    .await;
+
+    lock_action
+        .free_lock_action(&state, processor_merchant_id)
+        .await?;
+
+    if response.is_ok() {
+        req_state
+            .event_context
+            .event(AuditEvent::new(AuditEventType::PaymentOrderCancelled {
+                initiated_by: request.initiated_by,
+                cancellation_reason: request.cancellation_reason,
+            }))
+            .emit();
+    }
+
+    response
+}
+
+/// Lock the payment for voiding it. Captures hold the same lock, so a capture which is in
+/// progress is reported as a conflict without waiting for it to complete.
+async fn lock_payment_for_order_cancellation(
+    state: &SessionState,
+    merchant_id: &common_utils::id_type::MerchantId,
+    payment_id: &common_utils::id_type::PaymentId,
+) -> RouterResult<api_locking::LockAction> {
+    let lock_action = api_locking::LockAction::Hold {
+        input: api_locking::LockingInput {
+            unique_locking_key: payment_id.get_string_repr().to_owned(),
+            api_identifier: lock_utils::ApiIdentifier::Payments,
+            override_lock_retries: Some(1),
+        },
+    };
+    lock_action
+        .clone()
+        .perform_locking_action(state, merchant_id.clone())
+        .await
+        .map_err(|error| match error.current_context() {
+            errors::ApiErrorResponse::ResourceBusy => {
+                error.change_context(errors::ApiErrorResponse::PaymentCaptureConflict {
+                    message: format!(
+                        "The payment {} could not be voided as another operation on the payment, \
+                         such as a capture, is in progress",
+                        payment_id.get_string_repr()
+                    ),
+                })
+            }
+            _ => error,
+        })?;
+
+    Ok(lock_action)
+}
+
+async fn ensure_void_on_order_cancellation_enabled(
+    state: &SessionState,
+    merchant_id: &common_utils::id_type::MerchantId,
+) -> RouterResult<()> {
+    let is_enabled = state
+        .store
+        .find_config_by_key_unwrap_or(
+            &merchant_id.get_void_on_order_cancellation_key(),
+            Some("false".to_string()),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the void on order cancellation config")
+        .map(|config| serde_json::from_str::<bool>(&config.config).unwrap_or(false))?;
+
+    if !is_enabled {
+        Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: "Voiding payments on order cancellation is not enabled for the merchant"
+                .to_string(),
+        })?
+    }
+
+    Ok(())
+}
+
+async fn void_payment_for_cancelled_order(
+    state: SessionState,
+    req_state: ReqState,
+    platform: domain::Platform,
+    profile_id: Option<common_utils::id_type::ProfileId>,
+    request: api::PaymentsOrderCancelledRequest,
+    header_payload: HeaderPayload,
+) -> RouterResponse<api::PaymentsResponse> {
+    let db = &*state.store;
+    let processor = platform.get_processor();
+    let storage_scheme = processor.get_account().storage_scheme;
+
+    let payment_intent = db
+        .find_payment_intent_by_payment_id_processor_merchant_id(
+            &request.payment_id,
+            processor.get_account().get_id(),
+            processor.get_key_store(),
+            storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+    let payment_attempt = db
+        .find_payment_attempt_by_payment_id_processor_merchant_id_attempt_id(
+            &payment_intent.payment_id,
+            processor.get_account().get_id(),
+            payment_intent.active_attempt.get_id().as_str(),
+            storage_scheme,
+            processor.get_key_store(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    validate_payment_for_order_cancellation(
+        &payment_intent.payment_id,
+        payment_intent.status,
+        payment_attempt.status,
+        payment_attempt.capture_method,
+    )?;
+
+    logger::info!(
+        payment_id = %request.payment_id.get_string_repr(),
+        initiated_by = %request.initiated_by,
+        "Voiding the payment as its order was cancelled"
+    );
+
+    let cancel_request = api::PaymentsCancelRequest {
+        payment_id: request.payment_id,
+        cancellation_reason: Some(get_cancellation_reason(
+            &request.initiated_by,
+            request.cancellation_reason.as_deref(),
+        )),
+        merchant_connector_details: None,
+        all_keys_required: None,
+    };
+
+    Box::pin(payments::payments_core::<
+        api::Void,
+        api::PaymentsResponse,
+        _,
+        _,
+        _,
+        payments::PaymentData<api::Void>,
+    >(
+        state,
+        req_state,
+        platform,
+        profile_id,
+        payments::PaymentCancel,
+        cancel_request,
+        services::AuthFlow::Merchant,
+        payments::CallConnectorAction::Trigger,
+        None,
+        None,
+        header_payload,
+        None,
+    ))
+    .await
+}
+
+fn validate_payment_for_order_cancellation(
+    payment_id: &common_utils::id_type::PaymentId,
+    intent_status: enums::IntentStatus,
+    attempt_status: enums::AttemptStatus,
+    capture_method: Option<enums::CaptureMethod>,
+) -> RouterResult<()> {
+    if !matches!(
+        capture_method,
+        Some(enums::CaptureMethod::Manual | enums::CaptureMethod::ManualMultiple)
+    ) {
+        Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: "Only payments with manual capture are voided on order cancellation"
+                .to_string(),
+        })?
+    }
+
+    let is_capture_initiated = attempt_status == enums::AttemptStatus::CaptureInitiated
+        || matches!(
+            intent_status,
+            enums::IntentStatus::Processing
+                | enums::IntentStatus::Succeeded
+                | enums::IntentStatus::PartiallyCaptured
+                | enums::IntentStatus::PartiallyCapturedAndCapturable
+        );
+    if is_capture_initiated {
+        Err(errors::ApiErrorResponse::PaymentCaptureConflict {
+            message: format!(
+                "The payment {} could not be voided as a capture was already initiated for it, \
+                 the payment has a status of {}",
+                payment_id.get_string_repr(),
+                intent_status
+            ),
+        })?
+    }
+
+    if intent_status != enums::IntentStatus::RequiresCapture {
+        Err(errors::ApiErrorResponse::PaymentUnexpectedState {
+            current_flow: "voided on order cancellation".to_string(),
+            field_name: "status".to_string(),
+            current_value: intent_status.to_string(),
+            states: enums::IntentStatus::RequiresCapture.to_string(),
+        })?
+    }
+
+    Ok(())
+}
+
+fn get_cancellation_reason(initiated_by: &str, cancellation_reason: Option<&str>) -> String {
+    match cancellation_reason {
+        Some(reason) => format!("Order cancelled by {initiated_by}: {reason}"),
+        None => format!("Order cancelled by {initiated_by}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{borrow::Cow, sync::Arc};
+
+    use super::*;
+    use crate::{
+        configs::settings::Settings,
+        db::StorageImpl,
+        routes::{self, app::SessionStateInfo},
+    };
+
+    async fn get_session_state() -> SessionState {
+        let conf = Settings::new().expect("invalid settings");
+        let tx: tokio::sync::oneshot::Sender<()> = tokio::sync::oneshot::channel().0;
+        // The lock is held with the request ID, which the mock API client does not keep track of
+        let api_client = services::ProxyClient::new(&conf.proxy).unwrap();
+        let app_state = Box::pin(routes::AppState::with_storage(
+            conf,
+            StorageImpl::Mock,
+            tx,
+            Box::new(api_client),
+            env!("CARGO_PKG_NAME"),
+        ))
+        .await;
+        Arc::new(app_state)
+            .get_session_state(
+                &common_utils::id_type::TenantId::try_from_string("public".to_string()).unwrap(),
+                None,
+                || {},
+            )
+            .unwrap()
+    }
+
+    fn with_request_id(state: &SessionState) -> SessionState {
+        let mut state = state.clone();
+        state.add_request_id(router_env::RequestId::new_generated());
+        state
+    }
+
+    fn test_payment_id() -> common_utils::id_type::PaymentId {
+        common_utils::id_type::PaymentId::generate_test_payment_id_for_sample_data()
+    }
+
+    #[test]
+    fn test_authorized_manual_capture_payment_is_voided() {
+        for capture_method in [
+            enums::CaptureMethod::Manual,
+            enums::CaptureMethod::ManualMultiple,
+        ] {
+            assert!(validate_payment_for_order_cancellation(
+                &test_payment_id(),
+                enums::IntentStatus::RequiresCapture,
+                enums::AttemptStatus::Authorized,
+                Some(capture_method),
+            )
+            .is_ok());
+        }
+    }
+
+    #[test]
+    fn test_payment_with_capture_in_flight_is_a_conflict() {
+        for (intent_status, attempt_status) in [
+            (
+                enums::IntentStatus::RequiresCapture,
+                enums::AttemptStatus::CaptureInitiated,
+            ),
+            (
+                enums::IntentStatus::Processing,
+                enums::AttemptStatus::Authorized,
+            ),
+            (
+                enums::IntentStatus::PartiallyCapturedAndCapturable,
+                enums::AttemptStatus::PartialCharged,
+            ),
+        ] {
+            let error = validate_payment_for_order_cancellation(
+                &test_payment_id(),
+                intent_status,
+                attempt_status,
+                Some(enums::CaptureMethod::Manual),
+            )
+            .unwrap_err();
+            assert!(
+                matches!(
+                    error.current_context(),
+                    errors::ApiErrorResponse::PaymentCaptureConflict { .. }
+                ),
+                "{intent_status} / {attempt_status}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_automatic_capture_payment_is_not_voided() {
+        let error = validate_payment_for_order_cancellation(
+            &test_payment_id(),
+            enums::IntentStatus::RequiresCapture,
+            enums::AttemptStatus::Authorized,
+            Some(enums::CaptureMethod::Automatic),
+        )
+        .unwrap_err();
+
+        assert!(matches!(
+            error.current_context(),
+            errors::ApiErrorResponse::PreconditionFailed { .. }
+        ));
+    }
+
+    #[test]
+    fn test_cancellation_reason_records_the_initiating_system() {
+        assert_eq!(
+            get_cancellation_reason("order_management_system", Some("out_of_stock")),
+            "Order cancelled by order_management_system: out_of_stock"
+        );
+        assert_eq!(
+            get_cancellation_reason("order_management_system", None),
+            "Order cancelled by order_management_system"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_void_on_order_cancellation_is_opt_in() {
+        let state = get_session_state().await;
+        let merchant_id =
+            common_utils::id_type::MerchantId::try_from(Cow::from("merchant_order_cancel"))
+                .unwrap();
+
+        let error = ensure_void_on_order_cancellation_enabled(&state, &merchant_id)
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            error.current_context(),
+            errors::ApiErrorResponse::PreconditionFailed { .. }
+        ));
+
+        state
+            .store
+            .insert_config(diesel_models::configs::ConfigNew {
+                key: merchant_id.get_void_on_order_cancellation_key(),
+                config: "true".to_string(),
+            })
+            .await
+            .unwrap();
+        assert!(
+            ensure_void_on_order_cancellation_enabled(&state, &merchant_id)
+                .await
+                .is_ok()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_order_cancellation_during_capture_is_a_conflict() {
+        let state = get_session_state().await;
+        let merchant_id =
+            common_utils::id_type::MerchantId::try_from(Cow::from("merchant_order_cancel_lock"))
+                .unwrap();
+        let payment_id = test_payment_id();
+
+        // A capture of the payment holds the payment lock
+        let capture_state = with_request_id(&state);
+        let capture_lock = api_locking::LockAction::Hold {
+            input: api_locking::LockingInput {
+                unique_locking_key: payment_id.get_string_repr().to_owned(),
+                api_identifier: lock_utils::ApiIdentifier::Payments,
+                override_lock_retries: Some(1),
+            },
+        };
+        capture_lock
+            .clone()
+            .perform_locking_action(&capture_state, merchant_id.clone())
+            .await
+            .unwrap();
+
+        let cancellation_state = with_request_id(&state);
+        let error =
+            lock_payment_for_order_cancellation(&cancellation_state, &merchant_id, &payment_id)
+                .await
+                .unwrap_err();
+        assert!(matches!(
+            error.current_context(),
+            errors::ApiErrorResponse::PaymentCaptureConflict { .. }
+        ));
+
+        // The payment is voided once the capture has released the lock
+        capture_lock
+            .free_lock_action(&capture_state, merchant_id.clone())
+            .await
+            .unwrap();
+        lock_payment_for_order_cancellation(&cancellation_state, &merchant_id, &payment_id)
+            .await
+            .unwrap()
+            .free_lock_action(&cancellation_state, merchant_id)
+            .await
+            .unwrap();
+    }
+}
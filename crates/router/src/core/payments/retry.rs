@@ -51,6 +51,7 @@ pub async fn do_gsm_actions<'a, F, ApiRequest, FData, D>(
     business_profile: &domain::Profile,
     feature_config: &core_utils::FeatureConfig,
     _dimensions: &dimension_state::DimensionsWithProcessorAndProviderMerchantIdAndProfileId,
+    confirm_time_budget: Option<ConfirmTimeBudget>,
 ) -> RouterResult<types::RouterData<F, FData, types::PaymentsResponseData>>
 where
     F: Clone + Send + Sync + std::fmt::Debug + 'static,
@@ -103,26 +104,30 @@ where
     };
 
     if should_step_up {
-        router_data = Box::pin(do_retry(
-            &state.clone(),
-            req_state.clone(),
-            original_connector_data,
-            operation,
-            customer,
-            platform,
-            payment_data,
-            router_data,
-            validate_result,
-            schedule_time,
-            true,
-            frm_suggestion,
-            business_profile,
-            false, //should_retry_with_pan is not applicable for step-up
-            None,
-            initial_gsm.clone(),
-            feature_config,
-        ))
-        .await?;
+        if is_confirm_time_budget_exhausted(confirm_time_budget, router_data.external_latency) {
+            router_data = hand_off_to_scheduler(router_data)?;
+        } else {
+            router_data = Box::pin(do_retry(
+                &state.clone(),
+                req_state.clone(),
+                original_connector_data,
+                operation,
+                customer,
+                platform,
+                payment_data,
+                router_data,
+                validate_result,
+                schedule_time,
+                true,
+                frm_suggestion,
+                business_profile,
+                false, //should_retry_with_pan is not applicable for step-up
+                None,
+                initial_gsm.clone(),
+                feature_config,
+            ))
+            .await?;
+        }
     }
     // Step up is not applicable so proceed with auto retries flow
     else {
@@ -155,6 +160,14 @@ where
                         break;
                     }
 
+                    if is_confirm_time_budget_exhausted(
+                        confirm_time_budget,
+                        router_data.external_latency,
+                    ) {
+                        router_data = hand_off_to_scheduler(router_data)?;
+                        break;
+                    }
+
                     let is_network_token = payment_data
                         .get_payment_method_data()
                         .map(|pmd| pmd.is_network_token_payment_method_data())
@@ -241,6 +254,116 @@ where
     Ok(router_data)
 }
 
+/// Total time budget of a payment confirm. The budget starts with the confirm, so that the time
+/// spent on the steps preceding the connector calls, such as tokenization, is accounted for
+#[cfg(feature = "v1")]
+#[derive(Clone, Copy, Debug)]
+pub struct ConfirmTimeBudget {
+    started_at: std::time::Instant,
+    budget: std::time::Duration,
+}
+
+#[cfg(feature = "v1")]
+impl ConfirmTimeBudget {
+    pub fn new(started_at: std::time::Instant, business_profile: &domain::Profile) -> Option<Self> {
+        business_profile
+            .confirm_time_budget_in_secs
+            .and_then(|budget| u64::try_from(budget).ok())
+            .map(|budget| Self {
+                started_at,
+                budget: std::time::Duration::from_secs(budget),
+            })
+    }
+
+    /// Whether another connector call can complete within the budget, assuming that it takes as
+    /// long as the connector calls of the previous attempt, as tracked in their external latency
+    pub fn allows_connector_call(&self, previous_external_latency: Option<u128>) -> bool {
+        let expected_latency = previous_external_latency
+            .and_then(|latency| u64::try_from(latency).ok())
+            .map(std::time::Duration::from_millis)
+            .unwrap_or_default();
+
+        self.started_at.elapsed().saturating_add(expected_latency) < self.budget
+    }
+}
+
+#[cfg(feature = "v1")]
+fn is_confirm_time_budget_exhausted(
+    confirm_time_budget: Option<ConfirmTimeBudget>,
+    previous_external_latency: Option<u128>,
+) -> bool {
+    let is_exhausted = confirm_time_budget
+        .is_some_and(|budget| !budget.allows_connector_call(previous_external_latency));
+
+    if is_exhausted {
+        metrics::CONFIRM_TIME_BUDGET_EXHAUSTED_COUNT.add(1, &[]);
+        logger::info!("confirm time budget exhausted, not initiating further connector calls");
+    }
+    is_exhausted
+}
+
+/// Hands the payment over to the scheduler once the confirm time budget is used up. The attempt
+/// is persisted as pending, so that the payment sync task added for it reconciles its outcome,
+/// and the response asks the client to poll for the outcome instead of failing the payment.
+#[cfg(feature = "v1")]
+fn hand_off_to_scheduler<F, FData>(
+    mut router_data: types::RouterData<F, FData, types::PaymentsResponseData>,
+) -> RouterResult<types::RouterData<F, FData, types::PaymentsResponseData>> {
+    if let Err(error) = &router_data.response {
+        logger::info!(
+            error_code = %error.code,
+            "handing the payment over to the scheduler after the confirm time budget was used up"
+        );
+    }
+    router_data.response = Ok(get_processing_response(&router_data.response)?);
+    router_data.status = storage_enums::AttemptStatus::Pending;
+    Ok(router_data)
+}
+
+#[cfg(feature = "v1")]
+fn get_processing_response(
+    response: &Result<types::PaymentsResponseData, types::ErrorResponse>,
+) -> RouterResult<types::PaymentsResponseData> {
+    let resource_id = match response {
+        Ok(types::PaymentsResponseData::TransactionResponse { resource_id, .. }) => {
+            resource_id.clone()
+        }
+        Ok(_) => types::ResponseId::NoResponseId,
+        Err(error) => error.connector_transaction_id.clone().map_or(
+            types::ResponseId::NoResponseId,
+            types::ResponseId::ConnectorTransactionId,
+        ),
+    };
+
+    let current_time = time::OffsetDateTime::now_utc().unix_timestamp_nanos();
+    let wait_screen_instructions = api_models::payments::WaitScreenInstructions {
+        display_from_timestamp: current_time,
+        display_to_timestamp: None,
+        poll_config: Some(api_models::payments::PollConfig {
+            delay_in_secs: consts::CONFIRM_TIME_BUDGET_POLL_DELAY_IN_SECS,
+            frequency: consts::CONFIRM_TIME_BUDGET_POLL_FREQUENCY,
+        }),
+    };
+
+    Ok(types::PaymentsResponseData::TransactionResponse {
+        resource_id,
+        redirection_data: Box::new(None),
+        mandate_reference: Box::new(None),
+        connector_metadata: Some(
+            wait_screen_instructions
+                .encode_to_value()
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to encode the wait screen instructions")?,
+        ),
+        network_txn_id: None,
+        network_txn_link_id: None,
+        connector_response_reference_id: None,
+        incremental_authorization_allowed: None,
+        authentication_data: None,
+        charges: None,
+    })
+}
+
 #[instrument(skip_all)]
 pub async fn is_step_up_enabled_for_merchant_connector(
     state: &app::SessionState,
@@ -958,3 +1081,70 @@ impl<F: Send + Clone + Sync, FData: Send + Sync>
         }
     }
 }
+
+#[cfg(all(test, feature = "v1"))]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use std::time::{Duration, Instant};
+
+    use common_utils::ext_traits::ValueExt;
+
+    use super::*;
+
+    fn get_budget(started_secs_ago: u64, budget_in_secs: u64) -> ConfirmTimeBudget {
+        ConfirmTimeBudget {
+            started_at: Instant::now()
+                .checked_sub(Duration::from_secs(started_secs_ago))
+                .unwrap(),
+            budget: Duration::from_secs(budget_in_secs),
+        }
+    }
+
+    #[test]
+    fn test_budget_accounts_for_time_spent_before_connector_calls() {
+        assert!(get_budget(0, 30).allows_connector_call(None));
+        assert!(!get_budget(30, 30).allows_connector_call(None));
+    }
+
+    #[test]
+    fn test_budget_accounts_for_external_latency_of_previous_attempt() {
+        let budget = get_budget(10, 30);
+        assert!(budget.allows_connector_call(Some(5_000)));
+        assert!(!budget.allows_connector_call(Some(20_000)));
+    }
+
+    #[test]
+    fn test_processing_response_asks_to_poll_for_the_outcome() {
+        let response = Err(types::ErrorResponse {
+            connector_transaction_id: Some("pi_123".to_string()),
+            ..Default::default()
+        });
+
+        let types::PaymentsResponseData::TransactionResponse {
+            resource_id,
+            connector_metadata,
+            ..
+        } = get_processing_response(&response).unwrap()
+        else {
+            panic!("expected a transaction response");
+        };
+        assert!(matches!(
+            resource_id,
+            types::ResponseId::ConnectorTransactionId(id) if id == "pi_123"
+        ));
+
+        let wait_screen_instructions: api_models::payments::WaitScreenInstructions =
+            connector_metadata
+                .unwrap()
+                .parse_value("WaitScreenInstructions")
+                .unwrap();
+        assert_eq!(
+            wait_screen_instructions.poll_config,
+            Some(api_models::payments::PollConfig {
+                delay_in_secs: consts::CONFIRM_TIME_BUDGET_POLL_DELAY_IN_SECS,
+                frequency: consts::CONFIRM_TIME_BUDGET_POLL_FREQUENCY,
+            })
+        );
+    }
+}
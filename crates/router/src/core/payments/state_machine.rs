@@ -0,0 +1,587 @@
+//! Allowed payment attempt and payment intent status transitions.
+//!
+//! Attempt and intent status writes coming from connector responses (authorize, capture, void,
+//! sync, incoming webhooks and redirect completions) and from the scheduler sync are resolved
+//! through this module, so that out-of-order updates (for example a stale PSync response
+//! processed after a webhook already marked the attempt as charged) cannot move a payment out of
+//! a terminal state. Failed payments only move forward, when the connector later reports them as
+//! processed.
+//!
+//! Status writes made by an operation before the connector is called are not resolved here, as
+//! each operation validates the status of the payment it acts on. Neither are manual status
+//! updates, which are operator corrections meant to override the current status.
+//!
+//! Webhooks, syncs and redirect completions pass the origin of their update in the header payload,
+//! resolve their update against the persisted status and write the result back. These writers
+//! hold the payment status update lock, so that the read and the write of one update are not
+//! interleaved with those of another.
+
+pub use common_enums::StatusUpdateSource;
+use common_enums::{AttemptStatus, IntentStatus};
+#[cfg(feature = "v1")]
+use hyperswitch_domain_models::payments::payment_attempt::PaymentAttempt;
+use router_env::logger;
+
+use crate::routes::metrics;
+#[cfg(feature = "v1")]
+use crate::{
+    core::api_locking,
+    routes::{lock_utils, SessionState},
+    types::domain,
+};
+
+/// Error returned when an attempt or intent status update is not a permitted transition
+#[derive(Clone, Debug, Eq, PartialEq, thiserror::Error)]
+#[error("Illegal status transition from {from} to {to} via {update_source}")]
+pub struct IllegalStateTransition<S = AttemptStatus> {
+    pub from: S,
+    pub to: S,
+    pub update_source: StatusUpdateSource,
+}
+
+/// Statuses an attempt can be in before the connector has authorized the payment
+fn is_pre_authorization_status(status: AttemptStatus) -> bool {
+    matches!(
+        status,
+        AttemptStatus::Started
+            | AttemptStatus::AuthenticationPending
+            | AttemptStatus::AuthenticationSuccessful
+            | AttemptStatus::DeviceDataCollectionPending
+            | AttemptStatus::PaymentMethodAwaited
+            | AttemptStatus::ConfirmationAwaited
+            | AttemptStatus::Authorizing
+    )
+}
+
+/// Statuses an attempt can be in after the connector has authorized the payment
+fn is_post_authorization_status(status: AttemptStatus) -> bool {
+    matches!(
+        status,
+        AttemptStatus::Authorized
+            | AttemptStatus::PartiallyAuthorized
+            | AttemptStatus::CaptureInitiated
+            | AttemptStatus::VoidInitiated
+            | AttemptStatus::PartialChargedAndChargeable
+            | AttemptStatus::CaptureReview
+    )
+}
+
+/// Statuses an attempt is in after the connector failed to process it
+fn is_failed_status(status: AttemptStatus) -> bool {
+    matches!(
+        status,
+        AttemptStatus::Failure | AttemptStatus::CaptureFailed | AttemptStatus::VoidFailed
+    )
+}
+
+/// Check whether an attempt is allowed to move from `from` to `to`
+///
+/// - Failed attempts only move forward, to statuses the connector reports once it has processed
+///   the payment (a charge reported after a failure, or a capture or void retried after failing).
+/// - Other terminal statuses only move to post-charge statuses (void after charge, auto refund, or
+///   completing a partial charge).
+/// - Authorized attempts never move back to a pre-authorization status.
+/// - Every other transition is allowed.
+pub fn is_allowed_attempt_transition(from: AttemptStatus, to: AttemptStatus) -> bool {
+    if from == to {
+        return true;
+    }
+
+    if is_failed_status(from) {
+        return to.is_terminal_status() || is_post_authorization_status(to);
+    }
+
+    if from.is_terminal_status() {
+        return matches!(
+            (from, to),
+            (
+                AttemptStatus::Charged | AttemptStatus::PartialCharged,
+                AttemptStatus::VoidedPostCharge | AttemptStatus::AutoRefunded
+            ) | (AttemptStatus::PartialCharged, AttemptStatus::Charged)
+        );
+    }
+
+    if is_post_authorization_status(from) {
+        return !is_pre_authorization_status(to);
+    }
+
+    true
+}
+
+/// Validate an attempt status transition
+pub fn validate_attempt_transition(
+    from: AttemptStatus,
+    to: AttemptStatus,
+    source: StatusUpdateSource,
+) -> Result<AttemptStatus, IllegalStateTransition> {
+    if is_allowed_attempt_transition(from, to) {
+        Ok(to)
+    } else {
+        Err(IllegalStateTransition {
+            from,
+            to,
+            update_source: source,
+        })
+    }
+}
+
+/// Resolve the status to be persisted for an attempt. Illegal transitions are logged and
+/// counted, and the current (more terminal) status is retained.
+pub fn resolve_attempt_status(
+    from: AttemptStatus,
+    to: AttemptStatus,
+    source: StatusUpdateSource,
+    connector: &str,
+) -> AttemptStatus {
+    validate_attempt_transition(from, to, source)
+        .unwrap_or_else(|error| retain_current_status(error, "attempt", connector))
+}
+
+/// Statuses an intent can be in before the connector has authorized the payment
+fn is_pre_authorization_intent_status(status: IntentStatus) -> bool {
+    matches!(
+        status,
+        IntentStatus::RequiresPaymentMethod
+            | IntentStatus::RequiresConfirmation
+            | IntentStatus::RequiresCustomerAction
+    )
+}
+
+/// Statuses an intent can be in after the connector has authorized the payment
+fn is_post_authorization_intent_status(status: IntentStatus) -> bool {
+    matches!(
+        status,
+        IntentStatus::RequiresCapture
+            | IntentStatus::PartiallyAuthorizedAndRequiresCapture
+            | IntentStatus::PartiallyCapturedAndCapturable
+            | IntentStatus::PartiallyCapturedAndProcessing
+    )
+}
+
+/// Check whether an intent is allowed to move from `from` to `to`, following the rules of the
+/// attempt statuses the intent statuses are derived from
+///
+/// - Failed intents only move forward, to statuses the connector reports once it has processed
+///   the payment.
+/// - Other terminal statuses only move to post-capture statuses (void after capture, review of
+///   a failed capture, or completing a partial capture).
+/// - Authorized intents never move back to a pre-authorization status.
+/// - Every other transition is allowed.
+pub fn is_allowed_intent_transition(from: IntentStatus, to: IntentStatus) -> bool {
+    if from == to {
+        return true;
+    }
+
+    if from == IntentStatus::Failed {
+        return to.is_in_terminal_state() || is_post_authorization_intent_status(to);
+    }
+
+    if from.is_in_terminal_state() {
+        return matches!(
+            (from, to),
+            (
+                IntentStatus::Succeeded | IntentStatus::PartiallyCaptured,
+                IntentStatus::CancelledPostCapture | IntentStatus::Review
+            ) | (IntentStatus::PartiallyCaptured, IntentStatus::Succeeded)
+        );
+    }
+
+    if is_post_authorization_intent_status(from) {
+        return !is_pre_authorization_intent_status(to);
+    }
+
+    true
+}
+
+/// Validate an intent status transition
+pub fn validate_intent_transition(
+    from: IntentStatus,
+    to: IntentStatus,
+    source: StatusUpdateSource,
+) -> Result<IntentStatus, IllegalStateTransition<IntentStatus>> {
+    if is_allowed_intent_transition(from, to) {
+        Ok(to)
+    } else {
+        Err(IllegalStateTransition {
+            from,
+            to,
+            update_source: source,
+        })
+    }
+}
+
+/// Resolve the status to be persisted for an intent. Illegal transitions are logged and
+/// counted, and the current (more terminal) status is retained.
+pub fn resolve_intent_status(
+    from: IntentStatus,
+    to: IntentStatus,
+    source: StatusUpdateSource,
+    connector: &str,
+) -> IntentStatus {
+    validate_intent_transition(from, to, source)
+        .unwrap_or_else(|error| retain_current_status(error, "intent", connector))
+}
+
+fn retain_current_status<S>(
+    error: IllegalStateTransition<S>,
+    entity: &'static str,
+    connector: &str,
+) -> S
+where
+    S: std::fmt::Debug + std::fmt::Display,
+{
+    logger::warn!(illegal_state_transition=?error, entity, %connector, "{error}");
+    metrics::ILLEGAL_STATE_TRANSITION_PREVENTED.add(
+        1,
+        router_env::metric_attributes!(
+            ("entity", entity),
+            ("source", error.update_source.to_string()),
+            ("connector", connector.to_owned()),
+        ),
+    );
+    error.from
+}
+
+/// Lock to be held by the flows updating the status of a payment from a connector payload. It
+/// is the lock taken by the payment sync and redirect completion APIs for the payment.
+#[cfg(feature = "v1")]
+pub fn get_status_update_lock_action(
+    payment_id: &common_utils::id_type::PaymentId,
+) -> api_locking::LockAction {
+    api_locking::LockAction::Hold {
+        input: api_locking::LockingInput {
+            unique_locking_key: payment_id.get_string_repr().to_owned(),
+            api_identifier: lock_utils::ApiIdentifier::Payments,
+            override_lock_retries: None,
+        },
+    }
+}
+
+/// Get the status the update is to be resolved against. Updates that can race with each other
+/// (webhooks, syncs and redirect completions) are resolved against the latest persisted status
+/// instead of the one read at the start of the flow, falling back to the latter if the attempt
+/// cannot be fetched.
+///
+/// The caller must hold the lock returned by [`get_status_update_lock_action`] until the
+/// resolved status is persisted.
+#[cfg(feature = "v1")]
+pub async fn get_current_attempt_status(
+    state: &SessionState,
+    payment_attempt: &PaymentAttempt,
+    processor: &domain::Processor,
+    source: StatusUpdateSource,
+) -> AttemptStatus {
+    if source == StatusUpdateSource::Api {
+        return payment_attempt.status;
+    }
+
+    state
+        .store
+        .find_payment_attempt_by_payment_id_processor_merchant_id_attempt_id(
+            &payment_attempt.payment_id,
+            processor.get_account().get_id(),
+            &payment_attempt.attempt_id,
+            processor.get_account().storage_scheme,
+            processor.get_key_store(),
+        )
+        .await
+        .map(|latest_payment_attempt| latest_payment_attempt.status)
+        .unwrap_or_else(|error| {
+            logger::warn!(?error, "Failed to fetch latest payment attempt status");
+            payment_attempt.status
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use strum::IntoEnumIterator;
+
+    use super::*;
+
+    #[test]
+    fn every_status_can_transition_to_itself() {
+        for status in AttemptStatus::iter() {
+            assert!(is_allowed_attempt_transition(status, status));
+        }
+    }
+
+    #[test]
+    fn terminal_statuses_never_move_back_to_non_terminal_statuses() {
+        for from in AttemptStatus::iter()
+            .filter(|status| status.is_terminal_status() && !is_failed_status(*status))
+        {
+            for to in AttemptStatus::iter().filter(|status| !status.is_terminal_status()) {
+                let is_post_charge = matches!(
+                    (from, to),
+                    (
+                        AttemptStatus::Charged | AttemptStatus::PartialCharged,
+                        AttemptStatus::VoidedPostCharge
+                    )
+                );
+                assert_eq!(
+                    is_allowed_attempt_transition(from, to),
+                    is_post_charge,
+                    "{from} -> {to}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn authorized_statuses_never_move_back_to_pre_authorization_statuses() {
+        for from in AttemptStatus::iter().filter(|status| is_post_authorization_status(*status)) {
+            for to in AttemptStatus::iter() {
+                assert_eq!(
+                    is_allowed_attempt_transition(from, to),
+                    !is_pre_authorization_status(to),
+                    "{from} -> {to}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn non_terminal_pre_authorization_statuses_can_move_anywhere() {
+        for from in AttemptStatus::iter()
+            .filter(|status| !status.is_terminal_status() && !is_post_authorization_status(*status))
+        {
+            for to in AttemptStatus::iter() {
+                assert!(is_allowed_attempt_transition(from, to), "{from} -> {to}");
+            }
+        }
+    }
+
+    #[test]
+    fn illegal_transition_reports_states_and_source() {
+        let error = validate_attempt_transition(
+            AttemptStatus::Charged,
+            AttemptStatus::Authorizing,
+            StatusUpdateSource::Sync,
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            error,
+            IllegalStateTransition {
+                from: AttemptStatus::Charged,
+                to: AttemptStatus::Authorizing,
+                update_source: StatusUpdateSource::Sync,
+            }
+        );
+    }
+
+    #[test]
+    fn failed_statuses_only_move_forward() {
+        for from in AttemptStatus::iter().filter(|status| is_failed_status(*status)) {
+            for to in AttemptStatus::iter().filter(|status| *status != from) {
+                assert_eq!(
+                    is_allowed_attempt_transition(from, to),
+                    to.is_terminal_status() || is_post_authorization_status(to),
+                    "{from} -> {to}"
+                );
+            }
+        }
+        for to in IntentStatus::iter().filter(|status| *status != IntentStatus::Failed) {
+            assert_eq!(
+                is_allowed_intent_transition(IntentStatus::Failed, to),
+                to.is_in_terminal_state() || is_post_authorization_intent_status(to),
+                "failed -> {to}"
+            );
+        }
+    }
+
+    #[test]
+    fn charged_webhook_after_failure_is_applied_to_attempt_and_intent() {
+        assert_eq!(
+            resolve_attempt_status(
+                AttemptStatus::Failure,
+                AttemptStatus::Charged,
+                StatusUpdateSource::Webhook,
+                "stripe",
+            ),
+            AttemptStatus::Charged
+        );
+        assert_eq!(
+            resolve_intent_status(
+                IntentStatus::Failed,
+                IntentStatus::Succeeded,
+                StatusUpdateSource::Webhook,
+                "stripe",
+            ),
+            IntentStatus::Succeeded
+        );
+    }
+
+    #[test]
+    fn capture_retried_after_failed_capture_is_applied() {
+        for to in [AttemptStatus::CaptureInitiated, AttemptStatus::Charged] {
+            assert_eq!(
+                resolve_attempt_status(
+                    AttemptStatus::CaptureFailed,
+                    to,
+                    StatusUpdateSource::Api,
+                    "stripe",
+                ),
+                to
+            );
+        }
+    }
+
+    #[test]
+    fn stale_pending_sync_after_failure_keeps_attempt_and_intent_failed() {
+        assert_eq!(
+            resolve_attempt_status(
+                AttemptStatus::Failure,
+                AttemptStatus::Pending,
+                StatusUpdateSource::Sync,
+                "stripe",
+            ),
+            AttemptStatus::Failure
+        );
+        assert_eq!(
+            resolve_intent_status(
+                IntentStatus::Failed,
+                IntentStatus::Processing,
+                StatusUpdateSource::Sync,
+                "stripe",
+            ),
+            IntentStatus::Failed
+        );
+    }
+
+    #[test]
+    fn terminal_intent_statuses_never_move_back_to_non_terminal_statuses() {
+        for from in IntentStatus::iter()
+            .filter(|status| status.is_in_terminal_state() && *status != IntentStatus::Failed)
+        {
+            for to in IntentStatus::iter().filter(|status| !status.is_in_terminal_state()) {
+                let is_capture_review = matches!(
+                    (from, to),
+                    (
+                        IntentStatus::Succeeded | IntentStatus::PartiallyCaptured,
+                        IntentStatus::Review
+                    )
+                );
+                assert_eq!(
+                    is_allowed_intent_transition(from, to),
+                    is_capture_review,
+                    "{from} -> {to}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn authorized_intents_never_move_back_to_pre_authorization_statuses() {
+        for from in
+            IntentStatus::iter().filter(|status| is_post_authorization_intent_status(*status))
+        {
+            for to in IntentStatus::iter() {
+                assert_eq!(
+                    is_allowed_intent_transition(from, to),
+                    !is_pre_authorization_intent_status(to),
+                    "{from} -> {to}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn stale_psync_after_succeeded_webhook_keeps_intent_succeeded() {
+        let after_webhook = resolve_intent_status(
+            IntentStatus::Processing,
+            IntentStatus::Succeeded,
+            StatusUpdateSource::Webhook,
+            "stripe",
+        );
+        let after_sync = resolve_intent_status(
+            after_webhook,
+            IntentStatus::Processing,
+            StatusUpdateSource::Sync,
+            "stripe",
+        );
+
+        assert_eq!(after_sync, IntentStatus::Succeeded);
+        assert_eq!(
+            validate_intent_transition(
+                IntentStatus::Succeeded,
+                IntentStatus::Processing,
+                StatusUpdateSource::Sync,
+            )
+            .unwrap_err(),
+            IllegalStateTransition {
+                from: IntentStatus::Succeeded,
+                to: IntentStatus::Processing,
+                update_source: StatusUpdateSource::Sync,
+            }
+        );
+    }
+
+    #[test]
+    fn stale_psync_after_charged_webhook_keeps_charged() {
+        // Webhook marks the attempt as charged first
+        let after_webhook = resolve_attempt_status(
+            AttemptStatus::Authorizing,
+            AttemptStatus::Charged,
+            StatusUpdateSource::Webhook,
+            "stripe",
+        );
+        // A PSync response fetched before the webhook was processed arrives afterwards
+        let after_sync = resolve_attempt_status(
+            after_webhook,
+            AttemptStatus::Authorizing,
+            StatusUpdateSource::Sync,
+            "stripe",
+        );
+
+        assert_eq!(after_webhook, AttemptStatus::Charged);
+        assert_eq!(after_sync, AttemptStatus::Charged);
+    }
+
+    #[test]
+    fn webhook_and_psync_race_converges_regardless_of_order() {
+        let race_outcomes = [
+            (AttemptStatus::Pending, AttemptStatus::Charged),
+            (AttemptStatus::Authorizing, AttemptStatus::Failure),
+            (AttemptStatus::Authorized, AttemptStatus::Charged),
+            (
+                AttemptStatus::AuthenticationPending,
+                AttemptStatus::Authorized,
+            ),
+        ];
+
+        for (stale, final_status) in race_outcomes {
+            let webhook_first = resolve_attempt_status(
+                resolve_attempt_status(
+                    AttemptStatus::Started,
+                    final_status,
+                    StatusUpdateSource::Webhook,
+                    "stripe",
+                ),
+                stale,
+                StatusUpdateSource::Sync,
+                "stripe",
+            );
+            let sync_first = resolve_attempt_status(
+                resolve_attempt_status(
+                    AttemptStatus::Started,
+                    stale,
+                    StatusUpdateSource::Sync,
+                    "stripe",
+                ),
+                final_status,
+                StatusUpdateSource::Webhook,
+                "stripe",
+            );
+
+            assert_eq!(
+                webhook_first, final_status,
+                "webhook first: {stale} vs {final_status}"
+            );
+            assert_eq!(
+                sync_first, final_status,
+                "sync first: {stale} vs {final_status}"
+            );
+        }
+    }
+}
@@ -3970,6 +3970,8 @@ where
 
             let papal_sdk_next_action = paypal_sdk_next_steps_check(payment_attempt.clone())?;
 
+            let connector_sdk_next_action = connector_sdk_next_steps_check(&payment_attempt)?;
+
             let next_action_containing_fetch_qr_code_url =
                 fetch_qr_code_url_next_steps_check(payment_attempt.clone())?;
 
@@ -4050,6 +4052,11 @@ where
                                     next_action_data: paypal_next_action_data
                                 }
                             }))
+                            .or(connector_sdk_next_action.map(|connector_sdk_data| {
+                                api_models::payments::NextActionData::InvokeConnectorSdk {
+                                    connector_sdk_data,
+                                }
+                            }))
                             .or(upi_next_action)
                             .or(next_action_containing_wait_screen.map(|wait_screen_data| {
                                 api_models::payments::NextActionData::WaitScreenInformation {
@@ -4399,6 +4406,7 @@ where
             is_stored_credential: payment_attempt.is_stored_credential,
             request_extended_authorization: payment_attempt.request_extended_authorization,
             billing_descriptor: payment_intent.billing_descriptor,
+            attribution_tags: payment_intent.attribution_tags,
             partner_merchant_identifier_details: payment_intent.partner_merchant_identifier_details,
             payment_method_tokenization_details,
             installment_options: payment_intent.installment_options,
@@ -4509,6 +4517,20 @@ pub fn paypal_sdk_next_steps_check(
     Ok(paypal_next_steps)
 }
 
+pub fn connector_sdk_next_steps_check(
+    payment_attempt: &storage::PaymentAttempt,
+) -> RouterResult<Option<api_models::payments::ConnectorSdkNextActionData>> {
+    let connector_sdk_next_steps: Option<
+        Result<api_models::payments::ConnectorSdkNextActionData, _>,
+    > = payment_attempt
+        .connector_metadata
+        .clone()
+        .map(|metadata| metadata.parse_value("ConnectorSdkNextActionData"));
+
+    let connector_sdk_next_action = connector_sdk_next_steps.transpose().ok().flatten();
+    Ok(connector_sdk_next_action)
+}
+
 pub fn fetch_qr_code_url_next_steps_check(
     payment_attempt: storage::PaymentAttempt,
 ) -> RouterResult<Option<api_models::payments::FetchQrCodeInformation>> {
@@ -4790,6 +4812,7 @@ impl ForeignFrom<(storage::PaymentIntent, storage::PaymentAttempt)> for api::Pay
             is_stored_credential:pa.is_stored_credential,
             request_extended_authorization: pa.request_extended_authorization,
             billing_descriptor: pi.billing_descriptor,
+            attribution_tags: pi.attribution_tags,
             partner_merchant_identifier_details: pi.partner_merchant_identifier_details,
             payment_method_tokenization_details: None,
             installment_options: pi.installment_options,
@@ -19,6 +19,8 @@ use hyperswitch_masking::{ExposeInterface, PeekInterface, Secret, SwitchStrategy
 use router_env::logger;
 
 use super::PayoutData;
+#[cfg(feature = "v1")]
+use crate::core::customer_search;
 #[cfg(feature = "payouts")]
 use crate::core::payments::route_connector_v1_for_payouts;
 use crate::{
@@ -992,7 +994,7 @@ pub(super) async fn get_or_create_customer_details(
                     .change_context(errors::ApiErrorResponse::InternalServerError)
                     .attach_printable("Unable to encrypt document_details")?;
 
-                let customer = domain::Customer::new(
+                let mut customer = domain::Customer::new(
                     customer_id.clone(),
                     merchant_id.to_owned().clone(),
                     encryptable_customer.name,
@@ -1020,6 +1022,9 @@ pub(super) async fn get_or_create_customer_details(
                         .and_then(|initiator| initiator.to_created_by()), // Same as created_by on creation
                     id_type::GlobalCustomerId::generate(&state.conf.cell_information.id),
                 );
+                customer_search::set_customer_contact_hashes(state, &mut customer)
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to hash the contact details of the customer")?;
 
                 Ok(Some(
                     db.insert_customer(
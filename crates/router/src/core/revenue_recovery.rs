@@ -564,6 +564,11 @@ pub async fn perform_calculate_workflow(
     let billing_mca_id = revenue_recovery_payment_data.billing_mca.get_id();
 
     let mut event_type: Option<common_enums::EventType> = None;
+    // Dunning event of the failed recurring payment along with the time of its next retry
+    let mut recurring_payment_retry_event: Option<(
+        common_enums::EventType,
+        Option<time::PrimitiveDateTime>,
+    )> = None;
 
     logger::info!(
         process_id = %process.id,
@@ -682,6 +687,11 @@ pub async fn perform_calculate_workflow(
                     sch_errors::ProcessTrackerError::ProcessUpdateFailed
                 })?;
 
+            recurring_payment_retry_event = Some((
+                common_enums::EventType::RecurringPaymentRetryScheduled,
+                Some(scheduled_time),
+            ));
+
             logger::info!(
                 process_id = %process.id,
                 connector_customer_id = %connector_customer_id,
@@ -763,6 +773,10 @@ pub async fn perform_calculate_workflow(
                 })?;
 
             event_type = Some(common_enums::EventType::PaymentFailed);
+            recurring_payment_retry_event = Some((
+                common_enums::EventType::RecurringPaymentRecoveryExhausted,
+                None,
+            ));
 
             logger::info!(
                 process_id = %process.id,
@@ -772,6 +786,53 @@ pub async fn perform_calculate_workflow(
         }
     }
 
+    // Payments without an active attempt have not failed on our side yet, so there is nothing
+    // to notify about
+    if let (
+        Some((retry_event_type, next_retry_at)),
+        Some(ApplicationResponse::JsonWithHeaders((response, _headers))),
+    ) = (recurring_payment_retry_event, payments_response.as_ref())
+    {
+        let remaining_attempts = match next_retry_at {
+            Some(_) => {
+                revenue_recovery_workflow::get_remaining_retry_attempts(
+                    state,
+                    &connector_customer_id,
+                    payment_intent,
+                    revenue_recovery_payment_data.billing_mca.connector_name,
+                    retry_algorithm_type,
+                    process.retry_count,
+                )
+                .await
+            }
+            None => Some(0),
+        };
+
+        let _ = RevenueRecoveryOutgoingWebhook::send_recurring_payment_retry_webhook(
+            state,
+            retry_event_type,
+            payment_intent,
+            &platform,
+            profile,
+            tracking_data
+                .payment_attempt_id
+                .get_string_repr()
+                .to_string(),
+            response,
+            next_retry_at,
+            remaining_attempts,
+        )
+        .await
+        .map_err(|e| {
+            logger::error!(
+                error = ?e,
+                "Failed to send recurring payment retry webhook"
+            );
+            e
+        })
+        .ok();
+    }
+
     let _outgoing_webhook = event_type.and_then(|event_kind| {
         payments_response.map(|resp| Some((event_kind, resp)))
     })
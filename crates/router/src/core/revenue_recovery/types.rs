@@ -1628,4 +1628,267 @@ impl RevenueRecoveryOutgoingWebhook {
             }
         }
     }
+
+    /// Notifies the merchant of a failed recurring payment for which a retry is scheduled or the
+    /// recovery is exhausted, so that they can reach out to the customer. The customer is also
+    /// emailed if dunning emails are enabled.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn send_recurring_payment_retry_webhook(
+        state: &SessionState,
+        event_type: common_enums::EventType,
+        payment_intent: &PaymentIntent,
+        platform: &domain::Platform,
+        profile: &domain::Profile,
+        failed_attempt_id: String,
+        payments_response: &api_models::payments::PaymentsResponse,
+        next_retry_at: Option<PrimitiveDateTime>,
+        remaining_attempts: Option<u16>,
+    ) -> RecoveryResult<()> {
+        let Some(mut retry_response) = get_recurring_payment_retry_response(
+            &payment_intent.id,
+            payment_intent
+                .feature_metadata
+                .as_ref()
+                .and_then(|metadata| metadata.payment_revenue_recovery_metadata.as_ref()),
+            payments_response.error.clone(),
+            next_retry_at,
+            remaining_attempts,
+        ) else {
+            logger::debug!("Skipping recurring payment webhook for a non recovery payment");
+            return Ok(());
+        };
+
+        retry_response.decline_classification = match (
+            payments_response.connector.clone(),
+            payments_response.error.as_ref(),
+        ) {
+            (Some(connector), Some(error)) => helpers::get_gsm_record(
+                state,
+                connector,
+                REVENUE_RECOVERY,
+                consts::DEFAULT_SUBFLOW_STR,
+                Some(error.code.clone()),
+                Some(error.message.clone()),
+                None, // issuer_error_code not available in recovery context
+                None, // card_network
+            )
+            .await
+            .and_then(|gsm_record| gsm_record.error_category),
+            _ => None,
+        };
+
+        #[cfg(feature = "email")]
+        if state.conf.revenue_recovery.send_dunning_emails {
+            // Failing to email the customer must not prevent the merchant from being notified
+            send_recurring_payment_retry_email(
+                state,
+                &retry_response,
+                payments_response
+                    .billing
+                    .as_ref()
+                    .and_then(|billing| billing.email.clone()),
+                profile.profile_name.clone(),
+            )
+            .await
+            .map_err(|error| logger::error!(?error, "Failed to send recurring payment retry email"))
+            .ok();
+        }
+
+        let webhook_recipient = utils::resolve_webhook_recipient_from_created_by(
+            state,
+            platform,
+            profile,
+            payment_intent.created_by.as_ref(),
+        )
+        .await
+        .change_context(errors::RecoveryError::InvalidTask)
+        .attach_printable("Failed to resolve webhook recipient for revenue recovery")?;
+
+        create_event_and_trigger_outgoing_webhook(
+            state.clone(),
+            platform.clone(),
+            event_type,
+            common_enums::EventClass::Payments,
+            // Every failed attempt is notified once, as the payment can be retried several times
+            failed_attempt_id,
+            common_enums::EventObjectType::PaymentDetails,
+            api_models::webhooks::OutgoingWebhookContent::RecurringPaymentRetryDetails(Box::new(
+                retry_response,
+            )),
+            payment_intent.created_at,
+            webhook_recipient,
+        )
+        .await
+        .change_context(errors::RecoveryError::InvalidTask)
+        .attach_printable("Failed to send recurring payment retry webhook")
+    }
+}
+
+/// Details of the failed recurring payment to notify about. Only merchant initiated payments
+/// recovered by the retry engine carry the recovery metadata, so customer initiated payment
+/// failures are never notified.
+fn get_recurring_payment_retry_response(
+    payment_id: &id_type::GlobalPaymentId,
+    revenue_recovery_metadata: Option<&diesel_types::PaymentRevenueRecoveryMetadata>,
+    error: Option<api_models::payments::ErrorDetails>,
+    next_retry_at: Option<PrimitiveDateTime>,
+    remaining_attempts: Option<u16>,
+) -> Option<api_models::payments::RecurringPaymentRetryResponse> {
+    revenue_recovery_metadata.map(|revenue_recovery_metadata| {
+        api_models::payments::RecurringPaymentRetryResponse {
+            payment_id: payment_id.clone(),
+            mandate_id: revenue_recovery_metadata
+                .billing_connector_payment_details
+                .payment_processor_token
+                .clone(),
+            connector_customer_id: revenue_recovery_metadata
+                .billing_connector_payment_details
+                .connector_customer_id
+                .clone(),
+            decline_classification: None,
+            error,
+            next_retry_at,
+            remaining_attempts,
+        }
+    })
+}
+
+#[cfg(feature = "email")]
+async fn send_recurring_payment_retry_email(
+    state: &SessionState,
+    retry_response: &api_models::payments::RecurringPaymentRetryResponse,
+    customer_email: Option<common_utils::pii::Email>,
+    merchant_name: String,
+) -> RecoveryResult<()> {
+    let Some(recipient_email) = customer_email else {
+        logger::debug!("Skipping recurring payment retry email as the customer has no email");
+        return Ok(());
+    };
+
+    let email_contents = services::email::types::RecurringPaymentRetryEmail {
+        recipient_email,
+        subject: consts::EMAIL_SUBJECT_RECURRING_PAYMENT_FAILED,
+        payment_id: retry_response.payment_id.get_string_repr().to_string(),
+        merchant_name,
+        next_retry_at: retry_response.next_retry_at,
+    };
+
+    state
+        .email_client
+        .clone()
+        .compose_and_send_email(
+            crate::utils::user::get_base_url(state),
+            Box::new(email_contents),
+            state.conf.proxy.https_url.as_ref(),
+        )
+        .await
+        .change_context(errors::RecoveryError::InvalidTask)
+        .attach_printable("Failed to send recurring payment retry email")
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use std::borrow::Cow;
+
+    use time::macros::datetime;
+
+    use super::*;
+
+    fn get_revenue_recovery_metadata() -> diesel_types::PaymentRevenueRecoveryMetadata {
+        diesel_types::PaymentRevenueRecoveryMetadata {
+            total_retry_count: 1,
+            payment_connector_transmission:
+                common_enums::PaymentConnectorTransmission::ConnectorCallSucceeded,
+            billing_connector_id: id_type::MerchantConnectorAccountId::wrap(
+                "mca_billing".to_string(),
+            )
+            .unwrap(),
+            active_attempt_payment_connector_id: id_type::MerchantConnectorAccountId::wrap(
+                "mca_payment".to_string(),
+            )
+            .unwrap(),
+            billing_connector_payment_details: diesel_types::BillingConnectorPaymentDetails {
+                payment_processor_token: "pm_1234".to_string(),
+                connector_customer_id: "cus_1234".to_string(),
+            },
+            payment_method_type: common_enums::PaymentMethod::Card,
+            payment_method_subtype: common_enums::PaymentMethodType::Credit,
+            connector: common_enums::connector_enums::Connector::Stripe,
+            invoice_next_billing_time: None,
+            invoice_billing_started_at_time: None,
+            billing_connector_payment_method_details: None,
+            first_payment_attempt_pg_error_code: None,
+            first_payment_attempt_network_decline_code: None,
+            first_payment_attempt_network_advice_code: None,
+        }
+    }
+
+    fn get_payment_id() -> id_type::GlobalPaymentId {
+        id_type::GlobalPaymentId::try_from(Cow::Borrowed(
+            "12345_pay_01926c58bc6e77c09e809964e72af8c8",
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_retry_webhook_is_built_for_recovered_mit_payments() {
+        let revenue_recovery_metadata = get_revenue_recovery_metadata();
+        let next_retry_at = datetime!(2025-01-10 10:00);
+
+        let retry_response = get_recurring_payment_retry_response(
+            &get_payment_id(),
+            Some(&revenue_recovery_metadata),
+            None,
+            Some(next_retry_at),
+            Some(3),
+        )
+        .unwrap();
+
+        assert_eq!(retry_response.payment_id, get_payment_id());
+        assert_eq!(retry_response.mandate_id, "pm_1234");
+        assert_eq!(retry_response.connector_customer_id, "cus_1234");
+        assert_eq!(retry_response.next_retry_at, Some(next_retry_at));
+        assert_eq!(retry_response.remaining_attempts, Some(3));
+    }
+
+    #[test]
+    fn test_retry_webhook_is_not_built_for_cit_payments() {
+        let retry_response = get_recurring_payment_retry_response(
+            &get_payment_id(),
+            None,
+            None,
+            Some(datetime!(2025-01-10 10:00)),
+            Some(3),
+        );
+
+        assert!(retry_response.is_none());
+    }
+
+    #[cfg(feature = "email")]
+    #[tokio::test]
+    async fn test_retry_email_renders_next_retry_date() {
+        use external_services::email::EmailData;
+
+        let email = services::email::types::RecurringPaymentRetryEmail {
+            recipient_email: common_utils::pii::Email::try_from("customer@example.com".to_string())
+                .unwrap(),
+            subject: consts::EMAIL_SUBJECT_RECURRING_PAYMENT_FAILED,
+            payment_id: get_payment_id().get_string_repr().to_string(),
+            merchant_name: "Acme".to_string(),
+            next_retry_at: Some(datetime!(2025-01-10 10:00)),
+        };
+
+        let body = email
+            .get_email_data("https://example.com")
+            .await
+            .unwrap()
+            .body
+            .into_inner();
+
+        assert!(body.contains("12345_pay_01926c58bc6e77c09e809964e72af8c8"));
+        assert!(body.contains("2025-01-10"));
+        assert!(body.contains("Acme"));
+    }
 }
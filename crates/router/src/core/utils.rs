@@ -2269,6 +2269,18 @@ pub fn get_poll_id(
     processor_merchant_id.get_poll_id(&unique_id)
 }
 
+pub fn get_authorize_trace_context_key(
+    processor_merchant_id: &common_utils::id_type::MerchantId,
+    payment_id: &common_utils::id_type::PaymentId,
+) -> String {
+    format!(
+        "{}_{}_{}",
+        consts::AUTHORIZE_TRACE_CONTEXT_PREFIX,
+        processor_merchant_id.get_string_repr(),
+        payment_id.get_string_repr()
+    )
+}
+
 pub fn get_external_authentication_request_poll_id(
     payment_id: &common_utils::id_type::PaymentId,
 ) -> String {
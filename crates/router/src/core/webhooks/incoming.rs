@@ -895,13 +895,7 @@ async fn payments_incoming_webhook_flow(
         webhooks::ObjectReferenceId::PaymentId(ref id) => {
             let payment_id = payment_attempt.payment_id.clone();
 
-            let lock_action = api_locking::LockAction::Hold {
-                input: api_locking::LockingInput {
-                    unique_locking_key: payment_id.get_string_repr().to_owned(),
-                    api_identifier: lock_utils::ApiIdentifier::Payments,
-                    override_lock_retries: None,
-                },
-            };
+            let lock_action = payments::state_machine::get_status_update_lock_action(&payment_id);
 
             lock_action
                 .clone()
@@ -940,7 +934,9 @@ async fn payments_incoming_webhook_flow(
                 consume_or_trigger_flow.clone(),
                 shadow_ucs_call_connector_action,
                 None,
-                HeaderPayload::default(),
+                HeaderPayload::with_status_update_source(
+                    payments::state_machine::StatusUpdateSource::Webhook,
+                ),
                 None,
             ))
             .await;
@@ -411,8 +411,8 @@ async fn insert_event_and_spawn_webhook_delivery(
     let cloned_profile = webhook_recipient.profile.clone();
     let cloned_content = content.clone();
     // Using a tokio spawn here and not arbiter because not all caller of this function
-    // may have an actix arbiter
-    tokio::spawn(
+    // may have an actix arbiter. The delivery is waited for when the server shuts down.
+    services::graceful_shutdown::spawn_background_task(
         async move {
             Box::pin(trigger_webhook_and_raise_event(
                 cloned_state,
@@ -1422,6 +1422,9 @@ impl ForeignFrom<&api::OutgoingWebhookContent> for storage::EventMetadata {
                     payment_id: subscription.get_optional_payment_id(),
                 }
             }
+            webhooks::OutgoingWebhookContent::QuotaUsageDetails(quota_usage) => Self::Payment {
+                payment_id: quota_usage.payment_id.clone(),
+            },
         }
     }
 }
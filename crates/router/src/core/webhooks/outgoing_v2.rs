@@ -152,8 +152,8 @@ pub(crate) async fn create_event_and_trigger_outgoing_webhook(
     let cloned_provider_merchant_id = provider_merchant_id.clone();
     let cloned_processor_merchant_id = processor_merchant_id.clone();
     // Using a tokio spawn here and not arbiter because not all caller of this function
-    // may have an actix arbiter
-    tokio::spawn(
+    // may have an actix arbiter. The delivery is waited for when the server shuts down.
+    services::graceful_shutdown::spawn_background_task(
         async move {
             Box::pin(trigger_webhook_and_raise_event(
                 state,
@@ -638,6 +638,11 @@ impl ForeignFrom<&api::OutgoingWebhookContent> for storage::EventMetadata {
             webhooks::OutgoingWebhookContent::PayoutDetails(payout_response) => Self::Payout {
                 payout_id: payout_response.payout_id.clone(),
             },
+            webhooks::OutgoingWebhookContent::RecurringPaymentRetryDetails(retry_response) => {
+                Self::Payment {
+                    payment_id: retry_response.payment_id.clone(),
+                }
+            }
         }
     }
 }
@@ -11,6 +11,7 @@ pub mod callback_mapper;
 pub mod capture;
 pub mod card_issuer;
 pub mod configs;
+pub mod connector_customer_mapping;
 pub mod customers;
 pub mod dashboard_metadata;
 pub mod dispute;
@@ -102,6 +103,7 @@ pub trait StorageInterface:
     + api_keys::ApiKeyInterface
     + blocklist_lookup::BlocklistLookupInterface
     + configs::ConfigInterface<Error = StorageError>
+    + connector_customer_mapping::ConnectorCustomerMappingInterface
     + capture::CaptureInterface
     + customers::CustomerInterface<Error = StorageError>
     + dashboard_metadata::DashboardMetadataInterface
@@ -172,6 +174,7 @@ pub trait GlobalStorageInterface:
     + user_role::UserRoleInterface
     + user_key_store::UserKeyStoreInterface
     + role::RoleInterface
+    + configs::ConfigInterface<Error = StorageError>
     + RedisConnInterface
     + 'static
 {
@@ -0,0 +1,156 @@
+use common_utils::id_type;
+use error_stack::report;
+use router_env::{instrument, tracing};
+
+use super::{MockDb, Store};
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait ConnectorCustomerMappingInterface {
+    async fn insert_connector_customer_mapping(
+        &self,
+        new: storage::ConnectorCustomerMappingNew,
+    ) -> CustomResult<storage::ConnectorCustomerMapping, errors::StorageError>;
+
+    async fn find_connector_customer_mapping_by_merchant_id_customer_id_connector_label(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        customer_id: &id_type::CustomerId,
+        connector_label: &str,
+    ) -> CustomResult<storage::ConnectorCustomerMapping, errors::StorageError>;
+
+    async fn delete_connector_customer_mappings_by_merchant_id_customer_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        customer_id: &id_type::CustomerId,
+    ) -> CustomResult<bool, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl ConnectorCustomerMappingInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_connector_customer_mapping(
+        &self,
+        new: storage::ConnectorCustomerMappingNew,
+    ) -> CustomResult<storage::ConnectorCustomerMapping, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        new.insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_connector_customer_mapping_by_merchant_id_customer_id_connector_label(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        customer_id: &id_type::CustomerId,
+        connector_label: &str,
+    ) -> CustomResult<storage::ConnectorCustomerMapping, errors::StorageError> {
+        // Read from the primary, as the mapping is read right after a concurrent insert
+        let conn = connection::pg_connection_write(self).await?;
+        storage::ConnectorCustomerMapping::find_by_merchant_id_customer_id_connector_label(
+            &conn,
+            merchant_id,
+            customer_id,
+            connector_label,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn delete_connector_customer_mappings_by_merchant_id_customer_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        customer_id: &id_type::CustomerId,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::ConnectorCustomerMapping::delete_by_merchant_id_customer_id(
+            &conn,
+            merchant_id,
+            customer_id,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl ConnectorCustomerMappingInterface for MockDb {
+    async fn insert_connector_customer_mapping(
+        &self,
+        new: storage::ConnectorCustomerMappingNew,
+    ) -> CustomResult<storage::ConnectorCustomerMapping, errors::StorageError> {
+        let mut connector_customer_mappings = self.connector_customer_mappings.lock().await;
+
+        if connector_customer_mappings.iter().any(|mapping| {
+            mapping.merchant_id == new.merchant_id
+                && mapping.customer_id == new.customer_id
+                && mapping.connector_label == new.connector_label
+        }) {
+            Err(errors::StorageError::DuplicateValue {
+                entity: "connector_customer_mapping",
+                key: Some(new.connector_label.clone()),
+            })?;
+        }
+
+        let connector_customer_mapping = storage::ConnectorCustomerMapping {
+            merchant_id: new.merchant_id,
+            customer_id: new.customer_id,
+            connector_label: new.connector_label,
+            connector_customer_id: new.connector_customer_id,
+            created_at: common_utils::date_time::now(),
+        };
+        connector_customer_mappings.push(connector_customer_mapping.clone());
+
+        Ok(connector_customer_mapping)
+    }
+
+    async fn find_connector_customer_mapping_by_merchant_id_customer_id_connector_label(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        customer_id: &id_type::CustomerId,
+        connector_label: &str,
+    ) -> CustomResult<storage::ConnectorCustomerMapping, errors::StorageError> {
+        self.connector_customer_mappings
+            .lock()
+            .await
+            .iter()
+            .find(|mapping| {
+                &mapping.merchant_id == merchant_id
+                    && &mapping.customer_id == customer_id
+                    && mapping.connector_label == connector_label
+            })
+            .cloned()
+            .ok_or(
+                errors::StorageError::ValueNotFound(
+                    "No connector customer mapping found for the customer and connector"
+                        .to_string(),
+                )
+                .into(),
+            )
+    }
+
+    async fn delete_connector_customer_mappings_by_merchant_id_customer_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        customer_id: &id_type::CustomerId,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let mut connector_customer_mappings = self.connector_customer_mappings.lock().await;
+        let mappings_count = connector_customer_mappings.len();
+        connector_customer_mappings.retain(|mapping| {
+            &mapping.merchant_id != merchant_id || &mapping.customer_id != customer_id
+        });
+
+        if connector_customer_mappings.len() == mappings_count {
+            Err(errors::StorageError::ValueNotFound(
+                "No connector customer mapping found for the customer".to_string(),
+            ))?;
+        }
+        Ok(true)
+    }
+}
@@ -2070,6 +2070,8 @@ mod tests {
             card_testing_guard_config: None,
             card_testing_secret_key: None,
             surcharge_connector_details: None,
+            confirm_time_budget_in_secs: None,
+            time_zone: None,
             is_clear_pan_retries_enabled: false,
             force_3ds_challenge: false,
             is_debit_routing_enabled: false,
@@ -2210,6 +2212,7 @@ mod tests {
             is_stored_credential: None,
             request_extended_authorization: None,
             billing_descriptor: None,
+            attribution_tags: None,
             partner_merchant_identifier_details: None,
             payment_method_tokenization_details: None,
             error_details: None,
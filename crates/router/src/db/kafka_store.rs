@@ -72,6 +72,7 @@ use crate::{
         callback_mapper::CallbackMapperInterface,
         capture::CaptureInterface,
         configs::ConfigInterface,
+        connector_customer_mapping::ConnectorCustomerMappingInterface,
         customers::CustomerInterface,
         dispute::DisputeInterface,
         ephemeral_key::EphemeralKeyInterface,
@@ -1114,6 +1115,43 @@ impl EventInterface for KafkaStore {
     }
 }
 
+#[async_trait::async_trait]
+impl ConnectorCustomerMappingInterface for KafkaStore {
+    async fn insert_connector_customer_mapping(
+        &self,
+        new: storage::ConnectorCustomerMappingNew,
+    ) -> CustomResult<storage::ConnectorCustomerMapping, errors::StorageError> {
+        self.diesel_store
+            .insert_connector_customer_mapping(new)
+            .await
+    }
+
+    async fn find_connector_customer_mapping_by_merchant_id_customer_id_connector_label(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        customer_id: &id_type::CustomerId,
+        connector_label: &str,
+    ) -> CustomResult<storage::ConnectorCustomerMapping, errors::StorageError> {
+        self.diesel_store
+            .find_connector_customer_mapping_by_merchant_id_customer_id_connector_label(
+                merchant_id,
+                customer_id,
+                connector_label,
+            )
+            .await
+    }
+
+    async fn delete_connector_customer_mappings_by_merchant_id_customer_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        customer_id: &id_type::CustomerId,
+    ) -> CustomResult<bool, errors::StorageError> {
+        self.diesel_store
+            .delete_connector_customer_mappings_by_merchant_id_customer_id(merchant_id, customer_id)
+            .await
+    }
+}
+
 #[async_trait::async_trait]
 impl LockerMockUpInterface for KafkaStore {
     async fn find_locker_by_card_id(
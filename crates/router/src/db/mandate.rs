@@ -692,6 +692,7 @@ impl MandateInterface for MockDb {
             updated_by: mandate_new.updated_by,
             customer_user_agent_extended,
             network_transaction_link_id: mandate_new.network_transaction_link_id,
+            customer_acceptance_source: mandate_new.customer_acceptance_source,
         };
         mandates.push(mandate.clone());
         Ok(mandate)
@@ -130,6 +130,13 @@ impl EventsHandler {
             kafka_producer.set_tenancy(tenant_config);
         }
     }
+
+    /// Send the events pending to be emitted, waiting for at most the timeout
+    pub fn flush(&self, timeout: std::time::Duration) {
+        if let Self::Kafka(kafka_producer) = self {
+            kafka_producer.flush(timeout);
+        }
+    }
 }
 
 impl MessagingInterface for EventsHandler {
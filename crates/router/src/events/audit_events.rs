@@ -1,4 +1,5 @@
 use api_models::payments::Amount;
+use common_enums::AvsMatchResult;
 use common_utils::types::MinorUnit;
 use diesel_models::fraud_check::FraudCheck;
 use events::{Event, EventInfo};
@@ -40,6 +41,28 @@ pub enum AuditEventType {
         error_message: Option<String>,
     },
     PaymentRecurrence,
+    PaymentForceClosed {
+        reason: String,
+    },
+    UnmaskedHeadersUpdated {
+        previous_keys: Vec<String>,
+        keys: Vec<String>,
+    },
+    PaymentAvsAutoVoided {
+        rule_name: String,
+        avs_street_match: Option<AvsMatchResult>,
+        avs_postal_code_match: Option<AvsMatchResult>,
+    },
+    PaymentAvsAutoVoidFailed {
+        rule_name: String,
+        avs_street_match: Option<AvsMatchResult>,
+        avs_postal_code_match: Option<AvsMatchResult>,
+        error_message: String,
+    },
+    PaymentOrderCancelled {
+        initiated_by: String,
+        cancellation_reason: Option<String>,
+    },
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -85,6 +108,11 @@ impl Event for AuditEvent {
             AuditEventType::PaymentCompleteAuthorize => "payment_complete_authorize",
             AuditEventType::PaymentReject { .. } => "payment_rejected",
             AuditEventType::PaymentRecurrence => "payment_recurrence",
+            AuditEventType::PaymentForceClosed { .. } => "payment_force_closed",
+            AuditEventType::UnmaskedHeadersUpdated { .. } => "unmasked_headers_updated",
+            AuditEventType::PaymentAvsAutoVoided { .. } => "payment_avs_auto_voided",
+            AuditEventType::PaymentAvsAutoVoidFailed { .. } => "payment_avs_auto_void_failed",
+            AuditEventType::PaymentOrderCancelled { .. } => "payment_order_cancelled",
         };
         format!(
             "{event_type}-{}",
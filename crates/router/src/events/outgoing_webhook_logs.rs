@@ -133,6 +133,11 @@ impl OutgoingWebhookEventMetric for OutgoingWebhookContent {
                         .unwrap_or(serde_json::json!({"error":"failed to serialize"})),
                 })
             }
+            Self::QuotaUsageDetails(quota_payload) => Some(OutgoingWebhookEventContent::Payment {
+                payment_id: quota_payload.payment_id.clone(),
+                content: hyperswitch_masking::masked_serialize(&quota_payload)
+                    .unwrap_or(serde_json::json!({"error":"failed to serialize"})),
+            }),
         }
     }
 }
@@ -168,6 +173,13 @@ impl OutgoingWebhookEventMetric for OutgoingWebhookContent {
                 content: hyperswitch_masking::masked_serialize(&payout_payload)
                     .unwrap_or(serde_json::json!({"error":"failed to serialize"})),
             }),
+            Self::RecurringPaymentRetryDetails(retry_payload) => {
+                Some(OutgoingWebhookEventContent::Payment {
+                    payment_id: retry_payload.payment_id.clone(),
+                    content: hyperswitch_masking::masked_serialize(&retry_payload)
+                        .unwrap_or(serde_json::json!({"error":"failed to serialize"})),
+                })
+            }
         }
     }
 }
@@ -272,7 +272,8 @@ pub fn mk_app(
     server_app
 }
 
-/// Starts the server
+/// Starts the server, returning it along with the task shutting it down gracefully on receiving a
+/// shutdown signal. The task is to be awaited once the server has stopped.
 ///
 /// # Panics
 ///
@@ -281,7 +282,7 @@ pub fn mk_app(
 pub async fn start_server(
     conf: settings::Settings<SecuredSecret>,
     service_name: &'static str,
-) -> ApplicationResult<Server> {
+) -> ApplicationResult<(Server, tokio::task::JoinHandle<()>)> {
     logger::debug!(startup_config=?conf);
     let server = conf.server.clone();
     let (tx, rx) = oneshot::channel();
@@ -290,12 +291,24 @@ pub async fn start_server(
     })?);
     let state = Box::pin(AppState::new(conf, tx, api_client, service_name)).await;
     let request_body_limit = server.request_body_limit;
+    let shutdown_timeout = server.shutdown_timeout;
+    let event_handler = state.event_handler.clone();
+
+    crate::core::connector_smoke_test::spawn_connector_smoke_test(state.clone());
+    crate::core::connector_warmup::spawn_connector_warmup(
+        &state.conf.connector_warmup,
+        &state.conf.connectors,
+        crate::core::connector_warmup::ProxyWarmupClient::new(state.conf.proxy.clone()),
+    );
 
     let server_builder =
         actix_web::HttpServer::new(move || mk_app(state.clone(), request_body_limit))
             .bind((server.host.as_str(), server.port))?
             .workers(server.workers)
-            .shutdown_timeout(server.shutdown_timeout)
+            .shutdown_timeout(shutdown_timeout)
+            // Shutdown signals are handled by `graceful_shutdown`, which drains the connector
+            // calls in flight along with the requests
+            .disable_signals()
             .keep_alive(Some(std::time::Duration::from_secs(server.keep_alive)))
             .client_request_timeout(std::time::Duration::from_millis(
                 server.client_request_timeout,
@@ -357,7 +370,15 @@ pub async fn start_server(
     let server = server_builder.run();
 
     let _task_handle = tokio::spawn(receiver_for_error(rx, server.handle()).in_current_span());
-    Ok(server)
+    let shutdown_task_handle = tokio::spawn(
+        services::graceful_shutdown::handle_shutdown_signal(
+            server.handle(),
+            std::time::Duration::from_secs(shutdown_timeout),
+            event_handler,
+        )
+        .in_current_span(),
+    );
+    Ok((server, shutdown_task_handle))
 }
 
 pub async fn receiver_for_error(rx: oneshot::Receiver<()>, mut server: impl Stop) {
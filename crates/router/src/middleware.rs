@@ -7,7 +7,7 @@ use router_env::{
     tracing::{field::Empty, Instrument},
 };
 
-use crate::{headers, routes::metrics};
+use crate::{headers, routes::metrics, services::graceful_shutdown};
 
 /// Middleware for attaching default response headers. Headers with the same key already set in a
 /// response will not be overwritten.
@@ -390,6 +390,7 @@ where
         let request_method = Cow::<'static, str>::from(req.method().as_str().to_owned());
 
         Box::pin(async move {
+            let _in_flight_request = graceful_shutdown::InFlightGuard::track_request();
             let mut attributes =
                 router_env::metric_attributes!(("path", request_path), ("method", request_method))
                     .to_vec();
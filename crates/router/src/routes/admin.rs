@@ -2,6 +2,8 @@ use actix_web::{web, HttpRequest, HttpResponse};
 use router_env::{instrument, tracing, Flow};
 
 use super::app::AppState;
+#[cfg(all(feature = "olap", feature = "v1"))]
+use crate::core::{customer_search, merchant_quota, merchant_takeout};
 use crate::{
     core::{admin::*, api_locking, errors, merchant_connector_webhook_management::*},
     services::{api, authentication as auth, authorization::permissions::Permission},
@@ -1247,3 +1249,239 @@ pub async fn retrieve_connector_webhook(
     ))
     .await
 }
+
+/// Merchant Account - Takeout Create
+///
+/// Start generating an export of the configuration and transactional data of a merchant account.
+#[cfg(all(feature = "olap", feature = "v1"))]
+#[instrument(skip_all, fields(flow = ?Flow::MerchantTakeoutCreate))]
+pub async fn merchant_takeout_create(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::MerchantId>,
+    json_payload: web::Json<api_models::takeout::MerchantTakeoutRequest>,
+) -> HttpResponse {
+    let flow = Flow::MerchantTakeoutCreate;
+    let merchant_id = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, req, _| {
+            merchant_takeout::create_merchant_takeout(state, auth.platform, req)
+        },
+        auth::auth_type(
+            &auth::AdminApiAuthWithMerchantIdFromRoute(merchant_id.clone()),
+            &auth::JWTAuthMerchantFromRoute {
+                merchant_id,
+                required_permission: Permission::MerchantAccountWrite,
+                allow_connected: true,
+                allow_platform: false,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Merchant Account - Takeout Retrieve
+///
+/// Retrieve the status of a takeout, with the download URLs of the files once it is completed.
+#[cfg(all(feature = "olap", feature = "v1"))]
+#[instrument(skip_all, fields(flow = ?Flow::MerchantTakeoutRetrieve))]
+pub async fn merchant_takeout_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(common_utils::id_type::MerchantId, String)>,
+) -> HttpResponse {
+    let flow = Flow::MerchantTakeoutRetrieve;
+    let (merchant_id, job_id) = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        job_id,
+        |state, auth: auth::AuthenticationData, job_id, _| {
+            merchant_takeout::retrieve_merchant_takeout(state, auth.platform, job_id)
+        },
+        auth::auth_type(
+            &auth::AdminApiAuthWithMerchantIdFromRoute(merchant_id.clone()),
+            &auth::JWTAuthMerchantFromRoute {
+                merchant_id,
+                required_permission: Permission::MerchantAccountRead,
+                allow_connected: true,
+                allow_platform: false,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Merchant Account - Takeout File Download
+///
+/// Download an exported file of a takeout. The request is authenticated by the signature of the
+/// download URL returned when retrieving the takeout.
+#[cfg(all(feature = "olap", feature = "v1"))]
+#[instrument(skip_all, fields(flow = ?Flow::MerchantTakeoutFileDownload))]
+pub async fn merchant_takeout_file_download(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(common_utils::id_type::MerchantId, String, String)>,
+    query: web::Query<api_models::takeout::TakeoutFileDownloadQuery>,
+) -> HttpResponse {
+    let flow = Flow::MerchantTakeoutFileDownload;
+    let (merchant_id, job_id, file_name) = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        query.into_inner(),
+        |state, _, query, _| {
+            merchant_takeout::download_merchant_takeout_file(
+                state,
+                merchant_id.clone(),
+                job_id.clone(),
+                file_name.clone(),
+                query,
+            )
+        },
+        &auth::NoAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Merchant Account - Customer Contact Hashes Rehash
+///
+/// Start re-computing the hashes of the email and phone number of the customers and payments of a
+/// merchant account, with which payments are searched by customer email or phone number.
+#[cfg(all(feature = "olap", feature = "v1"))]
+#[instrument(skip_all, fields(flow = ?Flow::CustomerContactHashesRehash))]
+pub async fn customer_contact_hashes_rehash(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::MerchantId>,
+) -> HttpResponse {
+    let flow = Flow::CustomerContactHashesRehash;
+    let merchant_id = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        (),
+        |state, auth: auth::AuthenticationData, _, _| {
+            customer_search::start_customer_contact_rehash(state, auth.platform)
+        },
+        &auth::AdminApiAuthWithMerchantIdFromRoute(merchant_id),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Merchant Account - Quota Update
+///
+/// Set the quota of payments the merchant can create in a period, replacing the existing quota.
+#[cfg(all(feature = "olap", feature = "v1"))]
+#[instrument(skip_all, fields(flow = ?Flow::MerchantQuotaUpdate))]
+pub async fn merchant_quota_update(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::MerchantId>,
+    json_payload: web::Json<api_models::merchant_quota::MerchantQuota>,
+) -> HttpResponse {
+    let flow = Flow::MerchantQuotaUpdate;
+    let merchant_id = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, req, _| {
+            merchant_quota::update_merchant_quota(state, auth.platform, req)
+        },
+        &auth::PlatformOrgAdminAuthWithMerchantIdFromRoute {
+            merchant_id_from_route: merchant_id,
+            is_admin_auth_allowed: true,
+        },
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Merchant Account - Quota Delete
+///
+/// Remove the quota of the merchant, after which its payments are no longer limited.
+#[cfg(all(feature = "olap", feature = "v1"))]
+#[instrument(skip_all, fields(flow = ?Flow::MerchantQuotaDelete))]
+pub async fn merchant_quota_delete(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::MerchantId>,
+) -> HttpResponse {
+    let flow = Flow::MerchantQuotaDelete;
+    let merchant_id = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        (),
+        |state, auth: auth::AuthenticationData, _, _| {
+            merchant_quota::delete_merchant_quota(state, auth.platform)
+        },
+        &auth::PlatformOrgAdminAuthWithMerchantIdFromRoute {
+            merchant_id_from_route: merchant_id,
+            is_admin_auth_allowed: true,
+        },
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Merchant Account - Quota Usage
+///
+/// Retrieve the payments created by the merchant in the current period, against its quota.
+#[cfg(all(feature = "olap", feature = "v1"))]
+#[instrument(skip_all, fields(flow = ?Flow::MerchantQuotaUsage))]
+pub async fn merchant_quota_usage(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::MerchantId>,
+) -> HttpResponse {
+    let flow = Flow::MerchantQuotaUsage;
+    let merchant_id = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        (),
+        |state, auth: auth::AuthenticationData, _, _| {
+            merchant_quota::retrieve_merchant_quota_usage(state, auth.platform)
+        },
+        auth::auth_type(
+            &auth::PlatformOrgAdminAuthWithMerchantIdFromRoute {
+                merchant_id_from_route: merchant_id.clone(),
+                is_admin_auth_allowed: true,
+            },
+            &auth::JWTAuthMerchantFromRoute {
+                merchant_id,
+                required_permission: Permission::MerchantAccountRead,
+                allow_connected: true,
+                allow_platform: false,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
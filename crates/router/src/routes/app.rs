@@ -143,6 +143,9 @@ pub struct SessionState {
     pub infra_components: Option<serde_json::Value>,
     pub enhancement: Option<HashMap<String, String>>,
     pub superposition_service: Arc<SuperpositionClient>,
+    pub connector_call_outcome_sender:
+        Option<crate::services::connector_call_outcome::ConnectorCallOutcomeSender>,
+    pub connector_smoke_test_reports: crate::core::connector_smoke_test::ConnectorSmokeTestReports,
 }
 impl scheduler::SchedulerSessionState for SessionState {
     fn get_db(&self) -> Box<dyn SchedulerInterface> {
@@ -321,6 +324,66 @@ impl hyperswitch_interfaces::api_client::ApiClientWrapper for SessionState {
     fn event_handler(&self) -> &dyn hyperswitch_interfaces::events::EventHandlerInterface {
         &self.event_handler
     }
+    fn get_connector_request_locale(&self, connector: &str) -> Option<String> {
+        connector
+            .parse::<common_enums::connector_enums::Connector>()
+            .ok()
+            .filter(|connector| {
+                self.conf
+                    .connector_request_locale
+                    .connectors_with_locale_header
+                    .contains(connector)
+            })
+            .map(|_| self.locale.clone())
+    }
+    fn get_connector_request_header_order(&self, connector: &str) -> Option<Vec<String>> {
+        connector
+            .parse::<common_enums::connector_enums::Connector>()
+            .ok()
+            .and_then(|connector| {
+                self.conf
+                    .connector_request_header_order
+                    .header_order
+                    .get(&connector)
+                    .cloned()
+            })
+    }
+    fn get_connector_error_message_path(&self, connector: &str) -> Option<String> {
+        connector
+            .parse::<common_enums::connector_enums::Connector>()
+            .ok()
+            .and_then(|connector| {
+                self.conf
+                    .connector_error_message_path
+                    .paths
+                    .get(&connector)
+                    .cloned()
+            })
+    }
+    fn notify_connector_call_outcome(
+        &self,
+        outcome: hyperswitch_interfaces::events::connector_call_outcome::ConnectorCallOutcome,
+    ) {
+        crate::services::connector_call_outcome::send_connector_call_outcome(self, outcome)
+    }
+    fn get_connector_error_severity(
+        &self,
+        connector: &str,
+        category: hyperswitch_interfaces::connector_error_severity::ConnectorErrorCategory,
+    ) -> hyperswitch_interfaces::connector_error_severity::ErrorSeverity {
+        connector
+            .parse::<common_enums::connector_enums::Connector>()
+            .ok()
+            .and_then(|connector| {
+                self.conf
+                    .connector_error_severity
+                    .severity_overrides
+                    .get(&connector)?
+                    .get(&category)
+                    .copied()
+            })
+            .unwrap_or_else(|| category.get_default_severity())
+    }
 }
 #[derive(Clone)]
 pub struct AppState {
@@ -347,6 +410,9 @@ pub struct AppState {
     pub infra_components: Option<serde_json::Value>,
     pub enhancement: Option<HashMap<String, String>>,
     pub superposition_service: Arc<SuperpositionClient>,
+    pub connector_call_outcome_sender:
+        Option<crate::services::connector_call_outcome::ConnectorCallOutcomeSender>,
+    pub connector_smoke_test_reports: crate::core::connector_smoke_test::ConnectorSmokeTestReports,
 }
 impl scheduler::SchedulerAppState for AppState {
     fn get_tenants(&self) -> Vec<id_type::TenantId> {
@@ -514,6 +580,10 @@ impl AppState {
                 .get_superposition_client(service_name)
                 .await
                 .expect("Failed to initialize superposition client");
+            let connector_call_outcome_sender =
+                crate::services::connector_call_outcome::spawn_connector_call_outcome_worker(
+                    &conf.connector_call_outcome_webhook,
+                );
             Self {
                 flow_name: String::from("default"),
                 stores,
@@ -537,6 +607,8 @@ impl AppState {
                 infra_components: infra_component_values,
                 enhancement,
                 superposition_service,
+                connector_call_outcome_sender,
+                connector_smoke_test_reports: Arc::default(),
             }
         })
         .await
@@ -677,6 +749,8 @@ impl AppState {
             infra_components: self.infra_components.clone(),
             enhancement: self.enhancement.clone(),
             superposition_service: self.superposition_service.clone(),
+            connector_call_outcome_sender: self.connector_call_outcome_sender.clone(),
+            connector_smoke_test_reports: Arc::clone(&self.connector_smoke_test_reports),
         })
     }
 
@@ -967,6 +1041,10 @@ impl Payments {
                     web::resource("/{payment_id}/manual-status-update")
                         .route(web::post().to(payments::payments_manual_status_update)),
                 )
+                .service(
+                    web::resource("/{payment_id}/force-close")
+                        .route(web::post().to(payments::payments_force_close)),
+                )
         }
         #[cfg(feature = "oltp")]
         {
@@ -999,6 +1077,10 @@ impl Payments {
                     .route(web::post().to(payments::payments_cancel_post_capture))
                     .route(web::get().to(payments::payments_cancel_post_capture_retrieve))
                 )
+                .service(
+                    web::resource("/{payment_id}/order_cancelled")
+                        .route(web::post().to(payments::payments_order_cancelled)),
+                )
                 .service(
                     web::resource("/{payment_id}/capture").route(web::post().to(payments::payments_capture)),
                 )
@@ -2025,6 +2107,31 @@ impl MerchantAccount {
             .service(
                 web::resource("/kv").route(web::post().to(admin::merchant_account_toggle_all_kv)),
             )
+            .service(
+                web::resource("/{id}/takeout")
+                    .route(web::post().to(admin::merchant_takeout_create)),
+            )
+            .service(
+                web::resource("/{id}/quota")
+                    .route(web::put().to(admin::merchant_quota_update))
+                    .route(web::delete().to(admin::merchant_quota_delete)),
+            )
+            .service(
+                web::resource("/{id}/quota/usage")
+                    .route(web::get().to(admin::merchant_quota_usage)),
+            )
+            .service(
+                web::resource("/{id}/takeout/{job_id}")
+                    .route(web::get().to(admin::merchant_takeout_retrieve)),
+            )
+            .service(
+                web::resource("/{id}/takeout/{job_id}/files/{file_name}")
+                    .route(web::get().to(admin::merchant_takeout_file_download)),
+            )
+            .service(
+                web::resource("/{id}/contact_hashes/rehash")
+                    .route(web::post().to(admin::customer_contact_hashes_rehash)),
+            )
             .service(
                 web::resource("/{id}")
                     .route(web::get().to(admin::retrieve_merchant_account))
@@ -2251,6 +2358,15 @@ impl Configs {
         web::scope("/configs")
             .app_data(web::Data::new(config))
             .service(web::resource("/").route(web::post().to(config_key_create)))
+            .service(
+                web::resource("/unmasked_headers")
+                    .route(web::get().to(unmasked_headers_retrieve))
+                    .route(web::post().to(unmasked_headers_update)),
+            )
+            .service(
+                web::resource("/unmasked_headers/preview")
+                    .route(web::post().to(unmasked_headers_preview)),
+            )
             .service(
                 web::resource("/{key}")
                     .route(web::get().to(config_key_retrieve))
@@ -2266,6 +2382,15 @@ impl Configs {
         web::scope("/v2/configs")
             .app_data(web::Data::new(config))
             .service(web::resource("/").route(web::post().to(config_key_create)))
+            .service(
+                web::resource("/unmasked_headers")
+                    .route(web::get().to(unmasked_headers_retrieve))
+                    .route(web::post().to(unmasked_headers_update)),
+            )
+            .service(
+                web::resource("/unmasked_headers/preview")
+                    .route(web::post().to(unmasked_headers_preview)),
+            )
             .service(
                 web::resource("/{key}")
                     .route(web::get().to(config_key_retrieve))
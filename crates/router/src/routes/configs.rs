@@ -97,3 +97,66 @@ pub async fn config_key_delete(
     )
     .await
 }
+
+#[instrument(skip_all, fields(flow = ?Flow::UnmaskedHeadersRetrieve))]
+pub async fn unmasked_headers_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+) -> impl Responder {
+    let flow = Flow::UnmaskedHeadersRetrieve;
+
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        (),
+        |state, _, _, _| configs::unmasked_headers::retrieve_unmasked_headers(state),
+        &ADMIN_API_AUTH,
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::UnmaskedHeadersUpdate))]
+pub async fn unmasked_headers_update(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<api_types::UnmaskedHeadersUpdate>,
+) -> impl Responder {
+    let flow = Flow::UnmaskedHeadersUpdate;
+    let payload = json_payload.into_inner();
+
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _, payload, req_state| {
+            configs::unmasked_headers::update_unmasked_headers(state, req_state, payload)
+        },
+        &ADMIN_API_AUTH,
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::UnmaskedHeadersPreview))]
+pub async fn unmasked_headers_preview(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<api_types::UnmaskedHeadersPreviewRequest>,
+) -> impl Responder {
+    let flow = Flow::UnmaskedHeadersPreview;
+    let payload = json_payload.into_inner();
+
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _, payload, _| configs::unmasked_headers::preview_unmasked_headers(state, payload),
+        &ADMIN_API_AUTH,
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
@@ -4,10 +4,10 @@ use router_env::{instrument, logger, tracing, Flow};
 
 use super::app;
 use crate::{
-    core::{api_locking, health_check::HealthCheckInterface},
+    core::{api_locking, connector_smoke_test, health_check::HealthCheckInterface},
     errors::{self, RouterResponse},
     routes::metrics,
-    services::{api, authentication as auth},
+    services::{api, authentication as auth, graceful_shutdown},
 };
 /// .
 // #[logger::instrument(skip_all, name = "name1", level = "warn", fields( key1 = "val1" ))]
@@ -27,6 +27,12 @@ pub async fn deep_health_check(
 ) -> impl actix_web::Responder {
     metrics::HEALTH_METRIC.add(1, &[]);
 
+    // Report the server as not ready as soon as it starts shutting down, so that it is taken out
+    // of rotation while the requests in flight are drained
+    if graceful_shutdown::is_shutting_down() {
+        return actix_web::HttpResponse::ServiceUnavailable().body("server is shutting down");
+    }
+
     let flow = Flow::DeepHealthCheck;
 
     Box::pin(api::server_wrap(
@@ -179,6 +185,7 @@ async fn deep_health_check_func(
         #[cfg(feature = "dynamic_routing")]
         decision_engine: decision_engine_health_check.into(),
         unified_connector_service: unified_connector_service_status.into(),
+        connector_smoke_test: connector_smoke_test::get_latest_report(&state),
     };
 
     Ok(api::ApplicationResponse::Json(response))
@@ -64,7 +64,14 @@ impl From<Flow> for ApiIdentifier {
             | Flow::MerchantsAccountDelete
             | Flow::MerchantTransferKey
             | Flow::MerchantAccountList
-            | Flow::EnablePlatformAccount => Self::MerchantAccount,
+            | Flow::EnablePlatformAccount
+            | Flow::MerchantTakeoutCreate
+            | Flow::MerchantTakeoutRetrieve
+            | Flow::MerchantTakeoutFileDownload
+            | Flow::MerchantQuotaUpdate
+            | Flow::MerchantQuotaDelete
+            | Flow::MerchantQuotaUsage
+            | Flow::CustomerContactHashesRehash => Self::MerchantAccount,
             Flow::OrganizationCreate
             | Flow::OrganizationRetrieve
             | Flow::OrganizationUpdate
@@ -117,6 +124,9 @@ impl From<Flow> for ApiIdentifier {
             | Flow::ConfigKeyFetch
             | Flow::ConfigKeyUpdate
             | Flow::ConfigKeyDelete
+            | Flow::UnmaskedHeadersRetrieve
+            | Flow::UnmaskedHeadersUpdate
+            | Flow::UnmaskedHeadersPreview
             | Flow::CreateConfigKey => Self::Configs,
             Flow::CustomersCreate
             | Flow::CustomersRetrieve
@@ -159,6 +169,7 @@ impl From<Flow> for ApiIdentifier {
             | Flow::PaymentsCapture
             | Flow::PaymentsCancel
             | Flow::PaymentsCancelPostCapture
+            | Flow::PaymentsOrderCancelled
             | Flow::PaymentsApprove
             | Flow::PaymentsReject
             | Flow::PaymentsSessionToken
@@ -175,6 +186,7 @@ impl From<Flow> for ApiIdentifier {
             | Flow::PaymentsCompleteAuthorize
             | Flow::PaymentsManualUpdate
             | Flow::PaymentsManualStatusUpdate
+            | Flow::PaymentsForceClose
             | Flow::SessionUpdateTaxCalculation
             | Flow::PaymentsConfirmIntent
             | Flow::PaymentsCreateIntent
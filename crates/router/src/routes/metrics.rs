@@ -1,15 +1,20 @@
 pub mod bg_metrics_collector;
 pub mod request;
 
-use router_env::{counter_metric, global_meter, histogram_metric_f64};
+use router_env::{counter_metric, gauge_metric, global_meter, histogram_metric_f64};
 
 global_meter!(GLOBAL_METER, "ROUTER_API");
 
 counter_metric!(HEALTH_METRIC, GLOBAL_METER); // No. of health API hits
+gauge_metric!(CONNECTOR_SMOKE_TEST_FAILURE, GLOBAL_METER); // 1 if the last smoke test of a connector base URL failed
 counter_metric!(KV_MISS, GLOBAL_METER); // No. of KV misses
+// No. of connector call outcomes dropped as too many were waiting to be sent to the webhook
+counter_metric!(CONNECTOR_CALL_OUTCOME_DROPPED, GLOBAL_METER);
 
 // API Level Metrics
 counter_metric!(REQUESTS_RECEIVED, GLOBAL_METER);
+// No. of requests and connector calls which were still in flight when the server was shut down
+counter_metric!(IN_FLIGHT_AT_SHUTDOWN, GLOBAL_METER);
 histogram_metric_f64!(REQUEST_TIME, GLOBAL_METER);
 
 // Operation Level Metrics
@@ -25,6 +30,8 @@ histogram_metric_f64!(PAYMENT_LIST_OPENSEARCH_LATENCY, GLOBAL_METER);
 counter_metric!(REFUND_COUNT, GLOBAL_METER);
 counter_metric!(SUCCESSFUL_REFUND, GLOBAL_METER);
 
+counter_metric!(ILLEGAL_STATE_TRANSITION_PREVENTED, GLOBAL_METER);
+
 counter_metric!(PAYMENT_CANCEL_COUNT, GLOBAL_METER);
 counter_metric!(SUCCESSFUL_CANCEL, GLOBAL_METER);
 
@@ -102,6 +109,9 @@ counter_metric!(APPLE_PAY_SIMPLIFIED_FLOW_SUCCESSFUL_PAYMENT, GLOBAL_METER);
 counter_metric!(APPLE_PAY_MANUAL_FLOW_FAILED_PAYMENT, GLOBAL_METER);
 counter_metric!(APPLE_PAY_SIMPLIFIED_FLOW_FAILED_PAYMENT, GLOBAL_METER);
 
+// Wallet tokens which had expired before being sent to the connector
+counter_metric!(WALLET_TOKEN_EXPIRED, GLOBAL_METER);
+
 // Metrics for Payment Auto Retries
 counter_metric!(AUTO_RETRY_ELIGIBLE_REQUEST_COUNT, GLOBAL_METER);
 counter_metric!(AUTO_RETRY_GSM_MISS_COUNT, GLOBAL_METER);
@@ -109,6 +119,7 @@ counter_metric!(AUTO_RETRY_GSM_FETCH_FAILURE_COUNT, GLOBAL_METER);
 counter_metric!(AUTO_RETRY_GSM_MATCH_COUNT, GLOBAL_METER);
 counter_metric!(AUTO_RETRY_EXHAUSTED_COUNT, GLOBAL_METER);
 counter_metric!(AUTO_RETRY_PAYMENT_COUNT, GLOBAL_METER);
+counter_metric!(CONFIRM_TIME_BUDGET_EXHAUSTED_COUNT, GLOBAL_METER);
 
 // Metrics for Payout Auto Retries
 counter_metric!(AUTO_PAYOUT_RETRY_ELIGIBLE_REQUEST_COUNT, GLOBAL_METER);
@@ -628,7 +628,11 @@ pub async fn payments_retrieve(
         ..Default::default()
     };
     let header_payload = match HeaderPayload::foreign_try_from(req.headers()) {
-        Ok(headers) => headers,
+        // The sync races with webhooks and redirect completions of the payment
+        Ok(headers) => HeaderPayload {
+            status_update_source: Some(payments::state_machine::StatusUpdateSource::Sync),
+            ..headers
+        },
         Err(err) => {
             return api::log_and_return_error_response(err);
         }
@@ -765,7 +769,9 @@ pub async fn payments_retrieve_with_gateway_creds(
                 payments::CallConnectorAction::Trigger,
                 None,
                 None,
-                HeaderPayload::default(),
+                HeaderPayload::with_status_update_source(
+                    payments::state_machine::StatusUpdateSource::Sync,
+                ),
                 None,
             )
         },
@@ -1436,6 +1442,7 @@ pub async fn payments_redirect_response(
         param: Some(param_string.to_string()),
         connector: Some(connector),
         creds_identifier: None,
+        redirect_completion_details: Some(helpers::get_redirect_completion_details(&req)),
     };
     let locking_action = payload.get_locking_input(flow.clone());
     Box::pin(api::server_wrap(
@@ -1483,6 +1490,7 @@ pub async fn payments_redirect_response_with_creds_identifier(
         param: Some(param_string.to_string()),
         connector: Some(connector),
         creds_identifier: Some(creds_identifier),
+        redirect_completion_details: Some(helpers::get_redirect_completion_details(&req)),
     };
     let flow = Flow::PaymentsRedirect;
     let locking_action = payload.get_locking_input(flow.clone());
@@ -1532,6 +1540,7 @@ pub async fn payments_complete_authorize_redirect(
         force_sync: false,
         connector: Some(connector),
         creds_identifier: None,
+        redirect_completion_details: Some(helpers::get_redirect_completion_details(&req)),
     };
     let locking_action = payload.get_locking_input(flow.clone());
     Box::pin(api::server_wrap(
@@ -1581,6 +1590,7 @@ pub async fn payments_complete_authorize_redirect_with_creds_identifier(
         force_sync: false,
         connector: Some(connector),
         creds_identifier: Some(creds_identifier),
+        redirect_completion_details: Some(helpers::get_redirect_completion_details(&req)),
     };
     let locking_action = payload.get_locking_input(flow.clone());
     Box::pin(api::server_wrap(
@@ -1966,6 +1976,55 @@ pub async fn payments_cancel_post_capture(
     .await
 }
 
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentsOrderCancelled, payment_id))]
+pub async fn payments_order_cancelled(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    json_payload: web::Json<payment_types::PaymentsOrderCancelledRequest>,
+    path: web::Path<common_utils::id_type::PaymentId>,
+) -> impl Responder {
+    let flow = Flow::PaymentsOrderCancelled;
+    let mut payload = json_payload.into_inner();
+    let payment_id = path.into_inner();
+
+    tracing::Span::current().record("payment_id", payment_id.get_string_repr());
+
+    payload.payment_id = payment_id;
+
+    let header_payload = match HeaderPayload::foreign_try_from(req.headers()) {
+        Ok(headers) => headers,
+        Err(err) => {
+            return api::log_and_return_error_response(err);
+        }
+    };
+
+    // The payment is locked while it is being voided, with a conflict being reported if a capture
+    // is in progress for the payment
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, req, req_state| {
+            payments::order_cancellation::payments_order_cancelled(
+                state,
+                req_state,
+                auth.platform,
+                auth.profile.map(|profile| profile.get_id().clone()),
+                req,
+                header_payload.clone(),
+            )
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth {
+            allow_connected_scope_operation: true,
+            allow_platform_self_operation: false,
+        }),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 #[cfg(feature = "v1")]
 #[instrument(skip_all, fields(flow = ?Flow::PaymentsCancelPostCaptureSync, payment_id))]
 pub async fn payments_cancel_post_capture_retrieve(
@@ -2165,6 +2224,11 @@ pub async fn payments_list_by_filter(
 ) -> impl Responder {
     let flow = Flow::PaymentsList;
     let payload = payload.into_inner();
+    let permission = if payload.has_customer_contact_filters() {
+        Permission::MerchantCustomerSearchRead
+    } else {
+        Permission::MerchantPaymentRead
+    };
     Box::pin(api::server_wrap(
         flow,
         state,
@@ -2174,7 +2238,7 @@ pub async fn payments_list_by_filter(
             payments::apply_filters_on_payments(state, auth.platform, None, req)
         },
         &auth::JWTAuth {
-            permission: Permission::MerchantPaymentRead,
+            permission,
             allow_connected: true,
             allow_platform: false,
         },
@@ -2192,6 +2256,11 @@ pub async fn profile_payments_list_by_filter(
 ) -> impl Responder {
     let flow = Flow::PaymentsList;
     let payload = payload.into_inner();
+    let permission = if payload.has_customer_contact_filters() {
+        Permission::ProfileCustomerSearchRead
+    } else {
+        Permission::ProfilePaymentRead
+    };
     Box::pin(api::server_wrap(
         flow,
         state,
@@ -2206,7 +2275,7 @@ pub async fn profile_payments_list_by_filter(
             )
         },
         &auth::JWTAuth {
-            permission: Permission::ProfilePaymentRead,
+            permission,
             allow_connected: true,
             allow_platform: false,
         },
@@ -2730,7 +2799,7 @@ where
                         }
                     }
 
-                    payment_types::PaymentsResponse::generate_response(
+                    let response = payment_types::PaymentsResponse::generate_response(
                         payment_data,
                         auth_flow,
                         &state.base_url,
@@ -2740,7 +2809,17 @@ where
                         external_latency,
                         header_payload.x_hs_latency,
                         &platform,
-                    )
+                    )?;
+
+                    Box::pin(payments::avs_decision::apply_avs_decision_rules(
+                        state,
+                        req_state,
+                        platform,
+                        profile_id,
+                        header_payload,
+                        response,
+                    ))
+                    .await
                 }
             }
             api_models::enums::PaymentType::SetupMandate => {
@@ -2968,6 +3047,7 @@ pub async fn post_3ds_payments_authorize(
         param: Some(param_string.to_string()),
         connector: Some(connector),
         creds_identifier: None,
+        redirect_completion_details: Some(helpers::get_redirect_completion_details(&req)),
     };
 
     let locking_action = payload.get_locking_input(flow.clone());
@@ -3021,6 +3101,36 @@ pub async fn payments_manual_update(
     .await
 }
 
+#[cfg(all(feature = "olap", feature = "v1"))]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentsForceClose, payment_id))]
+pub async fn payments_force_close(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    json_payload: web::Json<payment_types::PaymentsForceCloseRequest>,
+    path: web::Path<common_utils::id_type::PaymentId>,
+) -> impl Responder {
+    let flow = Flow::PaymentsForceClose;
+    let mut payload = json_payload.into_inner();
+    let payment_id = path.into_inner();
+
+    tracing::Span::current().record("payment_id", payment_id.get_string_repr());
+
+    payload.payment_id = payment_id;
+
+    let locking_action = payload.get_locking_input(flow.clone());
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _auth, req, req_state| payments::payments_force_close(state, req_state, req),
+        &auth::AdminApiAuthWithMerchantIdFromHeader,
+        locking_action,
+    ))
+    .await
+}
+
 #[cfg(all(feature = "olap", feature = "v1"))]
 /// Manually update payment status from Review to Succeeded or Failed (Dashboard API with JWT auth)
 #[instrument(skip_all, fields(flow = ?Flow::PaymentsManualStatusUpdate, payment_id))]
@@ -3580,6 +3690,23 @@ impl GetLockingInput for payment_types::PaymentsExternalAuthenticationRequest {
     }
 }
 
+#[cfg(feature = "v1")]
+impl GetLockingInput for payment_types::PaymentsForceCloseRequest {
+    fn get_locking_input<F>(&self, flow: F) -> api_locking::LockAction
+    where
+        F: types::FlowMetric,
+        lock_utils::ApiIdentifier: From<F>,
+    {
+        api_locking::LockAction::Hold {
+            input: api_locking::LockingInput {
+                unique_locking_key: self.payment_id.get_string_repr().to_owned(),
+                api_identifier: lock_utils::ApiIdentifier::from(flow),
+                override_lock_retries: None,
+            },
+        }
+    }
+}
+
 #[cfg(feature = "v1")]
 impl GetLockingInput for payment_types::PaymentsManualUpdateRequest {
     fn get_locking_input<F>(&self, flow: F) -> api_locking::LockAction
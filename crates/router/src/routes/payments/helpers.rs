@@ -1,5 +1,7 @@
 use error_stack::ResultExt;
 
+#[cfg(feature = "v1")]
+use crate::core::payments;
 use crate::{
     core::errors::{self, RouterResult},
     logger,
@@ -7,6 +9,29 @@ use crate::{
     utils::{Encode, ValueExt},
 };
 
+/// Capture the customer's IP address and user agent when the customer is redirected back from the
+/// connector
+#[cfg(feature = "v1")]
+pub fn get_redirect_completion_details(
+    req: &actix_web::HttpRequest,
+) -> payments::RedirectCompletionDetails {
+    let ip_address = req
+        .connection_info()
+        .realip_remote_addr()
+        .map(|ip| hyperswitch_masking::Secret::new(ip.to_owned()));
+    let user_agent = req
+        .headers()
+        .get(actix_web::http::header::USER_AGENT)
+        .and_then(|user_agent| user_agent.to_str().ok())
+        .map(ToOwned::to_owned);
+
+    payments::RedirectCompletionDetails {
+        completed_at: common_utils::date_time::now(),
+        ip_address,
+        user_agent,
+    }
+}
+
 #[cfg(feature = "v1")]
 pub fn populate_browser_info(
     req: &actix_web::HttpRequest,
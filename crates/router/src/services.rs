@@ -1,10 +1,12 @@
 pub mod api;
 pub mod authentication;
 pub mod authorization;
+pub mod connector_call_outcome;
 pub mod connector_integration_interface;
 #[cfg(feature = "email")]
 pub mod email;
 pub mod encryption;
+pub mod graceful_shutdown;
 #[cfg(feature = "olap")]
 pub mod jwt;
 pub mod kafka;
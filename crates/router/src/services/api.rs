@@ -60,6 +60,7 @@ use crate::{
     configs::Settings,
     core::{
         api_locking,
+        configs::unmasked_headers,
         errors::{self, CustomResult},
     },
     events::api_logs::{ApiEvent, ApiEventMetric, ApiEventsType},
@@ -412,22 +413,16 @@ where
     let request_method = request.method().as_str();
     let url_path = request.path();
 
-    let unmasked_incoming_header_keys = state.conf().unmasked_headers.keys;
+    let unmasked_incoming_header_keys = unmasked_headers::get_unmasked_header_keys(
+        state.global_store.as_ref(),
+        &state.conf.unmasked_headers,
+    )
+    .await;
 
     let incoming_request_header = request.headers();
 
-    let incoming_header_to_log: HashMap<String, HeaderValue> =
-        incoming_request_header
-            .iter()
-            .fold(HashMap::new(), |mut acc, (key, value)| {
-                let key = key.to_string();
-                if unmasked_incoming_header_keys.contains(&key.as_str().to_lowercase()) {
-                    acc.insert(key.clone(), value.clone());
-                } else {
-                    acc.insert(key.clone(), HeaderValue::from_static("**MASKED**"));
-                }
-                acc
-            });
+    let incoming_header_to_log =
+        unmasked_headers::mask_headers(incoming_request_header, &unmasked_incoming_header_keys);
 
     tracing::Span::current().record("request_method", request_method);
     tracing::Span::current().record("request_url_path", url_path);
@@ -825,6 +820,7 @@ impl Authenticate for api_models::payments::PaymentsCancelRequest {
     }
 }
 impl Authenticate for api_models::payments::PaymentsCancelPostCaptureRequest {}
+impl Authenticate for api_models::payments::PaymentsOrderCancelledRequest {}
 impl Authenticate for common_utils::id_type::PaymentId {}
 impl Authenticate for api_models::payments::PaymentsCaptureRequest {
     #[cfg(feature = "v2")]
@@ -61,6 +61,7 @@ fn get_group_description(group: PermissionGroup) -> Option<&'static str> {
         PermissionGroup::ReconTransactionsManage => Some("View and edit recon staging entries and transactions"),
         PermissionGroup::ReconRulesView => Some("View reconciliation rules"),
         PermissionGroup::ReconRulesManage => Some("Create and edit reconciliation rules"),
+        PermissionGroup::CustomerSearchView => Some("Search Payments by customer email or phone number"),
     }
 }
 
@@ -82,5 +83,6 @@ pub fn get_parent_group_description(group: ParentGroup) -> Option<&'static str>
         ParentGroup::ReconExceptions => Some("Recon exception investigation and resolution"),
         ParentGroup::ReconTransactions => Some("Recon staging entries and transactions"),
         ParentGroup::ReconRules => Some("Reconciliation rules"),
+        ParentGroup::CustomerSearch => Some("Search payments by customer email or phone number"),
     }
 }
@@ -30,7 +30,8 @@ impl PermissionGroupExt for PermissionGroup {
             | Self::ReconSourcesView
             | Self::ReconTransactionsView
             | Self::ReconExceptionsView
-            | Self::ReconRulesView => PermissionScope::Read,
+            | Self::ReconRulesView
+            | Self::CustomerSearchView => PermissionScope::Read,
 
             Self::OperationsManage
             | Self::ConnectorsManage
@@ -69,6 +70,7 @@ impl PermissionGroupExt for PermissionGroup {
                 ParentGroup::ReconTransactions
             }
             Self::ReconRulesView | Self::ReconRulesManage => ParentGroup::ReconRules,
+            Self::CustomerSearchView => ParentGroup::CustomerSearch,
         }
     }
 
@@ -157,6 +159,8 @@ impl PermissionGroupExt for PermissionGroup {
                 Self::ReconRulesView,
                 Self::ReconTransactionsView,
             ],
+
+            Self::CustomerSearchView => vec![Self::CustomerSearchView, Self::OperationsView],
         }
     }
 
@@ -183,7 +187,8 @@ impl PermissionGroupExt for PermissionGroup {
             | Self::ThemeView
             | Self::ThemeManage
             | Self::ConfigurationsView
-            | Self::ConfigurationsManage => RoleProductCategory::Orchestration,
+            | Self::ConfigurationsManage
+            | Self::CustomerSearchView => RoleProductCategory::Orchestration,
 
             // Recon-only groups.
             Self::ReconSourcesView
@@ -225,6 +230,7 @@ impl ParentGroupExt for ParentGroup {
             Self::ReconExceptions => RECON_EXCEPTIONS.to_vec(),
             Self::ReconTransactions => RECON_TRANSACTIONS.to_vec(),
             Self::ReconRules => RECON_RULES.to_vec(),
+            Self::CustomerSearch => CUSTOMER_SEARCH.to_vec(),
         }
     }
 
@@ -316,3 +322,5 @@ pub static RECON_TRANSACTIONS: [Resource; 3] = [
 ];
 
 pub static RECON_RULES: [Resource; 2] = [Resource::ReconRule, Resource::Account];
+
+pub static CUSTOMER_SEARCH: [Resource; 1] = [Resource::CustomerSearch];
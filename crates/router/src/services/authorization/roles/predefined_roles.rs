@@ -32,6 +32,7 @@ pub static PREDEFINED_ROLES: LazyLock<HashMap<&'static str, RoleInfo>> = LazyLoc
                 PermissionGroup::ReconTransactionsManage,
                 PermissionGroup::ReconRulesView,
                 PermissionGroup::ReconRulesManage,
+                PermissionGroup::CustomerSearchView,
             ],
             role_id: common_utils::consts::ROLE_ID_INTERNAL_ADMIN.to_string(),
             role_name: "internal_admin".to_string(),
@@ -58,6 +59,7 @@ pub static PREDEFINED_ROLES: LazyLock<HashMap<&'static str, RoleInfo>> = LazyLoc
                 PermissionGroup::ReconExceptionsView,
                 PermissionGroup::ReconTransactionsView,
                 PermissionGroup::ReconRulesView,
+                PermissionGroup::CustomerSearchView,
             ],
             role_id: common_utils::consts::ROLE_ID_INTERNAL_VIEW_ONLY_USER.to_string(),
             role_name: "internal_view_only".to_string(),
@@ -94,6 +96,7 @@ pub static PREDEFINED_ROLES: LazyLock<HashMap<&'static str, RoleInfo>> = LazyLoc
                 PermissionGroup::ReconTransactionsManage,
                 PermissionGroup::ReconRulesView,
                 PermissionGroup::ReconRulesManage,
+                PermissionGroup::CustomerSearchView,
             ],
             role_id: common_utils::consts::ROLE_ID_TENANT_ADMIN.to_string(),
             role_name: "tenant_admin".to_string(),
@@ -136,6 +139,7 @@ pub static PREDEFINED_ROLES: LazyLock<HashMap<&'static str, RoleInfo>> = LazyLoc
                 PermissionGroup::ReconTransactionsManage,
                 PermissionGroup::ReconRulesView,
                 PermissionGroup::ReconRulesManage,
+                PermissionGroup::CustomerSearchView,
             ],
             role_id: common_utils::consts::ROLE_ID_ORGANIZATION_ADMIN.to_string(),
             role_name: "organization_admin".to_string(),
@@ -169,6 +173,7 @@ pub static PREDEFINED_ROLES: LazyLock<HashMap<&'static str, RoleInfo>> = LazyLoc
                 PermissionGroup::ConfigurationsView,
                 PermissionGroup::ConfigurationsManage,
                 PermissionGroup::CloneConnectorManage,
+                PermissionGroup::CustomerSearchView,
             ],
             role_id: consts::user_role::ROLE_ID_MERCHANT_ADMIN.to_string(),
             role_name: "merchant_admin".to_string(),
@@ -277,6 +282,7 @@ pub static PREDEFINED_ROLES: LazyLock<HashMap<&'static str, RoleInfo>> = LazyLoc
                 PermissionGroup::AnalyticsView,
                 PermissionGroup::UsersView,
                 PermissionGroup::AccountView,
+                PermissionGroup::CustomerSearchView,
             ],
             role_id: consts::user_role::ROLE_ID_MERCHANT_CUSTOMER_SUPPORT.to_string(),
             role_name: "customer_support".to_string(),
@@ -308,6 +314,7 @@ pub static PREDEFINED_ROLES: LazyLock<HashMap<&'static str, RoleInfo>> = LazyLoc
                 PermissionGroup::AccountManage,
                 PermissionGroup::ConfigurationsView,
                 PermissionGroup::ConfigurationsManage,
+                PermissionGroup::CustomerSearchView,
             ],
             role_id: consts::user_role::ROLE_ID_PROFILE_ADMIN.to_string(),
             role_name: "profile_admin".to_string(),
@@ -416,6 +423,7 @@ pub static PREDEFINED_ROLES: LazyLock<HashMap<&'static str, RoleInfo>> = LazyLoc
                 PermissionGroup::AnalyticsView,
                 PermissionGroup::UsersView,
                 PermissionGroup::AccountView,
+                PermissionGroup::CustomerSearchView,
             ],
             role_id: consts::user_role::ROLE_ID_PROFILE_CUSTOMER_SUPPORT.to_string(),
             role_name: "profile_customer_support".to_string(),
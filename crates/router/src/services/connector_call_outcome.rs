@@ -0,0 +1,239 @@
+//! Sends the outcome of each connector call to an internal webhook, for teams piping them into
+//! external observability pipelines.
+
+use common_utils::request::RequestContent;
+use futures::StreamExt;
+use hyperswitch_interfaces::events::connector_call_outcome::ConnectorCallOutcome;
+use router_env::{
+    logger,
+    tracing::{self, Instrument},
+};
+use tokio::sync::mpsc;
+
+use crate::{
+    configs::settings::ConnectorCallOutcomeWebhook,
+    routes::{metrics, SessionState},
+    services::{self, Request},
+};
+
+const CONNECTOR_CALL_OUTCOME_WEBHOOK_TIMEOUT_SECS: u64 = 5;
+
+/// Maximum number of outcomes waiting to be delivered, outcomes are dropped once it is reached
+const CONNECTOR_CALL_OUTCOME_QUEUE_CAPACITY: usize = 1024;
+
+/// Maximum number of outcomes being delivered to the webhook at the same time
+const MAX_CONCURRENT_CONNECTOR_CALL_OUTCOME_DELIVERIES: usize = 16;
+
+/// An outcome waiting to be delivered to the webhook
+pub struct ConnectorCallOutcomeDelivery {
+    state: SessionState,
+    request: Request,
+    span: tracing::Span,
+}
+
+/// Sender of the outcomes to the worker delivering them to the webhook
+pub type ConnectorCallOutcomeSender = mpsc::Sender<ConnectorCallOutcomeDelivery>;
+
+/// Build the request to the webhook, `None` if the webhook is not enabled
+fn build_connector_call_outcome_request(
+    config: &ConnectorCallOutcomeWebhook,
+    outcome: ConnectorCallOutcome,
+) -> Option<Request> {
+    config.enabled.then(|| {
+        services::RequestBuilder::new()
+            .method(services::Method::Post)
+            .url(&config.url)
+            .attach_default_headers()
+            .set_body(RequestContent::Json(Box::new(outcome)))
+            .build()
+    })
+}
+
+/// Spawn the worker delivering the outcomes to the webhook, `None` if the webhook is not enabled.
+/// The worker stops once every sender is dropped.
+pub fn spawn_connector_call_outcome_worker(
+    config: &ConnectorCallOutcomeWebhook,
+) -> Option<ConnectorCallOutcomeSender> {
+    config.enabled.then(|| {
+        let (sender, receiver) = mpsc::channel(CONNECTOR_CALL_OUTCOME_QUEUE_CAPACITY);
+        tokio::spawn(deliver_connector_call_outcomes(receiver));
+        sender
+    })
+}
+
+async fn deliver_connector_call_outcomes(receiver: mpsc::Receiver<ConnectorCallOutcomeDelivery>) {
+    futures::stream::unfold(receiver, |mut receiver| async move {
+        receiver.recv().await.map(|delivery| (delivery, receiver))
+    })
+    .for_each_concurrent(
+        MAX_CONCURRENT_CONNECTOR_CALL_OUTCOME_DELIVERIES,
+        |ConnectorCallOutcomeDelivery {
+             state,
+             request,
+             span,
+         }| {
+            async move {
+                if let Err(error) = state
+                    .api_client
+                    .send_request(
+                        &state,
+                        request,
+                        Some(CONNECTOR_CALL_OUTCOME_WEBHOOK_TIMEOUT_SECS),
+                        false,
+                    )
+                    .await
+                {
+                    logger::warn!(?error, "Failed to send the connector call outcome webhook");
+                }
+            }
+            .instrument(span)
+        },
+    )
+    .await
+}
+
+/// Queue the outcome of a connector call to be sent to the webhook in the background. Outcomes
+/// are dropped if too many of them are waiting to be delivered, and failures to deliver them are
+/// only logged, so that the webhook never affects the connector call.
+pub fn send_connector_call_outcome(state: &SessionState, outcome: ConnectorCallOutcome) {
+    let Some(sender) = &state.connector_call_outcome_sender else {
+        return;
+    };
+    let Some(request) =
+        build_connector_call_outcome_request(&state.conf.connector_call_outcome_webhook, outcome)
+    else {
+        return;
+    };
+
+    if let Err(error) = sender.try_send(ConnectorCallOutcomeDelivery {
+        state: state.clone(),
+        request,
+        span: tracing::Span::current(),
+    }) {
+        metrics::CONNECTOR_CALL_OUTCOME_DROPPED.add(1, &[]);
+        logger::debug!(%error, "Dropped the connector call outcome");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use std::{sync::Arc, time::Duration};
+
+    use hyperswitch_interfaces::events::connector_call_outcome::ConnectorCallStatus;
+    use wiremock::{matchers, Mock, MockServer, ResponseTemplate};
+
+    use super::*;
+    use crate::{configs::settings::Settings, db::StorageImpl, routes};
+
+    fn get_outcome() -> ConnectorCallOutcome {
+        ConnectorCallOutcome {
+            connector: "stripe".to_string(),
+            flow: "Authorize".to_string(),
+            status: ConnectorCallStatus::ErrorResponse,
+            http_status_code: Some(402),
+            latency_ms: 230,
+            request_id: Some("req_123".to_string()),
+        }
+    }
+
+    #[test]
+    fn test_outcome_is_posted_to_webhook_when_enabled() {
+        let config = ConnectorCallOutcomeWebhook {
+            enabled: true,
+            url: "https://observability.example.com/connector_calls".to_string(),
+        };
+
+        let request = build_connector_call_outcome_request(&config, get_outcome()).unwrap();
+
+        assert_eq!(request.method, services::Method::Post);
+        assert_eq!(
+            request.url,
+            "https://observability.example.com/connector_calls"
+        );
+        let body = match request.body {
+            Some(RequestContent::Json(body)) => body.masked_serialize().ok(),
+            _ => None,
+        };
+        assert_eq!(
+            body.unwrap(),
+            serde_json::json!({
+                "connector": "stripe",
+                "flow": "Authorize",
+                "status": "error_response",
+                "http_status_code": 402,
+                "latency_ms": 230,
+                "request_id": "req_123",
+            })
+        );
+    }
+
+    #[test]
+    fn test_outcome_is_not_sent_when_disabled() {
+        let config = ConnectorCallOutcomeWebhook {
+            enabled: false,
+            url: "https://observability.example.com/connector_calls".to_string(),
+        };
+
+        assert!(build_connector_call_outcome_request(&config, get_outcome()).is_none());
+    }
+
+    #[tokio::test]
+    async fn test_outcome_is_delivered_to_webhook_by_worker() {
+        let webhook_server = MockServer::start().await;
+        Mock::given(matchers::method("POST"))
+            .and(matchers::path("/connector_calls"))
+            .and(matchers::body_json(serde_json::json!({
+                "connector": "stripe",
+                "flow": "Authorize",
+                "status": "error_response",
+                "http_status_code": 402,
+                "latency_ms": 230,
+                "request_id": "req_123",
+            })))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(1)
+            .mount(&webhook_server)
+            .await;
+
+        let mut conf = Settings::new().expect("invalid settings");
+        conf.connector_call_outcome_webhook = ConnectorCallOutcomeWebhook {
+            enabled: true,
+            url: format!("{}/connector_calls", webhook_server.uri()),
+        };
+        let tx: tokio::sync::oneshot::Sender<()> = tokio::sync::oneshot::channel().0;
+        let api_client = services::ProxyClient::new(&conf.proxy).unwrap();
+        let app_state = Box::pin(routes::AppState::with_storage(
+            conf,
+            StorageImpl::Mock,
+            tx,
+            Box::new(api_client),
+            env!("CARGO_PKG_NAME"),
+        ))
+        .await;
+        let state = Arc::new(app_state)
+            .get_session_state(
+                &common_utils::id_type::TenantId::try_from_string("public".to_string()).unwrap(),
+                None,
+                || {},
+            )
+            .unwrap();
+
+        send_connector_call_outcome(&state, get_outcome());
+
+        tokio::time::timeout(Duration::from_secs(5), async {
+            while webhook_server
+                .received_requests()
+                .await
+                .unwrap_or_default()
+                .is_empty()
+            {
+                tokio::time::sleep(Duration::from_millis(10)).await;
+            }
+        })
+        .await
+        .expect("the outcome was not delivered to the webhook");
+        webhook_server.verify().await;
+    }
+}
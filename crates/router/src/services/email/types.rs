@@ -92,6 +92,11 @@ pub enum EmailBody {
         background_color: String,
         foreground_color: String,
     },
+    RecurringPaymentRetry {
+        payment_id: String,
+        retry_message: String,
+        merchant_name: String,
+    },
 }
 
 pub mod html {
@@ -275,6 +280,16 @@ Email         : {user_email}
                     foreground_color = foreground_color
                 )
             }
+            EmailBody::RecurringPaymentRetry {
+                payment_id,
+                retry_message,
+                merchant_name,
+            } => format!(
+                include_str!("assets/recurring_payment_retry.html"),
+                payment_id = payment_id,
+                retry_message = retry_message,
+                merchant_name = merchant_name,
+            ),
         }
     }
 }
@@ -707,6 +722,44 @@ impl EmailData for ApiKeyExpiryReminder {
     }
 }
 
+/// Dunning email sent to the customer when a recurring payment fails
+pub struct RecurringPaymentRetryEmail {
+    pub recipient_email: pii::Email,
+    pub subject: &'static str,
+    pub payment_id: String,
+    pub merchant_name: String,
+    /// Time at which the payment will be retried, absent once the recovery is exhausted
+    pub next_retry_at: Option<time::PrimitiveDateTime>,
+}
+
+#[async_trait::async_trait]
+impl EmailData for RecurringPaymentRetryEmail {
+    async fn get_email_data(&self, _base_url: &str) -> CustomResult<EmailContents, EmailError> {
+        let retry_message = match self.next_retry_at {
+            Some(next_retry_at) => format!(
+                "We will retry the payment on {}, please make sure that your payment method has \
+                 sufficient funds.",
+                next_retry_at.date()
+            ),
+            None => "We will not retry the payment again, please update your payment method to \
+                     continue your subscription."
+                .to_string(),
+        };
+
+        let body = html::get_html_body(EmailBody::RecurringPaymentRetry {
+            payment_id: self.payment_id.clone(),
+            retry_message,
+            merchant_name: self.merchant_name.clone(),
+        });
+
+        Ok(EmailContents {
+            subject: self.subject.to_string(),
+            body: external_services::email::IntermediateString::new(body),
+            recipient: self.recipient_email.clone(),
+        })
+    }
+}
+
 pub struct WelcomeToCommunity {
     pub recipient_email: domain::UserEmail,
 }
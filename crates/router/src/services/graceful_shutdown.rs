@@ -0,0 +1,200 @@
+//! Graceful shutdown of the application server.
+//!
+//! On receiving `SIGTERM` or `SIGINT`, the server is marked as not ready so that load balancers
+//! stop routing requests to it, and stops accepting new connections. The requests, connector
+//! calls and background tasks (such as outgoing webhook deliveries) in flight are given the drain
+//! window to complete, after which the remaining ones are recorded and the events pending to be
+//! emitted are flushed.
+
+use std::{
+    future::Future,
+    sync::atomic::{AtomicBool, AtomicUsize, Ordering},
+    time::Duration,
+};
+
+use actix_web::dev::ServerHandle;
+use hyperswitch_interfaces::api_client::get_in_flight_connector_calls;
+use router_env::{logger, tracing::Instrument};
+use tokio::sync::mpsc;
+
+use crate::{events::EventsHandler, routes::metrics};
+
+static IS_SHUTTING_DOWN: AtomicBool = AtomicBool::new(false);
+static IN_FLIGHT_REQUESTS: AtomicUsize = AtomicUsize::new(0);
+static IN_FLIGHT_BACKGROUND_TASKS: AtomicUsize = AtomicUsize::new(0);
+
+const DRAIN_POLL_INTERVAL: Duration = Duration::from_millis(100);
+const EVENTS_FLUSH_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Whether the server is shutting down, in which case it is not ready to serve requests
+pub fn is_shutting_down() -> bool {
+    IS_SHUTTING_DOWN.load(Ordering::Acquire)
+}
+
+/// Tracks a request or a background task as in flight for as long as it is held
+pub struct InFlightGuard {
+    in_flight: &'static AtomicUsize,
+}
+
+impl InFlightGuard {
+    fn track(in_flight: &'static AtomicUsize) -> Self {
+        in_flight.fetch_add(1, Ordering::AcqRel);
+        Self { in_flight }
+    }
+
+    pub fn track_request() -> Self {
+        Self::track(&IN_FLIGHT_REQUESTS)
+    }
+}
+
+impl Drop for InFlightGuard {
+    fn drop(&mut self) {
+        self.in_flight.fetch_sub(1, Ordering::AcqRel);
+    }
+}
+
+/// Spawn a task in the background, which is waited for when the server shuts down
+pub fn spawn_background_task<F>(task: F) -> tokio::task::JoinHandle<F::Output>
+where
+    F: Future + Send + 'static,
+    F::Output: Send + 'static,
+{
+    // Tracked before spawning, so that the task is waited for even if it has not started yet
+    let in_flight_task = InFlightGuard::track(&IN_FLIGHT_BACKGROUND_TASKS);
+    tokio::spawn(async move {
+        let _in_flight_task = in_flight_task;
+        task.await
+    })
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct InFlightCounts {
+    requests: usize,
+    connector_calls: usize,
+    background_tasks: usize,
+}
+
+impl InFlightCounts {
+    fn get() -> Self {
+        Self {
+            requests: IN_FLIGHT_REQUESTS.load(Ordering::Acquire),
+            connector_calls: get_in_flight_connector_calls(),
+            background_tasks: IN_FLIGHT_BACKGROUND_TASKS.load(Ordering::Acquire),
+        }
+    }
+
+    fn is_drained(&self) -> bool {
+        self.requests == 0 && self.connector_calls == 0 && self.background_tasks == 0
+    }
+}
+
+/// Wait for the requests, connector calls and background tasks in flight to complete, returning
+/// the ones which did not complete within the drain window
+async fn drain_in_flight(drain_window: Duration) -> InFlightCounts {
+    let deadline = tokio::time::Instant::now() + drain_window;
+    loop {
+        let in_flight = InFlightCounts::get();
+        if in_flight.is_drained() || tokio::time::Instant::now() >= deadline {
+            return in_flight;
+        }
+        tokio::time::sleep(DRAIN_POLL_INTERVAL).await;
+    }
+}
+
+/// Wait for the shutdown signal, then shut the server down gracefully. The task handling the
+/// signal is to be awaited once the server has stopped, for the shutdown to complete.
+pub async fn handle_shutdown_signal(
+    server: ServerHandle,
+    drain_window: Duration,
+    event_handler: EventsHandler,
+) {
+    let signals = match common_utils::signals::get_allowed_signals() {
+        Ok(signals) => signals,
+        Err(error) => {
+            logger::error!(?error, "Failed to create the shutdown signals handler");
+            return;
+        }
+    };
+    let signals_handle = signals.handle();
+    let (tx, mut rx) = mpsc::channel(1);
+    tokio::spawn(common_utils::signals::signal_handler(signals, tx).in_current_span());
+
+    if rx.recv().await.is_none() {
+        return;
+    }
+    signals_handle.close();
+
+    IS_SHUTTING_DOWN.store(true, Ordering::Release);
+    logger::info!(
+        drain_window_secs = drain_window.as_secs(),
+        "Shutting down the server, draining the requests, connector calls and background tasks in flight"
+    );
+
+    // The server stops accepting new connections, and is given the same drain window to complete
+    // the requests in flight before its workers are stopped
+    let ((), in_flight) = tokio::join!(server.stop(true), drain_in_flight(drain_window));
+
+    if !in_flight.is_drained() {
+        logger::warn!(
+            requests = in_flight.requests,
+            connector_calls = in_flight.connector_calls,
+            background_tasks = in_flight.background_tasks,
+            "Requests, connector calls or background tasks were still in flight when the server \
+             was shut down"
+        );
+        metrics::IN_FLIGHT_AT_SHUTDOWN.add(
+            u64::try_from(in_flight.requests).unwrap_or(u64::MAX),
+            router_env::metric_attributes!(("type", "request")),
+        );
+        metrics::IN_FLIGHT_AT_SHUTDOWN.add(
+            u64::try_from(in_flight.connector_calls).unwrap_or(u64::MAX),
+            router_env::metric_attributes!(("type", "connector_call")),
+        );
+        metrics::IN_FLIGHT_AT_SHUTDOWN.add(
+            u64::try_from(in_flight.background_tasks).unwrap_or(u64::MAX),
+            router_env::metric_attributes!(("type", "background_task")),
+        );
+    }
+
+    if let Err(error) =
+        tokio::task::spawn_blocking(move || event_handler.flush(EVENTS_FLUSH_TIMEOUT)).await
+    {
+        logger::error!(?error, "Failed to flush the events pending to be emitted");
+    }
+    logger::info!("Server shut down");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_drain_returns_once_in_flight_requests_complete() {
+        let request = InFlightGuard::track_request();
+        let drain = tokio::spawn(drain_in_flight(Duration::from_secs(5)));
+
+        tokio::time::sleep(DRAIN_POLL_INTERVAL * 2).await;
+        assert!(!drain.is_finished());
+
+        drop(request);
+        let in_flight = tokio::time::timeout(Duration::from_secs(1), drain).await;
+        assert!(matches!(in_flight, Ok(Ok(in_flight)) if in_flight.is_drained()));
+    }
+
+    #[tokio::test]
+    async fn test_drain_waits_for_background_tasks() {
+        let (tx, rx) = tokio::sync::oneshot::channel::<()>();
+        let task = spawn_background_task(async move {
+            let _ = rx.await;
+        });
+        let drain = tokio::spawn(drain_in_flight(Duration::from_secs(5)));
+
+        tokio::time::sleep(DRAIN_POLL_INTERVAL * 2).await;
+        assert!(!drain.is_finished());
+
+        tx.send(()).unwrap();
+        task.await.unwrap();
+        let in_flight = tokio::time::timeout(Duration::from_secs(1), drain).await;
+        assert!(matches!(in_flight, Ok(Ok(in_flight)) if in_flight.is_drained()));
+    }
+}
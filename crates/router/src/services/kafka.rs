@@ -324,6 +324,18 @@ impl KafkaProducer {
         self.ckh_database_name = Some(tenant_config.get_clickhouse_database().to_string());
     }
 
+    /// Send the events pending to be sent, waiting for at most the timeout
+    pub fn flush(&self, timeout: std::time::Duration) {
+        match self
+            .producer
+            .0
+            .flush(rdkafka::util::Timeout::After(timeout))
+        {
+            Ok(_) => router_env::logger::info!("Kafka events flush Successful"),
+            Err(error) => router_env::logger::error!("Failed to flush Kafka Events {error:?}"),
+        }
+    }
+
     pub async fn create(conf: &KafkaSettings) -> MQResult<Self> {
         Ok(Self {
             producer: Arc::new(RdKafkaProducer(
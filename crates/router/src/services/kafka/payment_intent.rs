@@ -59,6 +59,7 @@ pub struct KafkaPaymentIntent<'a> {
     pub organization_id: &'a id_type::OrganizationId,
     pub processor_merchant_id: &'a id_type::MerchantId,
     pub created_by: Option<&'a common_types::CreatedBy>,
+    pub attribution_tags: Option<String>,
     #[serde(flatten)]
     infra_values: Option<Value>,
 }
@@ -107,6 +108,10 @@ impl<'a> KafkaPaymentIntent<'a> {
             organization_id: &intent.organization_id,
             processor_merchant_id: &intent.processor_merchant_id,
             created_by: intent.created_by.as_ref(),
+            attribution_tags: intent
+                .attribution_tags
+                .as_ref()
+                .and_then(|tags| serde_json::to_string(tags).ok()),
             infra_values,
         }
     }
@@ -292,6 +292,8 @@ impl ForeignTryFrom<domain::Profile> for ProfileResponse {
             surcharge_connector_details: item
                 .surcharge_connector_details
                 .map(ForeignFrom::foreign_from),
+            confirm_time_budget_in_secs: item.confirm_time_budget_in_secs,
+            time_zone: item.time_zone,
         })
     }
 }
@@ -385,6 +387,8 @@ impl ForeignTryFrom<domain::Profile> for ProfileResponse {
             surcharge_connector_details: item
                 .surcharge_connector_details
                 .map(ForeignInto::foreign_into),
+            confirm_time_budget_in_secs: item.confirm_time_budget_in_secs,
+            time_zone: item.time_zone,
         })
     }
 }
@@ -574,5 +578,7 @@ pub async fn create_profile_from_merchant_account(
         surcharge_connector_details: request
             .surcharge_connector_details
             .map(ForeignInto::foreign_into),
+        confirm_time_budget_in_secs: request.confirm_time_budget_in_secs.map(i64::from),
+        time_zone: request.time_zone,
     }))
 }
@@ -1,3 +1,8 @@
+pub use api_models::configs::{
+    UnmaskedHeadersPreviewRequest, UnmaskedHeadersPreviewResponse, UnmaskedHeadersResponse,
+    UnmaskedHeadersUpdate,
+};
+
 #[derive(Clone, serde::Serialize, Debug, serde::Deserialize)]
 pub struct Config {
     pub key: String,
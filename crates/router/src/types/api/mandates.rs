@@ -121,6 +121,7 @@ impl MandateResponseExt for MandateResponse {
                     user_agent,
                 }),
             }),
+            customer_acceptance_source: mandate.customer_acceptance_source.unwrap_or_default(),
             card,
             status: mandate.mandate_status,
             payment_method: pm.to_string(),
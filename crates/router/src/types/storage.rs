@@ -12,6 +12,8 @@ pub mod capture;
 pub mod card_issuer;
 pub mod cards_info;
 pub mod configs;
+pub mod connector_customer_mapping;
+pub mod customer_contact_rehash;
 pub mod customers;
 pub mod dashboard_metadata;
 pub mod dispute;
@@ -31,6 +33,7 @@ pub mod mandate;
 pub mod merchant_account;
 pub mod merchant_connector_account;
 pub mod merchant_key_store;
+pub mod merchant_takeout;
 pub mod payment_attempt;
 pub mod payment_link;
 pub mod payment_method;
@@ -73,11 +76,12 @@ pub use scheduler::db::process_tracker;
 pub use self::{
     address::*, api_keys::*, authentication::*, authorization::*, batch_blocklist_job::*,
     blocklist::*, blocklist_fingerprint::*, blocklist_lookup::*, business_profile::*,
-    callback_mapper::*, capture::*, card_issuer::*, cards_info::*, configs::*, customers::*,
-    dashboard_metadata::*, dispute::*, dynamic_routing_stats::*, ephemeral_key::*, events::*,
-    file::*, fraud_check::*, generic_link::*, gsm::*, hyperswitch_ai_interaction::*,
-    locker_mock_up::*, mandate::*, merchant_account::*, merchant_connector_account::*,
-    merchant_key_store::*, payment_link::*, payment_method::*, process_tracker::*, refund::*,
-    reverse_lookup::*, role::*, routing_algorithm::*, unified_translations::*, user::*,
-    user_authentication_method::*, user_role::*,
+    callback_mapper::*, capture::*, card_issuer::*, cards_info::*, configs::*,
+    connector_customer_mapping::*, customer_contact_rehash::*, customers::*, dashboard_metadata::*,
+    dispute::*, dynamic_routing_stats::*, ephemeral_key::*, events::*, file::*, fraud_check::*,
+    generic_link::*, gsm::*, hyperswitch_ai_interaction::*, locker_mock_up::*, mandate::*,
+    merchant_account::*, merchant_connector_account::*, merchant_key_store::*, merchant_takeout::*,
+    payment_link::*, payment_method::*, process_tracker::*, refund::*, reverse_lookup::*, role::*,
+    routing_algorithm::*, unified_translations::*, user::*, user_authentication_method::*,
+    user_role::*,
 };
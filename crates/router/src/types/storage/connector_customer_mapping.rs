@@ -0,0 +1,3 @@
+pub use diesel_models::connector_customer_mapping::{
+    ConnectorCustomerMapping, ConnectorCustomerMappingNew,
+};
@@ -0,0 +1,24 @@
+use common_utils::id_type;
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct CustomerContactRehashTrackingData {
+    pub job_id: String,
+    pub merchant_id: id_type::MerchantId,
+    /// Upper bound on the creation time of the rehashed records, as the records created later are
+    /// hashed with the current key when they are written
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub snapshot_at: time::PrimitiveDateTime,
+    pub stage: CustomerContactRehashStage,
+    /// Number of records of the current stage that were already rehashed
+    pub offset: u32,
+}
+
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize, strum::Display,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum CustomerContactRehashStage {
+    Customers,
+    Payments,
+}
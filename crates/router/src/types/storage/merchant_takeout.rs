@@ -0,0 +1,25 @@
+use api_models::takeout::{TakeoutRedactionOptions, TakeoutResource};
+use common_utils::id_type;
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct MerchantTakeoutTrackingData {
+    pub job_id: String,
+    pub merchant_id: id_type::MerchantId,
+    pub redaction: TakeoutRedactionOptions,
+    /// Upper bound on the creation time of the exported records, so that the records created
+    /// while the takeout is being generated do not shift the pages of a resource
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub snapshot_at: time::PrimitiveDateTime,
+    pub completed_resources: Vec<TakeoutResource>,
+    /// Number of records of the resource currently being exported that were already exported
+    pub resource_offset: u32,
+    pub files: Vec<MerchantTakeoutFile>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct MerchantTakeoutFile {
+    pub resource: TakeoutResource,
+    pub file_name: String,
+    pub file_key: String,
+    pub record_count: u32,
+}
@@ -90,6 +90,10 @@ pub struct RevenueRecoverySettings {
     pub recovery_timestamp: RecoveryTimestamp,
     pub card_config: RetryLimitsConfig,
     pub redis_ttl_in_seconds: i64,
+    /// Whether to email the customer when a retry of a failed recurring payment is scheduled
+    /// and when the recovery is exhausted
+    #[serde(default)]
+    pub send_dunning_emails: bool,
 }
 
 #[derive(Debug, serde::Deserialize, Clone)]
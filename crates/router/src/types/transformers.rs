@@ -1680,6 +1680,7 @@ impl ForeignTryFrom<&HeaderMap> for hyperswitch_domain_models::payments::HeaderP
             x_app_id,
             x_redirect_uri,
             x_reference_id,
+            status_update_source: None,
         })
     }
 }
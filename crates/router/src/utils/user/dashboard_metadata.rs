@@ -498,6 +498,8 @@ impl ForeignFrom<payments::PaymentListFilterConstraints> for PaymentListFilterCo
             merchant_order_reference_id,
             card_discovery,
             customer_email,
+            customer_phone,
+            attribution_tags,
         } = item;
         Self {
             query,
@@ -529,6 +531,8 @@ impl ForeignFrom<payments::PaymentListFilterConstraints> for PaymentListFilterCo
             merchant_order_reference_id,
             card_discovery,
             customer_email,
+            customer_phone,
+            attribution_tags,
         }
     }
 }
@@ -566,6 +570,8 @@ impl ForeignFrom<PaymentListFilterConstraintsV1> for payments::PaymentListFilter
             merchant_order_reference_id,
             card_discovery,
             customer_email,
+            customer_phone,
+            attribution_tags,
         } = item;
         Self {
             query,
@@ -597,6 +603,8 @@ impl ForeignFrom<PaymentListFilterConstraintsV1> for payments::PaymentListFilter
             merchant_order_reference_id,
             card_discovery,
             customer_email,
+            customer_phone,
+            attribution_tags,
         }
     }
 }
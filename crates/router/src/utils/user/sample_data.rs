@@ -303,6 +303,9 @@ pub async fn generate_sample_data(
             profile_acquirer_id: None,
             external_surcharge_strategy: None,
             external_surcharge_applicable: None,
+            attribution_tags: None,
+            customer_email_hash: None,
+            customer_phone_hash: None,
         };
         let (connector_transaction_id, processor_transaction_data) =
             ConnectorTransactionId::form_id_and_data(attempt_id.clone());
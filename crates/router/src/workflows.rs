@@ -26,3 +26,9 @@ pub mod payout_sync;
 
 #[cfg(feature = "v1")]
 pub mod batch_blocklist_upload;
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub mod merchant_takeout;
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub mod customer_contact_rehash;
@@ -0,0 +1,201 @@
+use common_utils::ext_traits::ValueExt;
+use error_stack::ResultExt;
+use router_env::{instrument, tracing};
+use scheduler::{
+    consumer::{self, types::process_data},
+    utils as pt_utils,
+    workflows::ProcessTrackerWorkflow,
+};
+
+use crate::{
+    core::{
+        customer_search::{self, ContactHasher},
+        errors::{self, RouterResult},
+    },
+    logger::{error, info, warn},
+    routes::SessionState,
+    types::storage,
+};
+
+pub struct CustomerContactRehashWorkflow;
+
+/// Rehashes the customers and then the payments of the merchant one page at a time, persisting
+/// the progress after each page so that a retried job resumes from the last rehashed page.
+async fn run_rehash_job(
+    state: &SessionState,
+    process_id: &str,
+    mut tracking_data: storage::CustomerContactRehashTrackingData,
+) -> RouterResult<()> {
+    let db = &*state.store;
+    let merchant_id = tracking_data.merchant_id.clone();
+
+    let hasher = ContactHasher::from_state(state)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to derive the search hash key of the tenant")?
+        .ok_or(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Search hash key is not configured")?;
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(&merchant_id, &db.get_master_key().to_vec().into())
+        .await
+        .change_context(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+    let merchant_account = db
+        .find_merchant_account_by_merchant_id(&merchant_id, &key_store)
+        .await
+        .change_context(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    loop {
+        let record_count = match tracking_data.stage {
+            storage::CustomerContactRehashStage::Customers => {
+                customer_search::rehash_customers_page(
+                    state,
+                    &hasher,
+                    &merchant_account,
+                    &key_store,
+                    &tracking_data,
+                )
+                .await?
+            }
+            storage::CustomerContactRehashStage::Payments => {
+                customer_search::rehash_payments_page(
+                    state,
+                    &hasher,
+                    &merchant_account,
+                    &key_store,
+                    &tracking_data,
+                )
+                .await?
+            }
+        };
+
+        let is_last_page =
+            record_count < usize::from(customer_search::CUSTOMER_CONTACT_REHASH_BATCH_SIZE);
+        let stage = tracking_data.stage;
+        if is_last_page {
+            match stage {
+                storage::CustomerContactRehashStage::Customers => {
+                    tracking_data.stage = storage::CustomerContactRehashStage::Payments;
+                    tracking_data.offset = 0;
+                }
+                storage::CustomerContactRehashStage::Payments => return Ok(()),
+            }
+        } else {
+            tracking_data.offset = u32::try_from(record_count)
+                .ok()
+                .and_then(|record_count| tracking_data.offset.checked_add(record_count))
+                .ok_or(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Rehash record offset exceeds u32::MAX")?;
+        }
+
+        let td_value = serde_json::to_value(&tracking_data)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to serialise updated tracking_data")?;
+        db.as_scheduler()
+            .process_tracker_update_process_status_by_ids(
+                vec![process_id.to_owned()],
+                storage::ProcessTrackerUpdate::Update {
+                    name: None,
+                    retry_count: None,
+                    schedule_time: None,
+                    tracking_data: Some(td_value),
+                    business_status: None,
+                    status: None,
+                    updated_at: Some(common_utils::date_time::now()),
+                },
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable_lazy(|| {
+                format!("Failed to update tracking_data after rehashing {stage}")
+            })?;
+
+        info!(
+            job_id = %tracking_data.job_id,
+            %stage,
+            record_count,
+            "Rehashed customer contact page"
+        );
+    }
+}
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for CustomerContactRehashWorkflow {
+    /// Deserializes tracking data, rehashes the remaining records, then marks the job completed or schedules a retry on failure.
+    #[instrument(skip_all, fields(flow = ?router_env::Flow::CustomerContactHashesRehash))]
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db = &*state.store;
+
+        let tracking_data: storage::CustomerContactRehashTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("CustomerContactRehashTrackingData")
+            .map_err(errors::ProcessTrackerError::from)?;
+        let job_id = tracking_data.job_id.clone();
+
+        match run_rehash_job(state, &process.id, tracking_data).await {
+            Ok(()) => {
+                info!(job_id = %job_id, "Customer contact rehash completed");
+                db.as_scheduler()
+                    .finish_process_with_business_status(
+                        process,
+                        storage::business_status::COMPLETED_BY_PT,
+                    )
+                    .await
+                    .map_err(Into::<errors::ProcessTrackerError>::into)?;
+            }
+            Err(err) => {
+                let retry_count = process.retry_count;
+                error!(
+                    job_id = %job_id,
+                    error = ?err,
+                    "Customer contact rehash failed (retry_count={})",
+                    retry_count
+                );
+
+                let mapping = process_data::RetryMapping::default();
+                let time_delta = if retry_count == 0 {
+                    Some(mapping.start_after)
+                } else {
+                    pt_utils::get_delay(retry_count + 1, &mapping.frequencies)
+                };
+
+                match pt_utils::get_time_from_delta(time_delta) {
+                    Some(s_time) => {
+                        db.as_scheduler()
+                            .retry_process(process, s_time)
+                            .await
+                            .map_err(Into::<errors::ProcessTrackerError>::into)?;
+                    }
+                    None => {
+                        warn!(
+                            job_id = %job_id,
+                            "Customer contact rehash exceeded max retries, marking failed"
+                        );
+                        db.as_scheduler()
+                            .finish_process_with_business_status(
+                                process,
+                                storage::business_status::RETRIES_EXCEEDED,
+                            )
+                            .await
+                            .map_err(Into::<errors::ProcessTrackerError>::into)?;
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Delegates to the standard consumer error handler to reschedule or mark the process as failed.
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
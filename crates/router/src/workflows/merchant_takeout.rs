@@ -0,0 +1,227 @@
+use api_models::takeout::TakeoutResource;
+use common_utils::ext_traits::ValueExt;
+use error_stack::ResultExt;
+use router_env::{instrument, tracing};
+use scheduler::{
+    consumer::{self, types::process_data},
+    utils as pt_utils,
+    workflows::ProcessTrackerWorkflow,
+};
+use strum::IntoEnumIterator;
+
+use crate::{
+    core::{
+        errors::{self, RouterResult},
+        merchant_takeout,
+    },
+    logger::{error, info, warn},
+    routes::SessionState,
+    types::storage,
+};
+
+pub struct MerchantTakeoutWorkflow;
+
+/// Exports the resources of the takeout one page at a time, persisting the progress after each
+/// exported file so that a retried job resumes from the last exported file.
+async fn run_takeout_job(
+    state: &SessionState,
+    process_id: &str,
+    mut tracking_data: storage::MerchantTakeoutTrackingData,
+) -> RouterResult<()> {
+    let db = &*state.store;
+    let records_per_file = state.conf.merchant_takeout.get_inner().records_per_file;
+    let merchant_id = tracking_data.merchant_id.clone();
+
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(&merchant_id, &db.get_master_key().to_vec().into())
+        .await
+        .change_context(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+    let merchant_account = db
+        .find_merchant_account_by_merchant_id(&merchant_id, &key_store)
+        .await
+        .change_context(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    for resource in TakeoutResource::iter() {
+        if tracking_data.completed_resources.contains(&resource) {
+            continue;
+        }
+
+        loop {
+            let records = merchant_takeout::export_resource_page(
+                state,
+                &merchant_account,
+                &key_store,
+                &tracking_data,
+                resource,
+                tracking_data.resource_offset,
+                records_per_file,
+            )
+            .await?;
+            let record_count = u32::try_from(records.len())
+                .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+            // Every resource is exported to at least one file, so that resources without any
+            // records are distinguishable from the ones that were not exported.
+            if record_count > 0 || tracking_data.resource_offset == 0 {
+                let part = tracking_data
+                    .files
+                    .iter()
+                    .filter(|file| file.resource == resource)
+                    .count()
+                    + 1;
+                let file_name = merchant_takeout::takeout_file_name(resource, part);
+                let file_key = merchant_takeout::takeout_file_key(
+                    merchant_id.get_string_repr(),
+                    &tracking_data.job_id,
+                    &file_name,
+                );
+                state
+                    .file_storage_client
+                    .upload_file(&file_key, merchant_takeout::records_to_jsonl(&records)?)
+                    .await
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable_lazy(|| {
+                        format!("Failed to upload takeout file {file_name}")
+                    })?;
+
+                tracking_data.files.push(storage::MerchantTakeoutFile {
+                    resource,
+                    file_name,
+                    file_key,
+                    record_count,
+                });
+            }
+
+            let is_last_page = records.len() < usize::from(records_per_file);
+            if is_last_page {
+                tracking_data.completed_resources.push(resource);
+                tracking_data.resource_offset = 0;
+            } else {
+                tracking_data.resource_offset = tracking_data
+                    .resource_offset
+                    .checked_add(record_count)
+                    .ok_or(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Takeout record offset exceeds u32::MAX")?;
+            }
+
+            let td_value = serde_json::to_value(&tracking_data)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to serialise updated tracking_data")?;
+            db.as_scheduler()
+                .process_tracker_update_process_status_by_ids(
+                    vec![process_id.to_owned()],
+                    storage::ProcessTrackerUpdate::Update {
+                        name: None,
+                        retry_count: None,
+                        schedule_time: None,
+                        tracking_data: Some(td_value),
+                        business_status: None,
+                        status: None,
+                        updated_at: Some(common_utils::date_time::now()),
+                    },
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable_lazy(|| {
+                    format!("Failed to update tracking_data after exporting {resource}")
+                })?;
+
+            info!(
+                job_id = %tracking_data.job_id,
+                %resource,
+                record_count,
+                files = tracking_data.files.len(),
+                "Exported merchant takeout page"
+            );
+
+            if is_last_page {
+                break;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for MerchantTakeoutWorkflow {
+    /// Deserializes tracking data, exports all pending resources, then marks the job completed or schedules a retry on failure.
+    #[instrument(skip_all, fields(flow = ?router_env::Flow::MerchantTakeoutCreate))]
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db = &*state.store;
+
+        let tracking_data: storage::MerchantTakeoutTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("MerchantTakeoutTrackingData")
+            .map_err(errors::ProcessTrackerError::from)?;
+        let job_id = tracking_data.job_id.clone();
+
+        match run_takeout_job(state, &process.id, tracking_data).await {
+            Ok(()) => {
+                info!(job_id = %job_id, "Merchant takeout completed");
+                db.as_scheduler()
+                    .finish_process_with_business_status(
+                        process,
+                        storage::business_status::COMPLETED_BY_PT,
+                    )
+                    .await
+                    .map_err(Into::<errors::ProcessTrackerError>::into)?;
+            }
+            Err(err) => {
+                let retry_count = process.retry_count;
+                error!(
+                    job_id = %job_id,
+                    error = ?err,
+                    "Merchant takeout generation failed (retry_count={})",
+                    retry_count
+                );
+
+                let mapping = process_data::RetryMapping::default();
+                let time_delta = if retry_count == 0 {
+                    Some(mapping.start_after)
+                } else {
+                    pt_utils::get_delay(retry_count + 1, &mapping.frequencies)
+                };
+
+                match pt_utils::get_time_from_delta(time_delta) {
+                    Some(s_time) => {
+                        db.as_scheduler()
+                            .retry_process(process, s_time)
+                            .await
+                            .map_err(Into::<errors::ProcessTrackerError>::into)?;
+                    }
+                    None => {
+                        warn!(
+                            job_id = %job_id,
+                            "Merchant takeout exceeded max retries, marking failed"
+                        );
+                        db.as_scheduler()
+                            .finish_process_with_business_status(
+                                process,
+                                storage::business_status::RETRIES_EXCEEDED,
+                            )
+                            .await
+                            .map_err(Into::<errors::ProcessTrackerError>::into)?;
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Delegates to the standard consumer error handler to reschedule or mark the process as failed.
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
@@ -83,160 +83,197 @@ impl ProcessTrackerWorkflow<SessionState> for PaymentsSyncWorkflow {
         let dimensions = dimension_state::Dimensions::new()
             .with_processor_merchant_id(platform.get_processor().get_processor_merchant_id())
             .with_provider_merchant_id(platform.get_provider().get_provider_merchant_id());
-        // TODO: Add support for ReqState in PT flows
-        let (mut payment_data, _, _, _) = Box::pin(payment_flows::payments_operation_core::<
-            api::PSync,
-            _,
-            _,
-            _,
-            payment_flows::PaymentData<api::PSync>,
-        >(
-            state,
-            state.get_req_state(),
-            &platform,
-            None,
-            operations::PaymentStatus,
-            tracking_data.clone(),
-            payment_flows::CallConnectorAction::Trigger,
-            None,
-            services::AuthFlow::Client,
-            None,
-            hyperswitch_domain_models::payments::HeaderPayload::default(),
-            &dimensions,
-            None,
-        ))
+        let merchant_id = merchant_account.get_id().clone();
+        let payment_id = get_payment_id(
+            db,
+            &tracking_data.resource_id,
+            &merchant_account,
+            &key_store,
+        )
         .await?;
+        let status_update_lock =
+            payment_flows::state_machine::get_status_update_lock_action(&payment_id);
+        // The sync races with webhooks and redirect completions of the payment
+        status_update_lock
+            .clone()
+            .perform_locking_action(state, merchant_id.clone())
+            .await?;
 
-        let terminal_status = [
-            enums::AttemptStatus::RouterDeclined,
-            enums::AttemptStatus::Charged,
-            enums::AttemptStatus::AutoRefunded,
-            enums::AttemptStatus::Voided,
-            enums::AttemptStatus::VoidFailed,
-            enums::AttemptStatus::CaptureFailed,
-            enums::AttemptStatus::Failure,
-        ];
-
-        let is_post_capture_void_attempted_state =
-            payment_data.payment_intent.is_post_capture_void_applied()
-                || payment_data.payment_intent.is_post_capture_void_pending();
+        let sync_result: Result<(), sch_errors::ProcessTrackerError> = async {
+            // TODO: Add support for ReqState in PT flows
+            let (mut payment_data, _, _, _) = Box::pin(payment_flows::payments_operation_core::<
+                api::PSync,
+                _,
+                _,
+                _,
+                payment_flows::PaymentData<api::PSync>,
+            >(
+                state,
+                state.get_req_state(),
+                &platform,
+                None,
+                operations::PaymentStatus,
+                tracking_data.clone(),
+                payment_flows::CallConnectorAction::Trigger,
+                None,
+                services::AuthFlow::Client,
+                None,
+                hyperswitch_domain_models::payments::HeaderPayload::with_status_update_source(
+                    payment_flows::state_machine::StatusUpdateSource::Sync,
+                ),
+                &dimensions,
+                None,
+            ))
+            .await?;
 
-        match &payment_data.payment_attempt.status {
-            status if terminal_status.contains(status) || is_post_capture_void_attempted_state => {
-                state
-                    .store
-                    .as_scheduler()
-                    .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
-                    .await?
-            }
-            _ => {
-                let connector = payment_data
-                    .payment_attempt
-                    .connector
-                    .clone()
-                    .ok_or(sch_errors::ProcessTrackerError::MissingRequiredField)?;
+            let terminal_status = [
+                enums::AttemptStatus::RouterDeclined,
+                enums::AttemptStatus::Charged,
+                enums::AttemptStatus::AutoRefunded,
+                enums::AttemptStatus::Voided,
+                enums::AttemptStatus::VoidFailed,
+                enums::AttemptStatus::CaptureFailed,
+                enums::AttemptStatus::Failure,
+            ];
 
-                let is_last_retry = retry_sync_task(
-                    db,
-                    state.superposition_service.as_ref(),
-                    connector,
-                    payment_data.payment_attempt.merchant_id.clone(),
-                    process,
-                )
-                .await?;
+            let is_post_capture_void_attempted_state =
+                payment_data.payment_intent.is_post_capture_void_applied()
+                    || payment_data.payment_intent.is_post_capture_void_pending();
 
-                // If the payment status is still processing and there is no connector transaction_id
-                // then change the payment status to failed if all retries exceeded
-                if is_last_retry
-                    && payment_data.payment_attempt.status == enums::AttemptStatus::Pending
-                    && payment_data
+            match &payment_data.payment_attempt.status {
+                status if terminal_status.contains(status) || is_post_capture_void_attempted_state => {
+                    state
+                        .store
+                        .as_scheduler()
+                        .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+                        .await?
+                }
+                _ => {
+                    let connector = payment_data
                         .payment_attempt
-                        .connector_transaction_id
-                        .as_ref()
-                        .is_none()
-                {
-                    let payment_intent_update = hyperswitch_domain_models::payments::payment_intent::PaymentIntentUpdate::PGStatusUpdate { status: api_models::enums::IntentStatus::Failed,updated_by: merchant_account.storage_scheme.to_string(), incremental_authorization_allowed: Some(false), feature_metadata: payment_data.payment_intent.feature_metadata.clone(), };
-                    let payment_attempt_update =
-                        hyperswitch_domain_models::payments::payment_attempt::PaymentAttemptUpdate::ErrorUpdate {
-                            connector: None,
-                            status: api_models::enums::AttemptStatus::Failure,
-                            error_code: None,
-                            error_message: None,
-                            error_reason: Some(Some(
-                                consts::REQUEST_TIMEOUT_ERROR_MESSAGE_FROM_PSYNC.to_string(),
-                            )),
-                            amount_capturable: Some(common_utils::types::MinorUnit::new(0)),
-                            updated_by: merchant_account.storage_scheme.to_string(),
-                            unified_code: None,
-                            unified_message: None,
-                            standardised_code: None,
-                            description: None,
-                            user_guidance_message: None,
-                            connector_transaction_id: None,
-                            connector_response_reference_id: None,
-                            payment_method_data: None,
-                            authentication_type: None,
-                            issuer_error_code: None,
-                            issuer_error_message: None,
-                            network_details: None,
-                            network_error_message: None,
-                            advice_message: None,
-                            encrypted_payment_method_data: None,
-                            recommended_action: None,
-                            card_network: payment_data.payment_attempt.extract_card_network(),
-                        };
+                        .connector
+                        .clone()
+                        .ok_or(sch_errors::ProcessTrackerError::MissingRequiredField)?;
 
-                    payment_data.payment_attempt = db
-                        .update_payment_attempt_with_attempt_id(
-                            payment_data.payment_attempt,
-                            payment_attempt_update,
-                            merchant_account.storage_scheme,
-                            &key_store,
-                        )
-                        .await
-                        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+                    let is_last_retry = retry_sync_task(
+                        db,
+                        state.superposition_service.as_ref(),
+                        connector.clone(),
+                        payment_data.payment_attempt.merchant_id.clone(),
+                        process,
+                    )
+                    .await?;
 
-                    payment_data.payment_intent = db
-                        .update_payment_intent(
-                            payment_data.payment_intent,
-                            payment_intent_update,
-                            &key_store,
-                            merchant_account.storage_scheme,
-                        )
-                        .await
-                        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+                    // If the payment status is still processing and there is no connector transaction_id
+                    // then change the payment status to failed if all retries exceeded
+                    if is_last_retry
+                        && payment_data.payment_attempt.status == enums::AttemptStatus::Pending
+                        && payment_data
+                            .payment_attempt
+                            .connector_transaction_id
+                            .as_ref()
+                            .is_none()
+                    {
+                        let intent_status = payment_flows::state_machine::resolve_intent_status(
+                            payment_data.payment_intent.status,
+                            api_models::enums::IntentStatus::Failed,
+                            payment_flows::state_machine::StatusUpdateSource::Sync,
+                            &connector,
+                        );
+                        let payment_intent_update = hyperswitch_domain_models::payments::payment_intent::PaymentIntentUpdate::PGStatusUpdate { status: intent_status,updated_by: merchant_account.storage_scheme.to_string(), incremental_authorization_allowed: Some(false), feature_metadata: payment_data.payment_intent.feature_metadata.clone(), };
+                        let payment_attempt_update =
+                            hyperswitch_domain_models::payments::payment_attempt::PaymentAttemptUpdate::ErrorUpdate {
+                                connector: None,
+                                status: payment_flows::state_machine::resolve_attempt_status(
+                                    payment_data.payment_attempt.status,
+                                    api_models::enums::AttemptStatus::Failure,
+                                    payment_flows::state_machine::StatusUpdateSource::Sync,
+                                    &connector,
+                                ),
+                                error_code: None,
+                                error_message: None,
+                                error_reason: Some(Some(
+                                    consts::REQUEST_TIMEOUT_ERROR_MESSAGE_FROM_PSYNC.to_string(),
+                                )),
+                                amount_capturable: Some(common_utils::types::MinorUnit::new(0)),
+                                updated_by: merchant_account.storage_scheme.to_string(),
+                                unified_code: None,
+                                unified_message: None,
+                                standardised_code: None,
+                                description: None,
+                                user_guidance_message: None,
+                                connector_transaction_id: None,
+                                connector_response_reference_id: None,
+                                payment_method_data: None,
+                                authentication_type: None,
+                                issuer_error_code: None,
+                                issuer_error_message: None,
+                                network_details: None,
+                                network_error_message: None,
+                                advice_message: None,
+                                encrypted_payment_method_data: None,
+                                recommended_action: None,
+                                card_network: payment_data.payment_attempt.extract_card_network(),
+                            };
 
-                    let profile_id = payment_data
-                        .payment_intent
-                        .profile_id
-                        .as_ref()
-                        .get_required_value("profile_id")
-                        .change_context(errors::ApiErrorResponse::InternalServerError)
-                        .attach_printable("Could not find profile_id in payment intent")?;
+                        payment_data.payment_attempt = db
+                            .update_payment_attempt_with_attempt_id(
+                                payment_data.payment_attempt,
+                                payment_attempt_update,
+                                merchant_account.storage_scheme,
+                                &key_store,
+                            )
+                            .await
+                            .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
 
-                    let business_profile = db
-                        .find_business_profile_by_profile_id(&key_store, profile_id)
-                        .await
-                        .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
-                            id: profile_id.get_string_repr().to_owned(),
-                        })?;
+                        payment_data.payment_intent = db
+                            .update_payment_intent(
+                                payment_data.payment_intent,
+                                payment_intent_update,
+                                &key_store,
+                                merchant_account.storage_scheme,
+                            )
+                            .await
+                            .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
 
-                    // Trigger the outgoing webhook to notify the merchant about failed payment
-                    let operation = operations::PaymentStatus;
-                    Box::pin(utils::trigger_payments_webhook(
-                        &platform,
-                        business_profile,
-                        payment_data,
-                        state,
-                        operation,
-                    ))
-                    .await
-                    .map_err(|error| logger::warn!(payments_outgoing_webhook_error=?error))
-                    .ok();
+                        let profile_id = payment_data
+                            .payment_intent
+                            .profile_id
+                            .as_ref()
+                            .get_required_value("profile_id")
+                            .change_context(errors::ApiErrorResponse::InternalServerError)
+                            .attach_printable("Could not find profile_id in payment intent")?;
+
+                        let business_profile = db
+                            .find_business_profile_by_profile_id(&key_store, profile_id)
+                            .await
+                            .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+                                id: profile_id.get_string_repr().to_owned(),
+                            })?;
+
+                        // Trigger the outgoing webhook to notify the merchant about failed payment
+                        let operation = operations::PaymentStatus;
+                        Box::pin(utils::trigger_payments_webhook(
+                            &platform,
+                            business_profile,
+                            payment_data,
+                            state,
+                            operation,
+                        ))
+                        .await
+                        .map_err(|error| logger::warn!(payments_outgoing_webhook_error=?error))
+                        .ok();
+                    }
                 }
-            }
-        };
-        Ok(())
+            };
+            Ok(())
+        }
+        .await;
+
+        status_update_lock
+            .free_lock_action(state, merchant_id)
+            .await?;
+        sync_result
     }
 
     async fn error_handler<'a>(
@@ -249,6 +286,28 @@ impl ProcessTrackerWorkflow<SessionState> for PaymentsSyncWorkflow {
     }
 }
 
+/// Get the id of the payment being synced, the task tracks either the payment or its attempt
+#[cfg(feature = "v1")]
+async fn get_payment_id(
+    db: &dyn StorageInterface,
+    resource_id: &api::PaymentIdType,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+) -> Result<common_utils::id_type::PaymentId, sch_errors::ProcessTrackerError> {
+    match resource_id {
+        api::PaymentIdType::PaymentAttemptId(attempt_id) => Ok(db
+            .find_payment_attempt_by_attempt_id_processor_merchant_id(
+                attempt_id,
+                merchant_account.get_id(),
+                merchant_account.storage_scheme,
+                key_store,
+            )
+            .await?
+            .payment_id),
+        resource_id => Ok(api::PaymentIdTypeExt::get_payment_intent_id(resource_id)?),
+    }
+}
+
 /// Get the next schedule time
 ///
 /// The schedule time can be configured in configs by this key `pt_mapping_trustpay`
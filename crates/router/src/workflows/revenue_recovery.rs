@@ -248,6 +248,56 @@ pub(crate) async fn get_schedule_time_to_retry_mit_payments(
     scheduler_utils::get_time_from_delta(time_delta)
 }
 
+/// Number of retries left for the payment as per the retry algorithm, `None` if it cannot be
+/// determined
+#[cfg(feature = "v2")]
+pub async fn get_remaining_retry_attempts(
+    state: &SessionState,
+    connector_customer_id: &str,
+    payment_intent: &PaymentIntent,
+    billing_connector: common_enums::connector_enums::Connector,
+    retry_algorithm_type: RevenueRecoveryAlgorithmType,
+    retry_count: i32,
+) -> Option<u16> {
+    let remaining_retries = match retry_algorithm_type {
+        RevenueRecoveryAlgorithmType::Monitoring => return None,
+        RevenueRecoveryAlgorithmType::Cascading => {
+            let dimensions = crate::core::configs::dimension_state::Dimensions::new()
+                .with_processor_merchant_id(payment_intent.merchant_id.clone().into())
+                .with_connector(billing_connector);
+            let mapping = dimensions
+                .get_pt_mapping_pcr_retries(
+                    state.store.as_ref(),
+                    state.superposition_service.as_ref(),
+                    None,
+                )
+                .await;
+            scheduler_utils::get_pcr_payments_remaining_retries(&mapping, retry_count)
+        }
+        // Smart retries are bounded by the 30-day retry budget of each usable token
+        RevenueRecoveryAlgorithmType::Smart => {
+            RedisTokenManager::get_payment_processor_metadata_for_connector_customer(
+                state,
+                connector_customer_id,
+            )
+            .await
+            .inspect_err(|error| {
+                logger::error!(
+                    ?error,
+                    "Failed to fetch payment processor tokens from redis"
+                );
+            })
+            .ok()?
+            .values()
+            .filter(|token| !token.token_status.is_hard_decline.unwrap_or(false))
+            .map(|token| token.monthly_retry_remaining.max(0))
+            .sum()
+        }
+    };
+
+    u16::try_from(remaining_retries).ok()
+}
+
 #[derive(Debug, Clone)]
 pub struct RetryDecision {
     pub retry_time: time::PrimitiveDateTime,
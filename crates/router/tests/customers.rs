@@ -1,3 +1,13 @@
+use std::{
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
+use router::{configs::settings::Settings, core::payments::customers, routes, services};
+
 mod utils;
 
 // setting the connector in environment variables doesn't work when run in parallel. Neither does passing the paymentid
@@ -186,3 +196,77 @@ async fn customer_failure() {
         awc::http::StatusCode::UNPROCESSABLE_ENTITY
     );
 }
+
+#[actix_web::test]
+async fn concurrent_connector_customer_creation_stores_single_id() {
+    Box::pin(utils::setup()).await;
+    let (tx, _) = tokio::sync::oneshot::channel();
+    let mut conf = Settings::new().expect("invalid settings");
+    // The connector call outlives the lock retries of the concurrent authorize
+    conf.lock_settings.lock_retries = 2;
+    conf.lock_settings.delay_between_retries_in_milliseconds = 50;
+    let app_state = Box::pin(routes::AppState::new(
+        conf,
+        tx,
+        Box::new(services::MockApiClient),
+        env!("CARGO_PKG_NAME"),
+    ))
+    .await;
+    let state = Arc::new(app_state)
+        .get_session_state(
+            &common_utils::id_type::TenantId::try_from_string("public".to_string()).unwrap(),
+            None,
+            || {},
+        )
+        .unwrap();
+
+    let merchant_id =
+        common_utils::id_type::MerchantId::try_from(std::borrow::Cow::from("merchant_1")).unwrap();
+    let customer_id = common_utils::id_type::CustomerId::try_from(std::borrow::Cow::from(format!(
+        "customer_{}",
+        uuid::Uuid::new_v4()
+    )))
+    .unwrap();
+    let connector_label = "stripe_US_default";
+    let connector_customers_created = Arc::new(AtomicUsize::new(0));
+
+    // Both authorizes find no connector customer for the new customer, and try to create one
+    let authorize = |created_connector_customer_id: &'static str| {
+        let state = state.clone();
+        let merchant_id = merchant_id.clone();
+        let customer_id = customer_id.clone();
+        let connector_customers_created = connector_customers_created.clone();
+        tokio::spawn(async move {
+            customers::get_or_create_connector_customer_id(
+                &state,
+                &merchant_id,
+                &customer_id,
+                connector_label,
+                || async move {
+                    connector_customers_created.fetch_add(1, Ordering::SeqCst);
+                    // Keep the connector call in flight while the concurrent authorize runs
+                    tokio::time::sleep(Duration::from_millis(200)).await;
+                    Ok(Some(created_connector_customer_id.to_string()))
+                },
+            )
+            .await
+            .unwrap()
+        })
+    };
+    let (first_connector_customer_id, second_connector_customer_id) =
+        tokio::try_join!(authorize("cus_first"), authorize("cus_second")).unwrap();
+
+    assert_eq!(connector_customers_created.load(Ordering::SeqCst), 1);
+    assert_eq!(first_connector_customer_id, second_connector_customer_id);
+    assert_eq!(
+        customers::find_stored_connector_customer_id(
+            &state,
+            &merchant_id,
+            &customer_id,
+            connector_label,
+        )
+        .await
+        .unwrap(),
+        first_connector_customer_id
+    );
+}
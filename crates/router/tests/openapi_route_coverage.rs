@@ -0,0 +1,664 @@
+//! Checks that every route registered by the application has a corresponding operation in the
+//! generated OpenAPI specification, so that new endpoints cannot ship without being documented.
+//!
+//! The routes are read from the application built by `router::mk_app`: the resource patterns are
+//! taken from the resource map of the application, and each resource is requested with every
+//! HTTP method to find the methods it handles. Routes which are intentionally left out of the
+//! specification (internal, dashboard, health, and browser or connector facing routes) are listed
+//! in `tests/openapi_route_allowlist/<version>.txt`.
+
+// Integration test: assertions use panic!/expect(); allow the production-code
+// lints the v2 clippy profile denies.
+#![allow(clippy::panic, clippy::expect_used, clippy::unwrap_used)]
+
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt, fs,
+    iter::Peekable,
+    path::PathBuf,
+    str::Chars,
+};
+
+use actix_web::{
+    http::{Method, StatusCode},
+    test::{call_service, init_service, read_body, read_body_json, TestRequest},
+    web, HttpRequest, HttpResponse,
+};
+use router::{configs::settings::Settings, routes::AppState, services};
+
+const HTTP_METHODS: [&str; 6] = ["get", "post", "put", "patch", "delete", "head"];
+
+/// A route registered with actix, identified by its HTTP method and normalized path.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+struct RegisteredRoute {
+    method: String,
+    path: String,
+}
+
+impl RegisteredRoute {
+    fn new(method: &str, path: &str) -> Self {
+        Self {
+            method: method.to_uppercase(),
+            path: normalize_path(path),
+        }
+    }
+}
+
+impl fmt::Display for RegisteredRoute {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.method, self.path)
+    }
+}
+
+/// An operation documented in the OpenAPI specification.
+#[derive(Debug)]
+struct DocumentedOperation {
+    /// Whether the request body of the operation, if any, is documented with a schema
+    has_request_body_schema: bool,
+    has_responses: bool,
+}
+
+/// Path segment of an allowlist entry standing in for a segment which depends on the
+/// configuration, such as a prefix. It matches any segment of a registered path.
+const DYNAMIC_SEGMENT: &str = "*";
+
+/// Replaces path parameter names with `{}`, since actix and the specification may name the same
+/// parameter differently, and drops trailing slashes.
+fn normalize_path(path: &str) -> String {
+    let mut normalized = String::with_capacity(path.len());
+    let mut in_parameter = false;
+    for character in path.chars() {
+        match character {
+            '{' => {
+                in_parameter = true;
+                normalized.push_str("{}");
+            }
+            '}' => in_parameter = false,
+            _ if in_parameter => {}
+            _ => normalized.push(character),
+        }
+    }
+
+    let trimmed = normalized.trim_end_matches('/');
+    if trimmed.is_empty() {
+        "/".to_owned()
+    } else {
+        trimmed.to_owned()
+    }
+}
+
+/// Returns `true` if the `pattern` path is the `path`, with dynamic segments of the pattern
+/// matching any segment.
+fn path_matches(pattern: &str, path: &str) -> bool {
+    let pattern = pattern.split('/').collect::<Vec<_>>();
+    let path = path.split('/').collect::<Vec<_>>();
+    pattern.len() == path.len()
+        && pattern
+            .iter()
+            .zip(&path)
+            .all(|(pattern, path)| *pattern == DYNAMIC_SEGMENT || pattern == path)
+}
+
+fn repository_root() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .and_then(|crates| crates.parent())
+        .expect("Failed to find repository root")
+        .to_path_buf()
+}
+
+/// Path of the route added to the application by the test to list the registered resources.
+const RESOURCES_PATH: &str = "/__openapi_route_coverage/resources";
+
+/// Value parsed from the `Debug` representation of a value.
+#[derive(Debug)]
+enum DebugValue {
+    /// String literal, or any other value without fields
+    Text(String),
+    /// Struct with named fields
+    Struct(String, Vec<(String, DebugValue)>),
+    /// Tuple struct, enum variant, or unnamed tuple
+    Tuple(String, Vec<DebugValue>),
+    /// List, set, or the keys and values of a map
+    Sequence(Vec<DebugValue>),
+}
+
+impl DebugValue {
+    fn field(&self, name: &str) -> Option<&Self> {
+        match self {
+            Self::Struct(_, fields) => fields
+                .iter()
+                .find(|(field, _)| field == name)
+                .map(|(_, value)| value),
+            Self::Text(_) | Self::Tuple(..) | Self::Sequence(_) => None,
+        }
+    }
+}
+
+/// Parser of the `{:?}` representation of a value. It only needs to understand the output of
+/// derived `Debug` implementations and of the standard library types.
+struct DebugParser<'a> {
+    characters: Peekable<Chars<'a>>,
+}
+
+impl<'a> DebugParser<'a> {
+    fn parse(representation: &'a str) -> DebugValue {
+        let mut parser = Self {
+            characters: representation.chars().peekable(),
+        };
+        parser.value()
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self
+            .characters
+            .next_if(|character| character.is_whitespace())
+            .is_some()
+        {}
+    }
+
+    fn peek(&mut self) -> Option<char> {
+        self.skip_whitespace();
+        self.characters.peek().copied()
+    }
+
+    fn string(&mut self, quote: char) -> String {
+        let mut text = String::new();
+        while let Some(character) = self.characters.next() {
+            match character {
+                '\\' => text.extend(self.characters.next()),
+                _ if character == quote => break,
+                _ => text.push(character),
+            }
+        }
+        text
+    }
+
+    fn word(&mut self) -> String {
+        let mut word = String::new();
+        while let Some(character) = self
+            .characters
+            .next_if(|character| !character.is_whitespace() && !"{}()[],:\"'".contains(*character))
+        {
+            word.push(character);
+        }
+        // Paths such as `core::cell::RefCell` contain `::`
+        while self.characters.peek() == Some(&':') {
+            let mut lookahead = self.characters.clone();
+            lookahead.next();
+            if lookahead.peek() != Some(&':') {
+                break;
+            }
+            self.characters.nth(1);
+            word.push_str("::");
+            word.push_str(&self.word());
+        }
+        word
+    }
+
+    /// Parses values up to the `close` delimiter, consuming it.
+    fn values(&mut self, close: char) -> Vec<DebugValue> {
+        let mut values = Vec::new();
+        loop {
+            match self.peek() {
+                None => break,
+                Some(character) if character == close => {
+                    self.characters.next();
+                    break;
+                }
+                // Separators, and closing delimiters which do not belong to a value
+                Some(',' | ':' | ')' | ']' | '}') => {
+                    self.characters.next();
+                }
+                Some(_) => values.push(self.value()),
+            }
+        }
+        values
+    }
+
+    /// Parses named fields up to the closing brace, consuming it.
+    fn fields(&mut self) -> Vec<(String, DebugValue)> {
+        let mut fields = Vec::new();
+        loop {
+            match self.peek() {
+                None => break,
+                Some('}') => {
+                    self.characters.next();
+                    break;
+                }
+                Some(',') => {
+                    self.characters.next();
+                }
+                Some(_) => {
+                    let name = self.word();
+                    if self.peek() == Some(':') {
+                        self.characters.next();
+                        fields.push((name, self.value()));
+                    } else if name.is_empty() {
+                        // Not a field, skip the unexpected character
+                        self.characters.next();
+                    }
+                }
+            }
+        }
+        fields
+    }
+
+    fn value(&mut self) -> DebugValue {
+        match self.peek() {
+            Some(quote @ ('"' | '\'')) => {
+                self.characters.next();
+                DebugValue::Text(self.string(quote))
+            }
+            Some('[') => {
+                self.characters.next();
+                DebugValue::Sequence(self.values(']'))
+            }
+            Some('{') => {
+                self.characters.next();
+                DebugValue::Sequence(self.values('}'))
+            }
+            Some('(') => {
+                self.characters.next();
+                DebugValue::Tuple(String::new(), self.values(')'))
+            }
+            _ => {
+                let name = self.word();
+                match self.peek() {
+                    Some('{') => {
+                        self.characters.next();
+                        DebugValue::Struct(name, self.fields())
+                    }
+                    Some('(') => {
+                        self.characters.next();
+                        DebugValue::Tuple(name, self.values(')'))
+                    }
+                    _ => DebugValue::Text(name),
+                }
+            }
+        }
+    }
+}
+
+/// Collects the full path patterns of the resources in the `Debug` representation of an actix
+/// `ResourceMap`, whose nodes are the scopes and resources registered with the application.
+fn collect_resource_patterns(
+    resource_map: &DebugValue,
+    prefix: &str,
+    patterns: &mut BTreeSet<String>,
+) {
+    let resource_patterns = match resource_map
+        .field("pattern")
+        .and_then(|pattern| pattern.field("patterns"))
+    {
+        Some(DebugValue::Tuple(_, patterns)) => patterns
+            .iter()
+            .flat_map(|pattern| match pattern {
+                DebugValue::Text(pattern) => vec![pattern.as_str()],
+                DebugValue::Sequence(patterns) => patterns
+                    .iter()
+                    .filter_map(|pattern| match pattern {
+                        DebugValue::Text(pattern) => Some(pattern.as_str()),
+                        DebugValue::Struct(..)
+                        | DebugValue::Tuple(..)
+                        | DebugValue::Sequence(_) => None,
+                    })
+                    .collect(),
+                DebugValue::Struct(..) | DebugValue::Tuple(..) => Vec::new(),
+            })
+            .collect::<Vec<_>>(),
+        _ => panic!("Unexpected resource map representation: {resource_map:?}"),
+    };
+    let nodes = match resource_map.field("nodes") {
+        Some(DebugValue::Tuple(variant, nodes)) if variant == "Some" => match nodes.as_slice() {
+            [DebugValue::Sequence(nodes)] => Some(nodes),
+            _ => panic!("Unexpected resource map representation: {resource_map:?}"),
+        },
+        _ => None,
+    };
+
+    for resource_pattern in resource_patterns {
+        let pattern = format!("{prefix}{resource_pattern}");
+        match nodes {
+            Some(nodes) => nodes
+                .iter()
+                .for_each(|node| collect_resource_patterns(node, &pattern, patterns)),
+            None => {
+                patterns.insert(pattern);
+            }
+        }
+    }
+}
+
+/// Replaces the dynamic segments of an actix path pattern with a value, giving a path which the
+/// pattern matches.
+fn concrete_path(pattern: &str) -> String {
+    let mut path = String::with_capacity(pattern.len());
+    let mut characters = pattern.chars().peekable();
+    let mut depth = 0_usize;
+    while let Some(character) = characters.next() {
+        match character {
+            '{' => {
+                if depth == 0 {
+                    path.push_str("test");
+                }
+                depth += 1;
+            }
+            '}' => {
+                depth = depth.saturating_sub(1);
+                // Tail segments are written as `{tail}*`
+                if depth == 0 {
+                    characters.next_if_eq(&'*');
+                }
+            }
+            _ if depth > 0 => {}
+            _ => path.push(character),
+        }
+    }
+    path
+}
+
+/// Lists the path patterns of the resources of the application which handles the request.
+/// Resources which are shadowed by resources registered before them are left out, since they
+/// can never be reached.
+async fn list_resources(request: HttpRequest) -> HttpResponse {
+    let resource_map = request.resource_map();
+    let mut patterns = BTreeSet::new();
+    collect_resource_patterns(
+        &DebugParser::parse(&format!("{resource_map:?}")),
+        "",
+        &mut patterns,
+    );
+
+    HttpResponse::Ok().json(
+        patterns
+            .into_iter()
+            .filter(|pattern| pattern != RESOURCES_PATH)
+            .filter(|pattern| {
+                resource_map.match_pattern(&concrete_path(pattern)).as_ref() == Some(pattern)
+            })
+            .collect::<Vec<_>>(),
+    )
+}
+
+/// Builds the application and returns the routes it handles.
+///
+/// A resource handles a method unless requesting it with the method is answered with
+/// `405 Method Not Allowed`, or with an empty `404 Not Found` when a guard of the resource or of
+/// its scopes rejects the request.
+async fn registered_routes() -> BTreeSet<RegisteredRoute> {
+    let conf = Settings::new().expect("invalid settings");
+    let request_body_limit = conf.server.request_body_limit;
+    let tx: tokio::sync::oneshot::Sender<()> = tokio::sync::oneshot::channel().0;
+    let app_state = Box::pin(AppState::with_storage(
+        conf,
+        router::db::StorageImpl::Mock,
+        tx,
+        Box::new(services::MockApiClient),
+        env!("CARGO_PKG_NAME"),
+    ))
+    .await;
+    let app = init_service(
+        router::mk_app(app_state, request_body_limit)
+            .route(RESOURCES_PATH, web::get().to(list_resources)),
+    )
+    .await;
+
+    let request = TestRequest::get().uri(RESOURCES_PATH).to_request();
+    let patterns: Vec<String> = read_body_json(call_service(&app, request).await).await;
+
+    let mut routes = BTreeSet::new();
+    for pattern in patterns {
+        let path = concrete_path(&pattern);
+        for method in HTTP_METHODS {
+            let request = TestRequest::default()
+                .method(Method::from_bytes(method.to_uppercase().as_bytes()).unwrap())
+                .uri(&path)
+                .to_request();
+            let response = call_service(&app, request).await;
+            let status = response.status();
+            let is_handled = match status {
+                StatusCode::METHOD_NOT_ALLOWED => false,
+                StatusCode::NOT_FOUND => !read_body(response).await.is_empty(),
+                _ => true,
+            };
+            if is_handled {
+                routes.insert(RegisteredRoute::new(method, &pattern));
+            }
+        }
+    }
+    routes
+}
+
+fn documented_operations(version: &str) -> BTreeMap<RegisteredRoute, DocumentedOperation> {
+    let spec_path = repository_root().join(format!(
+        "api-reference/{version}/openapi_spec_{version}.json"
+    ));
+    let spec: serde_json::Value = serde_json::from_str(
+        &fs::read_to_string(&spec_path).expect("Failed to read OpenAPI specification"),
+    )
+    .expect("Failed to parse OpenAPI specification");
+
+    let mut operations = BTreeMap::new();
+    for (path, path_item) in spec["paths"].as_object().into_iter().flatten() {
+        for method in HTTP_METHODS {
+            let Some(operation) = path_item.get(method) else {
+                continue;
+            };
+            operations.insert(
+                RegisteredRoute::new(method, path),
+                DocumentedOperation {
+                    has_request_body_schema: operation.get("requestBody").is_none_or(
+                        |request_body| {
+                            request_body["content"].as_object().is_some_and(|content| {
+                                !content.is_empty()
+                                    && content.values().all(|media| media.get("schema").is_some())
+                            })
+                        },
+                    ),
+                    has_responses: operation["responses"]
+                        .as_object()
+                        .is_some_and(|responses| !responses.is_empty()),
+                },
+            );
+        }
+    }
+    operations
+}
+
+/// Reads the allowlist of routes which are not expected to be documented. Each line contains
+/// a method and a path, blank lines and lines starting with `#` are ignored.
+fn allowlisted_routes(version: &str) -> BTreeSet<RegisteredRoute> {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join(format!("tests/openapi_route_allowlist/{version}.txt"));
+    fs::read_to_string(&path)
+        .expect("Failed to read OpenAPI route allowlist")
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(|line| {
+            let (method, path) = line
+                .split_once(' ')
+                .unwrap_or_else(|| panic!("Invalid allowlist entry `{line}`"));
+            RegisteredRoute::new(method, path.trim())
+        })
+        .collect()
+}
+
+/// Returns the problems with the documentation of a single route.
+fn find_route_problems(
+    route: &RegisteredRoute,
+    operations: &BTreeMap<RegisteredRoute, DocumentedOperation>,
+) -> Vec<String> {
+    let mut problems = Vec::new();
+    let documented = operations
+        .iter()
+        .filter(|(operation, _)| {
+            operation.method == route.method && path_matches(&route.path, &operation.path)
+        })
+        .map(|(_, operation)| operation)
+        .collect::<Vec<_>>();
+
+    if documented.is_empty() {
+        problems.push(format!(
+            "{route}: no OpenAPI operation documents this route"
+        ));
+    }
+    if documented.iter().any(|operation| !operation.has_responses) {
+        problems.push(format!(
+            "{route}: the OpenAPI operation has no response schemas"
+        ));
+    }
+    if documented
+        .iter()
+        .any(|operation| !operation.has_request_body_schema)
+    {
+        problems.push(format!(
+            "{route}: the OpenAPI operation has no request body schema"
+        ));
+    }
+    problems
+}
+
+/// Returns the problems found with the documentation of `routes`, one per line.
+///
+/// Allowlisted routes which are fully documented are reported as well. Allowlisted routes which
+/// are not registered are not, since the registered routes depend on the enabled features.
+fn find_coverage_problems(
+    routes: &BTreeSet<RegisteredRoute>,
+    operations: &BTreeMap<RegisteredRoute, DocumentedOperation>,
+    allowlist: &BTreeSet<RegisteredRoute>,
+) -> Vec<String> {
+    let is_allowlisted = |route: &RegisteredRoute| {
+        allowlist
+            .iter()
+            .any(|entry| entry.method == route.method && path_matches(&entry.path, &route.path))
+    };
+
+    let mut problems = routes
+        .iter()
+        .filter(|route| !is_allowlisted(route))
+        .flat_map(|route| find_route_problems(route, operations))
+        .collect::<Vec<_>>();
+
+    problems.extend(
+        allowlist
+            .iter()
+            .filter(|route| find_route_problems(route, operations).is_empty())
+            .map(|route| {
+                format!(
+                    "{route}: the allowlisted route is documented, remove it from the allowlist"
+                )
+            }),
+    );
+
+    problems
+}
+
+async fn assert_routes_documented(version: &str) {
+    let routes = registered_routes().await;
+    assert!(!routes.is_empty(), "No routes were registered");
+
+    let problems = find_coverage_problems(
+        &routes,
+        &documented_operations(version),
+        &allowlisted_routes(version),
+    );
+    assert!(
+        problems.is_empty(),
+        "The {version} OpenAPI specification does not cover all registered routes. Document the \
+         routes with `utoipa::path` in the `openapi` crate and regenerate the specification, or \
+         add internal routes to `tests/openapi_route_allowlist/{version}.txt`:\n{}",
+        problems.join("\n")
+    );
+}
+
+#[cfg(feature = "v1")]
+#[actix_web::test]
+async fn v1_routes_are_documented_in_openapi_specification() {
+    assert_routes_documented("v1").await;
+}
+
+#[cfg(feature = "v2")]
+#[actix_web::test]
+async fn v2_routes_are_documented_in_openapi_specification() {
+    assert_routes_documented("v2").await;
+}
+
+#[test]
+fn coverage_problems_report_undocumented_and_stale_routes() {
+    let routes = BTreeSet::from([
+        RegisteredRoute::new("post", "/examples"),
+        RegisteredRoute::new("get", "/examples/{example_id}"),
+        RegisteredRoute::new("get", "/health"),
+        RegisteredRoute::new("get", "/prefix/internal"),
+    ]);
+    let operations = BTreeMap::from([
+        (
+            RegisteredRoute::new("post", "/examples"),
+            DocumentedOperation {
+                has_request_body_schema: false,
+                has_responses: true,
+            },
+        ),
+        (
+            RegisteredRoute::new("get", "/documented"),
+            DocumentedOperation {
+                has_request_body_schema: true,
+                has_responses: true,
+            },
+        ),
+    ]);
+    let allowlist = BTreeSet::from([
+        RegisteredRoute::new("get", "/health"),
+        RegisteredRoute::new("get", "/*/internal"),
+        RegisteredRoute::new("get", "/documented"),
+    ]);
+
+    assert_eq!(
+        find_coverage_problems(&routes, &operations, &allowlist),
+        vec![
+            "GET /examples/{}: no OpenAPI operation documents this route",
+            "POST /examples: the OpenAPI operation has no request body schema",
+            "GET /documented: the allowlisted route is documented, remove it from the allowlist",
+        ]
+    );
+}
+
+#[test]
+fn resource_patterns_are_collected_from_resource_map_representation() {
+    let resource = |patterns: &str, nodes: &str| {
+        format!(
+            "ResourceMap {{ pattern: ResourceDef {{ id: 0, name: None, is_prefix: false, \
+             patterns: {patterns}, segments: [Const(\"/\"), Var(\"id\")] }}, named: {{}}, \
+             parent: RefCell {{ value: (Weak) }}, nodes: {nodes} }}"
+        )
+    };
+    let payment = resource("Single(\"/{payment_id}\")", "None");
+    let payment_cancel = resource("Single(\"/{payment_id}/cancel\")", "None");
+    let payments = resource(
+        "Single(\"/payments\")",
+        &format!("Some([{payment}, {payment_cancel}])"),
+    );
+    let health = resource("List([\"/health\", \"/health/{tail:.*}\"])", "None");
+    let root = resource("Single(\"\")", &format!("Some([{payments}, {health}])"));
+
+    let mut patterns = BTreeSet::new();
+    collect_resource_patterns(&DebugParser::parse(&root), "", &mut patterns);
+
+    assert_eq!(
+        patterns,
+        BTreeSet::from([
+            "/health".to_owned(),
+            "/health/{tail:.*}".to_owned(),
+            "/payments/{payment_id}".to_owned(),
+            "/payments/{payment_id}/cancel".to_owned(),
+        ])
+    );
+    assert_eq!(
+        concrete_path("/payments/{payment_id}/cancel"),
+        "/payments/test/cancel"
+    );
+    assert_eq!(concrete_path("/files/{tail}*"), "/files/test");
+    assert_eq!(concrete_path("/health/{tail:.*}"), "/health/test");
+}
@@ -239,6 +239,7 @@ async fn payments_create_core() {
         is_stored_credential: None,
         request_extended_authorization: None,
         billing_descriptor: None,
+        attribution_tags: None,
         partner_merchant_identifier_details: None,
         payment_method_tokenization_details: None,
         error_details: None,
@@ -554,6 +555,7 @@ async fn payments_create_core_adyen_no_redirect() {
             is_stored_credential: None,
             request_extended_authorization: None,
             billing_descriptor: None,
+            attribution_tags: None,
             partner_merchant_identifier_details: None,
             payment_method_tokenization_details: None,
             error_details: None,
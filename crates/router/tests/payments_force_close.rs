@@ -0,0 +1,124 @@
+#![cfg(feature = "v1")]
+
+use std::sync::atomic::Ordering;
+
+use actix_web::{
+    http::StatusCode,
+    test::{call_service, init_service, read_body_json, TestRequest},
+};
+use router::types::storage::{self, enums};
+use serde_json::{json, Value};
+
+mod utils;
+
+use utils::stripe::{self, StripePayment, ADMIN_API_KEY};
+
+const FORCE_CLOSE_REASON: &str = "Payment stuck at the connector";
+
+struct ForceCloseOutcome {
+    status_code: StatusCode,
+    response: Value,
+    payment_intent: storage::PaymentIntent,
+    connector_requests: usize,
+}
+
+/// Creates a Stripe payment stuck in the given statuses, and force-closes it through the API while
+/// Stripe reports the payment intent in `stripe_status`
+async fn force_close_payment(
+    intent_status: enums::IntentStatus,
+    attempt_status: enums::AttemptStatus,
+    stripe_status: &'static str,
+) -> ForceCloseOutcome {
+    let (stripe_base_url, connector_requests) = stripe::start_stripe_mock(stripe_status);
+    let app_state = stripe::mk_app_state(stripe_base_url).await;
+    let request_body_limit = app_state.conf.server.request_body_limit;
+    let app = init_service(router::mk_app(app_state.clone(), request_body_limit)).await;
+    let payment = Box::pin(StripePayment::create(&app, app_state)).await;
+    payment.set_statuses(intent_status, attempt_status).await;
+
+    let merchant_id = payment.merchant_id.get_string_repr();
+    let request = TestRequest::post()
+        .uri(&format!(
+            "/payments/{}/force-close",
+            payment.payment_id.get_string_repr()
+        ))
+        .insert_header(("api-key", ADMIN_API_KEY))
+        .insert_header(("X-Merchant-Id", merchant_id))
+        .set_json(json!({ "merchant_id": merchant_id, "reason": FORCE_CLOSE_REASON }))
+        .to_request();
+    let response = call_service(&app, request).await;
+    let status_code = response.status();
+    let response: Value = read_body_json(response).await;
+
+    ForceCloseOutcome {
+        status_code,
+        response,
+        payment_intent: payment.payment_intent().await,
+        connector_requests: connector_requests.load(Ordering::SeqCst),
+    }
+}
+
+#[actix_web::test]
+async fn payment_unresolved_by_connector_is_force_closed() {
+    let outcome = Box::pin(force_close_payment(
+        enums::IntentStatus::Processing,
+        enums::AttemptStatus::Pending,
+        "processing",
+    ))
+    .await;
+
+    assert_eq!(outcome.status_code, StatusCode::OK);
+    assert_eq!(outcome.connector_requests, 1);
+    assert_eq!(outcome.response["force_closed"], json!(true));
+    assert_eq!(outcome.response["intent_status"], json!("failed"));
+    assert_eq!(outcome.response["attempt_status"], json!("failure"));
+    assert_eq!(outcome.payment_intent.status, enums::IntentStatus::Failed);
+    let force_close = outcome
+        .payment_intent
+        .state_metadata
+        .and_then(|state_metadata| state_metadata.force_close)
+        .unwrap();
+    assert_eq!(force_close.reason, FORCE_CLOSE_REASON);
+}
+
+#[actix_web::test]
+async fn payment_resolved_by_connector_is_not_force_closed() {
+    let outcome = Box::pin(force_close_payment(
+        enums::IntentStatus::Processing,
+        enums::AttemptStatus::Pending,
+        "succeeded",
+    ))
+    .await;
+
+    assert_eq!(outcome.status_code, StatusCode::OK);
+    assert_eq!(outcome.connector_requests, 1);
+    assert_eq!(outcome.response["force_closed"], json!(false));
+    assert_eq!(outcome.response["intent_status"], json!("succeeded"));
+    assert_eq!(outcome.response["attempt_status"], json!("charged"));
+    assert_eq!(
+        outcome.payment_intent.status,
+        enums::IntentStatus::Succeeded
+    );
+    assert!(!outcome
+        .payment_intent
+        .state_metadata
+        .is_some_and(|state_metadata| state_metadata.is_force_closed()));
+}
+
+#[actix_web::test]
+async fn payment_not_processing_cannot_be_force_closed() {
+    let outcome = Box::pin(force_close_payment(
+        enums::IntentStatus::RequiresCustomerAction,
+        enums::AttemptStatus::AuthenticationPending,
+        "requires_action",
+    ))
+    .await;
+
+    assert_eq!(outcome.status_code, StatusCode::BAD_REQUEST);
+    assert_eq!(outcome.connector_requests, 0);
+    assert_eq!(
+        outcome.payment_intent.status,
+        enums::IntentStatus::RequiresCustomerAction
+    );
+    assert!(outcome.payment_intent.state_metadata.is_none());
+}
@@ -0,0 +1,94 @@
+#![cfg(feature = "v1")]
+
+use actix_web::{
+    http::StatusCode,
+    test::{call_service, init_service, TestRequest},
+};
+use common_utils::crypto::{HmacSha256, SignMessage};
+use router::types::storage::enums;
+use serde_json::json;
+
+mod utils;
+
+use utils::stripe::{self, StripePayment, PAYMENT_INTENT_ID, WEBHOOK_SECRET};
+
+/// Number of times the webhook and the redirect completion are raced against each other
+const RACES: usize = 10;
+
+/// Signs the webhook body the way Stripe does, with the secret of the connector account
+fn get_stripe_signature(body: &str) -> String {
+    let timestamp = time::OffsetDateTime::now_utc().unix_timestamp();
+    let signature = HmacSha256
+        .sign_message(
+            WEBHOOK_SECRET.as_bytes(),
+            format!("{timestamp}.{body}").as_bytes(),
+        )
+        .unwrap();
+
+    format!("t={timestamp},v1={}", hex::encode(signature))
+}
+
+/// The webhook reports a Stripe 3DS payment as succeeded, while the redirect completion fetches a
+/// stale payment intent which still requires the customer's action. Both are sent concurrently
+/// through the API, and hold the status update lock of the payment, so the payment ends up
+/// succeeded whichever of them is processed first.
+#[actix_web::test]
+async fn stripe_3ds_webhook_and_redirect_race_never_downgrades_terminal_status() {
+    let (stripe_base_url, _) = stripe::start_stripe_mock("requires_action");
+    let app_state = stripe::mk_app_state(stripe_base_url).await;
+    let request_body_limit = app_state.conf.server.request_body_limit;
+    let app = init_service(router::mk_app(app_state.clone(), request_body_limit)).await;
+    let payment = Box::pin(StripePayment::create(&app, app_state)).await;
+    let merchant_id = payment.merchant_id.get_string_repr();
+    let redirect_uri = format!(
+        "/payments/{}/{merchant_id}/redirect/response/stripe",
+        payment.payment_id.get_string_repr()
+    );
+    let webhook_body = json!({
+        "type": "payment_intent.succeeded",
+        "data": {
+            "object": {
+                "id": PAYMENT_INTENT_ID,
+                "object": "payment_intent",
+                "amount": 1000,
+                "currency": "usd",
+                "created": 1_700_000_000,
+                "status": "succeeded",
+            },
+        },
+    })
+    .to_string();
+
+    for _ in 0..RACES {
+        payment
+            .set_statuses(
+                enums::IntentStatus::RequiresCustomerAction,
+                enums::AttemptStatus::AuthenticationPending,
+            )
+            .await;
+
+        let webhook = TestRequest::post()
+            .uri(&format!("/webhooks/{merchant_id}/stripe"))
+            .insert_header(("Content-Type", "application/json"))
+            .insert_header(("Stripe-Signature", get_stripe_signature(&webhook_body)))
+            .set_payload(webhook_body.clone())
+            .to_request();
+        let redirect = TestRequest::get()
+            .uri(&format!(
+                "{redirect_uri}?payment_intent={PAYMENT_INTENT_ID}"
+            ))
+            .to_request();
+        let (webhook_response, _) =
+            tokio::join!(call_service(&app, webhook), call_service(&app, redirect));
+
+        assert_eq!(webhook_response.status(), StatusCode::OK);
+        assert_eq!(
+            payment.payment_attempt().await.status,
+            enums::AttemptStatus::Charged
+        );
+        assert_eq!(
+            payment.payment_intent().await.status,
+            enums::IntentStatus::Succeeded
+        );
+    }
+}
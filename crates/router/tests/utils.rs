@@ -21,9 +21,10 @@ static SERVER: OnceCell<bool> = OnceCell::const_new();
 
 async fn spawn_server() -> bool {
     let conf = Settings::new().expect("invalid settings");
-    let server = Box::pin(router::start_server(conf, env!("CARGO_PKG_NAME")))
-        .await
-        .expect("failed to create server");
+    let (server, _shutdown_task_handle) =
+        Box::pin(router::start_server(conf, env!("CARGO_PKG_NAME")))
+            .await
+            .expect("failed to create server");
 
     let _server = tokio::spawn(server.in_current_span());
     true
@@ -412,3 +413,296 @@ pub struct PaymentId {
 pub struct Status {
     status: String,
 }
+
+/// Stripe payments created through the API of an app backed by the mock store, with Stripe served
+/// by a local mock
+#[cfg(feature = "v1")]
+pub mod stripe {
+    use std::{
+        borrow::Cow,
+        sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc,
+        },
+    };
+
+    use actix_http::{body::MessageBody, Request};
+    use actix_web::{
+        dev::{Service, ServiceResponse},
+        http::StatusCode,
+        test::{call_service, read_body_json, TestRequest},
+        web, App, HttpResponse, HttpServer,
+    };
+    use common_utils::id_type;
+    use router::{
+        configs::settings::Settings,
+        db::StorageImpl,
+        routes::{AppState, SessionState},
+        services,
+        types::{
+            domain,
+            storage::{self, enums},
+        },
+    };
+    use serde_json::{json, Value};
+
+    pub const ADMIN_API_KEY: &str = "test_admin";
+    /// ID of the payment intent served by the Stripe mock
+    pub const PAYMENT_INTENT_ID: &str = "pi_mock";
+    /// Secret with which Stripe signs the webhooks of the connector account
+    pub const WEBHOOK_SECRET: &str = "whsec_mock";
+
+    /// Serves every request as the Stripe payment intent in the given status, counting the
+    /// requests
+    pub fn start_stripe_mock(status: &'static str) -> (String, Arc<AtomicUsize>) {
+        let requests = Arc::new(AtomicUsize::new(0));
+        let server_requests = requests.clone();
+        let server = HttpServer::new(move || {
+            let requests = server_requests.clone();
+            App::new().default_service(web::to(move || {
+                requests.fetch_add(1, Ordering::SeqCst);
+                async move {
+                    HttpResponse::Ok().json(json!({
+                        "id": PAYMENT_INTENT_ID,
+                        "object": "payment_intent",
+                        "amount": 1000,
+                        "currency": "usd",
+                        "status": status,
+                    }))
+                }
+            }))
+        })
+        .workers(1)
+        .bind(("127.0.0.1", 0))
+        .unwrap();
+        let base_url = format!("http://{}/", server.addrs()[0]);
+        tokio::spawn(server.run());
+
+        (base_url, requests)
+    }
+
+    /// Builds the state of an app on the mock store, calling Stripe at the given base URL
+    pub async fn mk_app_state(stripe_base_url: String) -> AppState {
+        let mut conf = Settings::new().expect("invalid settings");
+        conf.connectors.stripe.base_url = stripe_base_url;
+        let api_client = services::ProxyClient::new(&conf.proxy).unwrap();
+        let tx: tokio::sync::oneshot::Sender<()> = tokio::sync::oneshot::channel().0;
+        Box::pin(AppState::with_storage(
+            conf,
+            StorageImpl::Mock,
+            tx,
+            Box::new(api_client),
+            env!("CARGO_PKG_NAME"),
+        ))
+        .await
+    }
+
+    pub struct StripePayment {
+        pub state: SessionState,
+        pub merchant_id: id_type::MerchantId,
+        pub payment_id: id_type::PaymentId,
+        pub key_store: domain::MerchantKeyStore,
+        pub storage_scheme: enums::MerchantStorageScheme,
+    }
+
+    impl StripePayment {
+        /// Creates a merchant with a Stripe connector account, and a payment confirmed with Stripe
+        /// as the payment intent [`PAYMENT_INTENT_ID`]
+        pub async fn create<S, B>(app: &S, app_state: AppState) -> Self
+        where
+            S: Service<Request, Response = ServiceResponse<B>, Error = actix_web::Error>,
+            B: MessageBody,
+        {
+            let merchant_id = format!("merchant_{}", uuid::Uuid::new_v4().simple());
+            let request = TestRequest::post()
+                .uri("/accounts")
+                .insert_header(("api-key", ADMIN_API_KEY))
+                .set_json(json!({ "merchant_id": merchant_id, "merchant_name": "Stripe Mock" }))
+                .to_request();
+            assert_eq!(call_service(app, request).await.status(), StatusCode::OK);
+
+            let request = TestRequest::post()
+                .uri(&format!("/account/{merchant_id}/connectors"))
+                .insert_header(("api-key", ADMIN_API_KEY))
+                .set_json(json!({
+                    "connector_type": "payment_processor",
+                    "connector_name": "stripe",
+                    "connector_account_details": {
+                        "auth_type": "HeaderKey",
+                        "api_key": "sk_test_mock",
+                    },
+                    "connector_webhook_details": {
+                        "merchant_secret": WEBHOOK_SECRET,
+                    },
+                    "test_mode": true,
+                    "disabled": false,
+                }))
+                .to_request();
+            let connector: Value = read_body_json(call_service(app, request).await).await;
+            let merchant_connector_id = id_type::MerchantConnectorAccountId::wrap(
+                connector["merchant_connector_id"]
+                    .as_str()
+                    .unwrap()
+                    .to_string(),
+            )
+            .unwrap();
+
+            let request = TestRequest::post()
+                .uri(&format!("/api_keys/{merchant_id}"))
+                .insert_header(("api-key", ADMIN_API_KEY))
+                .set_json(json!({ "name": "Stripe Mock", "expiration": "never" }))
+                .to_request();
+            let api_key: Value = read_body_json(call_service(app, request).await).await;
+
+            let request = TestRequest::post()
+                .uri("/payments")
+                .insert_header(("api-key", api_key["api_key"].as_str().unwrap()))
+                .set_json(json!({
+                    "amount": 1000,
+                    "currency": "USD",
+                    "confirm": false,
+                    "return_url": "https://example.com/return",
+                }))
+                .to_request();
+            let payment: Value = read_body_json(call_service(app, request).await).await;
+
+            let state = Arc::new(app_state)
+                .get_session_state(
+                    &id_type::TenantId::try_from_string("public".to_string()).unwrap(),
+                    None,
+                    || {},
+                )
+                .unwrap();
+            let merchant_id = id_type::MerchantId::try_from(Cow::from(merchant_id)).unwrap();
+            let payment_id = id_type::PaymentId::try_from(Cow::from(
+                payment["payment_id"].as_str().unwrap().to_string(),
+            ))
+            .unwrap();
+            let key_store = state
+                .store
+                .get_merchant_key_store_by_merchant_id(
+                    &merchant_id,
+                    &state.store.get_master_key().to_vec().into(),
+                )
+                .await
+                .unwrap();
+            let storage_scheme = state
+                .store
+                .find_merchant_account_by_merchant_id(&merchant_id, &key_store)
+                .await
+                .unwrap()
+                .storage_scheme;
+            let stripe_payment = Self {
+                state,
+                merchant_id,
+                payment_id,
+                key_store,
+                storage_scheme,
+            };
+
+            // Record the payment as sent to Stripe, as a confirm would
+            let mut payment_attempt = stripe_payment.payment_attempt().await;
+            let attempt_updates = [
+                storage::PaymentAttemptUpdate::UpdateTrackers {
+                    payment_token: None,
+                    connector: Some("stripe".to_string()),
+                    straight_through_algorithm: None,
+                    amount_capturable: None,
+                    surcharge_amount: None,
+                    tax_amount: None,
+                    updated_by: storage_scheme.to_string(),
+                    merchant_connector_id: Some(merchant_connector_id),
+                    routing_approach: None,
+                    is_stored_credential: None,
+                },
+                storage::PaymentAttemptUpdate::ConnectorResponse {
+                    authentication_data: None,
+                    encoded_data: None,
+                    connector_transaction_id: Some(PAYMENT_INTENT_ID.to_string()),
+                    connector: Some("stripe".to_string()),
+                    charges: None,
+                    updated_by: storage_scheme.to_string(),
+                },
+            ];
+            for attempt_update in attempt_updates {
+                payment_attempt = stripe_payment
+                    .state
+                    .store
+                    .update_payment_attempt_with_attempt_id(
+                        payment_attempt,
+                        attempt_update,
+                        storage_scheme,
+                        &stripe_payment.key_store,
+                    )
+                    .await
+                    .unwrap();
+            }
+
+            stripe_payment
+        }
+
+        pub async fn payment_intent(&self) -> storage::PaymentIntent {
+            self.state
+                .store
+                .find_payment_intent_by_payment_id_processor_merchant_id(
+                    &self.payment_id,
+                    &self.merchant_id,
+                    &self.key_store,
+                    self.storage_scheme,
+                )
+                .await
+                .unwrap()
+        }
+
+        pub async fn payment_attempt(&self) -> storage::PaymentAttempt {
+            let payment_intent = self.payment_intent().await;
+            self.state
+                .store
+                .find_payment_attempt_by_payment_id_processor_merchant_id_attempt_id(
+                    &self.payment_id,
+                    &self.merchant_id,
+                    &payment_intent.active_attempt.get_id(),
+                    self.storage_scheme,
+                    &self.key_store,
+                )
+                .await
+                .unwrap()
+        }
+
+        /// Moves the payment to the given statuses, as a manual update would
+        pub async fn set_statuses(
+            &self,
+            intent_status: enums::IntentStatus,
+            attempt_status: enums::AttemptStatus,
+        ) {
+            self.state
+                .store
+                .update_payment_attempt_with_attempt_id(
+                    self.payment_attempt().await,
+                    storage::PaymentAttemptUpdate::StatusUpdate {
+                        status: attempt_status,
+                        updated_by: self.storage_scheme.to_string(),
+                    },
+                    self.storage_scheme,
+                    &self.key_store,
+                )
+                .await
+                .unwrap();
+            self.state
+                .store
+                .update_payment_intent(
+                    self.payment_intent().await,
+                    storage::PaymentIntentUpdate::PGStatusUpdate {
+                        status: intent_status,
+                        incremental_authorization_allowed: None,
+                        updated_by: self.storage_scheme.to_string(),
+                        feature_metadata: None,
+                    },
+                    &self.key_store,
+                    self.storage_scheme,
+                )
+                .await
+                .unwrap();
+        }
+    }
+}
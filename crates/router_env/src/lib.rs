@@ -13,6 +13,7 @@ pub mod metrics;
 pub mod request_id;
 #[cfg(feature = "actix_web")]
 pub mod root_span;
+pub mod trace_link;
 /// `cargo` build instructions generation for obtaining information about the application
 /// environment.
 #[cfg(feature = "vergen")]
@@ -13,6 +13,19 @@ use crate::{config, FormattingLayer, StorageSubscription};
 #[derive(Debug)]
 pub struct TelemetryGuard {
     _log_guards: Vec<WorkerGuard>,
+    meter_provider: Option<opentelemetry_sdk::metrics::SdkMeterProvider>,
+}
+
+impl Drop for TelemetryGuard {
+    fn drop(&mut self) {
+        // Export the metrics recorded since the last periodic export, such as those recorded
+        // while the application was shutting down
+        if let Some(meter_provider) = self.meter_provider.take() {
+            if let Err(error) = meter_provider.shutdown() {
+                tracing::error!(?error, "Failed to flush the metrics on shutdown");
+            }
+        }
+    }
 }
 
 /// Setup logging sub-system specifying the logging configuration, service (binary) name, and a
@@ -33,8 +46,10 @@ pub fn setup(
         None
     };
 
-    if config.telemetry.metrics_enabled {
+    let meter_provider = if config.telemetry.metrics_enabled {
         setup_metrics_pipeline(&config.telemetry)
+    } else {
+        None
     };
 
     // Setup file logging
@@ -116,6 +131,7 @@ pub fn setup(
     // dropped
     Ok(TelemetryGuard {
         _log_guards: guards,
+        meter_provider,
     })
 }
 
@@ -292,7 +308,9 @@ fn setup_tracing_pipeline(
     )
 }
 
-fn setup_metrics_pipeline(config: &config::LogTelemetry) {
+fn setup_metrics_pipeline(
+    config: &config::LogTelemetry,
+) -> Option<opentelemetry_sdk::metrics::SdkMeterProvider> {
     use opentelemetry_otlp::WithExportConfig;
 
     let exporter_result = opentelemetry_otlp::MetricExporter::builder()
@@ -306,7 +324,7 @@ fn setup_metrics_pipeline(config: &config::LogTelemetry) {
         exporter_result
             .inspect_err(|error| eprintln!("Failed to build metrics exporter: {error:?}"))
             .ok();
-        return;
+        return None;
     } else {
         // Safety: This is conditional, there is an option to avoid this behavior at runtime.
         #[allow(clippy::expect_used)]
@@ -332,7 +350,8 @@ fn setup_metrics_pipeline(config: &config::LogTelemetry) {
         ]))
         .build();
 
-    opentelemetry::global::set_meter_provider(provider);
+    opentelemetry::global::set_meter_provider(provider.clone());
+    Some(provider)
 }
 
 fn get_envfilter(
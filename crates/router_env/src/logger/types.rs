@@ -84,6 +84,20 @@ pub enum Flow {
     MerchantConnectorsRetrieve,
     /// Merchant account list
     MerchantAccountList,
+    /// Merchant data takeout create flow.
+    MerchantTakeoutCreate,
+    /// Merchant data takeout retrieve flow.
+    MerchantTakeoutRetrieve,
+    /// Merchant data takeout file download flow.
+    MerchantTakeoutFileDownload,
+    /// Merchant quota update flow.
+    MerchantQuotaUpdate,
+    /// Merchant quota delete flow.
+    MerchantQuotaDelete,
+    /// Merchant quota usage flow.
+    MerchantQuotaUsage,
+    /// Customer contact hashes rehash flow.
+    CustomerContactHashesRehash,
     /// Merchant Connectors update flow.
     MerchantConnectorsUpdate,
     /// Merchant Connectors delete flow.
@@ -108,6 +122,12 @@ pub enum Flow {
     ConfigKeyUpdate,
     /// ConfigKey Delete flow.
     ConfigKeyDelete,
+    /// Unmasked headers retrieve flow.
+    UnmaskedHeadersRetrieve,
+    /// Unmasked headers update flow.
+    UnmaskedHeadersUpdate,
+    /// Unmasked headers preview flow.
+    UnmaskedHeadersPreview,
     /// Customers create flow.
     CustomersCreate,
     /// Customers retrieve flow.
@@ -192,6 +212,8 @@ pub enum Flow {
     PaymentsCancel,
     /// Payments cancel post capture flow.
     PaymentsCancelPostCapture,
+    /// Payments order cancelled flow.
+    PaymentsOrderCancelled,
     /// Payments cancel post capture sync flow.
     PaymentsCancelPostCaptureSync,
     /// Payments approve flow.
@@ -639,6 +661,8 @@ pub enum Flow {
     PaymentsManualUpdate,
     /// Manually update payment status from dashboard (Review → Succeeded/Failed)
     PaymentsManualStatusUpdate,
+    /// Force-close a payment stuck in a non-terminal status, after syncing it with the connector
+    PaymentsForceClose,
     #[cfg(feature = "payouts")]
     /// Manually update the payout details like status, error code, error message etc.
     PayoutsManualUpdate,
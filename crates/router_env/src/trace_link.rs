@@ -0,0 +1,55 @@
+//! Linking of spans across requests.
+//!
+//! A flow spanning multiple requests, such as a payment completed after a redirect, is recorded
+//! as separate traces. The context of the span of an earlier request is stored as a W3C
+//! `traceparent` value, and the span of a later request is linked to it so that the traces can be
+//! correlated.
+
+use opentelemetry::trace::{SpanContext, SpanId, TraceContextExt, TraceFlags, TraceId, TraceState};
+use tracing_opentelemetry::OpenTelemetrySpanExt;
+
+const TRACEPARENT_VERSION: &str = "00";
+
+/// Context of the current span in the W3C `traceparent` format, absent if the span is not
+/// recorded by OpenTelemetry
+pub fn get_current_trace_context() -> Option<String> {
+    let context = tracing::Span::current().context();
+    let span = context.span();
+    let span_context = span.span_context();
+
+    span_context.is_valid().then(|| {
+        format!(
+            "{TRACEPARENT_VERSION}-{}-{}-{:02x}",
+            span_context.trace_id(),
+            span_context.span_id(),
+            span_context.trace_flags().to_u8()
+        )
+    })
+}
+
+/// Link the span to the span whose context is in the W3C `traceparent` format, the link is not
+/// added if the context is malformed
+pub fn link_span_to_trace_context(span: &tracing::Span, trace_context: &str) {
+    match parse_trace_context(trace_context) {
+        Some(span_context) => span.add_link(span_context),
+        None => tracing::warn!(trace_context, "Failed to parse the trace context to link"),
+    }
+}
+
+fn parse_trace_context(trace_context: &str) -> Option<SpanContext> {
+    let mut parts = trace_context.split('-');
+    let (version, trace_id, span_id, trace_flags) =
+        (parts.next()?, parts.next()?, parts.next()?, parts.next()?);
+    if version != TRACEPARENT_VERSION || parts.next().is_some() {
+        return None;
+    }
+
+    let span_context = SpanContext::new(
+        TraceId::from_hex(trace_id).ok()?,
+        SpanId::from_hex(span_id).ok()?,
+        TraceFlags::new(u8::from_str_radix(trace_flags, 16).ok()?),
+        true,
+        TraceState::default(),
+    );
+    span_context.is_valid().then_some(span_context)
+}
@@ -0,0 +1,51 @@
+#![allow(clippy::unwrap_used)]
+
+use opentelemetry::trace::{TraceContextExt, TracerProvider as _};
+use opentelemetry_sdk::{testing::trace::InMemorySpanExporter, trace::TracerProvider};
+use router_env::trace_link::{get_current_trace_context, link_span_to_trace_context};
+use tracing_subscriber::layer::SubscriberExt;
+
+#[test]
+fn redirect_completion_span_is_linked_to_authorize_span() {
+    let exporter = InMemorySpanExporter::default();
+    let provider = TracerProvider::builder()
+        .with_simple_exporter(exporter.clone())
+        .build();
+    let subscriber = tracing_subscriber::registry()
+        .with(tracing_opentelemetry::layer().with_tracer(provider.tracer("router_env_test")));
+
+    tracing::subscriber::with_default(subscriber, || {
+        // The authorize and the redirect completion are separate requests, with unrelated traces
+        let authorize_trace_context = tracing::info_span!("authorize")
+            .in_scope(get_current_trace_context)
+            .unwrap();
+
+        let redirect_completion_span =
+            tracing::info_span!(parent: None, "redirect_completion_sync");
+        link_span_to_trace_context(&redirect_completion_span, &authorize_trace_context);
+        drop(redirect_completion_span);
+    });
+
+    let spans = exporter.get_finished_spans().unwrap();
+    let authorize_span = spans.iter().find(|span| span.name == "authorize").unwrap();
+    let redirect_completion_span = spans
+        .iter()
+        .find(|span| span.name == "redirect_completion_sync")
+        .unwrap();
+
+    assert_ne!(
+        redirect_completion_span.span_context.trace_id(),
+        authorize_span.span_context.trace_id()
+    );
+    assert!(redirect_completion_span
+        .links
+        .iter()
+        .any(|link| link.span_context == authorize_span.span_context));
+}
+
+#[test]
+fn trace_context_is_absent_without_opentelemetry() {
+    let _span = tracing::info_span!("authorize").entered();
+
+    assert!(get_current_trace_context().is_none());
+}
@@ -79,6 +79,12 @@ where
             Err(mpsc::error::TryRecvError::Empty) => {
                 interval.tick().await;
 
+                // A shutdown signal received while waiting for the interval must not be followed
+                // by picking up new tasks
+                if !rx.is_empty() {
+                    continue;
+                }
+
                 // A guard from env to disable the consumer
                 if settings.consumer.disabled {
                     continue;
@@ -392,6 +392,21 @@ pub fn get_pcr_payments_retry_schedule_time(
     }
 }
 
+/// Number of retries left for a revenue recovery payment once `retry_count` retries are scheduled
+pub fn get_pcr_payments_remaining_retries(
+    mapping: &process_data::RevenueRecoveryPaymentProcessTrackerMapping,
+    retry_count: i32,
+) -> i32 {
+    let total_retries: i32 = mapping
+        .default_mapping
+        .frequencies
+        .iter()
+        .map(|&(_, count)| count)
+        .sum();
+
+    total_retries.saturating_sub(retry_count.max(0)).max(0)
+}
+
 pub fn get_subscription_invoice_sync_retry_schedule_time(
     mapping: process_data::SubscriptionInvoiceSyncPTMapping,
     merchant_id: &common_utils::id_type::MerchantId,
@@ -495,4 +510,24 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_get_pcr_payments_remaining_retries() {
+        let mapping = process_data::RevenueRecoveryPaymentProcessTrackerMapping {
+            default_mapping: process_data::RetryMapping {
+                start_after: 60,
+                frequencies: vec![(300, 2), (3600, 3)],
+            },
+        };
+
+        let retry_counts_and_expected_remaining = [(0, 5), (1, 4), (4, 1), (5, 0), (7, 0)];
+
+        for (retry_count, expected_remaining) in retry_counts_and_expected_remaining {
+            assert_eq!(
+                get_pcr_payments_remaining_retries(&mapping, retry_count),
+                expected_remaining,
+                "Remaining retries differ for `retry_count` = {retry_count}"
+            );
+        }
+    }
 }
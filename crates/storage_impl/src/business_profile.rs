@@ -522,6 +522,8 @@ impl ForeignFrom<domain::ProfileUpdate> for ProfileUpdateInternal {
                     external_vault_connector_details,
                     billing_processor_id,
                     surcharge_connector_details,
+                    confirm_time_budget_in_secs,
+                    time_zone,
                     network_tokenization_credentials,
                     payment_method_blocking,
                 } = *update;
@@ -592,6 +594,8 @@ impl ForeignFrom<domain::ProfileUpdate> for ProfileUpdateInternal {
                     external_vault_connector_details,
                     billing_processor_id,
                     surcharge_connector_details,
+                    confirm_time_budget_in_secs,
+                    time_zone,
                     network_tokenization_credentials: network_tokenization_credentials
                         .map(Encryption::from),
                     payment_method_blocking,
@@ -658,6 +662,8 @@ impl ForeignFrom<domain::ProfileUpdate> for ProfileUpdateInternal {
                 external_vault_connector_details: None,
                 billing_processor_id: None,
                 surcharge_connector_details: None,
+                confirm_time_budget_in_secs: None,
+                time_zone: None,
                 is_l2_l3_enabled: None,
                 network_tokenization_credentials: None,
                 payment_method_blocking: None,
@@ -721,6 +727,8 @@ impl ForeignFrom<domain::ProfileUpdate> for ProfileUpdateInternal {
                 external_vault_connector_details: None,
                 billing_processor_id: None,
                 surcharge_connector_details: None,
+                confirm_time_budget_in_secs: None,
+                time_zone: None,
                 is_l2_l3_enabled: None,
                 network_tokenization_credentials: None,
                 payment_method_blocking: None,
@@ -784,6 +792,8 @@ impl ForeignFrom<domain::ProfileUpdate> for ProfileUpdateInternal {
                 external_vault_connector_details: None,
                 billing_processor_id: None,
                 surcharge_connector_details: None,
+                confirm_time_budget_in_secs: None,
+                time_zone: None,
                 is_l2_l3_enabled: None,
                 network_tokenization_credentials: None,
                 payment_method_blocking: None,
@@ -847,6 +857,8 @@ impl ForeignFrom<domain::ProfileUpdate> for ProfileUpdateInternal {
                 external_vault_connector_details: None,
                 billing_processor_id: None,
                 surcharge_connector_details: None,
+                confirm_time_budget_in_secs: None,
+                time_zone: None,
                 is_l2_l3_enabled: None,
                 network_tokenization_credentials: None,
                 payment_method_blocking: None,
@@ -911,6 +923,8 @@ impl ForeignFrom<domain::ProfileUpdate> for ProfileUpdateInternal {
                 external_vault_connector_details: None,
                 billing_processor_id: None,
                 surcharge_connector_details: None,
+                confirm_time_budget_in_secs: None,
+                time_zone: None,
                 is_l2_l3_enabled: None,
                 network_tokenization_credentials: network_tokenization_credentials
                     .map(Encryption::from),
@@ -975,6 +989,8 @@ impl ForeignFrom<domain::ProfileUpdate> for ProfileUpdateInternal {
                 external_vault_connector_details: None,
                 billing_processor_id: None,
                 surcharge_connector_details: None,
+                confirm_time_budget_in_secs: None,
+                time_zone: None,
                 is_l2_l3_enabled: None,
                 network_tokenization_credentials: None,
                 payment_method_blocking: None,
@@ -1039,6 +1055,8 @@ impl ForeignFrom<domain::ProfileUpdate> for ProfileUpdateInternal {
                 external_vault_connector_details: None,
                 billing_processor_id: None,
                 surcharge_connector_details: None,
+                confirm_time_budget_in_secs: None,
+                time_zone: None,
                 is_l2_l3_enabled: None,
                 network_tokenization_credentials: None,
                 payment_method_blocking: None,
@@ -1102,6 +1120,8 @@ impl ForeignFrom<domain::ProfileUpdate> for ProfileUpdateInternal {
                 external_vault_connector_details: None,
                 billing_processor_id: None,
                 surcharge_connector_details: None,
+                confirm_time_budget_in_secs: None,
+                time_zone: None,
                 is_l2_l3_enabled: None,
                 payment_method_blocking: None,
                 default_fallback_routing,
@@ -1191,6 +1211,8 @@ impl Conversion for domain::Profile {
             external_vault_connector_details,
             billing_processor_id: self.billing_processor_id,
             surcharge_connector_details: self.surcharge_connector_details,
+            confirm_time_budget_in_secs: self.confirm_time_budget_in_secs,
+            time_zone: self.time_zone,
             network_tokenization_credentials: self
                 .network_tokenization_credentials
                 .map(|name| name.into()),
@@ -1344,6 +1366,8 @@ impl Conversion for domain::Profile {
             external_vault_details,
             billing_processor_id: item.billing_processor_id,
             surcharge_connector_details: item.surcharge_connector_details,
+            confirm_time_budget_in_secs: item.confirm_time_budget_in_secs,
+            time_zone: item.time_zone,
             network_tokenization_credentials,
             payment_method_blocking: item.payment_method_blocking,
             default_fallback_routing: item.default_fallback_routing,
@@ -1418,6 +1442,8 @@ impl Conversion for domain::Profile {
             external_vault_connector_details,
             billing_processor_id: self.billing_processor_id,
             surcharge_connector_details: self.surcharge_connector_details,
+            confirm_time_budget_in_secs: self.confirm_time_budget_in_secs,
+            time_zone: self.time_zone,
             network_tokenization_credentials: self
                 .network_tokenization_credentials
                 .map(|name| name.into()),
@@ -1474,6 +1500,8 @@ impl ForeignFrom<domain::ProfileUpdate> for ProfileUpdateInternal {
                     split_txns_enabled,
                     billing_processor_id,
                     surcharge_connector_details,
+                    confirm_time_budget_in_secs,
+                    time_zone,
                 } = *update;
                 Self {
                     profile_name,
@@ -1531,6 +1559,8 @@ impl ForeignFrom<domain::ProfileUpdate> for ProfileUpdateInternal {
                     split_txns_enabled,
                     billing_processor_id,
                     surcharge_connector_details,
+                    confirm_time_budget_in_secs,
+                    time_zone,
                 }
             }
             domain::ProfileUpdate::RoutingAlgorithmUpdate {
@@ -1591,6 +1621,8 @@ impl ForeignFrom<domain::ProfileUpdate> for ProfileUpdateInternal {
                 split_txns_enabled: None,
                 billing_processor_id: None,
                 surcharge_connector_details: None,
+                confirm_time_budget_in_secs: None,
+                time_zone: None,
             },
             domain::ProfileUpdate::ExtendedCardInfoUpdate {
                 is_extended_card_info_enabled,
@@ -1649,6 +1681,8 @@ impl ForeignFrom<domain::ProfileUpdate> for ProfileUpdateInternal {
                 split_txns_enabled: None,
                 billing_processor_id: None,
                 surcharge_connector_details: None,
+                confirm_time_budget_in_secs: None,
+                time_zone: None,
             },
             domain::ProfileUpdate::ConnectorAgnosticMitUpdate {
                 is_connector_agnostic_mit_enabled,
@@ -1707,6 +1741,8 @@ impl ForeignFrom<domain::ProfileUpdate> for ProfileUpdateInternal {
                 split_txns_enabled: None,
                 billing_processor_id: None,
                 surcharge_connector_details: None,
+                confirm_time_budget_in_secs: None,
+                time_zone: None,
             },
             domain::ProfileUpdate::DefaultRoutingFallbackUpdate {
                 default_fallback_routing,
@@ -1765,6 +1801,8 @@ impl ForeignFrom<domain::ProfileUpdate> for ProfileUpdateInternal {
                 split_txns_enabled: None,
                 billing_processor_id: None,
                 surcharge_connector_details: None,
+                confirm_time_budget_in_secs: None,
+                time_zone: None,
             },
             domain::ProfileUpdate::NetworkTokenizationUpdate {
                 is_network_tokenization_enabled,
@@ -1823,6 +1861,8 @@ impl ForeignFrom<domain::ProfileUpdate> for ProfileUpdateInternal {
                 split_txns_enabled: None,
                 billing_processor_id: None,
                 surcharge_connector_details: None,
+                confirm_time_budget_in_secs: None,
+                time_zone: None,
             },
             domain::ProfileUpdate::CollectCvvDuringPaymentUpdate {
                 should_collect_cvv_during_payment,
@@ -1881,6 +1921,8 @@ impl ForeignFrom<domain::ProfileUpdate> for ProfileUpdateInternal {
                 split_txns_enabled: None,
                 billing_processor_id: None,
                 surcharge_connector_details: None,
+                confirm_time_budget_in_secs: None,
+                time_zone: None,
             },
             domain::ProfileUpdate::DecisionManagerRecordUpdate {
                 three_ds_decision_manager_config,
@@ -1939,6 +1981,8 @@ impl ForeignFrom<domain::ProfileUpdate> for ProfileUpdateInternal {
                 split_txns_enabled: None,
                 billing_processor_id: None,
                 surcharge_connector_details: None,
+                confirm_time_budget_in_secs: None,
+                time_zone: None,
             },
             domain::ProfileUpdate::CardTestingSecretKeyUpdate {
                 card_testing_secret_key,
@@ -1997,6 +2041,8 @@ impl ForeignFrom<domain::ProfileUpdate> for ProfileUpdateInternal {
                 split_txns_enabled: None,
                 billing_processor_id: None,
                 surcharge_connector_details: None,
+                confirm_time_budget_in_secs: None,
+                time_zone: None,
             },
             domain::ProfileUpdate::RevenueRecoveryAlgorithmUpdate {
                 revenue_recovery_retry_algorithm_type,
@@ -2056,6 +2102,8 @@ impl ForeignFrom<domain::ProfileUpdate> for ProfileUpdateInternal {
                 split_txns_enabled: None,
                 billing_processor_id: None,
                 surcharge_connector_details: None,
+                confirm_time_budget_in_secs: None,
+                time_zone: None,
             },
         }
     }
@@ -2141,6 +2189,8 @@ impl Conversion for domain::Profile {
             always_enable_overcapture: None,
             billing_processor_id: self.billing_processor_id,
             surcharge_connector_details: self.surcharge_connector_details,
+            confirm_time_budget_in_secs: self.confirm_time_budget_in_secs,
+            time_zone: self.time_zone,
             network_tokenization_credentials: None,
             payment_method_blocking: None,
         })
@@ -2245,6 +2295,8 @@ impl Conversion for domain::Profile {
                     split_txns_enabled: item.split_txns_enabled.unwrap_or_default(),
                     billing_processor_id: item.billing_processor_id,
                     surcharge_connector_details: item.surcharge_connector_details,
+                    confirm_time_budget_in_secs: item.confirm_time_budget_in_secs,
+                    time_zone: item.time_zone,
                 }
                 .into(),
             )
@@ -2321,6 +2373,8 @@ impl Conversion for domain::Profile {
             split_txns_enabled: Some(self.split_txns_enabled),
             billing_processor_id: self.billing_processor_id,
             surcharge_connector_details: self.surcharge_connector_details,
+            confirm_time_budget_in_secs: self.confirm_time_budget_in_secs,
+            time_zone: self.time_zone,
             payment_method_blocking: None,
         })
     }
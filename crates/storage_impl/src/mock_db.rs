@@ -27,6 +27,7 @@ use hyperswitch_domain_models::{PayoutAttemptInterface, PayoutsInterface};
 pub struct MockDb {
     pub addresses: Arc<Mutex<Vec<store::Address>>>,
     pub configs: Arc<Mutex<Vec<store::Config>>>,
+    pub connector_customer_mappings: Arc<Mutex<Vec<store::ConnectorCustomerMapping>>>,
     pub merchant_accounts: Arc<Mutex<Vec<store::MerchantAccount>>>,
     pub merchant_connector_accounts: Arc<Mutex<Vec<store::MerchantConnectorAccount>>>,
     pub payment_attempts: Arc<Mutex<Vec<PaymentAttempt>>>,
@@ -86,6 +87,7 @@ impl MockDb {
         Ok(Self {
             addresses: Default::default(),
             configs: Default::default(),
+            connector_customer_mappings: Default::default(),
             merchant_accounts: Default::default(),
             merchant_connector_accounts: Default::default(),
             payment_attempts: Default::default(),
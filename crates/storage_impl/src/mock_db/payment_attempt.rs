@@ -19,14 +19,27 @@ impl PaymentAttemptInterface for MockDb {
     #[cfg(feature = "v1")]
     async fn find_payment_attempt_by_payment_id_processor_merchant_id_attempt_id(
         &self,
-        _payment_id: &common_utils::id_type::PaymentId,
-        _processor_merchant_id: &common_utils::id_type::MerchantId,
-        _attempt_id: &str,
+        payment_id: &common_utils::id_type::PaymentId,
+        processor_merchant_id: &common_utils::id_type::MerchantId,
+        attempt_id: &str,
         _storage_scheme: storage_enums::MerchantStorageScheme,
         _merchant_key_store: &MerchantKeyStore,
     ) -> CustomResult<PaymentAttempt, StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(StorageError::MockDbError)?
+        let payment_attempts = self.payment_attempts.lock().await;
+        let payment_attempt = payment_attempts
+            .iter()
+            .find(|payment_attempt| {
+                payment_attempt.payment_id == *payment_id
+                    && payment_attempt
+                        .processor_merchant_id
+                        .eq(processor_merchant_id)
+                    && payment_attempt.attempt_id == attempt_id
+            })
+            .ok_or(StorageError::ValueNotFound(
+                "PaymentAttempt not found".to_string(),
+            ))?;
+
+        Ok(payment_attempt.clone())
     }
 
     #[cfg(all(feature = "v1", feature = "olap"))]
@@ -124,13 +137,25 @@ impl PaymentAttemptInterface for MockDb {
     #[cfg(feature = "v1")]
     async fn find_payment_attempt_by_processor_merchant_id_connector_txn_id(
         &self,
-        _processor_merchant_id: &common_utils::id_type::MerchantId,
-        _connector_txn_id: &str,
+        processor_merchant_id: &common_utils::id_type::MerchantId,
+        connector_txn_id: &str,
         _storage_scheme: storage_enums::MerchantStorageScheme,
         _merchant_key_store: &MerchantKeyStore,
     ) -> CustomResult<PaymentAttempt, StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(StorageError::MockDbError)?
+        let payment_attempts = self.payment_attempts.lock().await;
+        let payment_attempt = payment_attempts
+            .iter()
+            .find(|payment_attempt| {
+                payment_attempt
+                    .processor_merchant_id
+                    .eq(processor_merchant_id)
+                    && payment_attempt.get_connector_payment_id() == Some(connector_txn_id)
+            })
+            .ok_or(StorageError::ValueNotFound(
+                "PaymentAttempt not found".to_string(),
+            ))?;
+
+        Ok(payment_attempt.clone())
     }
 
     #[cfg(feature = "v2")]
@@ -7,6 +7,8 @@ use common_utils::ext_traits::{AsyncExt, Encode};
 use common_utils::fallback_reverse_lookup_not_found;
 #[cfg(feature = "olap")]
 use diesel::{associations::HasTable, ExpressionMethods, JoinOnDsl, QueryDsl};
+#[cfg(all(feature = "v1", feature = "olap"))]
+use diesel::{BoolExpressionMethods, NullableExpressionMethods, PgJsonbExpressionMethods};
 #[cfg(feature = "v1")]
 use diesel_models::payment_intent::PaymentIntentUpdate as DieselPaymentIntentUpdate;
 #[cfg(feature = "v2")]
@@ -17,6 +19,7 @@ use diesel_models::query::generics::db_metrics;
 use diesel_models::reverse_lookup::ReverseLookupNew;
 #[cfg(all(feature = "v1", feature = "olap"))]
 use diesel_models::schema::{
+    customers::dsl as customers_dsl,
     payment_attempt::{self as payment_attempt_schema, dsl as pa_dsl},
     payment_intent::dsl as pi_dsl,
 };
@@ -61,6 +64,53 @@ use crate::{
 #[cfg(feature = "v2")]
 use crate::{errors, lookup::ReverseLookupInterface};
 
+/// Filters a boxed payment intent `query` by the hashes of the customer email and phone number in
+/// the list `params`. Payments are matched by the hashes stored on the payment, or on its customer.
+///
+/// This is a macro since the payment intent queries which are filtered differ in type.
+#[cfg(all(feature = "v1", feature = "olap"))]
+macro_rules! filter_by_customer_contact_hashes {
+    (
+        @hash $query:expr,
+        $processor_merchant_id:expr,
+        $hash:expr,
+        $payment_column:ident,
+        $customer_column:ident
+    ) => {
+        match $hash {
+            Some(hash) => $query.filter(
+                pi_dsl::$payment_column
+                    .eq(hash.clone())
+                    .or(pi_dsl::customer_id.eq_any(
+                        customers_dsl::customers
+                            .filter(
+                                customers_dsl::merchant_id.eq($processor_merchant_id.to_owned()),
+                            )
+                            .filter(customers_dsl::$customer_column.eq(hash.clone()))
+                            .select(customers_dsl::customer_id.nullable()),
+                    )),
+            ),
+            None => $query,
+        }
+    };
+    ($query:expr, $processor_merchant_id:expr, $params:expr) => {{
+        let query = filter_by_customer_contact_hashes!(
+            @hash $query,
+            $processor_merchant_id,
+            &$params.customer_email_hash,
+            customer_email_hash,
+            email_hash
+        );
+        filter_by_customer_contact_hashes!(
+            @hash query,
+            $processor_merchant_id,
+            &$params.customer_phone_hash,
+            customer_phone_hash,
+            phone_hash
+        )
+    }};
+}
+
 #[async_trait::async_trait]
 impl<T: DatabaseStore> PaymentIntentInterface for KVRouterStore<T> {
     type Error = StorageError;
@@ -949,6 +999,11 @@ impl<T: DatabaseStore> PaymentIntentInterface for crate::RouterStore<T> {
                 if let Some(profile_id) = &params.profile_id {
                     query = query.filter(pi_dsl::profile_id.eq_any(profile_id.clone()));
                 }
+                if let Some(attribution_tags) = &params.attribution_tags {
+                    query =
+                        query.filter(pi_dsl::attribution_tags.contains(attribution_tags.clone()));
+                }
+                query = filter_by_customer_contact_hashes!(query, processor_merchant_id, params);
 
                 query = match (params.starting_at, &params.starting_after_id) {
                     (Some(starting_at), _) => query.filter(pi_dsl::created_at.ge(starting_at)),
@@ -1171,6 +1226,11 @@ impl<T: DatabaseStore> PaymentIntentInterface for crate::RouterStore<T> {
                 if let Some(profile_id) = &params.profile_id {
                     query = query.filter(pi_dsl::profile_id.eq_any(profile_id.clone()));
                 }
+                if let Some(attribution_tags) = &params.attribution_tags {
+                    query =
+                        query.filter(pi_dsl::attribution_tags.contains(attribution_tags.clone()));
+                }
+                query = filter_by_customer_contact_hashes!(query, processor_merchant_id, params);
 
                 query = match (params.starting_at, &params.starting_after_id) {
                     (Some(starting_at), _) => query.filter(pi_dsl::created_at.ge(starting_at)),
@@ -1668,6 +1728,11 @@ impl<T: DatabaseStore> PaymentIntentInterface for crate::RouterStore<T> {
                 if let Some(profile_id) = &params.profile_id {
                     query = query.filter(pi_dsl::profile_id.eq_any(profile_id.clone()));
                 }
+                if let Some(attribution_tags) = &params.attribution_tags {
+                    query =
+                        query.filter(pi_dsl::attribution_tags.contains(attribution_tags.clone()));
+                }
+                query = filter_by_customer_contact_hashes!(query, processor_merchant_id, params);
 
                 query = match params.starting_at {
                     Some(starting_at) => query.filter(pi_dsl::created_at.ge(starting_at)),
@@ -99,4 +99,30 @@ impl hyperswitch_interfaces::api_client::ApiClientWrapper for SubscriptionState
     fn event_handler(&self) -> &dyn hyperswitch_interfaces::events::EventHandlerInterface {
         self.event_handler.as_ref()
     }
+
+    fn get_connector_request_locale(&self, _connector: &str) -> Option<String> {
+        None
+    }
+
+    fn get_connector_request_header_order(&self, _connector: &str) -> Option<Vec<String>> {
+        None
+    }
+
+    fn get_connector_error_message_path(&self, _connector: &str) -> Option<String> {
+        None
+    }
+
+    fn notify_connector_call_outcome(
+        &self,
+        _outcome: hyperswitch_interfaces::events::connector_call_outcome::ConnectorCallOutcome,
+    ) {
+    }
+
+    fn get_connector_error_severity(
+        &self,
+        _connector: &str,
+        category: hyperswitch_interfaces::connector_error_severity::ConnectorErrorCategory,
+    ) -> hyperswitch_interfaces::connector_error_severity::ErrorSeverity {
+        category.get_default_severity()
+    }
 }
@@ -0,0 +1,2 @@
+ALTER TABLE mandate
+DROP COLUMN IF EXISTS customer_acceptance_source;
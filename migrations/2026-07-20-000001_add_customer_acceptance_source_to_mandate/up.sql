@@ -0,0 +1,2 @@
+ALTER TABLE mandate
+ADD COLUMN IF NOT EXISTS customer_acceptance_source VARCHAR(32);
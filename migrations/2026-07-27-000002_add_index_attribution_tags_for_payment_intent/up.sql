@@ -0,0 +1,2 @@
+-- Your SQL goes here
+CREATE INDEX CONCURRENTLY IF NOT EXISTS payment_intent_attribution_tags_index ON payment_intent USING GIN (attribution_tags jsonb_path_ops);
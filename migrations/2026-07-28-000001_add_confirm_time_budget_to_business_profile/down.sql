@@ -0,0 +1,2 @@
+-- Remove confirm_time_budget_in_secs from business_profile table
+ALTER TABLE business_profile DROP COLUMN IF EXISTS confirm_time_budget_in_secs;
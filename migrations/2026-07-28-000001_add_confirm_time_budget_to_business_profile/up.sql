@@ -0,0 +1,2 @@
+-- Add confirm_time_budget_in_secs to business_profile table
+ALTER TABLE business_profile ADD COLUMN IF NOT EXISTS confirm_time_budget_in_secs BIGINT;
@@ -0,0 +1,3 @@
+-- Add dunning event types for failed recurring payments
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'recurring_payment_retry_scheduled';
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'recurring_payment_recovery_exhausted';
@@ -0,0 +1,8 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE customers
+DROP COLUMN IF EXISTS email_hash,
+DROP COLUMN IF EXISTS phone_hash;
+
+ALTER TABLE payment_intent
+DROP COLUMN IF EXISTS customer_email_hash,
+DROP COLUMN IF EXISTS customer_phone_hash;
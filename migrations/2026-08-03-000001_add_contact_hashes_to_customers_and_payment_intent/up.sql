@@ -0,0 +1,8 @@
+-- Your SQL goes here
+ALTER TABLE customers
+ADD COLUMN IF NOT EXISTS email_hash VARCHAR(64),
+ADD COLUMN IF NOT EXISTS phone_hash VARCHAR(64);
+
+ALTER TABLE payment_intent
+ADD COLUMN IF NOT EXISTS customer_email_hash VARCHAR(64),
+ADD COLUMN IF NOT EXISTS customer_phone_hash VARCHAR(64);
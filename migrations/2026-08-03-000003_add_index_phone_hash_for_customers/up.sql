@@ -0,0 +1,2 @@
+-- Your SQL goes here
+CREATE INDEX CONCURRENTLY IF NOT EXISTS customers_merchant_id_phone_hash_index ON customers (merchant_id, phone_hash);
@@ -0,0 +1,2 @@
+-- Your SQL goes here
+CREATE INDEX CONCURRENTLY IF NOT EXISTS payment_intent_merchant_id_customer_email_hash_index ON payment_intent (merchant_id, customer_email_hash);
@@ -0,0 +1,2 @@
+-- Add the event type sent when a merchant reaches the warning threshold of its quota
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'quota_threshold_reached';
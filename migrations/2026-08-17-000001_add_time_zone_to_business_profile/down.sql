@@ -0,0 +1,2 @@
+-- Remove time_zone from business_profile table
+ALTER TABLE business_profile DROP COLUMN IF EXISTS time_zone;
@@ -0,0 +1,2 @@
+-- Add time_zone to business_profile table
+ALTER TABLE business_profile ADD COLUMN IF NOT EXISTS time_zone VARCHAR(64);
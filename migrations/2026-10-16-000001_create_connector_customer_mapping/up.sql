@@ -0,0 +1,9 @@
+-- Connector customer created for a customer, unique for the customer and connector
+CREATE TABLE IF NOT EXISTS connector_customer_mapping (
+    merchant_id VARCHAR(64) NOT NULL,
+    customer_id VARCHAR(64) NOT NULL,
+    connector_label VARCHAR(255) NOT NULL,
+    connector_customer_id VARCHAR(255) NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    PRIMARY KEY (merchant_id, customer_id, connector_label)
+);